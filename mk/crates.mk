@@ -83,12 +83,12 @@ DEPS_rustc_lint := rustc log syntax
 DEPS_rustc := syntax flate arena serialize getopts rbml \
               log graphviz rustc_llvm rustc_back rustc_data_structures
 DEPS_rustc_llvm := native:rustllvm libc std
-DEPS_rustc_back := std syntax rustc_llvm flate log libc
+DEPS_rustc_back := std syntax flate log libc
 DEPS_rustc_data_structures := std log serialize
 DEPS_rustdoc := rustc rustc_driver native:hoedown serialize getopts \
                 test rustc_lint
 DEPS_rustc_bitflags := core
-DEPS_flate := std native:miniz
+DEPS_flate := std
 DEPS_arena := std
 DEPS_graphviz := std
 DEPS_glob := std
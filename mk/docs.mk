@@ -294,8 +294,18 @@ doc/style/index.html: $(RUSTBOOK_EXE) $(wildcard $(S)/src/doc/style/*.md) | doc/
 	$(Q)rm -rf doc/style
 	$(Q)$(RUSTBOOK) build $(S)src/doc/style doc/style
 
-error-index: doc/error-index.html
+error-index: doc/error-index.html doc/error-index.json
 
+# Fails if an error code which is emitted somewhere has no long description
 doc/error-index.html: $(ERR_IDX_GEN_EXE) | doc/
 	$(Q)$(call E, error-index-generator: $@)
-	$(Q)$(ERR_IDX_GEN)
+	$(Q)$(ERR_IDX_GEN) check
+	$(Q)$(ERR_IDX_GEN) html $@
+
+doc/error-index.md: $(ERR_IDX_GEN_EXE) | doc/
+	$(Q)$(call E, error-index-generator: $@)
+	$(Q)$(ERR_IDX_GEN) markdown $@
+
+doc/error-index.json: $(ERR_IDX_GEN_EXE) | doc/
+	$(Q)$(call E, error-index-generator: $@)
+	$(Q)$(ERR_IDX_GEN) json $@
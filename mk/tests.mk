@@ -189,7 +189,8 @@ check-ref: check-sanitycheck cleantestlibs cleantmptestlogs check-stage2-rpass \
 	$(Q)$(CFG_PYTHON) $(S)src/etc/check-summary.py tmp/*.log
 
 # Only check the docs.
-check-docs: check-sanitycheck cleantestlibs cleantmptestlogs check-stage2-docs
+check-docs: check-sanitycheck cleantestlibs cleantmptestlogs check-stage2-docs \
+	check-error-index
 	$(Q)$(CFG_PYTHON) $(S)src/etc/check-summary.py tmp/*.log
 
 # Some less critical tests that are not prone to breakage.
@@ -867,6 +868,16 @@ $(foreach host,$(CFG_HOST), \
    $(foreach docname,$(DOC_NAMES), \
     $(eval $(call DEF_DOC_TEST,$(stage),$(target),$(host),$(docname)))))))
 
+# The code examples of the extended error descriptions, which must compile or
+# fail to compile as annotated.
+.PHONY: check-error-index
+check-error-index: doc/error-index.md \
+		$(TEST_SREQ2_T_$(CFG_BUILD)_H_$(CFG_BUILD)) \
+		$(RUSTDOC_EXE_2_T_$(CFG_BUILD)_H_$(CFG_BUILD))
+	@$(call E, run doc-error-index [$(CFG_BUILD)])
+	$(Q)$(RUSTDOC_2_T_$(CFG_BUILD)_H_$(CFG_BUILD)) --cfg dox --test $< \
+		--test-args "$(TESTARGS)"
+
 # Crates
 
 define DEF_CRATE_DOC_TEST
@@ -11,6 +11,7 @@
 #![crate_type = "bin"]
 
 #![feature(box_syntax)]
+#![feature(duration)]
 #![feature(dynamic_lib)]
 #![feature(libc)]
 #![feature(path_ext)]
@@ -32,6 +33,7 @@ extern crate log;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use getopts::{optopt, optflag, reqopt};
 use common::Config;
 use common::{Pretty, DebugInfoGdb, DebugInfoLldb};
@@ -258,16 +260,24 @@ pub fn run_tests(config: &Config) {
 
 pub fn test_opts(config: &Config) -> test::TestOpts {
     test::TestOpts {
-        filter: match config.filter {
-            None => None,
-            Some(ref filter) => Some(filter.clone()),
+        list: false,
+        filters: match config.filter {
+            None => Vec::new(),
+            Some(ref filter) => vec![filter.clone()],
         },
+        filter_exact: false,
+        skip: Vec::new(),
+        shuffle: None,
+        shard: None,
         run_ignored: config.run_ignored,
         logfile: config.logfile.clone(),
         run_tests: true,
         bench_benchmarks: true,
         nocapture: env::var("RUST_TEST_NOCAPTURE").is_ok(),
         color: test::AutoColor,
+        format: test::OutputFormat::Pretty,
+        test_timeout: None,
+        warn_timeout: Duration::from_secs(60),
     }
 }
 
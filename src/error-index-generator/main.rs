@@ -16,16 +16,42 @@ extern crate serialize as rustc_serialize;
 
 use std::collections::BTreeMap;
 use std::fs::{read_dir, File};
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::error::Error;
+use std::process;
 
 use syntax::diagnostics::metadata::{get_metadata_dir, ErrorMetadataMap};
 
 use rustdoc::html::markdown::Markdown;
 use rustc_serialize::json;
 
+enum OutputFormat {
+    HTML,
+    Markdown,
+    JSON,
+}
+
+impl OutputFormat {
+    fn from(format: &str) -> Option<OutputFormat> {
+        match format {
+            "html" => Some(OutputFormat::HTML),
+            "markdown" => Some(OutputFormat::Markdown),
+            "json" => Some(OutputFormat::JSON),
+            _ => None,
+        }
+    }
+
+    fn default_output(&self) -> &'static str {
+        match *self {
+            OutputFormat::HTML => "doc/error-index.html",
+            OutputFormat::Markdown => "doc/error-index.md",
+            OutputFormat::JSON => "doc/error-index.json",
+        }
+    }
+}
+
 /// Load all the metadata files from `metadata_dir` into an in-memory map.
 fn load_all_errors(metadata_dir: &Path) -> Result<ErrorMetadataMap, Box<Error>> {
     let mut all_errors = BTreeMap::new();
@@ -106,16 +132,94 @@ r##"<!DOCTYPE html>
     Ok(())
 }
 
-fn main_with_result() -> Result<(), Box<Error>> {
+/// Output a Markdown document for the errors in `err_map` to `output_path`.
+///
+/// Each error gets a level 1 header, so that when the document is run through
+/// `rustdoc --test` the code examples are named after the error they belong
+/// to.
+fn render_markdown(err_map: &ErrorMetadataMap, output_path: &Path) -> Result<(), Box<Error>> {
+    let mut output_file = try!(File::create(output_path));
+
+    try!(write!(&mut output_file, "% Rust Compiler Error Index\n"));
+
+    for (err_code, info) in err_map {
+        try!(write!(&mut output_file, "\n# {}\n\n", err_code));
+        match info.description {
+            Some(ref desc) => try!(write!(&mut output_file, "{}\n", desc.trim())),
+            None => try!(write!(&mut output_file, "No description.\n")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Output the errors in `err_map` as JSON to `output_path`.
+fn render_json(err_map: &ErrorMetadataMap, output_path: &Path) -> Result<(), Box<Error>> {
+    let mut output_file = try!(File::create(output_path));
+    try!(write!(&mut output_file, "{}", json::as_pretty_json(err_map)));
+    Ok(())
+}
+
+/// Check that every error code which is emitted somewhere has a long
+/// description, returning a message for each problem found.
+fn check_descriptions(err_map: &ErrorMetadataMap) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for (err_code, info) in err_map {
+        if let (&None, &Some(ref loc)) = (&info.description, &info.use_site) {
+            problems.push(format!("{}:{}: error code {} has no long \
+                                   description",
+                                  loc.filename, loc.line, err_code));
+        }
+    }
+
+    problems
+}
+
+fn main_with_result(args: &[String]) -> Result<(), Box<Error>> {
     let build_arch = try!(env::var("CFG_BUILD"));
     let metadata_dir = get_metadata_dir(&build_arch);
     let err_map = try!(load_all_errors(&metadata_dir));
-    try!(render_error_page(&err_map, Path::new("doc/error-index.html")));
+
+    if args.get(0).map(|s| &s[..]) == Some("check") {
+        let problems = check_descriptions(&err_map);
+        if !problems.is_empty() {
+            let mut stderr = io::stderr();
+            for problem in &problems {
+                try!(writeln!(&mut stderr, "{}", problem));
+            }
+            process::exit(1);
+        }
+        return Ok(())
+    }
+
+    let format = match args.get(0) {
+        Some(format) => match OutputFormat::from(format) {
+            Some(format) => format,
+            None => {
+                try!(writeln!(&mut io::stderr(),
+                              "usage: error-index-generator [html|markdown|json] \
+                               [output-path]\n       \
+                               error-index-generator check"));
+                process::exit(1);
+            }
+        },
+        None => OutputFormat::HTML,
+    };
+    let output_path = args.get(1).map(PathBuf::from)
+                          .unwrap_or(PathBuf::from(format.default_output()));
+
+    match format {
+        OutputFormat::HTML => try!(render_error_page(&err_map, &output_path)),
+        OutputFormat::Markdown => try!(render_markdown(&err_map, &output_path)),
+        OutputFormat::JSON => try!(render_json(&err_map, &output_path)),
+    }
     Ok(())
 }
 
 fn main() {
-    if let Err(e) = main_with_result() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if let Err(e) = main_with_result(&args) {
         panic!("{}", e.description());
     }
 }
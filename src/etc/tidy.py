@@ -23,7 +23,7 @@ tab_flag = "ignore-tidy-tab"
 linelength_flag = "ignore-tidy-linelength"
 
 interesting_files = ['.rs', '.py', '.js', '.sh', '.c', '.h']
-uninteresting_files = ['miniz.c', 'jquery', 'rust_android_dummy']
+uninteresting_files = ['jquery', 'rust_android_dummy']
 
 
 def report_error_name_no(name, no, s):
@@ -15,7 +15,8 @@
 //! types.
 //!
 //! This module defines atomic versions of a select number of primitive
-//! types, including `AtomicBool`, `AtomicIsize`, and `AtomicUsize`.
+//! types, including `AtomicBool`, `AtomicIsize`, and `AtomicUsize`, as well
+//! as fixed-size integers such as `AtomicU8` and `AtomicI64`.
 //! Atomic types present operations that, when used correctly, synchronize
 //! updates between threads.
 //!
@@ -73,11 +74,17 @@
 use self::Ordering::*;
 
 use marker::Sync;
+#[cfg(not(stage0))]
+use marker::Copy;
+#[cfg(not(stage0))]
+use cmp::PartialEq;
 
 use intrinsics;
 use cell::UnsafeCell;
 
 use default::Default;
+#[cfg(not(stage0))]
+use result::Result::{self, Ok, Err};
 
 /// A boolean type which can be safely shared between threads.
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -302,6 +309,88 @@ impl AtomicBool {
         unsafe { atomic_compare_and_swap(self.v.get(), current, new, order) > 0 }
     }
 
+    /// Stores a value into the `bool` if the current value is the same as the `current` value.
+    ///
+    /// The return value is a result indicating whether the new value was written and containing
+    /// the previous value. On success this value is guaranteed to be equal to `current`.
+    ///
+    /// `compare_exchange` takes two `Ordering` arguments to describe the memory ordering of this
+    /// operation. The first describes the required ordering if the operation succeeds while the
+    /// second describes the required ordering when the operation fails. The failure ordering can't
+    /// be `Release` or `AcqRel` and must be equivalent or weaker than the success ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// let some_bool = AtomicBool::new(true);
+    ///
+    /// assert_eq!(some_bool.compare_exchange(true, false, Ordering::Acquire, Ordering::Relaxed),
+    ///            Ok(true));
+    /// assert_eq!(some_bool.load(Ordering::Relaxed), false);
+    ///
+    /// assert_eq!(some_bool.compare_exchange(true, true, Ordering::SeqCst, Ordering::Acquire),
+    ///            Err(false));
+    /// assert_eq!(some_bool.load(Ordering::Relaxed), false);
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange(&self, current: bool, new: bool,
+                            success: Ordering, failure: Ordering) -> Result<bool, bool> {
+        let current = if current { UINT_TRUE } else { 0 };
+        let new = if new { UINT_TRUE } else { 0 };
+
+        match unsafe { atomic_compare_exchange(self.v.get(), current, new, success, failure) } {
+            Ok(x) => Ok(x > 0),
+            Err(x) => Err(x > 0),
+        }
+    }
+
+    /// Stores a value into the `bool` if the current value is the same as the `current` value.
+    ///
+    /// Unlike `compare_exchange`, this function is allowed to spuriously fail even when the
+    /// comparison succeeds, which can result in more efficient code on some platforms. The
+    /// return value is a result indicating whether the new value was written and containing the
+    /// previous value.
+    ///
+    /// `compare_exchange_weak` takes two `Ordering` arguments to describe the memory ordering of
+    /// this operation, with the same restrictions as for `compare_exchange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// let val = AtomicBool::new(false);
+    ///
+    /// let new = true;
+    /// let mut old = val.load(Ordering::Relaxed);
+    /// loop {
+    ///     match val.compare_exchange_weak(old, new, Ordering::SeqCst, Ordering::Relaxed) {
+    ///         Ok(_) => break,
+    ///         Err(x) => old = x,
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange_weak(&self, current: bool, new: bool,
+                                 success: Ordering, failure: Ordering) -> Result<bool, bool> {
+        let current = if current { UINT_TRUE } else { 0 };
+        let new = if new { UINT_TRUE } else { 0 };
+
+        match unsafe { atomic_compare_exchange_weak(self.v.get(), current, new,
+                                                    success, failure) } {
+            Ok(x) => Ok(x > 0),
+            Err(x) => Err(x > 0),
+        }
+    }
+
     /// Logical "and" with a boolean value.
     ///
     /// Performs a logical "and" operation on the current value and the argument `val`, and sets
@@ -542,6 +631,75 @@ impl AtomicIsize {
         unsafe { atomic_compare_and_swap(self.v.get(), current, new, order) }
     }
 
+    /// Stores a value into the `isize` if the current value is the same as the `current` value.
+    ///
+    /// The return value is a result indicating whether the new value was written and containing
+    /// the previous value. On success this value is guaranteed to be equal to `current`.
+    ///
+    /// `compare_exchange` takes two `Ordering` arguments to describe the memory ordering of this
+    /// operation. The first describes the required ordering if the operation succeeds while the
+    /// second describes the required ordering when the operation fails. The failure ordering can't
+    /// be `Release` or `AcqRel` and must be equivalent or weaker than the success ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicIsize, Ordering};
+    ///
+    /// let some_isize = AtomicIsize::new(5);
+    ///
+    /// assert_eq!(some_isize.compare_exchange(5, 10, Ordering::Acquire, Ordering::Relaxed),
+    ///            Ok(5));
+    /// assert_eq!(some_isize.load(Ordering::Relaxed), 10);
+    ///
+    /// assert_eq!(some_isize.compare_exchange(6, 12, Ordering::SeqCst, Ordering::Acquire),
+    ///            Err(10));
+    /// assert_eq!(some_isize.load(Ordering::Relaxed), 10);
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange(&self, current: isize, new: isize,
+                            success: Ordering, failure: Ordering) -> Result<isize, isize> {
+        unsafe { atomic_compare_exchange(self.v.get(), current, new, success, failure) }
+    }
+
+    /// Stores a value into the `isize` if the current value is the same as the `current` value.
+    ///
+    /// Unlike `compare_exchange`, this function is allowed to spuriously fail even when the
+    /// comparison succeeds, which can result in more efficient code on some platforms. The
+    /// return value is a result indicating whether the new value was written and containing the
+    /// previous value.
+    ///
+    /// `compare_exchange_weak` takes two `Ordering` arguments to describe the memory ordering of
+    /// this operation, with the same restrictions as for `compare_exchange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicIsize, Ordering};
+    ///
+    /// let val = AtomicIsize::new(4);
+    ///
+    /// let mut old = val.load(Ordering::Relaxed);
+    /// loop {
+    ///     let new = old * 2;
+    ///     match val.compare_exchange_weak(old, new, Ordering::SeqCst, Ordering::Relaxed) {
+    ///         Ok(_) => break,
+    ///         Err(x) => old = x,
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange_weak(&self, current: isize, new: isize,
+                                 success: Ordering, failure: Ordering) -> Result<isize, isize> {
+        unsafe { atomic_compare_exchange_weak(self.v.get(), current, new, success, failure) }
+    }
+
     /// Add an isize to the current value, returning the previous value.
     ///
     /// # Examples
@@ -592,6 +750,24 @@ impl AtomicIsize {
         unsafe { atomic_and(self.v.get(), val, order) }
     }
 
+    /// Bitwise nand with the current isize, returning the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(atomic_nand)]
+    /// use std::sync::atomic::{AtomicIsize, Ordering};
+    ///
+    /// let foo = AtomicIsize::new(0xf731);
+    /// assert_eq!(foo.fetch_nand(0x137f, Ordering::SeqCst), 0xf731);
+    /// assert_eq!(foo.load(Ordering::SeqCst), !(0xf731 & 0x137f));
+    /// ```
+    #[inline]
+    #[unstable(feature = "atomic_nand", reason = "recently added")]
+    pub fn fetch_nand(&self, val: isize, order: Ordering) -> isize {
+        unsafe { atomic_nand(self.v.get(), val, order) }
+    }
+
     /// Bitwise or with the current isize, returning the previous value.
     ///
     /// # Examples
@@ -736,6 +912,75 @@ impl AtomicUsize {
         unsafe { atomic_compare_and_swap(self.v.get(), current, new, order) }
     }
 
+    /// Stores a value into the `usize` if the current value is the same as the `current` value.
+    ///
+    /// The return value is a result indicating whether the new value was written and containing
+    /// the previous value. On success this value is guaranteed to be equal to `current`.
+    ///
+    /// `compare_exchange` takes two `Ordering` arguments to describe the memory ordering of this
+    /// operation. The first describes the required ordering if the operation succeeds while the
+    /// second describes the required ordering when the operation fails. The failure ordering can't
+    /// be `Release` or `AcqRel` and must be equivalent or weaker than the success ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let some_usize = AtomicUsize::new(5);
+    ///
+    /// assert_eq!(some_usize.compare_exchange(5, 10, Ordering::Acquire, Ordering::Relaxed),
+    ///            Ok(5));
+    /// assert_eq!(some_usize.load(Ordering::Relaxed), 10);
+    ///
+    /// assert_eq!(some_usize.compare_exchange(6, 12, Ordering::SeqCst, Ordering::Acquire),
+    ///            Err(10));
+    /// assert_eq!(some_usize.load(Ordering::Relaxed), 10);
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange(&self, current: usize, new: usize,
+                            success: Ordering, failure: Ordering) -> Result<usize, usize> {
+        unsafe { atomic_compare_exchange(self.v.get(), current, new, success, failure) }
+    }
+
+    /// Stores a value into the `usize` if the current value is the same as the `current` value.
+    ///
+    /// Unlike `compare_exchange`, this function is allowed to spuriously fail even when the
+    /// comparison succeeds, which can result in more efficient code on some platforms. The
+    /// return value is a result indicating whether the new value was written and containing the
+    /// previous value.
+    ///
+    /// `compare_exchange_weak` takes two `Ordering` arguments to describe the memory ordering of
+    /// this operation, with the same restrictions as for `compare_exchange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let val = AtomicUsize::new(4);
+    ///
+    /// let mut old = val.load(Ordering::Relaxed);
+    /// loop {
+    ///     let new = old * 2;
+    ///     match val.compare_exchange_weak(old, new, Ordering::SeqCst, Ordering::Relaxed) {
+    ///         Ok(_) => break,
+    ///         Err(x) => old = x,
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange_weak(&self, current: usize, new: usize,
+                                 success: Ordering, failure: Ordering) -> Result<usize, usize> {
+        unsafe { atomic_compare_exchange_weak(self.v.get(), current, new, success, failure) }
+    }
+
     /// Add to the current usize, returning the previous value.
     ///
     /// # Examples
@@ -786,6 +1031,24 @@ impl AtomicUsize {
         unsafe { atomic_and(self.v.get(), val, order) }
     }
 
+    /// Bitwise nand with the current usize, returning the previous value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(atomic_nand)]
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let foo = AtomicUsize::new(0xf731);
+    /// assert_eq!(foo.fetch_nand(0x137f, Ordering::SeqCst), 0xf731);
+    /// assert_eq!(foo.load(Ordering::SeqCst), !(0xf731 & 0x137f));
+    /// ```
+    #[inline]
+    #[unstable(feature = "atomic_nand", reason = "recently added")]
+    pub fn fetch_nand(&self, val: usize, order: Ordering) -> usize {
+        unsafe { atomic_nand(self.v.get(), val, order) }
+    }
+
     /// Bitwise or with the current usize, returning the previous value.
     ///
     /// # Examples
@@ -939,6 +1202,281 @@ impl<T> AtomicPtr<T> {
                                     new as usize, order) as *mut T
         }
     }
+
+    /// Stores a value into the pointer if the current value is the same as the `current` value.
+    ///
+    /// The return value is a result indicating whether the new value was written and containing
+    /// the previous value. On success this value is guaranteed to be equal to `current`.
+    ///
+    /// `compare_exchange` takes two `Ordering` arguments to describe the memory ordering of this
+    /// operation. The first describes the required ordering if the operation succeeds while the
+    /// second describes the required ordering when the operation fails. The failure ordering can't
+    /// be `Release` or `AcqRel` and must be equivalent or weaker than the success ordering.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let ptr = &mut 5;
+    /// let some_ptr  = AtomicPtr::new(ptr);
+    ///
+    /// let other_ptr   = &mut 10;
+    /// let another_ptr = &mut 10;
+    ///
+    /// let value = some_ptr.compare_exchange(other_ptr, another_ptr,
+    ///                                       Ordering::SeqCst, Ordering::Relaxed);
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange(&self, current: *mut T, new: *mut T,
+                            success: Ordering, failure: Ordering) -> Result<*mut T, *mut T> {
+        match unsafe { atomic_compare_exchange(self.p.get() as *mut usize, current as usize,
+                                               new as usize, success, failure) } {
+            Ok(x) => Ok(x as *mut T),
+            Err(x) => Err(x as *mut T),
+        }
+    }
+
+    /// Stores a value into the pointer if the current value is the same as the `current` value.
+    ///
+    /// Unlike `compare_exchange`, this function is allowed to spuriously fail even when the
+    /// comparison succeeds, which can result in more efficient code on some platforms. The
+    /// return value is a result indicating whether the new value was written and containing the
+    /// previous value.
+    ///
+    /// `compare_exchange_weak` takes two `Ordering` arguments to describe the memory ordering of
+    /// this operation, with the same restrictions as for `compare_exchange`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(extended_compare_and_swap)]
+    /// use std::sync::atomic::{AtomicPtr, Ordering};
+    ///
+    /// let some_ptr = AtomicPtr::new(&mut 5);
+    ///
+    /// let new = &mut 10;
+    /// let mut old = some_ptr.load(Ordering::Relaxed);
+    /// loop {
+    ///     match some_ptr.compare_exchange_weak(old, new, Ordering::SeqCst, Ordering::Relaxed) {
+    ///         Ok(_) => break,
+    ///         Err(x) => old = x,
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[cfg(not(stage0))]
+    #[unstable(feature = "extended_compare_and_swap", reason = "recently added")]
+    pub fn compare_exchange_weak(&self, current: *mut T, new: *mut T,
+                                 success: Ordering, failure: Ordering) -> Result<*mut T, *mut T> {
+        match unsafe { atomic_compare_exchange_weak(self.p.get() as *mut usize, current as usize,
+                                                    new as usize, success, failure) } {
+            Ok(x) => Ok(x as *mut T),
+            Err(x) => Err(x as *mut T),
+        }
+    }
+}
+
+macro_rules! atomic_int {
+    ($(#[$attr:meta])* struct $atomic_type:ident($int_type:ident);
+     $(#[$init_attr:meta])* const $atomic_init:ident;) => {
+        $(#[$attr])*
+        #[unstable(feature = "integer_atomics", reason = "recently added")]
+        pub struct $atomic_type {
+            v: UnsafeCell<$int_type>,
+        }
+
+        $(#[$init_attr])*
+        #[unstable(feature = "integer_atomics", reason = "recently added")]
+        pub const $atomic_init: $atomic_type = $atomic_type::new(0);
+
+        impl Default for $atomic_type {
+            fn default() -> Self {
+                Self::new(Default::default())
+            }
+        }
+
+        unsafe impl Sync for $atomic_type {}
+
+        impl $atomic_type {
+            /// Creates a new atomic integer.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub const fn new(v: $int_type) -> Self {
+                $atomic_type {v: UnsafeCell::new(v)}
+            }
+
+            /// Loads a value from the atomic integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is `Release` or `AcqRel`.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn load(&self, order: Ordering) -> $int_type {
+                unsafe { atomic_load(self.v.get(), order) }
+            }
+
+            /// Stores a value into the atomic integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is `Acquire` or `AcqRel`.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn store(&self, val: $int_type, order: Ordering) {
+                unsafe { atomic_store(self.v.get(), val, order); }
+            }
+
+            /// Stores a value into the atomic integer, returning the old value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn swap(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_swap(self.v.get(), val, order) }
+            }
+
+            /// Stores a value into the atomic integer if the current value is the same as the
+            /// `current` value.
+            ///
+            /// The return value is always the previous value. If it is equal to `current`, then
+            /// the value was updated.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn compare_and_swap(&self, current: $int_type, new: $int_type,
+                                    order: Ordering) -> $int_type {
+                unsafe { atomic_compare_and_swap(self.v.get(), current, new, order) }
+            }
+
+            /// Stores a value into the atomic integer if the current value is the same as the
+            /// `current` value.
+            ///
+            /// The return value is a result indicating whether the new value was written and
+            /// containing the previous value. On success this value is guaranteed to be equal
+            /// to `current`.
+            ///
+            /// The failure ordering can't be `Release` or `AcqRel` and must be equivalent or
+            /// weaker than the success ordering.
+            #[inline]
+            #[cfg(not(stage0))]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn compare_exchange(&self, current: $int_type, new: $int_type,
+                                    success: Ordering,
+                                    failure: Ordering) -> Result<$int_type, $int_type> {
+                unsafe { atomic_compare_exchange(self.v.get(), current, new, success, failure) }
+            }
+
+            /// Stores a value into the atomic integer if the current value is the same as the
+            /// `current` value.
+            ///
+            /// Unlike `compare_exchange`, this function is allowed to spuriously fail even when
+            /// the comparison succeeds, which can result in more efficient code on some
+            /// platforms.
+            #[inline]
+            #[cfg(not(stage0))]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn compare_exchange_weak(&self, current: $int_type, new: $int_type,
+                                         success: Ordering,
+                                         failure: Ordering) -> Result<$int_type, $int_type> {
+                unsafe {
+                    atomic_compare_exchange_weak(self.v.get(), current, new, success, failure)
+                }
+            }
+
+            /// Add to the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_add(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_add(self.v.get(), val, order) }
+            }
+
+            /// Subtract from the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_sub(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_sub(self.v.get(), val, order) }
+            }
+
+            /// Bitwise and with the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_and(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_and(self.v.get(), val, order) }
+            }
+
+            /// Bitwise nand with the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_nand(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_nand(self.v.get(), val, order) }
+            }
+
+            /// Bitwise or with the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_or(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_or(self.v.get(), val, order) }
+            }
+
+            /// Bitwise xor with the current value, returning the previous value.
+            #[inline]
+            #[unstable(feature = "integer_atomics", reason = "recently added")]
+            pub fn fetch_xor(&self, val: $int_type, order: Ordering) -> $int_type {
+                unsafe { atomic_xor(self.v.get(), val, order) }
+            }
+        }
+    }
+}
+
+atomic_int! {
+    #[doc="A 8-bit signed integer type which can be safely shared between threads."]
+    struct AtomicI8(i8);
+    #[doc="An `AtomicI8` initialized to `0`."]
+    const ATOMIC_I8_INIT;
+}
+atomic_int! {
+    #[doc="A 8-bit unsigned integer type which can be safely shared between threads."]
+    struct AtomicU8(u8);
+    #[doc="An `AtomicU8` initialized to `0`."]
+    const ATOMIC_U8_INIT;
+}
+atomic_int! {
+    #[doc="A 16-bit signed integer type which can be safely shared between threads."]
+    struct AtomicI16(i16);
+    #[doc="An `AtomicI16` initialized to `0`."]
+    const ATOMIC_I16_INIT;
+}
+atomic_int! {
+    #[doc="A 16-bit unsigned integer type which can be safely shared between threads."]
+    struct AtomicU16(u16);
+    #[doc="An `AtomicU16` initialized to `0`."]
+    const ATOMIC_U16_INIT;
+}
+atomic_int! {
+    #[doc="A 32-bit signed integer type which can be safely shared between threads."]
+    struct AtomicI32(i32);
+    #[doc="An `AtomicI32` initialized to `0`."]
+    const ATOMIC_I32_INIT;
+}
+atomic_int! {
+    #[doc="A 32-bit unsigned integer type which can be safely shared between threads."]
+    struct AtomicU32(u32);
+    #[doc="An `AtomicU32` initialized to `0`."]
+    const ATOMIC_U32_INIT;
+}
+atomic_int! {
+    #[doc="A 64-bit signed integer type which can be safely shared between threads."]
+    struct AtomicI64(i64);
+    #[doc="An `AtomicI64` initialized to `0`."]
+    const ATOMIC_I64_INIT;
+}
+atomic_int! {
+    #[doc="A 64-bit unsigned integer type which can be safely shared between threads."]
+    struct AtomicU64(u64);
+    #[doc="An `AtomicU64` initialized to `0`."]
+    const ATOMIC_U64_INIT;
 }
 
 #[inline]
@@ -1014,6 +1552,56 @@ unsafe fn atomic_compare_and_swap<T>(dst: *mut T, old:T, new:T, order: Ordering)
     }
 }
 
+#[inline]
+#[cfg(not(stage0))]
+unsafe fn atomic_compare_exchange<T>(dst: *mut T,
+                                     old: T,
+                                     new: T,
+                                     success: Ordering,
+                                     failure: Ordering) -> Result<T, T>
+    where T: Copy + PartialEq
+{
+    let val = match (success, failure) {
+        (Acquire, Acquire) => intrinsics::atomic_cxchg_acq(dst, old, new),
+        (Release, Relaxed) => intrinsics::atomic_cxchg_rel(dst, old, new),
+        (AcqRel,  Acquire) => intrinsics::atomic_cxchg_acqrel(dst, old, new),
+        (Relaxed, Relaxed) => intrinsics::atomic_cxchg_relaxed(dst, old, new),
+        (SeqCst,  SeqCst)  => intrinsics::atomic_cxchg(dst, old, new),
+        (Acquire, Relaxed) => intrinsics::atomic_cxchg_acq_failrelaxed(dst, old, new),
+        (AcqRel,  Relaxed) => intrinsics::atomic_cxchg_acqrel_failrelaxed(dst, old, new),
+        (SeqCst,  Relaxed) => intrinsics::atomic_cxchg_failrelaxed(dst, old, new),
+        (SeqCst,  Acquire) => intrinsics::atomic_cxchg_failacq(dst, old, new),
+        (_, Release) => panic!("there is no such thing as a release failure ordering"),
+        (_, AcqRel) => panic!("there is no such thing as an acquire/release failure ordering"),
+        _ => panic!("a failure ordering can't be stronger than a success ordering"),
+    };
+    if val == old { Ok(val) } else { Err(val) }
+}
+
+#[inline]
+#[cfg(not(stage0))]
+unsafe fn atomic_compare_exchange_weak<T>(dst: *mut T,
+                                          old: T,
+                                          new: T,
+                                          success: Ordering,
+                                          failure: Ordering) -> Result<T, T> {
+    let (val, ok) = match (success, failure) {
+        (Acquire, Acquire) => intrinsics::atomic_cxchgweak_acq(dst, old, new),
+        (Release, Relaxed) => intrinsics::atomic_cxchgweak_rel(dst, old, new),
+        (AcqRel,  Acquire) => intrinsics::atomic_cxchgweak_acqrel(dst, old, new),
+        (Relaxed, Relaxed) => intrinsics::atomic_cxchgweak_relaxed(dst, old, new),
+        (SeqCst,  SeqCst)  => intrinsics::atomic_cxchgweak(dst, old, new),
+        (Acquire, Relaxed) => intrinsics::atomic_cxchgweak_acq_failrelaxed(dst, old, new),
+        (AcqRel,  Relaxed) => intrinsics::atomic_cxchgweak_acqrel_failrelaxed(dst, old, new),
+        (SeqCst,  Relaxed) => intrinsics::atomic_cxchgweak_failrelaxed(dst, old, new),
+        (SeqCst,  Acquire) => intrinsics::atomic_cxchgweak_failacq(dst, old, new),
+        (_, Release) => panic!("there is no such thing as a release failure ordering"),
+        (_, AcqRel) => panic!("there is no such thing as an acquire/release failure ordering"),
+        _ => panic!("a failure ordering can't be stronger than a success ordering"),
+    };
+    if ok { Ok(val) } else { Err(val) }
+}
+
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe fn atomic_and<T>(dst: *mut T, val: T, order: Ordering) -> T {
@@ -1098,3 +1686,29 @@ pub fn fence(order: Ordering) {
         }
     }
 }
+
+/// A compiler memory fence.
+///
+/// Like `fence`, but only prevents the compiler from reordering memory
+/// operations across it; no fence instruction is emitted. This is enough to
+/// synchronize with code running on the same thread, such as a signal
+/// handler, but not with other threads.
+///
+/// Accepts `Acquire`, `Release`, `AcqRel` and `SeqCst` orderings.
+///
+/// # Panics
+///
+/// Panics if `order` is `Relaxed`.
+#[inline]
+#[unstable(feature = "compiler_fences", reason = "recently added")]
+pub fn compiler_fence(order: Ordering) {
+    unsafe {
+        match order {
+            Acquire => intrinsics::atomic_singlethreadfence_acq(),
+            Release => intrinsics::atomic_singlethreadfence_rel(),
+            AcqRel  => intrinsics::atomic_singlethreadfence_acqrel(),
+            SeqCst  => intrinsics::atomic_singlethreadfence(),
+            Relaxed => panic!("there is no such thing as a relaxed compiler fence")
+        }
+    }
+}
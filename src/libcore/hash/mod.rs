@@ -131,6 +131,13 @@ pub trait Hasher {
     fn write_u64(&mut self, i: u64) {
         self.write(&unsafe { mem::transmute::<_, [u8; 8]>(i) })
     }
+    /// Write a single `u128` into this hasher.
+    #[cfg(not(stage0))]
+    #[inline]
+    #[unstable(feature = "i128", reason = "recently added")]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&unsafe { mem::transmute::<_, [u8; 16]>(i) })
+    }
     /// Write a single `usize` into this hasher.
     #[inline]
     #[unstable(feature = "hasher_write", reason = "module was recently redesigned")]
@@ -158,6 +165,11 @@ pub trait Hasher {
     #[inline]
     #[unstable(feature = "hasher_write", reason = "module was recently redesigned")]
     fn write_i64(&mut self, i: i64) { self.write_u64(i as u64) }
+    /// Write a single `i128` into this hasher.
+    #[cfg(not(stage0))]
+    #[inline]
+    #[unstable(feature = "i128", reason = "recently added")]
+    fn write_i128(&mut self, i: i128) { self.write_u128(i as u128) }
     /// Write a single `isize` into this hasher.
     #[inline]
     #[unstable(feature = "hasher_write", reason = "module was recently redesigned")]
@@ -216,6 +228,11 @@ mod impls {
         (i64, write_i64),
         (isize, write_isize),
     }
+    #[cfg(not(stage0))]
+    impl_write! {
+        (u128, write_u128),
+        (i128, write_i128),
+    }
 
     #[stable(feature = "rust1", since = "1.0.0")]
     impl Hash for bool {
@@ -0,0 +1,77 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Division of 128-bit integers on targets compiler-rt doesn't cover.
+//!
+//! LLVM lowers `/` and `%` on `i128` and `u128` to calls to `__divti3`,
+//! `__udivti3`, `__modti3` and `__umodti3`, but compiler-rt only provides
+//! these where `long` is 64 bits wide (`__LP64__`), which leaves out 32-bit
+//! targets and 64-bit Windows. On those they are defined here, so that
+//! anything linking against libcore (libcore itself included, as formatting
+//! and parsing divide) can use them.
+
+// The functions are only public so that they are exported from libcore.
+#![doc(hidden)]
+#![unstable(feature = "div128",
+            reason = "compiler support routines, not meant to be called directly")]
+
+/// Returns the quotient and remainder of `n / d`. `d` must not be zero.
+fn udivmod(n: u128, d: u128) -> (u128, u128) {
+    if d > n {
+        return (0, n);
+    }
+    // Line the divisor up with the most significant bit of the dividend, and
+    // subtract it out one bit position at a time. None of this may divide
+    // 128-bit values, or it would call itself.
+    let shift = d.leading_zeros() - n.leading_zeros();
+    let mut d = d << shift;
+    let mut r = n;
+    let mut q = 0;
+    for _ in 0..shift + 1 {
+        q <<= 1;
+        if r >= d {
+            r -= d;
+            q |= 1;
+        }
+        d >>= 1;
+    }
+    (q, r)
+}
+
+/// The magnitude of `x`, which is `2^127` for `i128::MIN`.
+fn unsigned_abs(x: i128) -> u128 {
+    if x < 0 { (x as u128).wrapping_neg() } else { x as u128 }
+}
+
+/// Unsigned 128-bit division.
+#[no_mangle]
+pub extern "C" fn __udivti3(n: u128, d: u128) -> u128 {
+    udivmod(n, d).0
+}
+
+/// Unsigned 128-bit remainder.
+#[no_mangle]
+pub extern "C" fn __umodti3(n: u128, d: u128) -> u128 {
+    udivmod(n, d).1
+}
+
+/// Signed 128-bit division, rounding towards zero.
+#[no_mangle]
+pub extern "C" fn __divti3(n: i128, d: i128) -> i128 {
+    let q = udivmod(unsigned_abs(n), unsigned_abs(d)).0 as i128;
+    if (n < 0) != (d < 0) { q.wrapping_neg() } else { q }
+}
+
+/// Signed 128-bit remainder, which has the sign of the dividend.
+#[no_mangle]
+pub extern "C" fn __modti3(n: i128, d: i128) -> i128 {
+    let r = udivmod(unsigned_abs(n), unsigned_abs(d)).1 as i128;
+    if n < 0 { r.wrapping_neg() } else { r }
+}
@@ -42,7 +42,11 @@ use str::{FromStr, StrExt};
 pub struct Wrapping<T>(#[stable(feature = "rust1", since = "1.0.0")] pub T);
 
 pub mod wrapping;
+pub mod diy_float;
 pub mod flt2dec;
+pub mod dec2flt;
+#[cfg(all(not(stage0), any(target_pointer_width = "32", windows)))]
+pub mod div128;
 
 /// Types that have a "zero" value.
 ///
@@ -79,6 +83,8 @@ macro_rules! zero_one_impl {
     )*)
 }
 zero_one_impl! { u8 u16 u32 u64 usize i8 i16 i32 i64 isize }
+#[cfg(not(stage0))]
+zero_one_impl! { u128 i128 }
 
 macro_rules! zero_one_impl_float {
     ($($t:ty)*) => ($(
@@ -638,6 +644,15 @@ impl i64 {
         intrinsics::i64_mul_with_overflow }
 }
 
+#[cfg(not(stage0))]
+#[lang = "i128"]
+impl i128 {
+    int_impl! { i128, u128, 128,
+        intrinsics::i128_add_with_overflow,
+        intrinsics::i128_sub_with_overflow,
+        intrinsics::i128_mul_with_overflow }
+}
+
 #[cfg(target_pointer_width = "32")]
 #[lang = "isize"]
 impl isize {
@@ -1206,6 +1221,19 @@ impl u64 {
         intrinsics::u64_mul_with_overflow }
 }
 
+#[cfg(not(stage0))]
+#[lang = "u128"]
+impl u128 {
+    uint_impl! { u128, 128,
+        intrinsics::ctpop128,
+        intrinsics::ctlz128,
+        intrinsics::cttz128,
+        intrinsics::bswap128,
+        intrinsics::u128_add_with_overflow,
+        intrinsics::u128_sub_with_overflow,
+        intrinsics::u128_mul_with_overflow }
+}
+
 #[cfg(target_pointer_width = "32")]
 #[lang = "usize"]
 impl usize {
@@ -1372,16 +1400,25 @@ macro_rules! from_str_float_impl {
             /// This function accepts strings such as
             ///
             /// * '3.14'
+            /// * '+3.14', equivalent to '3.14'
             /// * '-3.14'
             /// * '2.5E10', or equivalently, '2.5e10'
             /// * '2.5E-10'
-            /// * '.' (understood as 0)
             /// * '5.'
             /// * '.5', or, equivalently,  '0.5'
             /// * 'inf', '-inf', 'NaN'
             ///
             /// Leading and trailing whitespace represent an error.
             ///
+            /// The result is correctly rounded: it is the representable value
+            /// closest to the exact decimal value of `src`, with ties broken
+            /// towards an even significand. In particular, parsing the string
+            /// produced by formatting a finite float with `{}` or `{:?}` gives
+            /// back exactly the same float. Strings whose number of significant
+            /// digits plus the magnitude of their decimal exponent exceeds
+            /// about 375 are too long to be converted exactly, and are rejected
+            /// rather than rounded incorrectly.
+            ///
             /// # Arguments
             ///
             /// * src - A string
@@ -1389,12 +1426,12 @@ macro_rules! from_str_float_impl {
             /// # Return value
             ///
             /// `Err(ParseFloatError)` if the string did not represent a valid
-            /// number.  Otherwise, `Ok(n)` where `n` is the floating-point
-            /// number represented by `src`.
+            /// number, or if it is too long to be converted exactly.
+            /// Otherwise, `Ok(n)` where `n` is the floating-point number
+            /// represented by `src`.
             #[inline]
-            #[allow(deprecated)]
             fn from_str(src: &str) -> Result<Self, ParseFloatError> {
-                Self::from_str_radix(src, 10)
+                dec2flt::dec2flt(src)
             }
         }
     }
@@ -1415,6 +1452,8 @@ macro_rules! from_str_radix_int_impl {
     )*}
 }
 from_str_radix_int_impl! { isize i8 i16 i32 i64 usize u8 u16 u32 u64 }
+#[cfg(not(stage0))]
+from_str_radix_int_impl! { i128 u128 }
 
 #[doc(hidden)]
 trait FromStrRadixHelper: PartialOrd + Copy {
@@ -1441,6 +1480,8 @@ macro_rules! doit {
     })*)
 }
 doit! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+#[cfg(not(stage0))]
+doit! { i128 u128 }
 
 fn from_str_radix<T: FromStrRadixHelper>(src: &str, radix: u32)
                                          -> Result<T, ParseIntError> {
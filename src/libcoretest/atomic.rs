@@ -9,7 +9,7 @@
 // except according to those terms.
 
 use core::atomic::*;
-use core::atomic::Ordering::SeqCst;
+use core::atomic::Ordering::{Relaxed, Release, Acquire, AcqRel, SeqCst};
 
 #[test]
 fn bool_() {
@@ -70,10 +70,115 @@ fn int_xor() {
     assert_eq!(x.load(SeqCst), 0xf731 ^ 0x137f);
 }
 
+#[test]
+fn int_nand() {
+    let x = AtomicIsize::new(0xf731);
+    assert_eq!(x.fetch_nand(0x137f, SeqCst), 0xf731);
+    assert_eq!(x.load(SeqCst), !(0xf731 & 0x137f));
+}
+
+#[test]
+fn uint_nand() {
+    let x = AtomicUsize::new(0xf731);
+    assert_eq!(x.fetch_nand(0x137f, SeqCst), 0xf731);
+    assert_eq!(x.load(SeqCst), !(0xf731 & 0x137f));
+}
+
+#[test]
+fn bool_compare_exchange() {
+    let a = AtomicBool::new(false);
+    assert_eq!(a.compare_exchange(false, true, SeqCst, Relaxed), Ok(false));
+    assert_eq!(a.compare_exchange(false, true, Acquire, Acquire), Err(true));
+    assert!(a.load(SeqCst));
+}
+
+#[test]
+fn uint_compare_exchange() {
+    let x = AtomicUsize::new(5);
+    assert_eq!(x.compare_exchange(5, 10, AcqRel, Relaxed), Ok(5));
+    assert_eq!(x.compare_exchange(6, 12, SeqCst, Acquire), Err(10));
+    assert_eq!(x.load(SeqCst), 10);
+}
+
+#[test]
+fn uint_compare_exchange_weak() {
+    let x = AtomicUsize::new(5);
+    let mut old = x.load(Relaxed);
+    loop {
+        match x.compare_exchange_weak(old, old * 2, Release, Relaxed) {
+            Ok(_) => break,
+            Err(v) => old = v,
+        }
+    }
+    assert_eq!(x.load(SeqCst), 10);
+    assert_eq!(x.compare_exchange_weak(5, 20, SeqCst, SeqCst), Err(10));
+}
+
+#[test]
+fn ptr_compare_exchange() {
+    let (mut a, mut b) = (1, 2);
+    let pa = &mut a as *mut i32;
+    let pb = &mut b as *mut i32;
+    let p = AtomicPtr::new(pa);
+    assert_eq!(p.compare_exchange(pa, pb, SeqCst, Relaxed), Ok(pa));
+    assert_eq!(p.compare_exchange(pa, pb, SeqCst, Relaxed), Err(pb));
+}
+
+#[test]
+#[should_panic]
+fn compare_exchange_release_failure() {
+    let x = AtomicUsize::new(5);
+    let _ = x.compare_exchange(5, 10, SeqCst, Release);
+}
+
+#[test]
+#[should_panic]
+fn compare_exchange_stronger_failure() {
+    let x = AtomicUsize::new(5);
+    let _ = x.compare_exchange(5, 10, Relaxed, Acquire);
+}
+
+#[test]
+fn sized_ints() {
+    let x = AtomicU8::new(0xf0);
+    assert_eq!(x.fetch_add(0x10, SeqCst), 0xf0);
+    assert_eq!(x.load(SeqCst), 0);
+    assert_eq!(x.fetch_sub(1, SeqCst), 0);
+    assert_eq!(x.load(SeqCst), 0xff);
+
+    let x = AtomicI16::new(-1);
+    assert_eq!(x.fetch_and(0x137f, SeqCst), -1);
+    assert_eq!(x.fetch_or(0x0080, SeqCst), 0x137f);
+    assert_eq!(x.fetch_xor(0x13ff, SeqCst), 0x13ff);
+    assert_eq!(x.fetch_nand(-1, SeqCst), 0);
+    assert_eq!(x.load(SeqCst), -1);
+
+    let x = AtomicU32::new(7);
+    assert_eq!(x.swap(8, SeqCst), 7);
+    assert_eq!(x.compare_and_swap(8, 9, SeqCst), 8);
+    assert_eq!(x.compare_exchange(9, 10, Acquire, Relaxed), Ok(9));
+    assert_eq!(x.compare_exchange(9, 10, Acquire, Relaxed), Err(10));
+
+    let x = AtomicI64::new(i64::min_value());
+    assert_eq!(x.fetch_sub(1, SeqCst), i64::min_value());
+    assert_eq!(x.load(SeqCst), i64::max_value());
+    x.store(-5, Release);
+    assert_eq!(x.load(Acquire), -5);
+}
+
+#[test]
+fn fences() {
+    fence(SeqCst);
+    fence(AcqRel);
+    compiler_fence(Acquire);
+    compiler_fence(Release);
+}
+
 static S_FALSE: AtomicBool = AtomicBool::new(false);
 static S_TRUE: AtomicBool = AtomicBool::new(true);
 static S_INT: AtomicIsize  = AtomicIsize::new(0);
 static S_UINT: AtomicUsize = AtomicUsize::new(0);
+static S_U64: AtomicU64 = ATOMIC_U64_INIT;
 
 #[test]
 fn static_init() {
@@ -81,4 +186,5 @@ fn static_init() {
     assert!(S_TRUE.load(SeqCst));
     assert!(S_INT.load(SeqCst) == 0);
     assert!(S_UINT.load(SeqCst) == 0);
+    assert!(S_U64.load(SeqCst) == 0);
 }
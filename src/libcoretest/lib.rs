@@ -9,19 +9,25 @@
 // except according to those terms.
 
 #![feature(as_unsafe_cell)]
+#![feature(atomic_nand)]
 #![feature(borrow_state)]
 #![feature(box_syntax)]
 #![feature(cell_extras)]
 #![feature(cmp_partial)]
+#![feature(compiler_fences)]
 #![feature(const_fn)]
 #![feature(core)]
 #![feature(core_float)]
+#![feature(dec2flt)]
+#![feature(extended_compare_and_swap)]
 #![feature(float_extras)]
 #![feature(float_from_str_radix)]
+#![feature(diy_float)]
 #![feature(flt2dec)]
 #![feature(fmt_radix)]
 #![feature(hash_default)]
 #![feature(hasher_write)]
+#![feature(integer_atomics)]
 #![feature(iter_arith)]
 #![feature(iter_arith)]
 #![feature(iter_cmp)]
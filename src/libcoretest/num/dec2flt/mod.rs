@@ -0,0 +1,202 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(overflowing_literals)]
+
+use std::{i64, f32, f64, fmt, iter, mem};
+use std::str::FromStr;
+use std::__rand as rand;
+use rand::{Rand, XorShiftRng};
+use rand::distributions::{IndependentSample, Range};
+
+mod parse;
+mod rawfp;
+
+// Take a float literal, turn it into a string in various ways (that are all trusted
+// to be correct) and see if those strings are parsed back to the value of the literal.
+// Requires a *polymorphic literal*, i.e. one that can serve as f64 as well as f32.
+macro_rules! test_literal {
+    ($x: expr) => ({
+        let x32: f32 = $x;
+        let x64: f64 = $x;
+        let inputs = &[stringify!($x).into(), format!("{:?}", x64), format!("{:e}", x64)];
+        for input in inputs {
+            assert_eq!(input.parse(), Ok(x64));
+            let neg_input = &format!("-{}", input);
+            assert_eq!(neg_input.parse(), Ok(-x64));
+        }
+        let inputs = &[stringify!($x).into(), format!("{:?}", x32), format!("{:e}", x32)];
+        for input in inputs {
+            assert_eq!(input.parse(), Ok(x32));
+            let neg_input = &format!("-{}", input);
+            assert_eq!(neg_input.parse(), Ok(-x32));
+        }
+    })
+}
+
+#[test]
+fn ordinary() {
+    test_literal!(1.0);
+    test_literal!(3e-5);
+    test_literal!(0.1);
+    test_literal!(12345.);
+    test_literal!(0.9999999);
+    test_literal!(2.2250738585072014e-308);
+}
+
+#[test]
+fn special_code_paths() {
+    test_literal!(36893488147419103229.0); // 2^65 - 3, triggers half-to-even with even significand
+    test_literal!(101e-33); // Triggers the tricky underflow case in AlgorithmM (for f32)
+    test_literal!(1e23); // Triggers AlgorithmR
+    test_literal!(2075e23); // Triggers another path through AlgorithmR
+    test_literal!(8713e-23); // ... and yet another.
+}
+
+#[test]
+fn large() {
+    test_literal!(1e300);
+    test_literal!(123456789.34567e250);
+    test_literal!(943794359898089732078308743689303290943794359843568973207830874368930329.);
+}
+
+#[test]
+fn long_inputs() {
+    let zeros: String = iter::repeat('0').take(400).collect();
+    let threes: String = iter::repeat('3').take(400).collect();
+    // Too long to be converted exactly, so these must be rejected rather than rounded using only
+    // some of their digits.
+    assert!(format!("0.{}", threes).parse::<f64>().is_err());
+    assert!(format!("0.{}", threes).parse::<f32>().is_err());
+    assert!(format!("9007199254740993.{}1", zeros).parse::<f64>().is_err());
+    // Just above half of the smallest subnormal, where only the last digits decide between
+    // 5e-324 and zero.
+    assert!("2.4703282292062327209e-324".parse::<f64>().is_err());
+    assert_eq!("2.4703282292062328e-324".parse(), Ok(5e-324f64));
+}
+
+#[test]
+fn subnormals() {
+    test_literal!(5e-324);
+    test_literal!(91e-324);
+    test_literal!(1e-322);
+    test_literal!(13245643e-320);
+    test_literal!(2.22507385851e-308);
+    test_literal!(2.1e-308);
+    test_literal!(4.9406564584124654e-324);
+}
+
+#[test]
+fn halfway_cases() {
+    // 2^53 + 1 and 2^53 + 3 are exactly halfway between two f64s.
+    assert_eq!("9007199254740993".parse(), Ok(9007199254740992.0f64));
+    assert_eq!("9007199254740995".parse(), Ok(9007199254740996.0f64));
+    // ...but a single non-zero digit far behind them breaks the tie.
+    assert_eq!("9007199254740993.0000000000000000000000000001".parse(),
+               Ok(9007199254740994.0f64));
+    // the largest subnormal and the smallest normal number are notoriously hard.
+    assert_eq!("2.2250738585072011e-308".parse(), Ok(2.225073858507201e-308f64));
+    assert_eq!("2.2250738585072012e-308".parse(), Ok(f64::MIN_POSITIVE));
+    // halfway between zero and the smallest subnormal rounds to (even) zero.
+    assert_eq!("2.4703282292062327e-324".parse(), Ok(0.0f64));
+    assert_eq!("2.4703282292062328e-324".parse(), Ok(5e-324f64));
+}
+
+#[test]
+fn infinity() {
+    assert_eq!("1e400".parse(), Ok(f64::INFINITY));
+    assert_eq!("1e309".parse(), Ok(f64::INFINITY));
+    assert_eq!("2e308".parse(), Ok(f64::INFINITY));
+    assert_eq!("1.7976931348624e308".parse(), Ok(f64::INFINITY));
+    assert_eq!("1.7976931348623157e308".parse(), Ok(f64::MAX));
+    assert_eq!("3.4028236e38".parse(), Ok(f32::INFINITY));
+    assert_eq!("3.4028235e38".parse(), Ok(f32::MAX));
+}
+
+#[test]
+fn zero() {
+    test_literal!(0.0);
+    assert_eq!("1e-325".parse(), Ok(0.0f64));
+    assert_eq!("1e-46".parse(), Ok(0.0f32));
+    assert_eq!("0.0000000000000000000000000000000000000000000000000".parse(), Ok(0.0f64));
+}
+
+#[test]
+fn large_exponents() {
+    assert_eq!(format!("1e{}", i64::MAX).parse(), Ok(f64::INFINITY));
+    assert_eq!(format!("1e-{}", i64::MAX).parse(), Ok(0.0f64));
+    assert_eq!("1e18446744073709551616".parse(), Ok(f64::INFINITY));
+    assert_eq!("0e18446744073709551616".parse(), Ok(0.0f64));
+    assert_eq!("1e-18446744073709551616".parse(), Ok(0.0f64));
+}
+
+#[test]
+fn special_values() {
+    assert_eq!("inf".parse(), Ok(f64::INFINITY));
+    assert_eq!("-inf".parse(), Ok(f64::NEG_INFINITY));
+    assert!("NaN".parse::<f64>().unwrap().is_nan());
+    assert_eq!("+1.5".parse(), Ok(1.5f64));
+}
+
+#[test]
+fn lonely_signs() {
+    assert!("-".parse::<f32>().is_err());
+    assert!("+".parse::<f64>().is_err());
+    assert!("".parse::<f64>().is_err());
+    assert!(".".parse::<f64>().is_err());
+    assert!("-.".parse::<f32>().is_err());
+}
+
+#[test]
+fn invalid() {
+    for s in &["1e", "e1", ".e1", "1.2.3", "0x10", " 1", "1 ", "--1", "in", "nan", "1e+"] {
+        assert!(s.parse::<f64>().is_err(), "accepted invalid {:?}", s);
+    }
+}
+
+fn random_roundtrip<T, F>(mut gen: F, n: usize)
+        where T: Copy + PartialEq + FromStr + fmt::Debug + fmt::Display + fmt::LowerExp,
+              T::Err: PartialEq,
+              F: FnMut() -> T {
+    for _ in 0..n {
+        let x = gen();
+        for s in &[format!("{}", x), format!("{:?}", x), format!("{:e}", x)] {
+            assert!(s.parse::<T>() == Ok(x), "roundtrip failed for {:?} via {:?}", x, s);
+        }
+    }
+}
+
+#[test]
+fn f32_random_roundtrip() {
+    let mut rng: XorShiftRng = Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0000_0001u32, 0x7f80_0000);
+    random_roundtrip(|| -> f32 {
+        unsafe { mem::transmute(f32_range.ind_sample(&mut rng)) }
+    }, 10_000);
+}
+
+#[test]
+fn f64_random_roundtrip() {
+    let mut rng: XorShiftRng = Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    random_roundtrip(|| -> f64 {
+        unsafe { mem::transmute(f64_range.ind_sample(&mut rng)) }
+    }, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn f32_exhaustive_roundtrip() {
+    // all positive finite f32 values, from 0x0000_0001 to 0x7f7f_ffff.
+    for i in 1..0x7f80_0000u32 {
+        let x: f32 = unsafe { mem::transmute(i) };
+        let s = format!("{:?}", x);
+        assert!(s.parse::<f32>() == Ok(x), "roundtrip failed for {:?} via {:?}", x, s);
+    }
+}
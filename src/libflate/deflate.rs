@@ -0,0 +1,79 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Raw DEFLATE streams, without any header or checksum.
+
+use std::io::{self, Read, Write};
+
+use Compression;
+use inflater::Inflater;
+use writer::{Writer, Checksum};
+
+/// Compresses everything written to it, writing the compressed stream to the
+/// underlying writer.
+///
+/// The stream is completed by `finish`, or when the encoder is dropped.
+pub struct Encoder<W: Write> {
+    inner: Writer<W>,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Creates a new encoder which writes its output to `w`.
+    pub fn new(w: W, level: Compression) -> Encoder<W> {
+        Encoder { inner: Writer::new(w, level, &[], Checksum::None) }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing to the underlying writer directly will corrupt the stream.
+    pub fn get_mut(&mut self) -> &mut W { self.inner.get_mut() }
+
+    /// Compresses any remaining input and finishes the stream, returning the
+    /// underlying writer.
+    pub fn finish(self) -> io::Result<W> { self.inner.finish() }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.inner.write(buf) }
+
+    /// Compresses all input so far and writes it out, such that it can all be
+    /// decompressed without the rest of the stream.
+    fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
+}
+
+/// Decompresses a raw DEFLATE stream read from the underlying reader.
+pub struct Decoder<R> {
+    inner: Inflater<R>,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Creates a new decoder which reads compressed data from `r`.
+    pub fn new(r: R) -> Decoder<R> {
+        Decoder { inner: Inflater::new(r) }
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Note that the decoder may have already buffered input beyond the point
+    /// it has decompressed up to.
+    pub fn get_mut(&mut self) -> &mut R { self.inner.get_mut() }
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.inflate(buf)
+    }
+}
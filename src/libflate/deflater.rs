@@ -0,0 +1,629 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The DEFLATE compressor.
+//!
+//! Input is buffered until a block's worth (`BLOCK_SIZE` bytes) is available,
+//! at which point it is turned into a sequence of literals and back-references
+//! by a hash-chain LZ77 matcher. Each block is then emitted with whichever of
+//! the stored, fixed-Huffman or dynamic-Huffman encodings is smallest.
+
+use std::cmp;
+
+use Compression;
+use {WINDOW_SIZE, LEN_BASE, LEN_EXTRA, DIST_BASE, DIST_EXTRA, CLEN_ORDER};
+
+const WINDOW_MASK: usize = WINDOW_SIZE - 1;
+const HASH_BITS: usize = 15;
+const HASH_SIZE: usize = 1 << HASH_BITS;
+const HASH_MASK: usize = HASH_SIZE - 1;
+
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+/// The amount of input compressed into each block. This is kept below the
+/// 65535 byte limit of stored blocks so that any block can fall back to being
+/// stored.
+const BLOCK_SIZE: usize = 1 << 15;
+
+const NUM_LITLEN: usize = 286;
+const NUM_DIST: usize = 30;
+const END_OF_BLOCK: usize = 256;
+
+#[derive(Clone, Copy)]
+enum Token {
+    Literal(u8),
+    /// A back-reference of the given length and distance.
+    Match(u16, u16),
+}
+
+pub struct Deflater {
+    /// Whether to skip matching altogether and only emit stored blocks.
+    stored: bool,
+    /// How many hash chain links to follow looking for a match.
+    max_chain: usize,
+    /// A match at least this long is taken without looking any further.
+    nice_length: usize,
+    /// Whether to defer a match by one byte if that yields a longer one.
+    lazy: bool,
+
+    /// Up to `WINDOW_SIZE` bytes of history followed by pending input.
+    buf: Vec<u8>,
+    /// The stream position of `buf[0]`.
+    offset: usize,
+    /// The index in `buf` of the first byte which hasn't been compressed.
+    pending: usize,
+    /// The most recent stream position (plus one) for each hash value, or
+    /// zero if there is none.
+    head: Vec<usize>,
+    /// The previous stream position (plus one) with the same hash as the
+    /// position it is indexed by, modulo the window size.
+    prev: Vec<usize>,
+    tokens: Vec<Token>,
+    bits: BitWriter,
+}
+
+impl Deflater {
+    pub fn new(level: Compression) -> Deflater {
+        let (max_chain, nice_length, lazy) = match level {
+            Compression::None => (0, 0, false),
+            Compression::Fast => (8, 32, false),
+            Compression::Default => (128, 128, true),
+            Compression::Best => (4096, MAX_MATCH, true),
+        };
+        Deflater {
+            stored: level == Compression::None,
+            max_chain: max_chain,
+            nice_length: nice_length,
+            lazy: lazy,
+            buf: Vec::with_capacity(WINDOW_SIZE + BLOCK_SIZE),
+            offset: 0,
+            pending: 0,
+            head: vec![0; HASH_SIZE],
+            prev: vec![0; WINDOW_SIZE],
+            tokens: Vec::new(),
+            bits: BitWriter::new(),
+        }
+    }
+
+    /// The compressed data produced so far which hasn't been consumed yet.
+    pub fn output(&mut self) -> &mut Vec<u8> {
+        &mut self.bits.out
+    }
+
+    /// Adds `data` to the stream, compressing any full blocks.
+    pub fn compress(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let room = BLOCK_SIZE - (self.buf.len() - self.pending);
+            let n = cmp::min(room, data.len());
+            self.buf.push_all(&data[..n]);
+            data = &data[n..];
+            if self.buf.len() - self.pending == BLOCK_SIZE {
+                self.write_block(false);
+            }
+        }
+    }
+
+    /// Compresses all pending input and pads the output to a byte boundary
+    /// with an empty stored block, so everything written so far can be
+    /// decompressed without waiting for the rest of the stream.
+    pub fn flush(&mut self) {
+        if self.buf.len() > self.pending {
+            self.write_block(false);
+        }
+        write_stored(&mut self.bits, &[], false);
+    }
+
+    /// Compresses all pending input into the final block of the stream.
+    pub fn finish(&mut self) {
+        self.write_block(true);
+        self.bits.align();
+    }
+
+    fn write_block(&mut self, last: bool) {
+        let (start, end) = (self.pending, self.buf.len());
+        self.tokens.truncate(0);
+        if !self.stored {
+            self.find_matches(start, end);
+        }
+        {
+            let data = &self.buf[start..end];
+            if self.stored {
+                write_stored(&mut self.bits, data, last);
+            } else {
+                write_compressed(&mut self.bits, &self.tokens, data, last);
+            }
+        }
+        self.pending = end;
+
+        // Only the last window's worth of input can be referred back to, so
+        // drop anything older.
+        if self.buf.len() > WINDOW_SIZE {
+            let excess = self.buf.len() - WINDOW_SIZE;
+            self.buf.drain(..excess);
+            self.offset += excess;
+            self.pending -= excess;
+        }
+    }
+
+    fn find_matches(&mut self, start: usize, end: usize) {
+        // The last couple of bytes of the previous block couldn't be hashed
+        // without the input which followed them, so do that now.
+        for i in cmp::max(start, MIN_MATCH - 1) - (MIN_MATCH - 1)..start {
+            self.insert(i, end);
+        }
+
+        let mut i = start;
+        while i < end {
+            let (mut len, mut dist) = self.longest_match(i, end);
+            self.insert(i, end);
+            if self.lazy {
+                while len >= MIN_MATCH && len < self.nice_length && i + 1 < end {
+                    let (next_len, next_dist) = self.longest_match(i + 1, end);
+                    if next_len <= len {
+                        break
+                    }
+                    self.tokens.push(Token::Literal(self.buf[i]));
+                    i += 1;
+                    self.insert(i, end);
+                    len = next_len;
+                    dist = next_dist;
+                }
+            }
+            if len >= MIN_MATCH {
+                self.tokens.push(Token::Match(len as u16, dist as u16));
+                for j in i + 1..i + len {
+                    self.insert(j, end);
+                }
+                i += len;
+            } else {
+                self.tokens.push(Token::Literal(self.buf[i]));
+                i += 1;
+            }
+        }
+    }
+
+    fn hash(&self, i: usize) -> usize {
+        let b = &self.buf[i..i + MIN_MATCH];
+        ((b[0] as usize) << 10 ^ (b[1] as usize) << 5 ^ b[2] as usize) & HASH_MASK
+    }
+
+    /// Records that the bytes at `buf[i]` can be matched against.
+    fn insert(&mut self, i: usize, end: usize) {
+        if i + MIN_MATCH > end {
+            return
+        }
+        let h = self.hash(i);
+        let pos = self.offset + i;
+        self.prev[pos & WINDOW_MASK] = self.head[h];
+        self.head[h] = pos + 1;
+    }
+
+    /// Finds the longest earlier occurrence of the bytes at `buf[i]`,
+    /// returning its length and distance, or a length of zero if there is no
+    /// usable match.
+    fn longest_match(&self, i: usize, end: usize) -> (usize, usize) {
+        if i + MIN_MATCH > end {
+            return (0, 0)
+        }
+        let max_len = cmp::min(MAX_MATCH, end - i);
+        let pos = self.offset + i;
+        let (mut best_len, mut best_dist) = (MIN_MATCH - 1, 0);
+        let mut next = self.head[self.hash(i)];
+        let mut chain = self.max_chain;
+        while next != 0 && chain > 0 {
+            let cand = next - 1;
+            if cand >= pos || pos - cand > WINDOW_SIZE || cand < self.offset {
+                break
+            }
+            let j = cand - self.offset;
+            if self.buf[j + best_len] == self.buf[i + best_len] {
+                let mut len = 0;
+                while len < max_len && self.buf[j + len] == self.buf[i + len] {
+                    len += 1;
+                }
+                if len > best_len {
+                    best_len = len;
+                    best_dist = pos - cand;
+                    if len >= self.nice_length || len == max_len {
+                        break
+                    }
+                }
+            }
+            next = self.prev[cand & WINDOW_MASK];
+            chain -= 1;
+        }
+        if best_len >= MIN_MATCH {
+            (best_len, best_dist)
+        } else {
+            (0, 0)
+        }
+    }
+}
+
+/// Accumulates bits least-significant first, as DEFLATE requires.
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u64,
+    nbits: u32,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter { out: Vec::new(), acc: 0, nbits: 0 }
+    }
+
+    fn write(&mut self, value: u32, nbits: u32) {
+        self.acc |= (value as u64) << self.nbits;
+        self.nbits += nbits;
+        while self.nbits >= 8 {
+            self.out.push(self.acc as u8);
+            self.acc >>= 8;
+            self.nbits -= 8;
+        }
+    }
+
+    fn write_code(&mut self, code: &Code, sym: usize) {
+        self.write(code.codes[sym] as u32, code.lens[sym] as u32);
+    }
+
+    /// Pads the output with zero bits up to the next byte boundary.
+    fn align(&mut self) {
+        if self.nbits > 0 {
+            self.out.push(self.acc as u8);
+            self.acc = 0;
+            self.nbits = 0;
+        }
+    }
+}
+
+/// A Huffman code: the length of each symbol's code and the code itself,
+/// bit-reversed so it can be written out least-significant bit first.
+struct Code {
+    lens: Vec<u8>,
+    codes: Vec<u16>,
+}
+
+impl Code {
+    fn from_lengths(lens: Vec<u8>) -> Code {
+        let mut count = [0u16; 16];
+        for &len in &lens {
+            count[len as usize] += 1;
+        }
+        count[0] = 0;
+        let mut next = [0u16; 16];
+        let mut code = 0;
+        for len in 1..16 {
+            code = (code + count[len - 1]) << 1;
+            next[len] = code;
+        }
+        let codes = lens.iter().map(|&len| {
+            if len == 0 {
+                return 0
+            }
+            let code = next[len as usize];
+            next[len as usize] += 1;
+            reverse_bits(code, len)
+        }).collect();
+        Code { lens: lens, codes: codes }
+    }
+
+    fn fixed_litlen() -> Code {
+        let mut lens = vec![8; 288];
+        for len in &mut lens[144..256] { *len = 9 }
+        for len in &mut lens[256..280] { *len = 7 }
+        Code::from_lengths(lens)
+    }
+
+    fn fixed_dist() -> Code {
+        Code::from_lengths(vec![5; NUM_DIST])
+    }
+}
+
+fn reverse_bits(mut code: u16, len: u8) -> u16 {
+    let mut rev = 0;
+    for _ in 0..len {
+        rev = (rev << 1) | (code & 1);
+        code >>= 1;
+    }
+    rev
+}
+
+/// Computes Huffman code lengths for the given symbol frequencies, limited to
+/// `limit` bits. At least two symbols are always given a code, as some
+/// decoders reject codes with a single symbol.
+fn huffman_lengths(freqs: &[u32], limit: usize) -> Vec<u8> {
+    let mut lens = vec![0; freqs.len()];
+    let mut syms = (0..freqs.len()).filter(|&i| freqs[i] > 0)
+                                   .collect::<Vec<_>>();
+    if syms.len() < 2 {
+        let other = if syms.get(0) == Some(&0) {1} else {0};
+        lens[other] = 1;
+        if let Some(&sym) = syms.get(0) {
+            lens[sym] = 1;
+        } else {
+            lens[1 - other] = 1;
+        }
+        return lens
+    }
+    syms.sort_by(|&a, &b| freqs[a].cmp(&freqs[b]));
+
+    // Build the tree with the two-queue method: leaves are taken in order of
+    // increasing frequency, and internal nodes are created in that order too.
+    let n = syms.len();
+    let mut weight = syms.iter().map(|&s| freqs[s] as u64).collect::<Vec<_>>();
+    let mut parent = vec![0; 2 * n - 1];
+    let (mut leaf, mut internal) = (0, n);
+    for _ in 0..n - 1 {
+        let (a, b) = {
+            let mut pick = || {
+                if leaf < n && (internal >= weight.len() ||
+                                weight[leaf] <= weight[internal]) {
+                    leaf += 1;
+                    leaf - 1
+                } else {
+                    internal += 1;
+                    internal - 1
+                }
+            };
+            (pick(), pick())
+        };
+        let node = weight.len();
+        let w = weight[a] + weight[b];
+        weight.push(w);
+        parent[a] = node;
+        parent[b] = node;
+    }
+
+    // Parents always come after their children, so depths can be filled in
+    // walking down from the root.
+    let mut depth = vec![0; 2 * n - 1];
+    for i in (0..2 * n - 2).rev() {
+        depth[i] = depth[parent[i]] + 1;
+    }
+
+    // Count the codes of each length, folding any which are too long into the
+    // longest allowed length, and then rebalance until the code is complete
+    // again by moving codes down from shorter lengths.
+    let mut count = vec![0usize; limit + 1];
+    for &d in &depth[..n] {
+        count[cmp::min(d, limit)] += 1;
+    }
+    let mut total = (1..limit + 1).fold(0, |t, len| t + (count[len] << (limit - len)));
+    while total != 1 << limit {
+        count[limit] -= 1;
+        for len in (1..limit).rev() {
+            if count[len] != 0 {
+                count[len] -= 1;
+                count[len + 1] += 2;
+                break
+            }
+        }
+        total -= 1;
+    }
+
+    // Hand out the lengths, longest first, to the least frequent symbols.
+    let mut syms = syms.into_iter();
+    for len in (1..limit + 1).rev() {
+        for _ in 0..count[len] {
+            lens[syms.next().unwrap()] = len as u8;
+        }
+    }
+    lens
+}
+
+fn len_code(len: u16) -> usize {
+    match LEN_BASE.binary_search(&len) {
+        Ok(i) => i,
+        Err(i) => i - 1,
+    }
+}
+
+fn dist_code(dist: u16) -> usize {
+    match DIST_BASE.binary_search(&dist) {
+        Ok(i) => i,
+        Err(i) => i - 1,
+    }
+}
+
+fn write_stored(w: &mut BitWriter, data: &[u8], last: bool) {
+    w.write(last as u32, 1);
+    w.write(0, 2);
+    w.align();
+    w.write(data.len() as u32, 16);
+    w.write(!data.len() as u32 & 0xffff, 16);
+    w.out.push_all(data);
+}
+
+fn write_compressed(w: &mut BitWriter, tokens: &[Token], data: &[u8], last: bool) {
+    let mut lit_freq = [0u32; NUM_LITLEN];
+    let mut dist_freq = [0u32; NUM_DIST];
+    for token in tokens {
+        match *token {
+            Token::Literal(b) => lit_freq[b as usize] += 1,
+            Token::Match(len, dist) => {
+                lit_freq[END_OF_BLOCK + 1 + len_code(len)] += 1;
+                dist_freq[dist_code(dist)] += 1;
+            }
+        }
+    }
+    lit_freq[END_OF_BLOCK] = 1;
+
+    let lit = Code::from_lengths(huffman_lengths(&lit_freq, 15));
+    let dist = Code::from_lengths(huffman_lengths(&dist_freq, 15));
+    let header = DynamicHeader::new(&lit, &dist);
+    let fixed_lit = Code::fixed_litlen();
+    let fixed_dist = Code::fixed_dist();
+
+    let dynamic_cost = header.cost() + data_cost(&lit_freq, &dist_freq, &lit, &dist);
+    let fixed_cost = data_cost(&lit_freq, &dist_freq, &fixed_lit, &fixed_dist);
+    // Three header bits, up to seven bits of padding and the two lengths.
+    let stored_cost = 3 + 7 + 32 + 8 * data.len() as u64;
+
+    if stored_cost <= cmp::min(dynamic_cost, fixed_cost) {
+        write_stored(w, data, last);
+        return
+    }
+    w.write(last as u32, 1);
+    if dynamic_cost < fixed_cost {
+        w.write(2, 2);
+        header.write(w);
+        write_tokens(w, tokens, &lit, &dist);
+    } else {
+        w.write(1, 2);
+        write_tokens(w, tokens, &fixed_lit, &fixed_dist);
+    }
+}
+
+/// The number of bits needed to encode a block with the given codes.
+fn data_cost(lit_freq: &[u32], dist_freq: &[u32], lit: &Code, dist: &Code) -> u64 {
+    let mut bits = 0;
+    for (sym, &freq) in lit_freq.iter().enumerate() {
+        let mut len = lit.lens[sym] as u64;
+        if sym > END_OF_BLOCK {
+            len += LEN_EXTRA[sym - END_OF_BLOCK - 1] as u64;
+        }
+        bits += freq as u64 * len;
+    }
+    for (sym, &freq) in dist_freq.iter().enumerate() {
+        bits += freq as u64 * (dist.lens[sym] as u64 + DIST_EXTRA[sym] as u64);
+    }
+    bits
+}
+
+fn write_tokens(w: &mut BitWriter, tokens: &[Token], lit: &Code, dist: &Code) {
+    for token in tokens {
+        match *token {
+            Token::Literal(b) => w.write_code(lit, b as usize),
+            Token::Match(len, d) => {
+                let lc = len_code(len);
+                w.write_code(lit, END_OF_BLOCK + 1 + lc);
+                w.write((len - LEN_BASE[lc]) as u32, LEN_EXTRA[lc] as u32);
+                let dc = dist_code(d);
+                w.write_code(dist, dc);
+                w.write((d - DIST_BASE[dc]) as u32, DIST_EXTRA[dc] as u32);
+            }
+        }
+    }
+    w.write_code(lit, END_OF_BLOCK);
+}
+
+/// The code lengths of a dynamic Huffman block, run-length encoded and
+/// themselves Huffman coded as described in RFC 1951, section 3.2.7.
+struct DynamicHeader {
+    nlit: usize,
+    ndist: usize,
+    nclen: usize,
+    /// Code length symbols along with the value of their extra bits.
+    lengths: Vec<(u8, u8)>,
+    clen: Code,
+}
+
+impl DynamicHeader {
+    fn new(lit: &Code, dist: &Code) -> DynamicHeader {
+        let mut nlit = NUM_LITLEN;
+        while nlit > END_OF_BLOCK + 1 && lit.lens[nlit - 1] == 0 {
+            nlit -= 1;
+        }
+        let mut ndist = NUM_DIST;
+        while ndist > 1 && dist.lens[ndist - 1] == 0 {
+            ndist -= 1;
+        }
+        let mut all = lit.lens[..nlit].to_vec();
+        all.push_all(&dist.lens[..ndist]);
+
+        let lengths = run_length_encode(&all);
+        let mut freq = [0u32; 19];
+        for &(sym, _) in &lengths {
+            freq[sym as usize] += 1;
+        }
+        let clen = Code::from_lengths(huffman_lengths(&freq, 7));
+        let mut nclen = CLEN_ORDER.len();
+        while nclen > 4 && clen.lens[CLEN_ORDER[nclen - 1]] == 0 {
+            nclen -= 1;
+        }
+        DynamicHeader {
+            nlit: nlit,
+            ndist: ndist,
+            nclen: nclen,
+            lengths: lengths,
+            clen: clen,
+        }
+    }
+
+    fn cost(&self) -> u64 {
+        let mut bits = 5 + 5 + 4 + 3 * self.nclen as u64;
+        for &(sym, _) in &self.lengths {
+            bits += self.clen.lens[sym as usize] as u64 + clen_extra(sym) as u64;
+        }
+        bits
+    }
+
+    fn write(&self, w: &mut BitWriter) {
+        w.write((self.nlit - END_OF_BLOCK - 1) as u32, 5);
+        w.write((self.ndist - 1) as u32, 5);
+        w.write((self.nclen - 4) as u32, 4);
+        for &sym in &CLEN_ORDER[..self.nclen] {
+            w.write(self.clen.lens[sym] as u32, 3);
+        }
+        for &(sym, extra) in &self.lengths {
+            w.write_code(&self.clen, sym as usize);
+            w.write(extra as u32, clen_extra(sym));
+        }
+    }
+}
+
+fn clen_extra(sym: u8) -> u32 {
+    match sym {
+        16 => 2,
+        17 => 3,
+        18 => 7,
+        _ => 0,
+    }
+}
+
+/// Encodes a sequence of code lengths with the repeat codes 16 (repeat the
+/// previous length 3-6 times), 17 (3-10 zeros) and 18 (11-138 zeros).
+fn run_length_encode(lens: &[u8]) -> Vec<(u8, u8)> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < lens.len() {
+        let len = lens[i];
+        let mut run = 1;
+        while i + run < lens.len() && lens[i + run] == len {
+            run += 1;
+        }
+        i += run;
+
+        if len == 0 {
+            while run >= 11 {
+                let n = cmp::min(run, 138);
+                out.push((18, (n - 11) as u8));
+                run -= n;
+            }
+            if run >= 3 {
+                out.push((17, (run - 3) as u8));
+                run = 0;
+            }
+        } else {
+            out.push((len, 0));
+            run -= 1;
+            while run >= 3 {
+                let n = cmp::min(run, 6);
+                out.push((16, (n - 3) as u8));
+                run -= n;
+            }
+        }
+        for _ in 0..run {
+            out.push((len, 0));
+        }
+    }
+    out
+}
@@ -0,0 +1,268 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! DEFLATE streams in the gzip file format ([RFC 1952][rfc]), which adds a
+//! header with optional metadata, and a trailer with a CRC-32 and the length
+//! of the uncompressed data.
+//!
+//! [rfc]: https://tools.ietf.org/html/rfc1952
+
+use std::io::{self, Read, Write};
+
+use Compression;
+use checksum::Crc32;
+use inflater::Inflater;
+use writer::{Writer, Checksum};
+
+const FHCRC: u8 = 1 << 1;
+const FEXTRA: u8 = 1 << 2;
+const FNAME: u8 = 1 << 3;
+const FCOMMENT: u8 = 1 << 4;
+const FRESERVED: u8 = 0xe0;
+
+/// The operating system field written by the encoder, meaning "unknown".
+const OS_UNKNOWN: u8 = 255;
+
+/// Compresses everything written to it, writing a gzip member to the
+/// underlying writer.
+///
+/// The member is completed by `finish`, or when the encoder is dropped.
+pub struct Encoder<W: Write> {
+    inner: Writer<W>,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Creates a new encoder which writes its output to `w`. The header
+    /// written carries no file name or modification time.
+    pub fn new(w: W, level: Compression) -> Encoder<W> {
+        let xfl = match level {
+            Compression::Best => 2,
+            Compression::None | Compression::Fast => 4,
+            Compression::Default => 0,
+        };
+        let header = [0x1f, 0x8b, 8, 0, 0, 0, 0, 0, xfl, OS_UNKNOWN];
+        Encoder {
+            inner: Writer::new(w, level, &header, Checksum::Crc32(Crc32::new(), 0)),
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing to the underlying writer directly will corrupt the stream.
+    pub fn get_mut(&mut self) -> &mut W { self.inner.get_mut() }
+
+    /// Compresses any remaining input and writes the trailer, returning the
+    /// underlying writer.
+    pub fn finish(self) -> io::Result<W> { self.inner.finish() }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.inner.write(buf) }
+
+    /// Compresses all input so far and writes it out, such that it can all be
+    /// decompressed without the rest of the stream.
+    fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
+}
+
+/// The metadata in the header of a gzip member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    filename: Option<Vec<u8>>,
+    comment: Option<Vec<u8>>,
+    mtime: u32,
+    os: u8,
+}
+
+impl Header {
+    /// The original name of the compressed file, if recorded.
+    pub fn filename(&self) -> Option<&[u8]> {
+        self.filename.as_ref().map(|s| &s[..])
+    }
+
+    /// A free-form comment, if recorded.
+    pub fn comment(&self) -> Option<&[u8]> {
+        self.comment.as_ref().map(|s| &s[..])
+    }
+
+    /// The modification time of the original file in seconds since the Unix
+    /// epoch, or zero if none was recorded.
+    pub fn mtime(&self) -> u32 {
+        self.mtime
+    }
+
+    /// The operating system the member was created on, as defined by the
+    /// RFC.
+    pub fn operating_system(&self) -> u8 {
+        self.os
+    }
+}
+
+/// Decompresses gzip data read from the underlying reader, verifying the
+/// trailer of each member.
+///
+/// Input consisting of several concatenated members is decompressed as a
+/// whole, as the `gzip` tool does.
+pub struct Decoder<R> {
+    inner: Inflater<R>,
+    header: Header,
+    crc: Crc32,
+    size: u32,
+    done: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Creates a new decoder which reads gzip data from `r`, reading and
+    /// checking the header of the first member.
+    pub fn new(r: R) -> io::Result<Decoder<R>> {
+        let mut inner = Inflater::new(r);
+        let header = try!(read_header(&mut inner));
+        Ok(Decoder {
+            inner: inner,
+            header: header,
+            crc: Crc32::new(),
+            size: 0,
+            done: false,
+        })
+    }
+
+    /// The header of the first member.
+    pub fn header(&self) -> &Header { &self.header }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Note that the decoder may have already buffered input beyond the point
+    /// it has decompressed up to.
+    pub fn get_mut(&mut self) -> &mut R { self.inner.get_mut() }
+
+    fn read_trailer(&mut self) -> io::Result<()> {
+        let crc = try!(read_u32_le(&mut self.inner));
+        let size = try!(read_u32_le(&mut self.inner));
+        if crc != self.crc.sum() {
+            return Err(invalid("gzip checksum mismatch"))
+        }
+        if size != self.size {
+            return Err(invalid("gzip length mismatch"))
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let n = try!(self.inner.inflate(buf));
+            self.crc.update(&buf[..n]);
+            self.size = self.size.wrapping_add(n as u32);
+            if n > 0 || buf.is_empty() || self.done {
+                return Ok(n)
+            }
+
+            // The end of this member; carry on into the next, if any.
+            try!(self.read_trailer());
+            if try!(self.inner.at_eof()) {
+                self.done = true;
+                return Ok(0)
+            }
+            try!(read_header(&mut self.inner));
+            self.inner.reset();
+            self.crc = Crc32::new();
+            self.size = 0;
+        }
+    }
+}
+
+fn read_header<R: Read>(inner: &mut Inflater<R>) -> io::Result<Header> {
+    let mut r = HeaderReader { inner: inner, crc: Crc32::new() };
+
+    let mut fixed = [0; 10];
+    for b in &mut fixed {
+        *b = try!(r.read_u8());
+    }
+    if fixed[0] != 0x1f || fixed[1] != 0x8b {
+        return Err(invalid("invalid gzip header"))
+    }
+    if fixed[2] != 8 {
+        return Err(invalid("unsupported gzip compression method"))
+    }
+    let flg = fixed[3];
+    if flg & FRESERVED != 0 {
+        return Err(invalid("invalid gzip header flags"))
+    }
+    let mtime = fixed[4] as u32 | (fixed[5] as u32) << 8 |
+                (fixed[6] as u32) << 16 | (fixed[7] as u32) << 24;
+
+    if flg & FEXTRA != 0 {
+        let len = try!(r.read_u8()) as usize | (try!(r.read_u8()) as usize) << 8;
+        for _ in 0..len {
+            try!(r.read_u8());
+        }
+    }
+    let filename = if flg & FNAME != 0 { Some(try!(r.read_str())) } else { None };
+    let comment = if flg & FCOMMENT != 0 { Some(try!(r.read_str())) } else { None };
+
+    if flg & FHCRC != 0 {
+        let expected = try!(r.inner.read_u8()) as u32 |
+                       (try!(r.inner.read_u8()) as u32) << 8;
+        if expected != r.crc.sum() & 0xffff {
+            return Err(invalid("gzip header checksum mismatch"))
+        }
+    }
+
+    Ok(Header {
+        filename: filename,
+        comment: comment,
+        mtime: mtime,
+        os: fixed[9],
+    })
+}
+
+/// Reads header fields, keeping a CRC of everything read for the optional
+/// header checksum.
+struct HeaderReader<'a, R: 'a> {
+    inner: &'a mut Inflater<R>,
+    crc: Crc32,
+}
+
+impl<'a, R: Read> HeaderReader<'a, R> {
+    fn read_u8(&mut self) -> io::Result<u8> {
+        let b = try!(self.inner.read_u8());
+        self.crc.update(&[b]);
+        Ok(b)
+    }
+
+    /// Reads a zero-terminated string.
+    fn read_str(&mut self) -> io::Result<Vec<u8>> {
+        let mut s = Vec::new();
+        loop {
+            match try!(self.read_u8()) {
+                0 => return Ok(s),
+                b => s.push(b),
+            }
+        }
+    }
+}
+
+fn read_u32_le<R: Read>(inner: &mut Inflater<R>) -> io::Result<u32> {
+    let mut n = 0;
+    for i in 0..4 {
+        n |= (try!(inner.read_u8()) as u32) << (8 * i);
+    }
+    Ok(n)
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
@@ -0,0 +1,601 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The DEFLATE decompressor.
+//!
+//! Compressed input is pulled from the underlying reader as it is needed and
+//! decoded a symbol at a time, so any amount of output can be produced on
+//! each call. Huffman codes of up to `FAST_BITS` bits, which covers nearly
+//! every symbol in practice, are decoded with a single table lookup. Longer
+//! codes fall back to decoding canonically a bit at a time, in the style of
+//! zlib's reference `puff` decoder. Stored blocks and back-references are
+//! copied a run of bytes at a time.
+
+use std::cmp;
+use std::io::{self, Read};
+use std::slice::bytes::copy_memory;
+
+use {WINDOW_SIZE, LEN_BASE, LEN_EXTRA, DIST_BASE, DIST_EXTRA, CLEN_ORDER};
+
+const WINDOW_MASK: usize = WINDOW_SIZE - 1;
+const MAX_BITS: usize = 15;
+const MAX_LITLEN: usize = 286;
+const MAX_DIST: usize = 30;
+const END_OF_BLOCK: usize = 256;
+/// How many bits of input the decoding tables are indexed by.
+const FAST_BITS: u32 = 9;
+const FAST_SIZE: usize = 1 << FAST_BITS;
+
+#[derive(Clone, Copy)]
+enum State {
+    /// Expecting the header of the next block.
+    Header,
+    /// Within a stored block, with this many bytes left.
+    Stored(usize),
+    /// Within a Huffman-coded block.
+    Block,
+    /// Copying a back-reference of the given length and distance.
+    Copy(usize, usize),
+    /// The last block has been decoded.
+    Done,
+}
+
+pub struct Inflater<R> {
+    input: BitReader<R>,
+    state: State,
+    /// Whether the current block is the last one in the stream.
+    last: bool,
+    lit: Huffman,
+    dist: Huffman,
+    /// The most recent output, which back-references copy from.
+    window: Vec<u8>,
+    /// Where the next byte of output goes in `window`.
+    wpos: usize,
+    /// How much of `window` holds output so far.
+    filled: usize,
+}
+
+impl<R: Read> Inflater<R> {
+    pub fn new(r: R) -> Inflater<R> {
+        Inflater {
+            input: BitReader::new(r),
+            state: State::Header,
+            last: false,
+            lit: Huffman::empty(),
+            dist: Huffman::empty(),
+            window: vec![0; WINDOW_SIZE],
+            wpos: 0,
+            filled: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R { &self.input.inner }
+
+    pub fn get_mut(&mut self) -> &mut R { &mut self.input.inner }
+
+    /// Prepares to decompress another stream which directly follows the one
+    /// just finished.
+    pub fn reset(&mut self) {
+        self.state = State::Header;
+        self.last = false;
+        self.wpos = 0;
+        self.filled = 0;
+    }
+
+    /// Reads a byte of input which isn't part of the compressed stream, such
+    /// as a header or trailer field.
+    pub fn read_u8(&mut self) -> io::Result<u8> {
+        self.input.read_u8()
+    }
+
+    /// Returns whether the input has been exhausted.
+    pub fn at_eof(&mut self) -> io::Result<bool> {
+        self.input.at_eof()
+    }
+
+    /// Decompresses data into `buf`, returning how many bytes were written.
+    /// Zero is only returned once the end of the stream has been reached.
+    pub fn inflate(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            match self.state {
+                State::Done => break,
+                State::Header => {
+                    if self.last {
+                        // Any trailer starts at the next byte boundary.
+                        self.input.align();
+                        self.state = State::Done;
+                    } else {
+                        try!(self.read_block_header());
+                    }
+                }
+                State::Stored(0) => self.state = State::Header,
+                State::Stored(left) => {
+                    let want = cmp::min(left, buf.len() - n);
+                    let got = try!(self.input.read_bytes(&mut buf[n..n + want]));
+                    self.push_slice(&buf[n..n + got]);
+                    n += got;
+                    self.state = State::Stored(left - got);
+                }
+                State::Copy(0, _) => self.state = State::Block,
+                State::Copy(len, dist) => {
+                    let k = self.copy_match(dist, len, &mut buf[n..]);
+                    n += k;
+                    self.state = State::Copy(len - k, dist);
+                }
+                State::Block => {
+                    let sym = try!(decode(&self.lit, &mut self.input));
+                    if sym < END_OF_BLOCK {
+                        self.push(sym as u8);
+                        buf[n] = sym as u8;
+                        n += 1;
+                    } else if sym == END_OF_BLOCK {
+                        self.state = State::Header;
+                    } else {
+                        let sym = sym - END_OF_BLOCK - 1;
+                        if sym >= LEN_BASE.len() {
+                            return Err(invalid("invalid literal/length code"))
+                        }
+                        let len = LEN_BASE[sym] as usize +
+                                  try!(self.input.bits(LEN_EXTRA[sym] as u32)) as usize;
+                        let sym = try!(decode(&self.dist, &mut self.input));
+                        if sym >= DIST_BASE.len() {
+                            return Err(invalid("invalid distance code"))
+                        }
+                        let dist = DIST_BASE[sym] as usize +
+                                   try!(self.input.bits(DIST_EXTRA[sym] as u32)) as usize;
+                        if dist > self.filled {
+                            return Err(invalid("invalid distance too far back"))
+                        }
+                        self.state = State::Copy(len, dist);
+                    }
+                }
+            }
+        }
+        Ok(n)
+    }
+
+    fn push(&mut self, b: u8) {
+        self.window[self.wpos] = b;
+        self.wpos = (self.wpos + 1) & WINDOW_MASK;
+        if self.filled < WINDOW_SIZE {
+            self.filled += 1;
+        }
+    }
+
+    fn push_slice(&mut self, mut bytes: &[u8]) {
+        // Only the end of the output can be referred back to.
+        if bytes.len() > WINDOW_SIZE {
+            bytes = &bytes[bytes.len() - WINDOW_SIZE..];
+        }
+        while !bytes.is_empty() {
+            let k = cmp::min(bytes.len(), WINDOW_SIZE - self.wpos);
+            copy_memory(&bytes[..k], &mut self.window[self.wpos..]);
+            self.wpos = (self.wpos + k) & WINDOW_MASK;
+            self.filled = cmp::min(self.filled + k, WINDOW_SIZE);
+            bytes = &bytes[k..];
+        }
+    }
+
+    /// Copies as much of a back-reference of `len` bytes, `dist` bytes back,
+    /// as can be done in one go to both the window and `out`, returning how
+    /// many bytes were copied.
+    fn copy_match(&mut self, dist: usize, len: usize, out: &mut [u8]) -> usize {
+        let src = (self.wpos + WINDOW_SIZE - dist) & WINDOW_MASK;
+        // Only copy as much as doesn't overlap the source or run off the end
+        // of the window. A reference a whole window back is the byte which is
+        // about to be replaced, so nothing has to move at all.
+        let room = if src < self.wpos {
+            dist
+        } else if src > self.wpos {
+            WINDOW_SIZE - dist
+        } else {
+            WINDOW_SIZE
+        };
+        let k = cmp::min(cmp::min(len, out.len()),
+                         cmp::min(room, WINDOW_SIZE - cmp::max(src, self.wpos)));
+        if src < self.wpos {
+            let (before, after) = self.window.split_at_mut(self.wpos);
+            copy_memory(&before[src..src + k], after);
+        } else if src > self.wpos {
+            let (before, after) = self.window.split_at_mut(src);
+            copy_memory(&after[..k], &mut before[self.wpos..]);
+        }
+        copy_memory(&self.window[self.wpos..self.wpos + k], out);
+        self.wpos = (self.wpos + k) & WINDOW_MASK;
+        self.filled = cmp::min(self.filled + k, WINDOW_SIZE);
+        k
+    }
+
+    fn read_block_header(&mut self) -> io::Result<()> {
+        self.last = try!(self.input.bits(1)) == 1;
+        match try!(self.input.bits(2)) {
+            0 => {
+                self.input.align();
+                let len = try!(self.input.read_u16_le());
+                let nlen = try!(self.input.read_u16_le());
+                if len != !nlen {
+                    return Err(invalid("invalid stored block lengths"))
+                }
+                self.state = State::Stored(len as usize);
+            }
+            1 => {
+                self.lit = try!(Huffman::new(&fixed_lit_lens()));
+                self.dist = try!(Huffman::new(&[5; MAX_DIST]));
+                self.state = State::Block;
+            }
+            2 => {
+                try!(self.read_dynamic_header());
+                self.state = State::Block;
+            }
+            _ => return Err(invalid("invalid block type")),
+        }
+        Ok(())
+    }
+
+    fn read_dynamic_header(&mut self) -> io::Result<()> {
+        let nlit = try!(self.input.bits(5)) as usize + END_OF_BLOCK + 1;
+        let ndist = try!(self.input.bits(5)) as usize + 1;
+        let nclen = try!(self.input.bits(4)) as usize + 4;
+        if nlit > MAX_LITLEN || ndist > MAX_DIST {
+            return Err(invalid("too many length or distance symbols"))
+        }
+
+        let mut lens = [0; MAX_LITLEN + MAX_DIST];
+        for &sym in &CLEN_ORDER[..nclen] {
+            lens[sym] = try!(self.input.bits(3)) as u8;
+        }
+        let clen = try!(Huffman::new(&lens[..CLEN_ORDER.len()]));
+
+        let mut i = 0;
+        while i < nlit + ndist {
+            let sym = try!(decode(&clen, &mut self.input));
+            if sym < 16 {
+                lens[i] = sym as u8;
+                i += 1;
+                continue
+            }
+            let (len, repeat) = match sym {
+                16 => {
+                    if i == 0 {
+                        return Err(invalid("repeated length with no previous length"))
+                    }
+                    (lens[i - 1], 3 + try!(self.input.bits(2)) as usize)
+                }
+                17 => (0, 3 + try!(self.input.bits(3)) as usize),
+                _ => (0, 11 + try!(self.input.bits(7)) as usize),
+            };
+            if i + repeat > nlit + ndist {
+                return Err(invalid("too many code lengths"))
+            }
+            for len_slot in &mut lens[i..i + repeat] {
+                *len_slot = len;
+            }
+            i += repeat;
+        }
+        if lens[END_OF_BLOCK] == 0 {
+            return Err(invalid("missing end-of-block code"))
+        }
+
+        self.lit = try!(Huffman::new(&lens[..nlit]));
+        self.dist = try!(Huffman::new(&lens[nlit..nlit + ndist]));
+        Ok(())
+    }
+}
+
+/// The code lengths of the fixed literal/length code.
+fn fixed_lit_lens() -> [u8; 288] {
+    let mut lens = [8; 288];
+    for len in &mut lens[144..256] { *len = 9 }
+    for len in &mut lens[256..280] { *len = 7 }
+    lens
+}
+
+/// A canonical Huffman code, stored as the number of codes of each length
+/// and the symbols ordered by their codes.
+struct Huffman {
+    count: [u16; MAX_BITS + 1],
+    symbol: Vec<u16>,
+    /// Indexed by the next `FAST_BITS` bits of input, holding
+    /// `symbol << 4 | length` for the code those bits start with, or zero if
+    /// that code is longer than `FAST_BITS` or missing.
+    fast: [u16; FAST_SIZE],
+}
+
+impl Huffman {
+    fn empty() -> Huffman {
+        Huffman {
+            count: [0; MAX_BITS + 1],
+            symbol: Vec::new(),
+            fast: [0; FAST_SIZE],
+        }
+    }
+
+    /// Builds a code from each symbol's code length. Incomplete codes are
+    /// accepted, but using one of the missing codes is an error when decoding.
+    fn new(lens: &[u8]) -> io::Result<Huffman> {
+        let mut count = [0u16; MAX_BITS + 1];
+        for &len in lens {
+            count[len as usize] += 1;
+        }
+        let mut left = 1i32;
+        for len in 1..MAX_BITS + 1 {
+            left <<= 1;
+            left -= count[len] as i32;
+            if left < 0 {
+                return Err(invalid("over-subscribed Huffman code"))
+            }
+        }
+
+        let mut offs = [0u16; MAX_BITS + 1];
+        for len in 1..MAX_BITS {
+            offs[len + 1] = offs[len] + count[len];
+        }
+        let mut symbol = vec![0; lens.len()];
+        for (sym, &len) in lens.iter().enumerate() {
+            if len != 0 {
+                symbol[offs[len as usize] as usize] = sym as u16;
+                offs[len as usize] += 1;
+            }
+        }
+
+        // Codes are assigned in the same order as `symbol`, but are read
+        // most significant bit first, so each one fills every table entry
+        // whose low bits are the code reversed.
+        let mut fast = [0u16; FAST_SIZE];
+        let (mut code, mut index) = (0u32, 0);
+        for len in 1..FAST_BITS + 1 {
+            for _ in 0..count[len as usize] {
+                let mut reversed = 0;
+                for bit in 0..len {
+                    reversed |= ((code >> bit) & 1) << (len - 1 - bit);
+                }
+                let entry = symbol[index] << 4 | len as u16;
+                let mut i = reversed as usize;
+                while i < FAST_SIZE {
+                    fast[i] = entry;
+                    i += 1 << len;
+                }
+                code += 1;
+                index += 1;
+            }
+            code <<= 1;
+        }
+        Ok(Huffman { count: count, symbol: symbol, fast: fast })
+    }
+}
+
+fn decode<R: Read>(h: &Huffman, input: &mut BitReader<R>) -> io::Result<usize> {
+    let (bits, avail) = try!(input.peek(FAST_BITS));
+    let entry = h.fast[bits as usize];
+    let len = (entry & 0xf) as u32;
+    if entry != 0 && len <= avail {
+        input.consume(len);
+        Ok((entry >> 4) as usize)
+    } else {
+        decode_slow(h, input)
+    }
+}
+
+fn decode_slow<R: Read>(h: &Huffman, input: &mut BitReader<R>) -> io::Result<usize> {
+    // `code` holds the bits read so far, `first` the first code of the
+    // current length and `index` the index of that code's symbol.
+    let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+    for len in 1..MAX_BITS + 1 {
+        code |= try!(input.bits(1)) as i32;
+        let count = h.count[len] as i32;
+        if code - first < count {
+            return Ok(h.symbol[(index + code - first) as usize] as usize)
+        }
+        index += count;
+        first += count;
+        first <<= 1;
+        code <<= 1;
+    }
+    Err(invalid("invalid Huffman code"))
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Reads bits least-significant first from a buffered reader.
+struct BitReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    cap: usize,
+    bitbuf: u32,
+    bitcnt: u32,
+}
+
+impl<R: Read> BitReader<R> {
+    fn new(inner: R) -> BitReader<R> {
+        BitReader {
+            inner: inner,
+            buf: vec![0; 8 * 1024],
+            pos: 0,
+            cap: 0,
+            bitbuf: 0,
+            bitcnt: 0,
+        }
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        if self.pos == self.cap {
+            loop {
+                match self.inner.read(&mut self.buf) {
+                    Ok(n) => { self.cap = n; break }
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            self.pos = 0;
+        }
+        Ok(())
+    }
+
+    fn next_byte(&mut self) -> io::Result<u8> {
+        try!(self.fill());
+        if self.pos == self.cap {
+            return Err(invalid("unexpected end of compressed stream"))
+        }
+        self.pos += 1;
+        Ok(self.buf[self.pos - 1])
+    }
+
+    /// Returns the next `n` bits without consuming them, along with how many
+    /// of them there actually are before the end of the input.
+    fn peek(&mut self, n: u32) -> io::Result<(u32, u32)> {
+        while self.bitcnt < n {
+            try!(self.fill());
+            if self.pos == self.cap {
+                break
+            }
+            self.bitbuf |= (self.buf[self.pos] as u32) << self.bitcnt;
+            self.pos += 1;
+            self.bitcnt += 8;
+        }
+        Ok((self.bitbuf & ((1 << n) - 1), cmp::min(self.bitcnt, n)))
+    }
+
+    fn consume(&mut self, n: u32) {
+        self.bitbuf >>= n;
+        self.bitcnt -= n;
+    }
+
+    fn bits(&mut self, n: u32) -> io::Result<u32> {
+        while self.bitcnt < n {
+            let b = try!(self.next_byte());
+            self.bitbuf |= (b as u32) << self.bitcnt;
+            self.bitcnt += 8;
+        }
+        let v = self.bitbuf & ((1 << n) - 1);
+        self.bitbuf >>= n;
+        self.bitcnt -= n;
+        Ok(v)
+    }
+
+    /// Discards bits up to the next byte boundary.
+    fn align(&mut self) {
+        let n = self.bitcnt % 8;
+        self.bitbuf >>= n;
+        self.bitcnt -= n;
+    }
+
+    fn read_u8(&mut self) -> io::Result<u8> {
+        if self.bitcnt >= 8 {
+            self.bits(8).map(|b| b as u8)
+        } else {
+            self.next_byte()
+        }
+    }
+
+    /// Reads whole bytes into `out` from the next byte boundary onwards,
+    /// returning how many were read. Fewer than asked for may be read, but
+    /// running out of input entirely is an error.
+    fn read_bytes(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let mut n = 0;
+        while n < out.len() && self.bitcnt >= 8 {
+            out[n] = self.bitbuf as u8;
+            self.consume(8);
+            n += 1;
+        }
+        if n < out.len() {
+            try!(self.fill());
+            let k = cmp::min(self.cap - self.pos, out.len() - n);
+            copy_memory(&self.buf[self.pos..self.pos + k], &mut out[n..]);
+            self.pos += k;
+            n += k;
+        }
+        if n == 0 && !out.is_empty() {
+            return Err(invalid("unexpected end of compressed stream"))
+        }
+        Ok(n)
+    }
+
+    fn read_u16_le(&mut self) -> io::Result<u16> {
+        let lo = try!(self.read_u8()) as u16;
+        let hi = try!(self.read_u8()) as u16;
+        Ok(lo | hi << 8)
+    }
+
+    fn at_eof(&mut self) -> io::Result<bool> {
+        if self.bitcnt >= 8 {
+            return Ok(false)
+        }
+        try!(self.fill());
+        Ok(self.pos == self.cap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitReader, Huffman, decode, decode_slow, fixed_lit_lens};
+    use std::__rand::{thread_rng, Rng};
+    use std::io;
+    use test::Bencher;
+
+    // The fixed literal/length code is complete, so any input at all decodes
+    // as a sequence of its symbols.
+    fn noise() -> Vec<u8> {
+        thread_rng().gen_iter::<u8>().take(64 * 1024).collect()
+    }
+
+    fn decode_all<F>(input: &[u8], mut f: F) -> Vec<usize>
+        where F: FnMut(&mut BitReader<&[u8]>) -> io::Result<usize>
+    {
+        let mut reader = BitReader::new(input);
+        let mut syms = Vec::new();
+        while let Ok(sym) = f(&mut reader) {
+            syms.push(sym);
+        }
+        syms
+    }
+
+    #[test]
+    fn test_table_matches_bitwise() {
+        let input = noise();
+        let lit = Huffman::new(&fixed_lit_lens()).unwrap();
+        let table = decode_all(&input, |r| decode(&lit, r));
+        let bitwise = decode_all(&input, |r| decode_slow(&lit, r));
+        assert!(table.len() > input.len() * 8 / 9);
+        assert!(table == bitwise);
+
+        // Codes longer than the table are still decoded.
+        let mut lens = [15; 16];
+        for (i, len) in lens[..15].iter_mut().enumerate() {
+            *len = i as u8 + 1;
+        }
+        let long = Huffman::new(&lens).unwrap();
+        let table = decode_all(&input, |r| decode(&long, r));
+        let bitwise = decode_all(&input, |r| decode_slow(&long, r));
+        assert!(table.iter().any(|&sym| sym > 8));
+        assert!(table == bitwise);
+    }
+
+    #[bench]
+    fn bench_decode_table(b: &mut Bencher) {
+        let input = noise();
+        let lit = Huffman::new(&fixed_lit_lens()).unwrap();
+        b.bytes = input.len() as u64;
+        b.iter(|| decode_all(&input, |r| decode(&lit, r)));
+    }
+
+    // How symbols were decoded before the lookup tables were added, for
+    // comparison.
+    #[bench]
+    fn bench_decode_bitwise(b: &mut Bencher) {
+        let input = noise();
+        let lit = Huffman::new(&fixed_lit_lens()).unwrap();
+        b.bytes = input.len() as u64;
+        b.iter(|| decode_all(&input, |r| decode_slow(&lit, r)));
+    }
+}
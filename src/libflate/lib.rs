@@ -8,11 +8,17 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Simple [DEFLATE][def]-based compression. This is a wrapper around the
-//! [`miniz`][mz] library, which is a one-file pure-C implementation of zlib.
+//! Simple [DEFLATE][def]-based compression, along with the [zlib][zlib] and
+//! [gzip][gzip] container formats.
+//!
+//! The `deflate`, `zlib` and `gzip` modules provide streaming encoders, which
+//! compress everything written to them, and decoders, which decompress
+//! everything read through them. The functions at the top level work on whole
+//! buffers at a time.
 //!
 //! [def]: https://en.wikipedia.org/wiki/DEFLATE
-//! [mz]: https://code.google.com/p/miniz/
+//! [zlib]: https://tools.ietf.org/html/rfc1950
+//! [gzip]: https://tools.ietf.org/html/rfc1952
 
 // Do not remove on snapshot creation. Needed for bootstrap. (Issue #22364)
 #![cfg_attr(stage0, feature(custom_attribute))]
@@ -25,20 +31,27 @@
        html_favicon_url = "https://doc.rust-lang.org/favicon.ico",
        html_root_url = "http://doc.rust-lang.org/nightly/")]
 
-#![feature(libc)]
+#![feature(drain)]
+#![feature(slice_bytes)]
 #![feature(staged_api)]
-#![feature(unique)]
-#![cfg_attr(test, feature(rustc_private, rand, vec_push_all))]
+#![feature(vec_push_all)]
+#![cfg_attr(test, feature(rustc_private, rand, test))]
 
 #[cfg(test)] #[macro_use] extern crate log;
+#[cfg(test)] extern crate test;
 
-extern crate libc;
-
-use libc::{c_void, size_t, c_int};
 use std::fmt;
+use std::io::{Read, Write};
 use std::ops::Deref;
-use std::ptr::Unique;
-use std::slice;
+
+pub mod deflate;
+pub mod gzip;
+pub mod zlib;
+
+mod checksum;
+mod deflater;
+mod inflater;
+mod writer;
 
 #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Error {
@@ -60,102 +73,119 @@ impl fmt::Debug for Error {
 }
 
 pub struct Bytes {
-    ptr: Unique<u8>,
-    len: usize,
+    inner: Vec<u8>,
 }
 
 impl Deref for Bytes {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
-        unsafe { slice::from_raw_parts(*self.ptr, self.len) }
+        &self.inner
     }
 }
 
-impl Drop for Bytes {
-    fn drop(&mut self) {
-        unsafe { libc::free(*self.ptr as *mut _); }
-    }
+/// How hard the compressor should work to make its output smaller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// Don't compress at all, only wrap the data in DEFLATE's framing.
+    None,
+    /// Favour speed over the size of the output.
+    Fast,
+    /// A balance between speed and the size of the output.
+    Default,
+    /// Favour the size of the output over speed.
+    Best,
 }
 
-#[link(name = "miniz", kind = "static")]
-extern {
-    /// Raw miniz compression function.
-    fn tdefl_compress_mem_to_heap(psrc_buf: *const c_void,
-                                  src_buf_len: size_t,
-                                  pout_len: *mut size_t,
-                                  flags: c_int)
-                                  -> *mut c_void;
-
-    /// Raw miniz decompression function.
-    fn tinfl_decompress_mem_to_heap(psrc_buf: *const c_void,
-                                    src_buf_len: size_t,
-                                    pout_len: *mut size_t,
-                                    flags: c_int)
-                                    -> *mut c_void;
-}
+// Tables shared by the compressor and decompressor, from RFC 1951.
 
-const LZ_NORM: c_int = 0x80;  // LZ with 128 probes, "normal"
-const TINFL_FLAG_PARSE_ZLIB_HEADER: c_int = 0x1; // parse zlib header and adler32 checksum
-const TDEFL_WRITE_ZLIB_HEADER: c_int = 0x01000; // write zlib header and adler32 checksum
-
-fn deflate_bytes_internal(bytes: &[u8], flags: c_int) -> Bytes {
-    unsafe {
-        let mut outsz: size_t = 0;
-        let res = tdefl_compress_mem_to_heap(bytes.as_ptr() as *const _,
-                                             bytes.len() as size_t,
-                                             &mut outsz,
-                                             flags);
-        assert!(!res.is_null());
-        Bytes {
-            ptr: Unique::new(res as *mut u8),
-            len: outsz as usize,
-        }
-    }
-}
+/// How far back a match may refer to.
+const WINDOW_SIZE: usize = 1 << 15;
+
+/// The smallest length for each length code, starting with code 257.
+static LEN_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31,
+    35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+
+/// The number of extra bits following each length code.
+static LEN_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2,
+    3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// The smallest distance for each distance code.
+static DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193,
+    257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577,
+];
+
+/// The number of extra bits following each distance code.
+static DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6,
+    7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+
+/// The order in which the lengths of the code length code are stored.
+static CLEN_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
 
 /// Compress a buffer, without writing any sort of header on the output.
 pub fn deflate_bytes(bytes: &[u8]) -> Bytes {
-    deflate_bytes_internal(bytes, LZ_NORM)
+    let mut encoder = deflate::Encoder::new(Vec::new(), Compression::Default);
+    encoder.write_all(bytes).unwrap();
+    Bytes { inner: encoder.finish().unwrap() }
 }
 
 /// Compress a buffer, using a header that zlib can understand.
 pub fn deflate_bytes_zlib(bytes: &[u8]) -> Bytes {
-    deflate_bytes_internal(bytes, LZ_NORM | TDEFL_WRITE_ZLIB_HEADER)
+    let mut encoder = zlib::Encoder::new(Vec::new(), Compression::Default);
+    encoder.write_all(bytes).unwrap();
+    Bytes { inner: encoder.finish().unwrap() }
 }
 
-fn inflate_bytes_internal(bytes: &[u8], flags: c_int) -> Result<Bytes,Error> {
-    unsafe {
-        let mut outsz: size_t = 0;
-        let res = tinfl_decompress_mem_to_heap(bytes.as_ptr() as *const _,
-                                               bytes.len() as size_t,
-                                               &mut outsz,
-                                               flags);
-        if !res.is_null() {
-            Ok(Bytes {
-                ptr: Unique::new(res as *mut u8),
-                len: outsz as usize,
-            })
-        } else {
-            Err(Error::new())
-        }
+fn read_all<R: Read>(mut decoder: R) -> Result<Bytes, Error> {
+    let mut out = Vec::new();
+    match decoder.read_to_end(&mut out) {
+        Ok(_) => Ok(Bytes { inner: out }),
+        Err(_) => Err(Error::new()),
     }
 }
 
 /// Decompress a buffer, without parsing any sort of header on the input.
 pub fn inflate_bytes(bytes: &[u8]) -> Result<Bytes,Error> {
-    inflate_bytes_internal(bytes, 0)
+    read_all(deflate::Decoder::new(bytes))
 }
 
 /// Decompress a buffer that starts with a zlib header.
 pub fn inflate_bytes_zlib(bytes: &[u8]) -> Result<Bytes,Error> {
-    inflate_bytes_internal(bytes, TINFL_FLAG_PARSE_ZLIB_HEADER)
+    match zlib::Decoder::new(bytes) {
+        Ok(decoder) => read_all(decoder),
+        Err(_) => Err(Error::new()),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     #![allow(deprecated)]
-    use super::{inflate_bytes, deflate_bytes};
+    use super::{inflate_bytes, deflate_bytes, inflate_bytes_zlib, deflate_bytes_zlib};
+    use super::{deflate, gzip, zlib, Compression};
+    use super::checksum::{Adler32, Crc32};
     use std::__rand::{thread_rng, Rng};
+    use std::io::{Read, Write};
+    use test::Bencher;
+
+    const LEVELS: [Compression; 4] = [Compression::None, Compression::Fast,
+                                      Compression::Default, Compression::Best];
+
+    fn sample_input() -> Vec<u8> {
+        let mut input = Vec::new();
+        for i in 0..20000 {
+            input.push_all(format!("line {} of the log: {}\n", i, i % 7).as_bytes());
+        }
+        input
+    }
 
     #[test]
     fn test_flate_round_trip() {
@@ -189,4 +219,168 @@ mod tests {
         let inflated = inflate_bytes(&deflated).unwrap();
         assert_eq!(&*inflated, &*bytes);
     }
+
+    #[test]
+    fn test_checksums() {
+        let mut crc = Crc32::new();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.sum(), 0xcbf43926);
+
+        let mut adler = Adler32::new();
+        adler.update(b"Wikipedia");
+        assert_eq!(adler.sum(), 0x11e60398);
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let input = sample_input();
+        for &level in &LEVELS {
+            let mut encoder = deflate::Encoder::new(Vec::new(), level);
+            for chunk in input.chunks(1000) {
+                encoder.write_all(chunk).unwrap();
+            }
+            let compressed = encoder.finish().unwrap();
+            if level != Compression::None {
+                assert!(compressed.len() < input.len() / 4);
+            }
+
+            let mut decoder = deflate::Decoder::new(&compressed[..]);
+            let mut out = Vec::new();
+            let mut buf = [0; 333];
+            loop {
+                match decoder.read(&mut buf).unwrap() {
+                    0 => break,
+                    n => out.push_all(&buf[..n]),
+                }
+            }
+            assert!(out == input);
+        }
+    }
+
+    #[test]
+    fn test_flush() {
+        let mut encoder = deflate::Encoder::new(Vec::new(), Compression::Default);
+        encoder.write_all(b"hello").unwrap();
+        encoder.flush().unwrap();
+        assert!(encoder.get_ref().ends_with(&[0, 0, 0xff, 0xff]));
+
+        // What has been written so far decompresses on its own.
+        {
+            let mut decoder = deflate::Decoder::new(&encoder.get_ref()[..]);
+            let mut buf = [0; 5];
+            let mut n = 0;
+            while n < 5 {
+                n += decoder.read(&mut buf[n..]).unwrap();
+            }
+            assert_eq!(&buf, b"hello");
+        }
+
+        encoder.write_all(b" world").unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert_eq!(&*inflate_bytes(&compressed).unwrap(), b"hello world");
+    }
+
+    #[test]
+    fn test_zlib() {
+        // As produced by the reference implementation.
+        let hello = [0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00,
+                     0x06, 0x2c, 0x02, 0x15];
+        assert_eq!(&*inflate_bytes_zlib(&hello).unwrap(), b"hello");
+
+        let mut corrupt = hello;
+        corrupt[12] ^= 1;
+        assert!(inflate_bytes_zlib(&corrupt).is_err());
+
+        let input = sample_input();
+        for &level in &LEVELS {
+            let mut encoder = zlib::Encoder::new(Vec::new(), level);
+            encoder.write_all(&input).unwrap();
+            let compressed = encoder.finish().unwrap();
+            assert!(*inflate_bytes_zlib(&compressed).unwrap() == *input);
+        }
+        assert_eq!(&*inflate_bytes_zlib(&deflate_bytes_zlib(b"")).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_gzip() {
+        // As produced by the reference implementation, with a file name.
+        let hello = [0x1f, 0x8b, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+                     0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x74, 0x78, 0x74, 0x00,
+                     0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x28, 0xcf, 0x2f, 0xca,
+                     0x49, 0xe1, 0x02, 0x00, 0x2d, 0x3b, 0x08, 0xaf, 0x0c, 0x00,
+                     0x00, 0x00];
+        let mut decoder = gzip::Decoder::new(&hello[..]).unwrap();
+        assert_eq!(decoder.header().filename(), Some(&b"hello.txt"[..]));
+        assert_eq!(decoder.header().operating_system(), 3);
+        let mut out = Vec::new();
+        decoder.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"hello world\n");
+
+        // Concatenated members decompress as one.
+        let mut encoder = gzip::Encoder::new(hello.to_vec(), Compression::Fast);
+        encoder.write_all(b"goodbye\n").unwrap();
+        let both = encoder.finish().unwrap();
+        let mut out = Vec::new();
+        gzip::Decoder::new(&both[..]).unwrap().read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"hello world\ngoodbye\n");
+
+        let mut corrupt = hello;
+        corrupt[38] ^= 1;
+        let mut out = Vec::new();
+        assert!(gzip::Decoder::new(&corrupt[..]).unwrap().read_to_end(&mut out).is_err());
+    }
+
+    #[test]
+    fn test_long_runs() {
+        // Back-references which overlap themselves and wrap around the end of
+        // the window, read back in awkwardly sized pieces.
+        let mut input = vec![b'a'; 100000];
+        input.push_all(&sample_input()[..70000]);
+        input.push_all(&[b'b'; 40000]);
+        for &level in &LEVELS {
+            let mut encoder = deflate::Encoder::new(Vec::new(), level);
+            encoder.write_all(&input).unwrap();
+            let compressed = encoder.finish().unwrap();
+            for &size in &[1, 7, 258, 4096, 65536] {
+                let mut decoder = deflate::Decoder::new(&compressed[..]);
+                let mut out = Vec::new();
+                let mut buf = vec![0; size];
+                loop {
+                    match decoder.read(&mut buf).unwrap() {
+                        0 => break,
+                        n => out.push_all(&buf[..n]),
+                    }
+                }
+                assert!(out == input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(inflate_bytes(&[0xff; 16]).is_err());
+        // A stream which ends partway through.
+        let compressed = deflate_bytes(&sample_input());
+        assert!(inflate_bytes(&compressed[..compressed.len() / 2]).is_err());
+        assert!(gzip::Decoder::new(&b"not gzip"[..]).is_err());
+    }
+
+    #[bench]
+    fn bench_inflate(b: &mut Bencher) {
+        let input = sample_input();
+        let compressed = deflate_bytes(&input);
+        b.bytes = input.len() as u64;
+        b.iter(|| inflate_bytes(&compressed).unwrap());
+    }
+
+    #[bench]
+    fn bench_inflate_stored(b: &mut Bencher) {
+        let input = sample_input();
+        let mut encoder = deflate::Encoder::new(Vec::new(), Compression::None);
+        encoder.write_all(&input).unwrap();
+        let compressed = encoder.finish().unwrap();
+        b.bytes = input.len() as u64;
+        b.iter(|| inflate_bytes(&compressed).unwrap());
+    }
 }
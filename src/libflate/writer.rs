@@ -0,0 +1,140 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `Write` adapter shared by the raw, zlib and gzip encoders.
+
+use std::io::{self, Write};
+
+use Compression;
+use checksum::{Adler32, Crc32};
+use deflater::Deflater;
+
+/// The checksum a container format appends after the compressed data.
+pub enum Checksum {
+    None,
+    /// zlib's Adler-32 of the uncompressed data, stored big-endian.
+    Adler32(Adler32),
+    /// gzip's CRC-32 and length (modulo 2^32) of the uncompressed data, both
+    /// stored little-endian.
+    Crc32(Crc32, u32),
+}
+
+impl Checksum {
+    fn update(&mut self, data: &[u8]) {
+        match *self {
+            Checksum::None => {}
+            Checksum::Adler32(ref mut adler) => adler.update(data),
+            Checksum::Crc32(ref mut crc, ref mut size) => {
+                crc.update(data);
+                *size = size.wrapping_add(data.len() as u32);
+            }
+        }
+    }
+
+    fn trailer(&self) -> Vec<u8> {
+        match *self {
+            Checksum::None => vec![],
+            Checksum::Adler32(ref adler) => {
+                let sum = adler.sum();
+                vec![(sum >> 24) as u8, (sum >> 16) as u8, (sum >> 8) as u8, sum as u8]
+            }
+            Checksum::Crc32(ref crc, size) => {
+                let sum = crc.sum();
+                vec![sum as u8, (sum >> 8) as u8, (sum >> 16) as u8, (sum >> 24) as u8,
+                     size as u8, (size >> 8) as u8, (size >> 16) as u8, (size >> 24) as u8]
+            }
+        }
+    }
+}
+
+pub struct Writer<W: Write> {
+    // The writer is only taken out by `finish`, after which this is dropped.
+    obj: Option<W>,
+    deflater: Deflater,
+    checksum: Checksum,
+    finished: bool,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(w: W, level: Compression, header: &[u8],
+               checksum: Checksum) -> Writer<W> {
+        let mut deflater = Deflater::new(level);
+        deflater.output().push_all(header);
+        Writer {
+            obj: Some(w),
+            deflater: deflater,
+            checksum: checksum,
+            finished: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &W { self.obj.as_ref().unwrap() }
+
+    pub fn get_mut(&mut self) -> &mut W { self.obj.as_mut().unwrap() }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        try!(self.finish_stream());
+        Ok(self.obj.take().unwrap())
+    }
+
+    fn finish_stream(&mut self) -> io::Result<()> {
+        if !self.finished {
+            self.deflater.finish();
+            let trailer = self.checksum.trailer();
+            self.deflater.output().push_all(&trailer);
+            self.finished = true;
+        }
+        self.dump()
+    }
+
+    /// Writes out all of the compressed data produced so far.
+    fn dump(&mut self) -> io::Result<()> {
+        let obj = self.obj.as_mut().unwrap();
+        let out = self.deflater.output();
+        while !out.is_empty() {
+            match obj.write(out) {
+                Ok(0) => {
+                    return Err(io::Error::new(io::ErrorKind::WriteZero,
+                                              "failed to write compressed data"))
+                }
+                Ok(n) => { out.drain(..n); }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Output from earlier writes goes out first, so that an error here
+        // means none of `buf` has been consumed.
+        try!(self.dump());
+        self.deflater.compress(buf);
+        self.checksum.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.deflater.flush();
+        try!(self.dump());
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for Writer<W> {
+    fn drop(&mut self) {
+        if self.obj.is_some() {
+            // dtors should not panic, so we ignore a failed write
+            let _r = self.finish_stream();
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! DEFLATE streams in the zlib container format ([RFC 1950][rfc]), which adds
+//! a two byte header and an Adler-32 checksum of the uncompressed data.
+//!
+//! [rfc]: https://tools.ietf.org/html/rfc1950
+
+use std::io::{self, Read, Write};
+
+use Compression;
+use checksum::Adler32;
+use inflater::Inflater;
+use writer::{Writer, Checksum};
+
+/// Compresses everything written to it, writing a zlib stream to the
+/// underlying writer.
+///
+/// The stream is completed by `finish`, or when the encoder is dropped.
+pub struct Encoder<W: Write> {
+    inner: Writer<W>,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Creates a new encoder which writes its output to `w`.
+    pub fn new(w: W, level: Compression) -> Encoder<W> {
+        // A 32K window with DEFLATE, and the level in FLEVEL. The check bits
+        // make the header a multiple of 31.
+        let flg = match level {
+            Compression::None | Compression::Fast => 0x01,
+            Compression::Default => 0x9c,
+            Compression::Best => 0xda,
+        };
+        let header = [0x78, flg];
+        Encoder {
+            inner: Writer::new(w, level, &header, Checksum::Adler32(Adler32::new())),
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing to the underlying writer directly will corrupt the stream.
+    pub fn get_mut(&mut self) -> &mut W { self.inner.get_mut() }
+
+    /// Compresses any remaining input and writes the checksum, returning the
+    /// underlying writer.
+    pub fn finish(self) -> io::Result<W> { self.inner.finish() }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.inner.write(buf) }
+
+    /// Compresses all input so far and writes it out, such that it can all be
+    /// decompressed without the rest of the stream.
+    fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
+}
+
+/// Decompresses a zlib stream read from the underlying reader, verifying its
+/// checksum once the end of the stream is reached.
+pub struct Decoder<R> {
+    inner: Inflater<R>,
+    adler: Adler32,
+    checked: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    /// Creates a new decoder which reads a zlib stream from `r`, reading and
+    /// checking its header.
+    pub fn new(r: R) -> io::Result<Decoder<R>> {
+        let mut inner = Inflater::new(r);
+        let cmf = try!(inner.read_u8());
+        let flg = try!(inner.read_u8());
+        if cmf & 0x0f != 8 || cmf >> 4 > 7 || ((cmf as u16) << 8 | flg as u16) % 31 != 0 {
+            return Err(invalid("invalid zlib header"))
+        }
+        if flg & 0x20 != 0 {
+            return Err(invalid("zlib streams with a preset dictionary are not supported"))
+        }
+        Ok(Decoder { inner: inner, adler: Adler32::new(), checked: false })
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R { self.inner.get_ref() }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Note that the decoder may have already buffered input beyond the point
+    /// it has decompressed up to.
+    pub fn get_mut(&mut self) -> &mut R { self.inner.get_mut() }
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = try!(self.inner.inflate(buf));
+        self.adler.update(&buf[..n]);
+        if n == 0 && !buf.is_empty() && !self.checked {
+            let mut sum = 0;
+            for _ in 0..4 {
+                sum = sum << 8 | try!(self.inner.read_u8()) as u32;
+            }
+            if sum != self.adler.sum() {
+                return Err(invalid("zlib checksum mismatch"))
+            }
+            self.checked = true;
+        }
+        Ok(n)
+    }
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
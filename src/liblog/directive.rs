@@ -11,6 +11,8 @@
 use std::ascii::AsciiExt;
 use std::cmp;
 
+use filter::Filter;
+
 #[derive(Debug, Clone)]
 pub struct LogDirective {
     pub name: Option<String>,
@@ -29,11 +31,11 @@ fn parse_log_level(level: &str) -> Option<u32> {
 }
 
 /// Parse a logging specification string (e.g: "crate1,crate2::mod3,crate3::x=1/foo")
-/// and return a vector with log directives.
+/// and return a vector with log directives and the message filter, if any.
 ///
 /// Valid log levels are 0-255, with the most likely ones being 1-4 (defined in
 /// std::).  Also supports string log levels of error, warn, info, and debug
-pub fn parse_logging_spec(spec: &str) -> (Vec<LogDirective>, Option<String>) {
+pub fn parse_logging_spec(spec: &str) -> (Vec<LogDirective>, Option<Filter>) {
     let mut dirs = Vec::new();
 
     let mut parts = spec.split('/');
@@ -79,7 +81,18 @@ pub fn parse_logging_spec(spec: &str) -> (Vec<LogDirective>, Option<String>) {
         });
     }});
 
-    (dirs, filter.map(|s| s.to_string()))
+    let filter = filter.and_then(|s| {
+        match Filter::new(s) {
+            Ok(filter) => Some(filter),
+            Err(e) => {
+                println!("warning: invalid logging filter '{}', \
+                         ignoring it ({})", s, e);
+                None
+            }
+        }
+    });
+
+    (dirs, filter)
 }
 
 #[cfg(test)]
@@ -185,4 +198,22 @@ mod tests {
         assert_eq!(dirs[0].level, ::MAX_LOG_LEVEL);
         assert!(filter.is_some() && filter.unwrap().to_string() == "a*c");
     }
+
+    #[test]
+    fn parse_logging_spec_regex_filter() {
+        let (dirs, filter) = parse_logging_spec("crate1=warn/^[0-9]+ scopes$");
+        assert_eq!(dirs.len(), 1);
+        let filter = filter.unwrap();
+        assert!(filter.is_match("12 scopes"));
+        assert!(!filter.is_match("12 scopes left"));
+        assert!(!filter.is_match("no scopes"));
+    }
+
+    #[test]
+    fn parse_logging_spec_invalid_filter() {
+        let (dirs, filter) = parse_logging_spec("crate1=warn/[a-");
+        assert_eq!(dirs.len(), 1);
+        assert_eq!(dirs[0].name, Some("crate1".to_string()));
+        assert!(filter.is_none());
+    }
 }
@@ -0,0 +1,307 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Message filters for `RUST_LOG` directives.
+//!
+//! liblog can't depend on a full regex engine, so this implements the small
+//! subset of regular expressions which is useful for picking out log lines:
+//! literals, `.`, character classes (`[a-z]`, `[^0-9]`, `\d`, `\w`, `\s`),
+//! the `*`, `+` and `?` repetitions and the `^`/`$` anchors. Without groups
+//! or alternation a pattern is just a sequence of pieces, so matching tracks
+//! the set of pieces reached so far instead of backtracking, which keeps it
+//! linear in the length of the message whatever the pattern.
+
+use std::fmt;
+use std::mem;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Clone, Debug)]
+enum Atom {
+    Char(char),
+    Any,
+    Class { negated: bool, ranges: Vec<(char, char)> },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Repeat {
+    One,
+    ZeroOrOne,
+    ZeroOrMore,
+}
+
+#[derive(Clone, Debug)]
+struct Piece {
+    atom: Atom,
+    repeat: Repeat,
+}
+
+/// A compiled message filter.
+#[derive(Clone, Debug)]
+pub struct Filter {
+    source: String,
+    pieces: Vec<Piece>,
+    anchor_start: bool,
+    anchor_end: bool,
+}
+
+impl Filter {
+    /// Compiles `re`, returning a description of the problem if it isn't a
+    /// filter we understand.
+    pub fn new(re: &str) -> Result<Filter, String> {
+        let mut chars = re.chars().peekable();
+        let anchor_start = chars.peek() == Some(&'^');
+        if anchor_start {
+            chars.next();
+        }
+
+        let mut pieces = Vec::new();
+        let mut anchor_end = false;
+        while let Some(c) = chars.next() {
+            let atom = match c {
+                '.' => Atom::Any,
+                '[' => try!(parse_class(&mut chars)),
+                '\\' => match chars.next() {
+                    Some(c) => escape(c),
+                    None => return Err("trailing `\\`".to_string()),
+                },
+                '$' if chars.peek().is_none() => {
+                    anchor_end = true;
+                    break
+                }
+                '*' | '+' | '?' => {
+                    return Err(format!("`{}` does not follow anything to \
+                                        repeat", c))
+                }
+                '(' | ')' | '|' => {
+                    return Err(format!("`{}` is not supported", c))
+                }
+                c => Atom::Char(c),
+            };
+            let repeat = match chars.peek() {
+                Some(&'*') => Repeat::ZeroOrMore,
+                Some(&'+') => {
+                    // `x+` is `xx*`
+                    pieces.push(Piece { atom: atom.clone(), repeat: Repeat::One });
+                    Repeat::ZeroOrMore
+                }
+                Some(&'?') => Repeat::ZeroOrOne,
+                _ => Repeat::One,
+            };
+            if chars.peek().map_or(false, |&c| c == '*' || c == '+' || c == '?') {
+                chars.next();
+            }
+            pieces.push(Piece { atom: atom, repeat: repeat });
+        }
+
+        Ok(Filter {
+            source: re.to_string(),
+            pieces: pieces,
+            anchor_start: anchor_start,
+            anchor_end: anchor_end,
+        })
+    }
+
+    /// Tests whether this filter matches anywhere in `s`.
+    pub fn is_match(&self, s: &str) -> bool {
+        // `states[i]` is whether some match attempt has got as far as piece
+        // `i`, with `states[pieces.len()]` meaning the whole pattern matched.
+        let end = self.pieces.len();
+        let mut states = vec![false; end + 1];
+        let mut next = vec![false; end + 1];
+        self.enter(&mut states, 0);
+        let mut chars = s.chars();
+        loop {
+            if states[end] && !self.anchor_end {
+                return true
+            }
+            let c = match chars.next() {
+                Some(c) => c,
+                None => return states[end],
+            };
+            for state in &mut next {
+                *state = false;
+            }
+            for (i, piece) in self.pieces.iter().enumerate() {
+                if states[i] && piece.atom.matches(c) {
+                    let to = if piece.repeat == Repeat::ZeroOrMore {i} else {i + 1};
+                    self.enter(&mut next, to);
+                }
+            }
+            // Unanchored patterns may start matching at any position.
+            if !self.anchor_start {
+                self.enter(&mut next, 0);
+            }
+            mem::swap(&mut states, &mut next);
+        }
+    }
+
+    /// Marks piece `i` as reached in `states`, along with the pieces after it
+    /// that can be reached by repeating it zero times.
+    fn enter(&self, states: &mut [bool], mut i: usize) {
+        loop {
+            states[i] = true;
+            match self.pieces.get(i) {
+                Some(piece) if piece.repeat != Repeat::One => i += 1,
+                _ => return,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Atom {
+    fn matches(&self, c: char) -> bool {
+        match *self {
+            Atom::Char(a) => a == c,
+            Atom::Any => c != '\n',
+            Atom::Class { negated, ref ranges } => {
+                ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != negated
+            }
+        }
+    }
+}
+
+fn escape(c: char) -> Atom {
+    let ranges = match c {
+        'd' => vec![('0', '9')],
+        'w' => vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+        's' => vec![('\t', '\n'), ('\r', '\r'), (' ', ' ')],
+        c => return Atom::Char(c),
+    };
+    Atom::Class { negated: false, ranges: ranges }
+}
+
+fn parse_class(chars: &mut Peekable<Chars>) -> Result<Atom, String> {
+    let negated = chars.peek() == Some(&'^');
+    if negated {
+        chars.next();
+    }
+
+    let unclosed = || "unclosed character class".to_string();
+    let mut ranges = Vec::new();
+    let mut first = true;
+    loop {
+        let c = match chars.next() {
+            // a `]` right at the start is taken literally
+            Some(']') if !first => break,
+            Some('\\') => try!(chars.next().ok_or_else(&unclosed)),
+            Some(c) => c,
+            None => return Err(unclosed()),
+        };
+        first = false;
+
+        if chars.peek() != Some(&'-') {
+            ranges.push((c, c));
+            continue
+        }
+        chars.next();
+        match chars.next() {
+            // a trailing `-` is taken literally too
+            Some(']') => {
+                ranges.push((c, c));
+                ranges.push(('-', '-'));
+                break
+            }
+            Some(hi) if hi < c => {
+                return Err(format!("invalid range `{}-{}`", c, hi))
+            }
+            Some(hi) => ranges.push((c, hi)),
+            None => return Err(unclosed()),
+        }
+    }
+    Ok(Atom::Class { negated: negated, ranges: ranges })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+
+    fn is_match(re: &str, s: &str) -> bool {
+        Filter::new(re).unwrap().is_match(s)
+    }
+
+    #[test]
+    fn literal() {
+        assert!(is_match("foo", "foo"));
+        assert!(is_match("foo", "a foo b"));
+        assert!(!is_match("foo", "fo o"));
+        assert!(is_match("", "anything"));
+    }
+
+    #[test]
+    fn any() {
+        assert!(is_match("f.o", "foo"));
+        assert!(is_match("f.o", "f1o"));
+        assert!(!is_match("f.o", "fo"));
+    }
+
+    #[test]
+    fn repeat() {
+        assert!(is_match("foo*foo", "foofoo"));
+        assert!(is_match("foo*foo", "fofoo"));
+        assert!(is_match("foo*foo", "fooooooofoo"));
+        assert!(!is_match("foo+foo", "fofoo"));
+        assert!(is_match("colou?r", "color"));
+        assert!(is_match("colou?r", "colour"));
+        assert!(is_match("a.*b", "a xyz b"));
+        assert!(is_match("a+b+", "xaabbb"));
+        assert!(!is_match("^a+b", "b"));
+    }
+
+    #[test]
+    fn no_backtracking_blowup() {
+        let text = (0..10000).map(|_| "a").collect::<String>();
+        assert!(!is_match("a*a*a*a*a*a*a*a*a*a*a*a*b", &text));
+        assert!(is_match("a*a*a*a*a*a*a*a*a*a*a*a*$", &text));
+    }
+
+    #[test]
+    fn class() {
+        assert!(is_match("[0-9] scopes", "there are 3 scopes"));
+        assert!(!is_match("[0-9] scopes", "there are no scopes"));
+        assert!(is_match("[^a-z]", "abc1"));
+        assert!(!is_match("[^a-z]", "abc"));
+        assert!(is_match("[]x]", "]"));
+        assert!(is_match("[a-]", "-"));
+        assert!(is_match("\\d+ms", "took 125ms"));
+        assert!(is_match("\\w\\s\\w", "a b"));
+    }
+
+    #[test]
+    fn anchors() {
+        assert!(is_match("^foo", "foo bar"));
+        assert!(!is_match("^bar", "foo bar"));
+        assert!(is_match("bar$", "foo bar"));
+        assert!(!is_match("foo$", "foo bar"));
+        assert!(is_match("a$b", "a$b"));
+    }
+
+    #[test]
+    fn escapes() {
+        assert!(is_match("a\\.b", "a.b"));
+        assert!(!is_match("a\\.b", "axb"));
+        assert!(is_match("\\[x\\]", "[x]"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Filter::new("*foo").is_err());
+        assert!(Filter::new("[abc").is_err());
+        assert!(Filter::new("[z-a]").is_err());
+        assert!(Filter::new("a|b").is_err());
+        assert!(Filter::new("foo\\").is_err());
+    }
+}
@@ -123,11 +123,16 @@
 //!
 //! # Filtering results
 //!
-//! A RUST_LOG directive may include a string filter. The syntax is to append
-//! `/` followed by a string. Each message is checked against the string and is
-//! only logged if it contains the string. Note that the matching is done after
-//! formatting the log string but before adding any logging meta-data. There is
-//! a single filter for all modules.
+//! A RUST_LOG directive may include a regex filter. The syntax is to append `/`
+//! followed by a regex. Each message is checked against the regex, and is only
+//! logged if it matches. Note that the matching is done after formatting the
+//! log string but before adding any logging meta-data. There is a single filter
+//! for all modules.
+//!
+//! Only a subset of regex syntax is supported: literal characters, `.`,
+//! character classes such as `[a-z]`, `[^0-9]`, `\d`, `\w` and `\s`, the `*`,
+//! `+` and `?` repetitions, and the `^` and `$` anchors. A filter which can't
+//! be parsed is ignored with a warning.
 //!
 //! Some examples:
 //!
@@ -141,6 +146,39 @@
 //!  hello. In both cases the log message must include a single digit number
 //!  followed by 'scopes'
 //!
+//! # Structured fields
+//!
+//! A log statement may attach key/value fields to its record by putting them
+//! in braces before the format string:
+//!
+//! ```
+//! #[macro_use] extern crate log;
+//!
+//! fn main() {
+//!     let (user, elapsed) = ("alice", 42);
+//!     info!({ "user" => user, "elapsed_ms" => elapsed }; "request finished");
+//! }
+//! ```
+//!
+//! The fields are available to loggers through `LogRecord::fields`, so sinks
+//! which emit JSON or another structured format don't need to re-parse the
+//! formatted message. The default logger appends them as `key=value` pairs.
+//!
+//! # Routing to multiple loggers
+//!
+//! By default each record is passed to the logger of the current thread (see
+//! `set_logger`). `add_logger` registers a process-wide logger for a *target*,
+//! a module path prefix just like the name in a `RUST_LOG` directive. Records
+//! from matching modules are passed to every logger registered for a matching
+//! target instead of the thread's logger. `RUST_LOG` still decides which
+//! records are emitted at all.
+//!
+//! Each registered logger handles one record at a time, but different loggers
+//! run concurrently, and no locking happens at all until a logger has been
+//! registered. A record logged on a thread which is already running one of
+//! these loggers, from within the logger itself for example, goes to the
+//! thread's logger rather than being routed again.
+//!
 //! # Performance and Side Effects
 //!
 //! Each of these macros will expand to code similar to:
@@ -177,7 +215,7 @@
 #![feature(staged_api)]
 #![feature(static_mutex)]
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::io::{self, Stderr};
 use std::io::prelude::*;
@@ -185,7 +223,8 @@ use std::mem;
 use std::env;
 use std::rt;
 use std::slice;
-use std::sync::{Once, StaticMutex};
+use std::sync::{Arc, Mutex, Once, StaticMutex};
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 
 use directive::LOG_LEVEL_NAMES;
 
@@ -193,6 +232,7 @@ use directive::LOG_LEVEL_NAMES;
 pub mod macros;
 
 mod directive;
+mod filter;
 
 /// Maximum logging level of a module that can be specified. Common logging
 /// levels are found in the DEBUG/INFO/WARN/ERROR constants.
@@ -212,7 +252,16 @@ static mut DIRECTIVES: *mut Vec<directive::LogDirective> =
     0 as *mut Vec<directive::LogDirective>;
 
 /// Optional filter.
-static mut FILTER: *mut String = 0 as *mut _;
+static mut FILTER: *mut filter::Filter = 0 as *mut _;
+
+/// Loggers registered through `add_logger`, along with their targets.
+static mut TARGETS: *mut Vec<(String, Arc<Mutex<Box<Logger + Send>>>)> = 0 as *mut _;
+
+static TARGETS_LOCK: StaticMutex = StaticMutex::new();
+
+/// Whether `add_logger` has ever been called, so that logging needn't take
+/// `TARGETS_LOCK` until then.
+static HAS_TARGETS: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// Debug log level
 pub const DEBUG: u32 = 4;
@@ -229,6 +278,11 @@ thread_local! {
     }
 }
 
+thread_local! {
+    /// Whether this thread is running loggers registered with `add_logger`.
+    static ROUTING: Cell<bool> = Cell::new(false)
+}
+
 /// A trait used to represent an interface to a thread-local logger. Each thread
 /// can have its own custom logger which can respond to logging messages
 /// however it likes.
@@ -255,11 +309,21 @@ impl fmt::Display for LogLevel {
 
 impl Logger for DefaultLogger {
     fn log(&mut self, record: &LogRecord) {
-        match writeln!(&mut self.handle,
-                       "{}:{}: {}",
-                       record.level,
-                       record.module_path,
-                       record.args) {
+        // Lock the handle so the fields end up on the same line as the
+        // message.
+        let mut handle = self.handle.lock();
+        let mut write = || -> io::Result<()> {
+            try!(write!(handle,
+                        "{}:{}: {}",
+                        record.level,
+                        record.module_path,
+                        record.args));
+            for field in record.fields {
+                try!(write!(handle, " {}={}", field.key, field.value));
+            }
+            writeln!(handle, "")
+        };
+        match write() {
             Err(e) => panic!("failed to log: {:?}", e),
             Ok(()) => {}
         }
@@ -285,6 +349,16 @@ impl Drop for DefaultLogger {
 /// invoked through the logging family of macros.
 #[doc(hidden)]
 pub fn log(level: u32, loc: &'static LogLocation, args: fmt::Arguments) {
+    log_fields(level, loc, &[], args)
+}
+
+/// Like `log`, but also attaches the structured `fields` to the record.
+///
+/// It is not recommended to call this function directly, rather it should be
+/// invoked through the logging family of macros.
+#[doc(hidden)]
+pub fn log_fields(level: u32, loc: &'static LogLocation, fields: &[LogField],
+                  args: fmt::Arguments) {
     // Test the literal string from args against the current filter, if there
     // is one.
     unsafe {
@@ -293,14 +367,26 @@ pub fn log(level: u32, loc: &'static LogLocation, args: fmt::Arguments) {
             0 => {}
             1 => panic!("cannot log after main thread has exited"),
             n => {
-                let filter = mem::transmute::<_, &String>(n);
-                if !args.to_string().contains(&filter[..]) {
+                let filter = mem::transmute::<_, &filter::Filter>(n);
+                if !filter.is_match(&args.to_string()) {
                     return
                 }
             }
         }
     }
 
+    let record = LogRecord {
+        level: LogLevel(level),
+        args: args,
+        file: loc.file,
+        module_path: loc.module_path,
+        line: loc.line,
+        fields: fields,
+    };
+    if route(&record) {
+        return
+    }
+
     // Completely remove the local logger from TLS in case anyone attempts to
     // frob the slot while we're doing the logging. This will destroy any logger
     // set during logging.
@@ -309,16 +395,54 @@ pub fn log(level: u32, loc: &'static LogLocation, args: fmt::Arguments) {
     }).unwrap_or_else(|| {
         box DefaultLogger { handle: io::stderr() }
     });
-    logger.log(&LogRecord {
-        level: LogLevel(level),
-        args: args,
-        file: loc.file,
-        module_path: loc.module_path,
-        line: loc.line,
-    });
+    logger.log(&record);
     set_logger(logger);
 }
 
+/// Passes `record` to each logger registered for a target matching its module
+/// path, returning whether there were any.
+fn route(record: &LogRecord) -> bool {
+    if !HAS_TARGETS.load(Ordering::SeqCst) || ROUTING.with(|r| r.get()) {
+        return false
+    }
+
+    // Only hold the lock while picking out the loggers, so that records going
+    // to different loggers don't wait on each other.
+    let loggers = {
+        let _g = TARGETS_LOCK.lock();
+        unsafe {
+            match TARGETS as usize {
+                0 => return false,
+                1 => panic!("cannot log after main thread has exited"),
+                _ => {}
+            }
+            (*TARGETS).iter().filter(|&&(ref target, _)| {
+                record.module_path.starts_with(&target[..])
+            }).map(|&(_, ref logger)| logger.clone()).collect::<Vec<_>>()
+        }
+    };
+    if loggers.is_empty() {
+        return false
+    }
+
+    struct Routing;
+    impl Drop for Routing {
+        fn drop(&mut self) {
+            ROUTING.with(|r| r.set(false));
+        }
+    }
+
+    ROUTING.with(|r| r.set(true));
+    let _routing = Routing;
+    for logger in &loggers {
+        // A logger which panicked before is skipped.
+        if let Ok(mut logger) = logger.lock() {
+            logger.log(record);
+        }
+    }
+    true
+}
+
 /// Getter for the global log level. This is a function so that it can be called
 /// safely
 #[doc(hidden)]
@@ -334,6 +458,51 @@ pub fn set_logger(logger: Box<Logger + Send>) -> Option<Box<Logger + Send>> {
     })
 }
 
+/// Registers `logger` for all records logged from modules under `target`.
+///
+/// `target` is a module path prefix, like the name in a `RUST_LOG` directive.
+/// A record is passed to every logger registered for a matching target, and
+/// only goes to the thread-local logger (see `set_logger`) if there are none.
+/// Loggers registered here are shared by all threads and live until the main
+/// thread exits. Anything they log themselves goes to the thread-local logger.
+pub fn add_logger(target: &str, logger: Box<Logger + Send>) {
+    let _g = TARGETS_LOCK.lock();
+    unsafe {
+        match TARGETS as usize {
+            0 => {
+                TARGETS = Box::into_raw(box Vec::new());
+
+                // Drop (and so flush) the loggers when the runtime exits.
+                let _ = rt::at_exit(move || {
+                    let _g = TARGETS_LOCK.lock();
+                    let _targets = Box::from_raw(TARGETS);
+                    TARGETS = 1 as *mut _;
+                });
+            }
+            1 => panic!("cannot add a logger after main thread has exited"),
+            _ => {}
+        }
+        (*TARGETS).push((target.to_string(), Arc::new(Mutex::new(logger))));
+    }
+    HAS_TARGETS.store(true, Ordering::SeqCst);
+}
+
+/// A structured key/value pair attached to a `LogRecord`.
+#[derive(Copy, Clone)]
+pub struct LogField<'a> {
+    /// The name of the field.
+    pub key: &'a str,
+
+    /// The value of the field.
+    pub value: &'a fmt::Display,
+}
+
+impl<'a> fmt::Debug for LogField<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}: {}", self.key, self.value)
+    }
+}
+
 /// A LogRecord is created by the logging macros, and passed as the only
 /// argument to Loggers.
 #[derive(Debug)]
@@ -353,6 +522,9 @@ pub struct LogRecord<'a> {
 
     /// The line number of where the LogRecord originated.
     pub line: u32,
+
+    /// The structured fields attached to the log line, if any.
+    pub fields: &'a [LogField<'a>],
 }
 
 #[doc(hidden)]
@@ -47,8 +47,38 @@
 /// WARN:main: this is a warning message
 /// 6:main: this is a custom logging level: 6
 /// ```
+///
+/// Structured fields can be attached to the record by listing them in braces
+/// before the format string. This works with all of the logging macros:
+///
+/// ```
+/// #[macro_use] extern crate log;
+///
+/// fn main() {
+///     let status = 404;
+///     log!(log::WARN, { "status" => status, "path" => "/" }; "not found");
+/// }
+/// ```
+///
+/// ```{.bash}
+/// $ RUST_LOG=warn ./main
+/// WARN:main: not found status=404 path=/
+/// ```
 #[macro_export]
 macro_rules! log {
+    ($lvl:expr, { $($key:expr => $value:expr),* }; $($arg:tt)+) => ({
+        static LOC: ::log::LogLocation = ::log::LogLocation {
+            line: line!(),
+            file: file!(),
+            module_path: module_path!(),
+        };
+        let lvl = $lvl;
+        if log_enabled!(lvl) {
+            ::log::log_fields(lvl, &LOC,
+                              &[$(::log::LogField { key: $key, value: &$value }),*],
+                              format_args!($($arg)+))
+        }
+    });
     ($lvl:expr, $($arg:tt)+) => ({
         static LOC: ::log::LogLocation = ::log::LogLocation {
             line: line!(),
@@ -75,11 +75,11 @@ the following is invalid as it requires the entire `Option<String>` to be moved
 into a variable called `op_string` while simultaneously requiring the inner
 String to be moved into a variable called `s`.
 
-```
+```compile_fail,E0007
 let x = Some("s".to_string());
 match x {
-    op_string @ Some(s) => ...
-    None => ...
+    op_string @ Some(s) => {},
+    None => {},
 }
 ```
 
@@ -92,10 +92,10 @@ name is bound by move in a pattern, it should also be moved to wherever it is
 referenced in the pattern guard code. Doing so however would prevent the name
 from being available in the body of the match arm. Consider the following:
 
-```
+```compile_fail,E0008
 match Some("hi".to_string()) {
-    Some(s) if s.len() == 0 => // use s.
-    ...
+    Some(s) if s.len() == 0 => {}, // use s.
+    _ => {},
 }
 ```
 
@@ -106,11 +106,11 @@ therefore become unavailable in the body of the arm. Although this example seems
 innocuous, the problem is most clear when considering functions that take their
 argument by value.
 
-```
+```compile_fail,E0008
 match Some("hi".to_string()) {
     Some(s) if { drop(s); false } => (),
-    Some(s) => // use s.
-    ...
+    Some(s) => {}, // use s.
+    _ => {},
 }
 ```
 
@@ -129,7 +129,7 @@ This limitation may be removed in a future version of Rust.
 
 Wrong example:
 
-```
+```compile_fail,E0009
 struct X { x: (), }
 
 let x = Some((X { x: () }, X { x: () }));
@@ -183,7 +183,7 @@ at compile time.
 
 Bad example:
 
-```
+```compile_fail,E0011
 use std::ops::Index;
 
 struct Foo { a: u8 }
@@ -213,7 +213,7 @@ Static and const variables can refer to other const variables. But a const
 variable cannot refer to a static variable. For example, `Y` cannot refer to `X`
 here:
 
-```
+```compile_fail,E0013
 static X: i32 = 42;
 const Y: i32 = X;
 ```
@@ -233,7 +233,7 @@ version of Rust, a call to a const function. This error indicates the use
 of a path (like a::b, or x) denoting something other than one of these
 allowed items. Example:
 
-```
+```compile_fail
 const FOO: i32 = { let x = 0; x }; // 'x' isn't a constant nor a function!
 ```
 
@@ -242,7 +242,7 @@ To avoid it, you have to replace the non-constant value:
 ```
 const FOO: i32 = { const X : i32 = 0; X };
 // or even:
-const FOO: i32 = { 0 }; // but brackets are useless here
+const FOO2: i32 = { 0 }; // but brackets are useless here
 ```
 "##,
 
@@ -260,7 +260,7 @@ E0016: r##"
 Blocks in constants may only contain items (such as constant, function
 definition, etc...) and a tail expression. Example:
 
-```
+```compile_fail
 const FOO: i32 = { let x = 0; x }; // 'x' isn't an item!
 ```
 
@@ -271,6 +271,27 @@ const FOO: i32 = { const X : i32 = 0; X };
 ```
 "##,
 
+E0017: r##"
+References in statics and constants may only refer to immutable values. Example:
+
+```compile_fail,E0017
+static X: i32 = 1;
+const C: i32 = 2;
+
+// these three are not allowed:
+const CR: &'static mut i32 = &mut C;
+static STATIC_REF: &'static mut i32 = &mut X;
+static CONST_REF: &'static mut i32 = &mut C;
+```
+
+Statics are shared everywhere, and if they refer to mutable data one might
+violate memory safety since holding multiple mutable references to shared data
+is not allowed.
+
+If you really want global mutable state, try using `static mut` or a global
+`UnsafeCell`.
+"##,
+
 E0018: r##"
 The value of static and const variables must be known at compile time. You
 can't cast a pointer as an integer because we can't know what value the
@@ -287,7 +308,7 @@ const Y: *const u32 = &X;
 Therefore, casting one of these non-constant pointers to an integer results
 in a non-constant integer which lead to this error. Example:
 
-```
+```compile_fail,E0018
 const X: u32 = 1;
 const Y: usize = &X as *const u32 as usize;
 println!("{}", Y);
@@ -299,7 +320,7 @@ A function call isn't allowed in the const's initialization expression
 because the expression's value must be known at compile-time. Example of
 erroneous code:
 
-```
+```compile_fail
 enum Test {
     V1
 }
@@ -321,6 +342,16 @@ Remember: you can't use a function call inside a const's initialization
 expression! However, you can totally use it elsewhere you want:
 
 ```
+enum Test {
+    V1
+}
+
+impl Test {
+    fn func(&self) -> i32 {
+        12
+    }
+}
+
 fn main() {
     const FOO: Test = Test::V1;
 
@@ -335,6 +366,73 @@ This error indicates that an attempt was made to divide by zero (or take the
 remainder of a zero divisor) in a static or constant expression.
 "##,
 
+E0022: r##"
+Constant functions are not allowed to mutate anything. Thus, binding to an
+argument with a mutable pattern is not allowed. For example,
+
+```compile_fail,E0022
+#![feature(const_fn)]
+
+const fn foo(mut x: u8) {
+    // do stuff
+}
+```
+
+is bad because the function body may not mutate `x`.
+
+Remove any mutable bindings from the argument list to fix this error. In case
+you need to mutate the argument, try lazily initializing a global variable
+instead of using a `const fn`, or refactoring the code to a functional style to
+avoid mutation if possible.
+"##,
+
+E0038: r##"
+Trait objects like `Box<Trait>` can only be constructed when certain
+requirements are satisfied by the trait in question. A trait which satisfies
+them is said to be object-safe. The most common reasons for a trait not to be
+object-safe are:
+
+ - It requires `Self: Sized`.
+ - One of its methods returns `Self`, or takes `Self` as an argument other
+   than the receiver, such as `fn foo(&self) -> Self`.
+ - One of its methods has type parameters, such as `fn foo<T>(&self, t: T)`.
+ - One of its methods has no receiver, such as `fn foo()`.
+
+When a method is called on a trait object, the call is dispatched through a
+table of function pointers. For the methods above there is no single function
+which could be stored in that table: a method with type parameters has one
+instance for every type it is used with, for example, and the size of `Self`
+isn't known for a trait object.
+
+For example:
+
+```compile_fail,E0038
+trait Trait {
+    fn foo(&self) -> Self;
+}
+
+fn call_foo(x: Box<Trait>) {
+    let y = x.foo(); // What type is y?
+    // ...
+}
+```
+
+If only some of the methods cause the problem, you can exclude them from trait
+objects by adding a `where Self: Sized` bound to them. Such methods can't be
+called on a trait object, but the trait can be used to make one:
+
+```
+trait Trait {
+    fn foo(&self) -> Self where Self: Sized;
+    fn bar(&self) -> i32;
+}
+
+fn call_bar(x: Box<Trait>) -> i32 {
+    x.bar()
+}
+```
+"##,
+
 E0079: r##"
 Enum variants which contain no data can be given a custom integer
 representation. This error indicates that the value provided is not an
@@ -346,7 +444,7 @@ This error indicates that the compiler was unable to sensibly evaluate an
 integer expression provided as an enum discriminant. Attempting to divide by 0
 or causing integer overflow are two ways to induce this error. For example:
 
-```
+```compile_fail,E0080
 enum Enum {
     X = (1 << 500),
     Y = (1 / 0)
@@ -364,7 +462,7 @@ E0109: r##"
 You tried to give a type parameter to a type which doesn't need it. Erroneous
 code example:
 
-```
+```compile_fail,E0109
 type X = u32<i32>; // error: type parameters are not allowed on this type
 ```
 
@@ -381,7 +479,7 @@ E0110: r##"
 You tried to give a lifetime parameter to a type which doesn't need it.
 Erroneous code example:
 
-```
+```compile_fail,E0110
 type X = u32<'static>; // error: lifetime parameters are not allowed on
                        //        this type
 ```
@@ -411,12 +509,135 @@ fn main() {
 See also https://doc.rust-lang.org/book/unsafe.html
 "##,
 
+E0134: r##"
+You tried to modify a string through indexing, as in `s[i] = c`. String slices
+can't be modified in place like this: a `str` holds UTF-8 encoded text, in which
+characters don't all have the same size, so a single character can't be
+overwritten without possibly shifting all of the following ones.
+
+To change the contents of a string, build a new `String` instead, for example
+with `replace` or by collecting an iterator of `char`s.
+"##,
+
+E0135: r##"
+You tried to modify a string through indexing, as in `s[i] = c`. String slices
+can't be modified in place like this: a `str` holds UTF-8 encoded text, in which
+characters don't all have the same size, so a single character can't be
+overwritten without possibly shifting all of the following ones.
+
+To change the contents of a string, build a new `String` instead, for example
+with `replace` or by collecting an iterator of `char`s.
+"##,
+
+E0136: r##"
+This error indicates that the compiler found multiple functions named `main` at
+the top level of a crate. This is an error because there must be a unique entry
+point into a Rust program.
+
+Usually two items of the same name in a module are already reported as a
+duplicate definition; rename or remove one of the functions.
+"##,
+
 E0137: r##"
 This error indicates that the compiler found multiple functions with the
 `#[main]` attribute. This is an error because there must be a unique entry
 point into a Rust program.
 "##,
 
+E0138: r##"
+This error indicates that the compiler found multiple functions with the
+`#[start]` attribute. This is an error because there must be a unique entry
+point into a Rust program. Example:
+
+```compile_fail,E0138
+#![feature(start)]
+
+#[start]
+fn foo(argc: isize, argv: *const *const u8) -> isize { 0 }
+
+#[start]
+fn f(argc: isize, argv: *const *const u8) -> isize { 0 }
+// error: multiple 'start' functions
+```
+"##,
+
+E0139: r##"
+There are various restrictions on transmuting between types in Rust; for example
+types being transmuted must have the same size. To apply all these restrictions,
+the compiler must know the exact types that may be transmuted. When type
+parameters are involved, this cannot always be done.
+
+So, for example, the following is not allowed:
+
+```compile_fail,E0139
+use std::mem::transmute;
+
+struct Foo<T>(T);
+
+fn foo<T>(x: T) {
+    // we are transmuting between T and Foo<T> here
+    let y: Foo<T> = unsafe { transmute(x) };
+    // do something with y
+}
+```
+
+In this specific case there's a good chance that the transmute is harmless (but
+this is not guaranteed by Rust). However, when alignment and enum optimizations
+come into the picture, it's quite likely that the sizes may or may not match
+with different type parameter substitutions. It's not possible to check this
+for _all_ possible types, so `transmute()` simply only accepts types without any
+unsubstituted type parameters.
+
+If you need this, there's a good chance you're doing something wrong. Keep in
+mind that Rust doesn't guarantee much about the layout of different structs
+(even two structs with identical declarations may have different layouts). If
+there is a solution that avoids the transmute entirely, try it instead.
+
+If it's possible, hand-monomorphize the code by writing the function for each
+possible type substitution. It's possible to use traits to do this cleanly, for
+example:
+
+```
+use std::mem::transmute;
+
+struct Foo<T>(T);
+
+trait MyTransmutableType {
+    fn transmute(self) -> Foo<Self>;
+}
+
+impl MyTransmutableType for u8 {
+    fn transmute(self) -> Foo<u8> {
+        unsafe { transmute(self) }
+    }
+}
+
+fn foo<T: MyTransmutableType>(x: T) {
+    let y: Foo<T> = <T as MyTransmutableType>::transmute(x);
+    // do something with y
+}
+```
+
+Each impl will be checked for a size match in the transmute as usual, and since
+there are no unbound type parameters involved, this should compile unless there
+is a size mismatch in one of the impls.
+
+It is also possible to manually transmute:
+
+```
+use std::mem;
+use std::ptr;
+
+struct Foo<T>(T);
+
+fn foo<T>(x: T) {
+    let y: Foo<T> = unsafe { ptr::read(&x as *const T as *const Foo<T>) };
+    mem::forget(x);
+    // do something with y
+}
+```
+"##,
+
 E0152: r##"
 Lang items are already implemented in the standard library. Unless you are
 writing a free-standing application (e.g. a kernel), you do not need to provide
@@ -425,7 +646,7 @@ them yourself.
 You can build a free-standing crate by adding `#![no_std]` to the crate
 attributes:
 
-```
+```ignore
 #![feature(no_std)]
 #![no_std]
 ```
@@ -447,8 +668,8 @@ If you want to match against a `static`, consider using a guard instead:
 ```
 static FORTY_TWO: i32 = 42;
 match Some(42) {
-    Some(x) if x == FORTY_TWO => ...
-    ...
+    Some(x) if x == FORTY_TWO => {},
+    _ => {},
 }
 ```
 "##,
@@ -466,19 +687,25 @@ An if-let pattern attempts to match the pattern, and enters the body if the
 match was successful. If the match is irrefutable (when it cannot fail to
 match), use a regular `let`-binding instead. For instance:
 
-```
+```compile_fail,E0162
 struct Irrefutable(i32);
 let irr = Irrefutable(0);
 
 // This fails to compile because the match is irrefutable.
 if let Irrefutable(x) = irr {
     // This body will always be executed.
-    foo(x);
+    println!("{}", x);
 }
+```
+
+Try this instead:
+
+```
+struct Irrefutable(i32);
+let irr = Irrefutable(0);
 
-// Try this instead:
 let Irrefutable(x) = irr;
-foo(x);
+println!("{}", x);
 ```
 "##,
 
@@ -487,19 +714,25 @@ A while-let pattern attempts to match the pattern, and enters the body if the
 match was successful. If the match is irrefutable (when it cannot fail to
 match), use a regular `let`-binding inside a `loop` instead. For instance:
 
-```
+```compile_fail,E0165
 struct Irrefutable(i32);
 let irr = Irrefutable(0);
 
 // This fails to compile because the match is irrefutable.
 while let Irrefutable(x) = irr {
-    ...
+    // ...
 }
+```
+
+Try this instead:
+
+```no_run
+struct Irrefutable(i32);
+let irr = Irrefutable(0);
 
-// Try this instead:
 loop {
     let Irrefutable(x) = irr;
-    ...
+    // ...
 }
 ```
 "##,
@@ -517,9 +750,11 @@ enum Method {
 you would match it using:
 
 ```
+# enum Method { GET, POST }
+# let m = Method::GET;
 match m {
-    Method::GET => ...
-    Method::POST => ...
+    Method::GET => {},
+    Method::POST => {},
 }
 ```
 
@@ -533,6 +768,7 @@ you prefer them unqualified, you can import the variants into scope:
 ```
 use Method::*;
 enum Method { GET, POST }
+# fn main() {}
 ```
 "##,
 
@@ -542,7 +778,7 @@ used.
 
 These two examples illustrate the problem:
 
-```
+```compile_fail,E0261
 // error, use of undeclared lifetime name `'a`
 fn foo(x: &'a str) { }
 
@@ -568,7 +804,7 @@ Declaring certain lifetime names in parameters is disallowed. For example,
 because the `'static` lifetime is a special built-in lifetime name denoting
 the lifetime of the entire program, this is an error:
 
-```
+```compile_fail,E0262
 // error, illegal lifetime parameter name `'static`
 fn foo<'static>(x: &'static str) { }
 ```
@@ -578,28 +814,61 @@ E0263: r##"
 A lifetime name cannot be declared more than once in the same scope. For
 example:
 
-```
+```compile_fail,E0263
 // error, lifetime name `'a` declared twice in the same scope
 fn foo<'a, 'b, 'a>(x: &'a str, y: &'b str) { }
 ```
 "##,
 
+E0264: r##"
+An unknown external lang item was used. Erroneous code example:
+
+```compile_fail,E0264
+#![feature(lang_items)]
+
+extern "C" {
+    #[lang = "cake"] // error: unknown external lang item: `cake`
+    fn cake();
+}
+```
+
+A list of available external lang items is available in
+`src/librustc/middle/weak_lang_items.rs`. Example:
+
+```
+#![feature(lang_items)]
+
+extern "C" {
+    #[lang = "panic_fmt"] // ok!
+    fn cake();
+}
+```
+"##,
+
 E0265: r##"
 This error indicates that a static or constant references itself.
 All statics and constants need to resolve to a value in an acyclic manner.
 
 For example, neither of the following can be sensibly compiled:
 
-```
+```compile_fail,E0265
 const X: u32 = X;
 ```
 
-```
+```compile_fail,E0265
 const X: u32 = Y;
 const Y: u32 = X;
 ```
 "##,
 
+E0266: r##"
+A path in a constant or static expression refers to a constant, but the
+declaration the compiler found for it isn't an item, trait item or impl item.
+
+This indicates a bug in the compiler rather than in the code being compiled.
+If you run into it, please report it, ideally with the code which causes it.
+"##,
+
 E0267: r##"
 This error indicates the use of a loop keyword (`break` or `continue`) inside a
 closure but outside of any loop. Break and continue can be used as normal inside
@@ -613,6 +882,127 @@ of a loop. Without a loop to break out of or continue in, no sensible action can
 be taken.
 "##,
 
+E0269: r##"
+Functions must eventually return a value of their return type. For example, in
+the following function
+
+```compile_fail
+fn foo(x: u8) -> u8 {
+    if x > 0 {
+        x // alternatively, `return x`
+    }
+    // nothing here
+}
+```
+
+if the condition is true, the value `x` is returned, but if the condition is
+false, control exits the `if` block and reaches a place where nothing is being
+returned. All possible control paths must eventually return a `u8`, which is not
+happening here.
+
+An easy fix for this in a complicated function is to specify a default return
+value, if possible:
+
+```
+fn foo(x: u8) -> u8 {
+    if x > 0 {
+        x // alternatively, `return x`
+    }
+    // lots of other if branches
+    0 // return 0 if all else fails
+}
+```
+
+It is advisable to find out what the unhandled cases are and check for them,
+returning an appropriate value or panicking if necessary.
+
+A common cause of this error is a semicolon after the last expression of a
+function, which turns it into a statement:
+
+```compile_fail,E0269
+fn foo() -> String {
+    "this won't work".to_string();
+}
+```
+"##,
+
+E0270: r##"
+Rust lets you define functions which are known to never return, i.e. are
+'diverging', by marking its return type as `!`.
+
+For example, the following functions never return:
+
+```no_run
+fn foo() -> ! {
+    loop {}
+}
+
+fn bar() -> ! {
+    foo() // foo() is diverging, so this will diverge too
+}
+
+fn baz() -> ! {
+    panic!(); // this macro internally expands to a call to a diverging function
+}
+```
+
+Such functions can be used in a place where a value is expected without
+returning a value of that type, for instance:
+
+```no_run
+fn foo() -> ! {
+    loop {}
+}
+
+let x = 3;
+
+let y = match x {
+    1 => 1,
+    2 => 4,
+    _ => foo() // diverging function called here
+};
+println!("{}", y)
+```
+
+If the third arm of the match block is reached, since `foo()` doesn't ever
+return control to the match block, it is fine to use it in a place where an
+integer was expected. The `match` block will never finish executing, and any
+point where `y` (like the print statement) is needed will not be reached.
+
+However, if we had a diverging function that actually does finish execution:
+
+```compile_fail,E0270
+fn foo(x: u8) -> ! {
+    if x > 0 {
+        panic!("oh no!");
+    }
+    // this point is reached if x == 0
+}
+```
+
+then we would have an unknown value for `y` in the following code:
+
+```ignore
+let y = match x {
+    1 => 1,
+    2 => 4,
+    _ => foo(0)
+};
+println!("{}", y);
+```
+
+In the previous example, the print statement was never reached when the
+wildcard match arm was hit, so we were okay with `foo()` not returning an
+integer that we could set to `y`. But in this example, `foo()` actually does
+return control, so the print statement will be executed with an uninitialized
+value.
+
+Obviously we cannot have functions which are allowed to be used in such
+positions and yet can return control. So, if you are defining a function that
+returns `!`, make sure that there is no way for it to actually finish
+executing.
+"##,
+
 E0271: r##"
 This is because of a type mismatch between the associated type of some
 trait (e.g. `T::Bar`, where `T` implements `trait Quux { type Bar; }`)
@@ -621,7 +1011,7 @@ Examples follow.
 
 Here is a basic example:
 
-```
+```compile_fail,E0271
 trait Trait { type AssociatedType; }
 fn foo<T>(t: T) where T: Trait<AssociatedType=u32> {
     println!("in foo");
@@ -632,7 +1022,7 @@ foo(3_i8);
 
 Here is that same example again, with some explanatory comments:
 
-```
+```compile_fail,E0271
 trait Trait { type AssociatedType; }
 
 fn foo<T>(t: T) where T: Trait<AssociatedType=u32> {
@@ -652,7 +1042,7 @@ fn foo<T>(t: T) where T: Trait<AssociatedType=u32> {
 }
 
 impl Trait for i8 { type AssociatedType = &'static str; }
-~~~~~~~~~~~~~~~~~   ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+//~~~~~~~~~~~~~~~   ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 //      |                             |
 // `i8` does have                     |
 // implementation                     |
@@ -670,7 +1060,7 @@ foo(3_i8);
 Here is a more subtle instance of the same problem, that can
 arise with for-loops in Rust:
 
-```
+```compile_fail
 let vs: Vec<i32> = vec![1, 2, 3, 4];
 for v in &vs {
     match v {
@@ -684,7 +1074,7 @@ The above fails because of an analogous type mismatch,
 though may be harder to see. Again, here are some
 explanatory comments for the same example:
 
-```
+```compile_fail
 {
     let vs = vec![1, 2, 3, 4];
 
@@ -749,11 +1139,149 @@ for v in &vs {
 ```
 "##,
 
+E0272: r##"
+The `#[rustc_on_unimplemented]` attribute lets you specify a custom error
+message for when a particular trait isn't implemented on a type placed in a
+position that needs that trait. For example, when the following code is
+compiled:
+
+```compile_fail
+#![feature(on_unimplemented)]
+
+fn foo<T: Index<u8>>(x: T){}
+
+#[rustc_on_unimplemented = "the type `{Self}` cannot be indexed by `{Idx}`"]
+trait Index<Idx> { /* ... */ }
+
+foo(true); // `bool` does not implement `Index<u8>`
+```
+
+there will be an error about `bool` not implementing `Index<u8>`, followed by a
+note saying "the type `bool` cannot be indexed by `u8`".
+
+As you can see, you can specify type parameters in curly braces for
+substitution with the actual types (using the regular format string syntax) in
+a given situation. Furthermore, `{Self}` will substitute to the type (in this
+case, `bool`) that we tried to use.
+
+This error appears when the curly braces contain an identifier which doesn't
+match with any of the type parameters or the string `Self`. This might happen if
+you misspelled a type parameter, or if you intended to use literal curly braces.
+If it is the latter, escape the curly braces with a second curly brace of the
+same type; e.g. a literal `{` is `{{`. For example:
+
+```compile_fail,E0272
+#![feature(on_unimplemented)]
+
+fn foo<T: Index<u8>>(x: T){}
+
+#[rustc_on_unimplemented = "the type `{Self}` cannot be indexed by `{Idxx}`"]
+trait Index<Idx> { /* ... */ }
+
+foo(true); // error: `Idxx` is not a type parameter of `Index`
+```
+"##,
+
+E0273: r##"
+The `#[rustc_on_unimplemented]` attribute lets you specify a custom error
+message for when a particular trait isn't implemented on a type placed in a
+position that needs that trait. See the explanation of E0272 for how it is
+used.
+
+This error appears when the curly braces do not contain an identifier. Please
+add one of the same name as a type parameter. If you intended to use literal
+braces, use `{{` and `}}` to escape them. For example:
+
+```compile_fail,E0273
+#![feature(on_unimplemented)]
+
+fn foo<T: Index<u8>>(x: T){}
+
+#[rustc_on_unimplemented = "the type `{Self}` cannot be indexed by `{}`"]
+trait Index<Idx> { /* ... */ }
+
+foo(true); // error: the format argument has no name
+```
+"##,
+
+E0274: r##"
+The `#[rustc_on_unimplemented]` attribute lets you specify a custom error
+message for when a particular trait isn't implemented on a type placed in a
+position that needs that trait. See the explanation of E0272 for how it is
+used.
+
+For this to work, some note must be specified. An empty attribute will not do
+anything, please remove it or add some helpful note for users of the trait. For
+example:
+
+```compile_fail,E0274
+#![feature(on_unimplemented)]
+
+fn foo<T: Index<u8>>(x: T){}
+
+#[rustc_on_unimplemented]
+trait Index<Idx> { /* ... */ }
+
+foo(true); // error: the attribute has no value
+```
+"##,
+
+E0275: r##"
+This error occurs when there was a recursive trait requirement that overflowed
+before it could be evaluated. Often this means that there is unbounded recursion
+in resolving some type bounds.
+
+For example, in the following code
+
+```compile_fail,E0275
+trait Foo {}
+
+struct Bar<T>(T);
+
+impl<T> Foo for T where Bar<T>: Foo {}
+
+fn takes_foo<F: Foo>(f: F) {}
+
+takes_foo(0u8);
+```
+
+to determine if a `T` is `Foo`, we need to check if `Bar<T>` is `Foo`. However,
+to do this check, we need to determine that `Bar<Bar<T>>` is `Foo`. To determine
+this, we check if `Bar<Bar<Bar<T>>>` is `Foo`, and so on. This is clearly a
+recursive requirement that can't be resolved directly.
+
+Consider changing your trait bounds so that they're less self-referential. If
+the requirement is legitimate but merely needs more steps than the compiler
+allows, the limit can be raised with the `#![recursion_limit]` crate attribute.
+"##,
+
+E0276: r##"
+This error occurs when a bound in an implementation of a trait does not match
+the bounds specified in the original trait. For example:
+
+```compile_fail,E0276
+trait Foo {
+    fn foo<T>(x: T);
+}
+
+impl Foo for bool {
+    fn foo<T>(x: T) where T: Copy {}
+}
+```
+
+Here, all types implementing `Foo` must have a method `foo<T>(x: T)` which can
+take any type `T`. However, in the `impl` for `bool`, we have added an extra
+bound that `T` is `Copy`, which isn't compatible with the original trait.
+
+Consider removing the bound from the method or adding the bound to the original
+method definition in the trait.
+"##,
+
 E0277: r##"
 You tried to use a type which doesn't implement some trait in a place which
 expected that trait. Erroneous code example:
 
-```
+```compile_fail,E0277
 // here we declare the Foo trait with a bar method
 trait Foo {
     fn bar(&self);
@@ -797,6 +1325,53 @@ fn main() {
 ```
 "##,
 
+E0278: r##"
+A requirement that two types be equal was not satisfied. The error message shows
+the requirement and the reason it doesn't hold.
+
+Equality requirements normally arise from the compiler's own reasoning about
+the program rather than being written down directly, so the types involved are
+often best understood by looking at the obligation noted with the error.
+"##,
+
+E0279: r##"
+A requirement that one lifetime outlive another, such as `'a: 'b`, was not
+satisfied. The error message shows the requirement and the reason it doesn't
+hold.
+
+Check that the lifetimes passed to the function or type which has the
+requirement are actually related in the way it needs, and add a `'a: 'b` bound
+of your own where they should be.
+"##,
+
+E0280: r##"
+A requirement on an associated type or on how long a type lives, such as
+`T: 'a` or `<T as Iterator>::Item == u32`, was not satisfied.
+
+Check the bounds of the item the requirement comes from, and make sure the types
+you use with it meet them, adding bounds of your own where needed.
+"##,
+
+E0281: r##"
+You tried to supply a type which doesn't implement some trait in a location
+which expected that trait. This error typically occurs when working with
+`Fn`-based types. Erroneous code example:
+
+```compile_fail,E0281
+fn foo<F: Fn()>(x: F) { }
+
+fn main() {
+    // type mismatch: the type ... implements the trait `core::ops::Fn<(_,)>`,
+    // but the trait `core::ops::Fn<()>` is required (expected (), found tuple
+    // [E0281]
+    foo(|y| { });
+}
+```
+
+The issue in this case is that `foo` is defined as accepting a `Fn` with no
+arguments, but the closure we attempted to pass to it requires one argument.
+"##,
+
 E0282: r##"
 This error indicates that type inference did not result in one unique possible
 type, and extra information is required. In most cases this can be provided
@@ -808,7 +1383,7 @@ parameter with a `FromIterator` bound, which for a `char` iterator is
 implemented by `Vec` and `String` among others. Consider the following snippet
 that reverses the characters of a string:
 
-```
+```compile_fail,E0282
 let x = "hello".chars().rev().collect();
 ```
 
@@ -845,9 +1420,9 @@ occur when a type parameter of a struct or trait cannot be inferred. In that
 case it is not always possible to use a type annotation, because all candidates
 have the same return type. For instance:
 
-```
+```compile_fail,E0282
 struct Foo<T> {
-    // Some fields omitted.
+    num: T,
 }
 
 impl<T> Foo<T> {
@@ -865,6 +1440,92 @@ This will fail because the compiler does not know which instance of `Foo` to
 call `bar` on. Change `Foo::bar()` to `Foo::<T>::bar()` to resolve the error.
 "##,
 
+E0283: r##"
+This error occurs when the compiler doesn't have enough information
+to unambiguously choose an implementation.
+
+For example:
+
+```compile_fail,E0283
+trait Generator {
+    fn create() -> u32;
+}
+
+struct Impl;
+
+impl Generator for Impl {
+    fn create() -> u32 { 1 }
+}
+
+struct AnotherImpl;
+
+impl Generator for AnotherImpl {
+    fn create() -> u32 { 2 }
+}
+
+fn main() {
+    let cont: u32 = Generator::create();
+    // error, impossible to choose one of Generator trait implementation
+    // Impl or AnotherImpl? Maybe anything else?
+}
+```
+
+To resolve this error use the concrete type:
+
+```
+trait Generator {
+    fn create() -> u32;
+}
+
+struct AnotherImpl;
+
+impl Generator for AnotherImpl {
+    fn create() -> u32 { 2 }
+}
+
+fn main() {
+    let gen1 = AnotherImpl::create();
+
+    // if there are multiple methods with same name (different traits)
+    let gen2 = <AnotherImpl as Generator>::create();
+}
+```
+"##,
+
+E0284: r##"
+This error occurs when the compiler is unable to unambiguously infer the
+return type of a function or method which is generic on return type, such
+as the `collect` method for `Iterator`s.
+
+For example:
+
+```compile_fail
+fn foo() -> Result<bool, ()> {
+    let results = [Ok(true), Ok(false), Err(())].iter().cloned();
+    let v: Vec<bool> = try!(results.collect());
+    // Do things with v...
+    Ok(true)
+}
+```
+
+Here we have an iterator `results` over `Result<bool, ()>`. Hence,
+`results.collect()` can return any type implementing
+`FromIterator<Result<bool, ()>>`. On the other hand, the `try!` macro can
+only accept a `Result<T, E>` and can't infer which one from what follows.
+
+To fix this, write the type of the intermediate result:
+
+```
+fn foo() -> Result<bool, ()> {
+    let results = [Ok(true), Ok(false), Err(())].iter().cloned();
+    let v: Result<Vec<bool>, ()> = results.collect();
+    let v = try!(v);
+    // Do things with v...
+    Ok(true)
+}
+```
+"##,
+
 E0296: r##"
 This error indicates that the given recursion limit could not be parsed. Ensure
 that the value provided is a positive integer between quotes, like so:
@@ -880,29 +1541,62 @@ that a name will be extracted in all cases. Instead of pattern matching the
 loop variable, consider using a `match` or `if let` inside the loop body. For
 instance:
 
-```
+```compile_fail,E0297
+let xs : Vec<Option<i32>> = vec!(Some(1), None);
+
 // This fails because `None` is not covered.
 for Some(x) in xs {
-    ...
+    // ...
 }
+```
+
+Match inside the loop instead:
+
+```
+let xs : Vec<Option<i32>> = vec!(Some(1), None);
 
-// Match inside the loop instead:
 for item in xs {
     match item {
-        Some(x) => ...
-        None => ...
+        Some(x) => {},
+        None => {},
     }
 }
+```
+
+Or use `if let`:
+
+```
+let xs : Vec<Option<i32>> = vec!(Some(1), None);
 
-// Or use `if let`:
 for item in xs {
     if let Some(x) = item {
-        ...
+        // ...
     }
 }
 ```
 "##,
 
+E0298: r##"
+A literal in a `match` pattern has a different type from the value being
+matched, so the compiler couldn't tell whether it matches. The type checker
+normally reports this as a type mismatch first; make the pattern and the
+matched value the same type.
+"##,
+
+E0299: r##"
+A range in a `match` pattern has a different type from the value being matched,
+so the compiler couldn't tell whether it matches. The type checker normally
+reports this as a type mismatch first; make the bounds of the range and the
+matched value the same type.
+"##,
+
+E0300: r##"
+A macro was found in a pattern after macro expansion should have replaced it.
+
+This indicates a bug in the compiler rather than in the code being compiled.
+If you run into it, please report it, ideally with the code which causes it.
+"##,
+
 E0301: r##"
 Mutable borrows are not allowed in pattern guards, because matching cannot have
 side effects. Side effects could alter the matched object or the environment
@@ -910,7 +1604,7 @@ on which the match depends in such a way, that the match would not be
 exhaustive. For instance, the following would not match any arm if mutable
 borrows were allowed:
 
-```
+```compile_fail,E0301
 match Some(()) {
     None => { },
     option if option.take().is_none() => { /* impossible, option is `Some` */ },
@@ -926,10 +1620,10 @@ on which the match depends in such a way, that the match would not be
 exhaustive. For instance, the following would not match any arm if assignments
 were allowed:
 
-```
+```compile_fail,E0302
 match Some(()) {
     None => { },
-    option if { option = None; false } { },
+    option if { option = None; false } => { },
     Some(_) => { } // When the previous match failed, the option became `None`.
 }
 ```
@@ -940,20 +1634,22 @@ In certain cases it is possible for sub-bindings to violate memory safety.
 Updates to the borrow checker in a future version of Rust may remove this
 restriction, but for now patterns must be rewritten without sub-bindings.
 
-```
-// Code like this...
-match Some(5) {
-    ref op_num @ Some(num) => ...
-    None => ...
+```compile_fail,E0303
+match Some("hi".to_string()) {
+    ref op_string_ref @ Some(ref s) => {},
+    None => {},
 }
+```
+
+After:
 
-// After.
+```
 match Some("hi".to_string()) {
     Some(ref s) => {
-        let op_string_ref = &Some(&s);
-        ...
-    }
-    None => ...
+        let op_string_ref = &Some(s);
+        // ...
+    },
+    None => {},
 }
 ```
 
@@ -981,7 +1677,7 @@ variable.
 
 For example:
 
-```
+```compile_fail,E0308
 let x: i32 = "I am not a number!";
 //     ~~~   ~~~~~~~~~~~~~~~~~~~~
 //      |             |
@@ -998,14 +1694,17 @@ how long the data stored within them is guaranteed to be live. This lifetime
 must be as long as the data needs to be alive, and missing the constraint that
 denotes this will cause this error.
 
-```
+```compile_fail,E0309
 // This won't compile because T is not constrained, meaning the data
 // stored in it is not guaranteed to last as long as the reference
 struct Foo<'a, T> {
     foo: &'a T
 }
+```
+
+This will compile, because it has the constraint on the type parameter:
 
-// This will compile, because it has the constraint on the type parameter
+```
 struct Foo<'a, T: 'a> {
     foo: &'a T
 }
@@ -1018,27 +1717,162 @@ how long the data stored within them is guaranteed to be live. This lifetime
 must be as long as the data needs to be alive, and missing the constraint that
 denotes this will cause this error.
 
-```
+```compile_fail,E0310
 // This won't compile because T is not constrained to the static lifetime
 // the reference needs
 struct Foo<T> {
     foo: &'static T
 }
+```
+
+This will compile, because it has the constraint on the type parameter:
 
-// This will compile, because it has the constraint on the type parameter
+```
 struct Foo<T: 'static> {
     foo: &'static T
 }
 ```
 "##,
 
+E0311: r##"
+This error occurs when a type parameter is used in a place which requires it to
+outlive some lifetime, but nothing guarantees that it does. For example:
+
+```compile_fail
+fn no_restriction<T>(x: &()) -> &() {
+    with_restriction::<T>(x)
+}
+
+fn with_restriction<'a, T: 'a>(x: &'a ()) -> &'a () {
+    x
+}
+```
+
+`with_restriction` requires `T` to outlive the lifetime `'a` of its argument,
+but `no_restriction` doesn't know anything about how long its `T` lives. Add a
+bound which guarantees it, naming the lifetime if necessary:
+
+```
+fn no_restriction<'a, T: 'a>(x: &'a ()) -> &'a () {
+    with_restriction::<T>(x)
+}
+
+fn with_restriction<'a, T: 'a>(x: &'a ()) -> &'a () {
+    x
+}
+```
+"##,
+
+E0312: r##"
+A reference is used for longer than the data it borrows is known to be valid.
+For example:
+
+```compile_fail
+pub fn opt_str<'a>(maybestr: &'a Option<String>) -> &'static str {
+    if maybestr.is_none() {
+        "(none)"
+    } else {
+        let s: &'a str = maybestr.as_ref().unwrap();
+        s  // Invalid lifetime!
+    }
+}
+```
+
+The string borrowed from `maybestr` is only valid for `'a`, but the function
+promises to return a string which is valid for the whole program. Either
+shorten the lifetime of the reference or lengthen the lifetime of the data it
+refers to, here by returning a `&'a str`:
+
+```
+pub fn opt_str<'a>(maybestr: &'a Option<String>) -> &'a str {
+    if maybestr.is_none() {
+        "(none)"
+    } else {
+        let s: &'a str = maybestr.as_ref().unwrap();
+        s  // Ok!
+    }
+}
+```
+"##,
+
+E0313: r##"
+A closure borrows data out of a variable it has captured by reference, and the
+borrow is used for longer than the captured variable lives.
+
+Make sure that references obtained through a captured variable don't escape
+the scope of that variable, for example by capturing it by value with a `move`
+closure, or by cloning the data instead of borrowing it.
+"##,
+
+E0314: r##"
+A closure is used for longer than the stack frame it borrows from is valid.
+
+A closure which refers to local variables without taking ownership of them
+can't outlive the function which created it. Capture the variables by value with
+a `move` closure, or make sure that the closure doesn't escape the function.
+"##,
+
+E0315: r##"
+A closure is called after the data it refers to has gone out of scope.
+
+Only call a closure while all of the variables it borrows are still alive, or
+make it own its captures with a `move` closure.
+"##,
+
+E0316: r##"
+A `for<'a>` quantification of lifetimes is nested inside another one. For
+example:
+
+```compile_fail,E0316
+trait Tr<'a, 'b> {}
+
+fn foo<T>(t: T) where for<'a> &'a T: for<'b> Tr<'a, 'b> {}
+```
+
+Higher-ranked lifetimes can be declared on the bound as a whole or on the trait,
+but not on both. Declare all of them in one place instead:
+
+```
+trait Tr<'a, 'b> {}
+
+fn foo<T>(t: T) where for<'a, 'b> T: Tr<'a, 'b> {}
+```
+"##,
+
+E0370: r##"
+The maximum value of an enum's discriminant was exceeded. Erroneous code
+example:
+
+```compile_fail,E0370
+#[repr(u8)]
+enum Foo {
+    X = 0xff,
+    Y, // error: enum discriminant overflowed on value after 255: u8; set
+       //        explicitly via Y = 0 if that is desired outcome
+}
+```
+
+To fix this, set the discriminant of the following variant explicitly, or pick
+a larger representation for the enum:
+
+```
+#[repr(u16)]
+enum Foo {
+    X = 0xff,
+    Y, // ok, Y = 256
+}
+```
+"##,
+
 E0378: r##"
 Method calls that aren't calls to inherent `const` methods are disallowed
 in statics, constants, and constant functions.
 
 For example:
 
-```
+```compile_fail,E0378
+#![feature(const_fn)]
+
 const BAZ: i32 = Foo(25).bar(); // error, `bar` isn't `const`
 
 struct Foo(i32);
@@ -1070,7 +1904,7 @@ E0395: r##"
 The value assigned to a constant expression must be known at compile time,
 which is not the case when comparing raw pointers. Erroneous code example:
 
-```
+```compile_fail,E0395
 static foo: i32 = 42;
 static bar: i32 = 43;
 
@@ -1095,7 +1929,7 @@ The value assigned to a constant expression must be known at compile time,
 which is not the case when dereferencing raw pointers. Erroneous code
 example:
 
-```
+```compile_fail,E0396
 const foo: i32 = 42;
 const baz: *const i32 = (&foo as *const i32);
 
@@ -1121,7 +1955,7 @@ E0397: r##"
 It is not allowed for a mutable static to allocate or have destructors. For
 example:
 
-```
+```compile_fail,E0397
 // error: mutable statics are not allowed to have boxes
 static mut FOO: Option<Box<usize>> = None;
 
@@ -1134,40 +1968,7 @@ static mut BAR: Option<Vec<i32>> = None;
 
 
 register_diagnostics! {
-    E0017,
-    E0022,
-    E0038,
-    E0134,
-    E0135,
-    E0136,
-    E0138,
-    E0139,
-    E0264, // unknown external lang item
-    E0266, // expected item
-    E0269, // not all control paths return a value
-    E0270, // computation may converge in a function marked as diverging
-    E0272, // rustc_on_unimplemented attribute refers to non-existent type parameter
-    E0273, // rustc_on_unimplemented must have named format arguments
-    E0274, // rustc_on_unimplemented must have a value
-    E0275, // overflow evaluating requirement
-    E0276, // requirement appears on impl method but not on corresponding trait method
-    E0278, // requirement is not satisfied
-    E0279, // requirement is not satisfied
-    E0280, // requirement is not satisfied
-    E0281, // type implements trait but other trait is required
-    E0283, // cannot resolve type
-    E0284, // cannot resolve type
     E0285, // overflow evaluation builtin bounds
-    E0298, // mismatched types between arms
-    E0299, // mismatched types between arms
-    E0300, // unexpanded macro
     E0304, // expected signed integer constant
-    E0305, // expected constant
-    E0311, // thing may not live long enough
-    E0312, // lifetime of reference outlives lifetime of borrowed content
-    E0313, // lifetime of borrowed pointer outlives lifetime of captured variable
-    E0314, // closure outlives stack frame
-    E0315, // cannot invoke closure outside of its lifetime
-    E0316, // nested quantification of lifetimes
-    E0370  // discriminant overflow
+    E0305  // expected constant
 }
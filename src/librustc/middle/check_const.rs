@@ -458,6 +458,11 @@ impl<'a, 'tcx, 'v> Visitor<'v> for CheckCrateVisitor<'a, 'tcx> {
                         if !self.qualif.intersects(ConstQualif::NOT_CONST) {
                             match const_eval::eval_const_expr_partial(self.tcx, ex, None) {
                                 Ok(_) => {}
+                                // Constant evaluation only has 64 bits to work with, so
+                                // this isn't an error in the 128-bit constant itself.
+                                Err(const_eval::ConstEvalErr {
+                                    kind: const_eval::ErrKind::WideIntOutOfRange, ..
+                                }) => {}
                                 Err(msg) => {
                                     span_err!(self.tcx.sess, msg.span, E0020,
                                               "{} in a constant expression",
@@ -376,6 +376,7 @@ pub enum ErrKind {
     ModuloWithOverflow,
     ShiftLeftWithOverflow,
     ShiftRightWithOverflow,
+    WideIntOutOfRange,
     MissingStructField,
     NonConstPath,
     ExpectedConstTuple,
@@ -421,6 +422,8 @@ impl ConstEvalErr {
             ModuloWithOverflow   => "attempted remainder with overflow".into_cow(),
             ShiftLeftWithOverflow => "attempted left shift with overflow".into_cow(),
             ShiftRightWithOverflow => "attempted right shift with overflow".into_cow(),
+            WideIntOutOfRange => "128-bit constant does not fit in 64 bits, which is all \
+                                  constant evaluation supports".into_cow(),
             MissingStructField  => "nonexistent struct field".into_cow(),
             NonConstPath        => "non-constant path in constant expr".into_cow(),
             ExpectedConstTuple => "expected constant tuple".into_cow(),
@@ -437,9 +440,9 @@ pub type EvalResult = Result<ConstVal, ConstEvalErr>;
 pub type CastResult = Result<ConstVal, ErrKind>;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub enum IntTy { I8, I16, I32, I64 }
+pub enum IntTy { I8, I16, I32, I64, I128 }
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub enum UintTy { U8, U16, U32, U64 }
+pub enum UintTy { U8, U16, U32, U64, U128 }
 
 impl IntTy {
     pub fn from(tcx: &ty::ctxt, t: ast::IntTy) -> IntTy {
@@ -454,6 +457,7 @@ impl IntTy {
             ast::TyI16 => IntTy::I16,
             ast::TyI32 => IntTy::I32,
             ast::TyI64 => IntTy::I64,
+            ast::TyI128 => IntTy::I128,
         }
     }
 }
@@ -471,6 +475,7 @@ impl UintTy {
             ast::TyU16 => UintTy::U16,
             ast::TyU32 => UintTy::U32,
             ast::TyU64 => UintTy::U64,
+            ast::TyU128 => UintTy::U128,
         }
     }
 }
@@ -496,6 +501,20 @@ macro_rules! signal {
 // such a case; we just do 64-bit arithmetic and assume that later
 // passes will do it again with the type information, and thus do the
 // overflow checks then.
+//
+// Constant values are only 64 bits wide, so `i128` and `u128` arithmetic is
+// also done in 64 bits. A result which doesn't fit there is not necessarily
+// an overflow of the 128-bit type, so it signals `WideIntOutOfRange` instead.
+
+macro_rules! signal_overflow {
+    ($e:expr, $opt_ety:expr, $wide:path, $exn:expr) => {
+        if $opt_ety == Some($wide) {
+            signal!($e, WideIntOutOfRange)
+        } else {
+            signal!($e, $exn)
+        }
+    }
+}
 
 pub fn const_int_checked_neg<'a>(
     a: i64, e: &'a Expr, opt_ety: Option<IntTy>) -> EvalResult {
@@ -507,12 +526,12 @@ pub fn const_int_checked_neg<'a>(
         Some(IntTy::I8) =>  (-(i8::MAX as i64), -(i8::MIN as i64)),
         Some(IntTy::I16) => (-(i16::MAX as i64), -(i16::MIN as i64)),
         Some(IntTy::I32) => (-(i32::MAX as i64), -(i32::MIN as i64)),
-        None | Some(IntTy::I64) => (-i64::MAX, -(i64::MIN+1)),
+        None | Some(IntTy::I64) | Some(IntTy::I128) => (-i64::MAX, -(i64::MIN+1)),
     };
 
     let oflo = a < min || a > max;
     if oflo {
-        signal!(e, NegateWithOverflow(a));
+        signal_overflow!(e, opt_ety, IntTy::I128, NegateWithOverflow(a));
     } else {
         Ok(Int(-a))
     }
@@ -524,21 +543,23 @@ pub fn const_uint_checked_neg<'a>(
     Ok(Uint((!a).wrapping_add(1)))
 }
 
-fn const_uint_not(a: u64, opt_ety: Option<UintTy>) -> ConstVal {
+fn const_uint_not<'a>(a: u64, e: &'a Expr, opt_ety: Option<UintTy>) -> EvalResult {
     let mask = match opt_ety {
         Some(UintTy::U8) => u8::MAX as u64,
         Some(UintTy::U16) => u16::MAX as u64,
         Some(UintTy::U32) => u32::MAX as u64,
         None | Some(UintTy::U64) => u64::MAX,
+        // the upper 64 bits of the result are all set
+        Some(UintTy::U128) => signal!(e, WideIntOutOfRange),
     };
-    Uint(!a & mask)
+    Ok(Uint(!a & mask))
 }
 
 macro_rules! overflow_checking_body {
     ($a:ident, $b:ident, $ety:ident, $overflowing_op:ident,
      lhs: $to_8_lhs:ident $to_16_lhs:ident $to_32_lhs:ident,
      rhs: $to_8_rhs:ident $to_16_rhs:ident $to_32_rhs:ident $to_64_rhs:ident,
-     $EnumTy:ident $T8: ident $T16: ident $T32: ident $T64: ident,
+     $EnumTy:ident $T8: ident $T16: ident $T32: ident $T64: ident $T128: ident,
      $result_type: ident) => { {
         let (a,b,opt_ety) = ($a,$b,$ety);
         match opt_ety {
@@ -563,7 +584,7 @@ macro_rules! overflow_checking_body {
                 }
                 (None, _) | (_, None) => (0, true)
             },
-            None | Some($EnumTy::$T64) => match b.$to_64_rhs() {
+            None | Some($EnumTy::$T64) | Some($EnumTy::$T128) => match b.$to_64_rhs() {
                 Some(b) => a.$overflowing_op(b),
                 None => (0, true),
             }
@@ -576,7 +597,7 @@ macro_rules! int_arith_body {
         overflow_checking_body!(
             $a, $b, $ety, $overflowing_op,
             lhs: to_i8 to_i16 to_i32,
-            rhs: to_i8 to_i16 to_i32 to_i64, IntTy I8 I16 I32 I64, i64)
+            rhs: to_i8 to_i16 to_i32 to_i64, IntTy I8 I16 I32 I64 I128, i64)
     }
 }
 
@@ -585,7 +606,7 @@ macro_rules! uint_arith_body {
         overflow_checking_body!(
             $a, $b, $ety, $overflowing_op,
             lhs: to_u8 to_u16 to_u32,
-            rhs: to_u8 to_u16 to_u32 to_u64, UintTy U8 U16 U32 U64, u64)
+            rhs: to_u8 to_u16 to_u32 to_u64, UintTy U8 U16 U32 U64 U128, u64)
     }
 }
 
@@ -594,7 +615,7 @@ macro_rules! int_shift_body {
         overflow_checking_body!(
             $a, $b, $ety, $overflowing_op,
             lhs: to_i8 to_i16 to_i32,
-            rhs: to_u32 to_u32 to_u32 to_u32, IntTy I8 I16 I32 I64, i64)
+            rhs: to_u32 to_u32 to_u32 to_u32, IntTy I8 I16 I32 I64 I128, i64)
     }
 }
 
@@ -603,12 +624,12 @@ macro_rules! uint_shift_body {
         overflow_checking_body!(
             $a, $b, $ety, $overflowing_op,
             lhs: to_u8 to_u16 to_u32,
-            rhs: to_u32 to_u32 to_u32 to_u32, UintTy U8 U16 U32 U64, u64)
+            rhs: to_u32 to_u32 to_u32 to_u32, UintTy U8 U16 U32 U64 U128, u64)
     }
 }
 
 macro_rules! pub_fn_checked_op {
-    {$fn_name:ident ($a:ident : $a_ty:ty, $b:ident : $b_ty:ty,.. $WhichTy:ident) {
+    {$fn_name:ident ($a:ident : $a_ty:ty, $b:ident : $b_ty:ty,.. $WhichTy:ident::$Wide:ident) {
         $ret_oflo_body:ident $overflowing_op:ident
             $const_ty:ident $signal_exn:expr
     }} => {
@@ -617,20 +638,24 @@ macro_rules! pub_fn_checked_op {
                             e: &'a Expr,
                             opt_ety: Option<$WhichTy>) -> EvalResult {
             let (ret, oflo) = $ret_oflo_body!($a, $b, opt_ety, $overflowing_op);
-            if !oflo { Ok($const_ty(ret)) } else { signal!(e, $signal_exn) }
+            if !oflo {
+                Ok($const_ty(ret))
+            } else {
+                signal_overflow!(e, opt_ety, $WhichTy::$Wide, $signal_exn)
+            }
         }
     }
 }
 
-pub_fn_checked_op!{ const_int_checked_add(a: i64, b: i64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_add(a: i64, b: i64,.. IntTy::I128) {
            int_arith_body overflowing_add Int AddiWithOverflow(a, b)
 }}
 
-pub_fn_checked_op!{ const_int_checked_sub(a: i64, b: i64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_sub(a: i64, b: i64,.. IntTy::I128) {
            int_arith_body overflowing_sub Int SubiWithOverflow(a, b)
 }}
 
-pub_fn_checked_op!{ const_int_checked_mul(a: i64, b: i64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_mul(a: i64, b: i64,.. IntTy::I128) {
            int_arith_body overflowing_mul Int MuliWithOverflow(a, b)
 }}
 
@@ -638,41 +663,49 @@ pub fn const_int_checked_div<'a>(
     a: i64, b: i64, e: &'a Expr, opt_ety: Option<IntTy>) -> EvalResult {
     if b == 0 { signal!(e, DivideByZero); }
     let (ret, oflo) = int_arith_body!(a, b, opt_ety, overflowing_div);
-    if !oflo { Ok(Int(ret)) } else { signal!(e, DivideWithOverflow) }
+    if !oflo {
+        Ok(Int(ret))
+    } else {
+        signal_overflow!(e, opt_ety, IntTy::I128, DivideWithOverflow)
+    }
 }
 
 pub fn const_int_checked_rem<'a>(
     a: i64, b: i64, e: &'a Expr, opt_ety: Option<IntTy>) -> EvalResult {
     if b == 0 { signal!(e, ModuloByZero); }
     let (ret, oflo) = int_arith_body!(a, b, opt_ety, overflowing_rem);
-    if !oflo { Ok(Int(ret)) } else { signal!(e, ModuloWithOverflow) }
+    if !oflo {
+        Ok(Int(ret))
+    } else {
+        signal_overflow!(e, opt_ety, IntTy::I128, ModuloWithOverflow)
+    }
 }
 
-pub_fn_checked_op!{ const_int_checked_shl(a: i64, b: i64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_shl(a: i64, b: i64,.. IntTy::I128) {
            int_shift_body overflowing_shl Int ShiftLeftWithOverflow
 }}
 
-pub_fn_checked_op!{ const_int_checked_shl_via_uint(a: i64, b: u64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_shl_via_uint(a: i64, b: u64,.. IntTy::I128) {
            int_shift_body overflowing_shl Int ShiftLeftWithOverflow
 }}
 
-pub_fn_checked_op!{ const_int_checked_shr(a: i64, b: i64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_shr(a: i64, b: i64,.. IntTy::I128) {
            int_shift_body overflowing_shr Int ShiftRightWithOverflow
 }}
 
-pub_fn_checked_op!{ const_int_checked_shr_via_uint(a: i64, b: u64,.. IntTy) {
+pub_fn_checked_op!{ const_int_checked_shr_via_uint(a: i64, b: u64,.. IntTy::I128) {
            int_shift_body overflowing_shr Int ShiftRightWithOverflow
 }}
 
-pub_fn_checked_op!{ const_uint_checked_add(a: u64, b: u64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_add(a: u64, b: u64,.. UintTy::U128) {
            uint_arith_body overflowing_add Uint AdduWithOverflow(a, b)
 }}
 
-pub_fn_checked_op!{ const_uint_checked_sub(a: u64, b: u64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_sub(a: u64, b: u64,.. UintTy::U128) {
            uint_arith_body overflowing_sub Uint SubuWithOverflow(a, b)
 }}
 
-pub_fn_checked_op!{ const_uint_checked_mul(a: u64, b: u64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_mul(a: u64, b: u64,.. UintTy::U128) {
            uint_arith_body overflowing_mul Uint MuluWithOverflow(a, b)
 }}
 
@@ -690,19 +723,19 @@ pub fn const_uint_checked_rem<'a>(
     if !oflo { Ok(Uint(ret)) } else { signal!(e, ModuloWithOverflow) }
 }
 
-pub_fn_checked_op!{ const_uint_checked_shl(a: u64, b: u64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_shl(a: u64, b: u64,.. UintTy::U128) {
            uint_shift_body overflowing_shl Uint ShiftLeftWithOverflow
 }}
 
-pub_fn_checked_op!{ const_uint_checked_shl_via_int(a: u64, b: i64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_shl_via_int(a: u64, b: i64,.. UintTy::U128) {
            uint_shift_body overflowing_shl Uint ShiftLeftWithOverflow
 }}
 
-pub_fn_checked_op!{ const_uint_checked_shr(a: u64, b: u64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_shr(a: u64, b: u64,.. UintTy::U128) {
            uint_shift_body overflowing_shr Uint ShiftRightWithOverflow
 }}
 
-pub_fn_checked_op!{ const_uint_checked_shr_via_int(a: u64, b: i64,.. UintTy) {
+pub_fn_checked_op!{ const_uint_checked_shr_via_int(a: u64, b: i64,.. UintTy::U128) {
            uint_shift_body overflowing_shr Uint ShiftRightWithOverflow
 }}
 
@@ -764,7 +797,7 @@ pub fn eval_const_expr_with_substs<'tcx, S>(tcx: &ty::ctxt<'tcx>,
       ast::ExprUnary(ast::UnNot, ref inner) => {
         match try!(eval_const_expr_partial(tcx, &**inner, ety)) {
           Int(i) => Int(!i),
-          Uint(i) => const_uint_not(i, expr_uint_type),
+          Uint(i) => try!(const_uint_not(i, e, expr_uint_type)),
           Bool(b) => Bool(!b),
           Str(_) => signal!(e, NotOnString),
           Float(_) => signal!(e, NotOnFloat),
@@ -951,7 +984,12 @@ pub fn eval_const_expr_with_substs<'tcx, S>(tcx: &ty::ctxt<'tcx>,
           try!(eval_const_expr_partial(tcx, const_expr, ety))
       }
       ast::ExprLit(ref lit) => {
-          lit_to_const(&**lit, ety)
+          match lit.node {
+              ast::LitInt(n, _) if expr_int_type == Some(IntTy::I128) && n > i64::MAX as u64 => {
+                  signal!(e, WideIntOutOfRange)
+              }
+              _ => lit_to_const(&**lit, ety)
+          }
       }
       ast::ExprParen(ref e) => try!(eval_const_expr_partial(tcx, &**e, ety)),
       ast::ExprBlock(ref block) => {
@@ -1106,11 +1144,19 @@ fn cast_const<'tcx>(tcx: &ty::ctxt<'tcx>, val: ConstVal, ty: Ty) -> CastResult {
         ty::TyInt(ast::TyI16) => convert_val!(i16, Int, i64),
         ty::TyInt(ast::TyI32) => convert_val!(i32, Int, i64),
         ty::TyInt(ast::TyI64) => convert_val!(i64, Int, i64),
+        ty::TyInt(ast::TyI128) => match val {
+            Uint(u) if u > i64::MAX as u64 => Err(ErrKind::WideIntOutOfRange),
+            _ => convert_val!(i64, Int, i64),
+        },
 
         ty::TyUint(ast::TyU8) => convert_val!(u8, Uint, u64),
         ty::TyUint(ast::TyU16) => convert_val!(u16, Uint, u64),
         ty::TyUint(ast::TyU32) => convert_val!(u32, Uint, u64),
         ty::TyUint(ast::TyU64) => convert_val!(u64, Uint, u64),
+        ty::TyUint(ast::TyU128) => match val {
+            Int(i) if i < 0 => Err(ErrKind::WideIntOutOfRange),
+            _ => convert_val!(u64, Uint, u64),
+        },
 
         ty::TyFloat(ast::TyF32) => convert_val!(f32, Float, f64),
         ty::TyFloat(ast::TyF64) => convert_val!(f64, Float, f64),
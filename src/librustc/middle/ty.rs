@@ -226,11 +226,13 @@ impl IntTypeExt for attr::IntType {
             SignedInt(ast::TyI16)     => cx.types.i16,
             SignedInt(ast::TyI32)     => cx.types.i32,
             SignedInt(ast::TyI64)     => cx.types.i64,
+            SignedInt(ast::TyI128)    => cx.types.i128,
             SignedInt(ast::TyIs)   => cx.types.isize,
             UnsignedInt(ast::TyU8)    => cx.types.u8,
             UnsignedInt(ast::TyU16)   => cx.types.u16,
             UnsignedInt(ast::TyU32)   => cx.types.u32,
             UnsignedInt(ast::TyU64)   => cx.types.u64,
+            UnsignedInt(ast::TyU128)  => cx.types.u128,
             UnsignedInt(ast::TyUs) => cx.types.usize,
         }
     }
@@ -246,8 +248,10 @@ impl IntTypeExt for attr::IntType {
             UnsignedInt(ast::TyU32) => val.to_u32() .map(|v| v as Disr),
             UnsignedInt(ast::TyU64) => val.to_u64() .map(|v| v as Disr),
 
-            UnsignedInt(ast::TyUs) |
-            SignedInt(ast::TyIs) => unreachable!(),
+            // `attr::find_repr_attrs` never produces a 128-bit repr, since
+            // discriminants (`Disr`) are only 64 bits wide.
+            UnsignedInt(ast::TyUs) | UnsignedInt(ast::TyU128) |
+            SignedInt(ast::TyIs) | SignedInt(ast::TyI128) => unreachable!(),
         }
     }
 
@@ -262,8 +266,8 @@ impl IntTypeExt for attr::IntType {
             UnsignedInt(ast::TyU32) => val.to_u32() .map(|v| v as Disr),
             UnsignedInt(ast::TyU64) => val.to_u64() .map(|v| v as Disr),
 
-            UnsignedInt(ast::TyUs) |
-            SignedInt(ast::TyIs) => unreachable!(),
+            UnsignedInt(ast::TyUs) | UnsignedInt(ast::TyU128) |
+            SignedInt(ast::TyIs) | SignedInt(ast::TyI128) => unreachable!(),
         }
     }
 
@@ -285,8 +289,8 @@ impl IntTypeExt for attr::IntType {
             UnsignedInt(ast::TyU32) => add1!(val.to_u32()),
             UnsignedInt(ast::TyU64) => add1!(Some(val)),
 
-            UnsignedInt(ast::TyUs) |
-            SignedInt(ast::TyIs) => unreachable!(),
+            UnsignedInt(ast::TyUs) | UnsignedInt(ast::TyU128) |
+            SignedInt(ast::TyIs) | SignedInt(ast::TyI128) => unreachable!(),
         }
     }
 
@@ -304,8 +308,8 @@ impl IntTypeExt for attr::IntType {
             UnsignedInt(ast::TyU32) => format!("{}", val as u32),
             UnsignedInt(ast::TyU64) => format!("{}", val as u64),
 
-            UnsignedInt(ast::TyUs) |
-            SignedInt(ast::TyIs) => unreachable!(),
+            UnsignedInt(ast::TyUs) | UnsignedInt(ast::TyU128) |
+            SignedInt(ast::TyIs) | SignedInt(ast::TyI128) => unreachable!(),
         }
     }
 
@@ -324,8 +328,8 @@ impl IntTypeExt for attr::IntType {
             UnsignedInt(ast::TyU32) => add1!(val as u32),
             UnsignedInt(ast::TyU64) => add1!(val as u64),
 
-            UnsignedInt(ast::TyUs) |
-            SignedInt(ast::TyIs) => unreachable!(),
+            UnsignedInt(ast::TyUs) | UnsignedInt(ast::TyU128) |
+            SignedInt(ast::TyIs) | SignedInt(ast::TyI128) => unreachable!(),
         }
     }
 }
@@ -803,11 +807,13 @@ pub struct CommonTypes<'tcx> {
     pub i16: Ty<'tcx>,
     pub i32: Ty<'tcx>,
     pub i64: Ty<'tcx>,
+    pub i128: Ty<'tcx>,
     pub usize: Ty<'tcx>,
     pub u8: Ty<'tcx>,
     pub u16: Ty<'tcx>,
     pub u32: Ty<'tcx>,
     pub u64: Ty<'tcx>,
+    pub u128: Ty<'tcx>,
     pub f32: Ty<'tcx>,
     pub f64: Ty<'tcx>,
     pub err: Ty<'tcx>,
@@ -3193,11 +3199,13 @@ impl<'tcx> CommonTypes<'tcx> {
             i16: mk(TyInt(ast::TyI16)),
             i32: mk(TyInt(ast::TyI32)),
             i64: mk(TyInt(ast::TyI64)),
+            i128: mk(TyInt(ast::TyI128)),
             usize: mk(TyUint(ast::TyUs)),
             u8: mk(TyUint(ast::TyU8)),
             u16: mk(TyUint(ast::TyU16)),
             u32: mk(TyUint(ast::TyU32)),
             u64: mk(TyUint(ast::TyU64)),
+            u128: mk(TyUint(ast::TyU128)),
             f32: mk(TyFloat(ast::TyF32)),
             f64: mk(TyFloat(ast::TyF64)),
         }
@@ -3585,6 +3593,7 @@ impl<'tcx> ctxt<'tcx> {
             ast::TyI16  => self.types.i16,
             ast::TyI32  => self.types.i32,
             ast::TyI64  => self.types.i64,
+            ast::TyI128 => self.types.i128,
         }
     }
 
@@ -3595,6 +3604,7 @@ impl<'tcx> ctxt<'tcx> {
             ast::TyU16  => self.types.u16,
             ast::TyU32  => self.types.u32,
             ast::TyU64  => self.types.u64,
+            ast::TyU128 => self.types.u128,
         }
     }
 
@@ -15,7 +15,8 @@ use session::Session;
 
 use syntax::ext::base::{SyntaxExtension, NamedSyntaxExtension, NormalTT};
 use syntax::ext::base::{IdentTT, Decorator, Modifier, MultiModifier, MultiDecorator};
-use syntax::ext::base::{MacroExpanderFn, MacroRulesTT};
+use syntax::ext::base::{MacroExpanderFn, MacroRulesTT, MultiItemDecorator};
+use syntax::ext::deriving;
 use syntax::codemap::Span;
 use syntax::parse::token;
 use syntax::ptr::P;
@@ -58,6 +59,9 @@ pub struct Registry<'a> {
 
     #[doc(hidden)]
     pub attributes: Vec<(String, AttributeType)>,
+
+    #[doc(hidden)]
+    pub custom_derives: Vec<String>,
 }
 
 impl<'a> Registry<'a> {
@@ -72,6 +76,7 @@ impl<'a> Registry<'a> {
             lint_groups: HashMap::new(),
             llvm_passes: vec!(),
             attributes: vec!(),
+            custom_derives: vec!(),
         }
     }
 
@@ -119,6 +124,28 @@ impl<'a> Registry<'a> {
                                        NormalTT(Box::new(expander), None, false));
     }
 
+    /// Register an implementation of `#[derive(name)]`.
+    ///
+    /// `expander` is called for every item with `name` in its `derive`
+    /// attribute and pushes the items (usually an impl) to generate for it.
+    /// Unlike a `derive_*` attribute registered through
+    /// `register_syntax_extension`, this doesn't require the crate using it to
+    /// enable the `custom_derive` feature. The `TraitDef` machinery that
+    /// implements the built-in derives is available to plugins in
+    /// `syntax::ext::deriving::generic`.
+    pub fn register_custom_derive(&mut self, name: &str,
+                                  expander: Box<MultiItemDecorator + 'static>) {
+        if deriving::is_builtin_trait(name) {
+            self.sess.span_err(self.krate_span,
+                               &format!("plugin tried to register a custom derive \
+                                         for the built-in trait `{}`", name));
+            return;
+        }
+        self.register_syntax_extension(token::intern(&format!("derive_{}", name)),
+                                       MultiDecorator(expander));
+        self.custom_derives.push(name.to_owned());
+    }
+
     /// Register a compiler lint pass.
     pub fn register_lint_pass(&mut self, lint_pass: LintPassObject) {
         self.lint_passes.push(lint_pass);
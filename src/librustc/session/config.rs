@@ -109,6 +109,7 @@ pub struct Options {
     pub prints: Vec<PrintRequest>,
     pub cg: CodegenOptions,
     pub color: ColorConfig,
+    pub error_format: ErrorOutputType,
     pub show_span: Option<String>,
     pub externs: HashMap<String, Vec<String>>,
     pub crate_name: Option<String>,
@@ -120,6 +121,14 @@ pub struct Options {
     pub unstable_features: UnstableFeatures
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ErrorOutputType {
+    /// Colored, human readable text on stderr (the default).
+    HumanReadable,
+    /// One JSON object per diagnostic on stderr.
+    Json,
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub enum PrintRequest {
     FileNames,
@@ -219,6 +228,7 @@ pub fn basic_options() -> Options {
         prints: Vec::new(),
         cg: basic_codegen_options(),
         color: Auto,
+        error_format: ErrorOutputType::HumanReadable,
         show_span: None,
         externs: HashMap::new(),
         crate_name: None,
@@ -463,7 +473,7 @@ options! {CodegenOptions, CodegenSetter, basic_codegen_options,
          build_codegen_options, "C", "codegen",
          CG_OPTIONS, cg_type_desc, cgsetters,
     ar: Option<String> = (None, parse_opt_string,
-        "tool to assemble archives with"),
+        "this option is deprecated and does nothing"),
     linker: Option<String> = (None, parse_opt_string,
         "system linker to link outputs with"),
     link_args: Option<Vec<String>> = (None, parse_opt_list,
@@ -561,6 +571,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
         "List the symbols defined by a library crate"),
     save_analysis: bool = (false, parse_bool,
         "Write syntax and type analysis information in addition to normal output"),
+    save_analysis_json: bool = (false, parse_bool,
+        "Write syntax and type analysis information as JSON in addition to normal output"),
     print_move_fragments: bool = (false, parse_bool,
         "Print out move-fragment data for every fn"),
     flowgraph_print_loans: bool = (false, parse_bool,
@@ -594,6 +606,12 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "Force drop flag checks on or off"),
     trace_macros: bool = (false, parse_bool,
           "For every macro invocation, print its name and arguments"),
+    incremental: Option<String> = (None, parse_opt_string,
+          "Skip translating codegen units which are unchanged since the \
+           previous session, reusing their object files cached in the given \
+           directory (requires -C metadata)"),
+    incremental_info: bool = (false, parse_bool,
+          "Print which codegen units were reused from the previous session"),
 }
 
 pub fn default_lib_output() -> CrateType {
@@ -813,6 +831,9 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
             auto   = colorize, if output goes to a tty (default);
             always = always colorize output;
             never  = never colorize output", "auto|always|never"),
+        opt::opt_u("", "error-format", "How errors and other messages are produced:
+            human = human readable output (default);
+            json  = one JSON object per diagnostic", "human|json"),
 
         opt::flagopt_u("", "pretty",
                    "Pretty-print the input instead of compiling;
@@ -981,6 +1002,18 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         }
     }).collect::<Vec<_>>();
 
+    if cg.ar.is_some() {
+        early_warn("-C ar is deprecated and ignored, archives are now written \
+                    without an external tool");
+    }
+
+    // Symbol names don't include the crate's hash in incremental mode, so
+    // something else has to tell different builds of the crate apart.
+    if debugging_opts.incremental.is_some() && cg.metadata.is_empty() {
+        early_error("-Z incremental requires -C metadata, to distinguish the \
+                     symbols of different builds of the crate");
+    }
+
     if !cg.remark.is_empty() && debuginfo == NoDebugInfo {
         early_warn("-C remark will not show source locations without \
                     --debuginfo");
@@ -1000,6 +1033,19 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         }
     };
 
+    let error_format = match matches.opt_str("error-format").as_ref().map(|s| &s[..]) {
+        Some("human") => ErrorOutputType::HumanReadable,
+        Some("json") => ErrorOutputType::Json,
+
+        None => ErrorOutputType::HumanReadable,
+
+        Some(arg) => {
+            early_error(&format!("argument for --error-format must be human or \
+                                 json (instead was `{}`)",
+                                arg))
+        }
+    };
+
     let mut externs = HashMap::new();
     for arg in &matches.opt_strs("extern") {
         let mut parts = arg.splitn(2, '=');
@@ -1039,6 +1085,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         prints: prints,
         cg: cg,
         color: color,
+        error_format: error_format,
         show_span: None,
         externs: externs,
         crate_name: crate_name,
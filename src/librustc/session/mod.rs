@@ -369,8 +369,16 @@ pub fn build_session(sopts: config::Options,
         .unwrap_or(true);
 
     let codemap = codemap::CodeMap::new();
+    let emitter: Box<Emitter + Send> = match sopts.error_format {
+        config::ErrorOutputType::HumanReadable => {
+            Box::new(diagnostic::EmitterWriter::stderr(sopts.color, Some(registry)))
+        }
+        config::ErrorOutputType::Json => {
+            Box::new(diagnostic::json::JsonEmitter::stderr(Some(registry)))
+        }
+    };
     let diagnostic_handler =
-        diagnostic::Handler::new(sopts.color, Some(registry), can_print_warnings);
+        diagnostic::Handler::with_emitter(can_print_warnings, emitter);
     let span_diagnostic_handler =
         diagnostic::SpanHandler::new(diagnostic_handler, codemap);
 
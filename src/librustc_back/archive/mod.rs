@@ -0,0 +1,498 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A helper class for dealing with static archives
+//!
+//! Archives are read and written in-process rather than by running `ar`, so
+//! building an rlib or a staticlib doesn't depend on any external tools.
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use syntax::diagnostic::Handler as ErrorHandler;
+
+use self::reader::ArchiveReader;
+
+pub mod reader;
+pub mod writer;
+mod symbols;
+
+pub const METADATA_FILENAME: &'static str = "rust.metadata.bin";
+
+const MAGIC: &'static [u8] = b"!<arch>\n";
+const HEADER_SIZE: usize = 60;
+
+pub struct ArchiveConfig<'a> {
+    pub handler: &'a ErrorHandler,
+    pub dst: PathBuf,
+    pub lib_search_paths: Vec<PathBuf>,
+    pub slib_prefix: String,
+    pub slib_suffix: String,
+    /// The format new archives are written in.
+    pub kind: ArchiveKind,
+}
+
+/// The flavors of archive format which can be written.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ArchiveKind {
+    /// The System V/GNU format, with long names kept in a `//` member and a
+    /// big-endian symbol table in a `/` member.
+    Gnu,
+    /// The BSD format used on OSX, with long names stored inline and a
+    /// little-endian `__.SYMDEF` symbol table.
+    Bsd,
+}
+
+/// A file within an archive.
+pub struct Member<'a> {
+    pub name: &'a str,
+    pub data: &'a [u8],
+}
+
+pub struct Archive<'a> {
+    config: ArchiveConfig<'a>,
+}
+
+/// Helper for adding many files to an archive, which is written out in one go
+/// by `build`.
+#[must_use = "must call build() to finish building the archive"]
+pub struct ArchiveBuilder<'a> {
+    archive: Archive<'a>,
+    /// Name and contents of each member that should be added to the archive.
+    members: Vec<(String, Vec<u8>)>,
+    should_update_symbols: bool,
+}
+
+pub fn find_library(name: &str, osprefix: &str, ossuffix: &str,
+                    search_paths: &[PathBuf],
+                    handler: &ErrorHandler) -> PathBuf {
+    // On Windows, static libraries sometimes show up as libfoo.a and other
+    // times show up as foo.lib
+    let oslibname = format!("{}{}{}", osprefix, name, ossuffix);
+    let unixlibname = format!("lib{}.a", name);
+
+    for path in search_paths {
+        debug!("looking for {} inside {:?}", name, path);
+        let test = path.join(&oslibname[..]);
+        if test.exists() { return test }
+        if oslibname != unixlibname {
+            let test = path.join(&unixlibname[..]);
+            if test.exists() { return test }
+        }
+    }
+    handler.fatal(&format!("could not find native static library `{}`, \
+                           perhaps an -L flag is missing?",
+                          name));
+}
+
+impl<'a> Archive<'a> {
+    fn new(config: ArchiveConfig<'a>) -> Archive<'a> {
+        Archive { config: config }
+    }
+
+    /// Opens an existing static archive
+    pub fn open(config: ArchiveConfig<'a>) -> Archive<'a> {
+        let archive = Archive::new(config);
+        assert!(archive.config.dst.exists());
+        archive
+    }
+
+    /// Removes a file from this archive
+    pub fn remove_file(&mut self, file: &str) {
+        let data = self.read();
+        let result = ArchiveReader::new(&data).and_then(|archive| {
+            let members = archive.members().iter().filter(|m| m.name != file)
+                                 .map(|m| Member { name: m.name, data: m.data })
+                                 .collect::<Vec<_>>();
+            self.write(archive.kind(), &members, archive.has_symbol_table())
+        });
+        if let Err(e) = result {
+            self.config.handler.fatal(&format!("failed to remove {} from {}: {}",
+                                               file, self.config.dst.display(),
+                                               e));
+        }
+    }
+
+    /// Lists all files in an archive
+    pub fn files(&self) -> Vec<String> {
+        let data = match read_file(&self.config.dst) {
+            Ok(data) => data,
+            Err(..) => return Vec::new(),
+        };
+        match ArchiveReader::new(&data) {
+            Ok(archive) => archive.members().iter()
+                                  .map(|m| m.name.to_string())
+                                  .collect(),
+            Err(..) => Vec::new(),
+        }
+    }
+
+    /// Creates an `ArchiveBuilder` for adding files to this archive.
+    pub fn extend(self) -> ArchiveBuilder<'a> {
+        let data = self.read();
+        let mut builder = ArchiveBuilder::new(self);
+        match ArchiveReader::new(&data) {
+            Ok(archive) => {
+                builder.members.extend(archive.members().iter().map(|m| {
+                    (m.name.to_string(), m.data.to_vec())
+                }));
+                builder.should_update_symbols = archive.has_symbol_table();
+            }
+            Err(e) => {
+                let config = &builder.archive.config;
+                config.handler.fatal(&format!("failed to read archive {}: {}",
+                                              config.dst.display(), e));
+            }
+        }
+        builder
+    }
+
+    fn read(&self) -> Vec<u8> {
+        match read_file(&self.config.dst) {
+            Ok(data) => data,
+            Err(e) => {
+                self.config.handler.fatal(&format!("failed to read archive {}: {}",
+                                                   self.config.dst.display(), e));
+            }
+        }
+    }
+
+    fn write(&self, kind: ArchiveKind, members: &[Member],
+             symbols: bool) -> io::Result<()> {
+        let mut w = BufWriter::new(try!(File::create(&self.config.dst)));
+        try!(writer::write_archive(&mut w, kind, members, symbols));
+        w.flush()
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    try!(try!(File::open(path)).read_to_end(&mut data));
+    Ok(data)
+}
+
+impl<'a> ArchiveBuilder<'a> {
+    fn new(archive: Archive<'a>) -> ArchiveBuilder<'a> {
+        ArchiveBuilder {
+            archive: archive,
+            members: vec![],
+            should_update_symbols: false,
+        }
+    }
+
+    /// Create a new static archive, ready for adding files.
+    pub fn create(config: ArchiveConfig<'a>) -> ArchiveBuilder<'a> {
+        let archive = Archive::new(config);
+        ArchiveBuilder::new(archive)
+    }
+
+    /// Adds all of the contents of a native library to this archive. This will
+    /// search in the relevant locations for a library named `name`.
+    pub fn add_native_library(&mut self, name: &str) -> io::Result<()> {
+        let location = find_library(name,
+                                    &self.archive.config.slib_prefix,
+                                    &self.archive.config.slib_suffix,
+                                    &self.archive.config.lib_search_paths,
+                                    self.archive.config.handler);
+        self.add_archive(&location, name, |_| false)
+    }
+
+    /// Adds all of the contents of the rlib at the specified path to this
+    /// archive.
+    ///
+    /// This ignores adding the bytecode from the rlib, and if LTO is enabled
+    /// then the object file also isn't added.
+    pub fn add_rlib(&mut self, rlib: &Path, name: &str,
+                    lto: bool) -> io::Result<()> {
+        // Ignoring obj file starting with the crate name
+        // as simple comparison is not enough - there
+        // might be also an extra name suffix
+        let obj_start = format!("{}", name);
+        let obj_start = &obj_start[..];
+        // Ignoring all bytecode files, no matter of
+        // name
+        let bc_ext = ".bytecode.deflate";
+
+        self.add_archive(rlib, &name[..], |fname: &str| {
+            let skip_obj = lto && fname.starts_with(obj_start)
+                && fname.ends_with(".o");
+            skip_obj || fname.ends_with(bc_ext) || fname == METADATA_FILENAME
+        })
+    }
+
+    /// Adds an arbitrary file to this archive, replacing any existing member
+    /// of the same name.
+    pub fn add_file(&mut self, file: &Path) -> io::Result<()> {
+        let filename = file.file_name().unwrap().to_string_lossy().into_owned();
+        let data = try!(read_file(file));
+        self.members.retain(|&(ref name, _)| *name != filename);
+        self.members.push((filename, data));
+        Ok(())
+    }
+
+    /// Indicate that the next call to `build` should write a symbol table for
+    /// all of the objects in the archive.
+    pub fn update_symbols(&mut self) {
+        self.should_update_symbols = true;
+    }
+
+    /// Combine the provided files, rlibs, and native libraries into a single
+    /// `Archive`.
+    pub fn build(self) -> Archive<'a> {
+        {
+            let members = self.members.iter().map(|&(ref name, ref data)| {
+                Member { name: &name[..], data: &data[..] }
+            }).collect::<Vec<_>>();
+            let config = &self.archive.config;
+            if let Err(e) = self.archive.write(config.kind, &members,
+                                               self.should_update_symbols) {
+                config.handler.fatal(&format!("failed to write archive {}: {}",
+                                              config.dst.display(), e));
+            }
+        }
+        self.archive
+    }
+
+    fn add_archive<F>(&mut self, archive: &Path, name: &str,
+                      mut skip: F) -> io::Result<()>
+        where F: FnMut(&str) -> bool,
+    {
+        let data = try!(read_file(archive));
+        let archive = try!(ArchiveReader::new(&data));
+
+        // Next, we must rename all of the inputs to "guaranteed unique names".
+        // The reason for this renaming is that archives are keyed off the name
+        // of the files, so if two files have the same name they will override
+        // one another in the archive (bad).
+        //
+        // We skip any files explicitly desired for skipping. The reader has
+        // already dropped the symbol table, which gets re-created when we
+        // make a new archive anyway.
+        for file in archive.members() {
+            if skip(file.name) { continue }
+
+            // Archives on unix systems typically do not have slashes in
+            // filenames as the `ar` utility generally only uses the last
+            // component of a path for the filename list in the archive. On
+            // Windows, however, archives assembled with `lib.exe` will preserve
+            // the full path to the file that was placed in the archive,
+            // including path separators.
+            //
+            // The code below is munging paths so it'll go wrong pretty quickly
+            // if there's some unexpected slashes in the filename, so here we
+            // just chop off everything but the filename component. Note that
+            // this can cause duplicate filenames, but that's also handled below
+            // as well.
+            let filename = Path::new(file.name).file_name().unwrap()
+                                               .to_str().unwrap();
+
+            // An archive can contain files of the same name multiple times, so
+            // we need to be sure to not have them overwrite one another when we
+            // add them. Consequently we need to find a truly unique file name
+            // for us!
+            let mut new_filename = String::new();
+            for n in 0.. {
+                let n = if n == 0 {String::new()} else {format!("-{}", n)};
+                new_filename = format!("r{}-{}-{}", n, name, filename);
+
+                // LLDB (as mentioned in back::link) crashes on filenames of
+                // exactly
+                // 16 bytes in length. If we're including an object file with
+                //    exactly 16-bytes of characters, give it some prefix so
+                //    that it's not 16 bytes.
+                new_filename = if new_filename.len() == 16 {
+                    format!("lldb-fix-{}", new_filename)
+                } else {
+                    new_filename
+                };
+
+                let present = self.members.iter().any(|&(ref s, _)| {
+                    *s == new_filename
+                });
+                if !present {
+                    break
+                }
+            }
+            self.members.push((new_filename, file.data.to_vec()));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArchiveKind, Member};
+    use super::reader::ArchiveReader;
+    use super::writer::write_archive;
+
+    /// Builds a minimal little-endian ELF64 relocatable object whose symbol
+    /// table defines each of `defined` and references `undefined`.
+    fn elf_object(defined: &[&str], undefined: &[&str]) -> Vec<u8> {
+        fn push(buf: &mut Vec<u8>, n: u64, width: usize) {
+            for i in 0..width {
+                buf.push((n >> (8 * i)) as u8);
+            }
+        }
+
+        let mut strtab = vec![0];
+        let mut symtab = vec![0; 24];
+        for (i, name) in defined.iter().chain(undefined.iter()).enumerate() {
+            push(&mut symtab, strtab.len() as u64, 4);
+            push(&mut symtab, 0x12, 1); // STB_GLOBAL, STT_FUNC
+            push(&mut symtab, 0, 1);
+            push(&mut symtab, if i < defined.len() {1} else {0}, 2);
+            push(&mut symtab, 0, 16);
+            strtab.push_all(name.as_bytes());
+            strtab.push(0);
+        }
+
+        let symoff = 64 + 3 * 64;
+        let stroff = symoff + symtab.len();
+        let mut obj = b"\x7fELF\x02\x01\x01".to_vec();
+        obj.push_all(&[0; 9]);
+        push(&mut obj, 1, 2);          // e_type = ET_REL
+        push(&mut obj, 62, 2);         // e_machine = EM_X86_64
+        push(&mut obj, 1, 4);          // e_version
+        push(&mut obj, 0, 16);         // e_entry, e_phoff
+        push(&mut obj, 64, 8);         // e_shoff
+        push(&mut obj, 0, 4);          // e_flags
+        push(&mut obj, 64, 2);         // e_ehsize
+        push(&mut obj, 0, 4);          // e_phentsize, e_phnum
+        push(&mut obj, 64, 2);         // e_shentsize
+        push(&mut obj, 3, 2);          // e_shnum
+        push(&mut obj, 0, 2);          // e_shstrndx
+
+        let sections = [(0, 0, 0, 0, 0),
+                        (2, symoff, symtab.len(), 2, 24),
+                        (3, stroff, strtab.len(), 0, 0)];
+        for &(ty, off, size, link, entsize) in &sections {
+            push(&mut obj, 0, 4);
+            push(&mut obj, ty, 4);
+            push(&mut obj, 0, 16);
+            push(&mut obj, off as u64, 8);
+            push(&mut obj, size as u64, 8);
+            push(&mut obj, link, 4);
+            push(&mut obj, 0, 12);
+            push(&mut obj, entsize, 8);
+        }
+        obj.push_all(&symtab);
+        obj.push_all(&strtab);
+        obj
+    }
+
+    fn round_trip(kind: ArchiveKind) {
+        let foo = elf_object(&["foo", "foo_too"], &["bar"]);
+        let bar = elf_object(&["bar"], &["foo"]);
+        let members = [
+            Member { name: "foo.o", data: &foo },
+            Member { name: "a-rather-long-member-name.o", data: &bar },
+            Member { name: "odd.txt", data: b"odd" },
+            Member { name: "rust.metadata.bin", data: b"metadata" },
+        ];
+
+        let mut out = Vec::new();
+        write_archive(&mut out, kind, &members, true).unwrap();
+        let archive = ArchiveReader::new(&out).unwrap();
+        assert_eq!(archive.kind(), kind);
+
+        let read = archive.members();
+        assert_eq!(read.len(), members.len());
+        for (a, b) in read.iter().zip(members.iter()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.data, b.data);
+        }
+
+        let symbols = archive.symbols().unwrap().into_iter().map(|s| {
+            (s.name, s.member)
+        }).collect::<Vec<_>>();
+        assert_eq!(symbols, [(&b"foo"[..], 0), (&b"foo_too"[..], 0),
+                             (&b"bar"[..], 1)]);
+    }
+
+    #[test]
+    fn gnu_round_trip() {
+        round_trip(ArchiveKind::Gnu);
+    }
+
+    #[test]
+    fn bsd_round_trip() {
+        round_trip(ArchiveKind::Bsd);
+    }
+
+    #[test]
+    fn no_symbol_table() {
+        let obj = elf_object(&["foo"], &[]);
+        let members = [Member { name: "foo.o", data: &obj }];
+        for &kind in &[ArchiveKind::Gnu, ArchiveKind::Bsd] {
+            let mut out = Vec::new();
+            write_archive(&mut out, kind, &members, false).unwrap();
+            let archive = ArchiveReader::new(&out).unwrap();
+            assert!(!archive.has_symbol_table());
+            assert_eq!(archive.members().len(), 1);
+        }
+    }
+
+    #[test]
+    fn msvc_linker_members() {
+        // `lib.exe` writes a second `/` member with a little-endian table,
+        // and member names may not be UTF-8.
+        let obj = elf_object(&["foo"], &[]);
+        let mut out = Vec::new();
+        write_archive(&mut out, ArchiveKind::Gnu, &[
+            Member { name: "foo.o", data: &obj },
+        ], true).unwrap();
+        if out.len() % 2 == 1 {
+            out.push(b'\n');
+        }
+        // Appended so that the offsets in the first table stay valid.
+        out.push_all(&member_header(b"/", 4));
+        out.push_all(b"\x01\0\0\0");
+        out.push_all(&member_header(b"\xff\xfe.o/", 2));
+        out.push_all(b"hi");
+
+        let archive = ArchiveReader::new(&out).unwrap();
+        let names = archive.members().iter().map(|m| m.name).collect::<Vec<_>>();
+        assert_eq!(names, ["foo.o"]);
+        let symbols = archive.symbols().unwrap().into_iter().map(|s| {
+            (s.name, s.member)
+        }).collect::<Vec<_>>();
+        assert_eq!(symbols, [(&b"foo"[..], 0)]);
+    }
+
+    fn member_header(name: &[u8], size: usize) -> Vec<u8> {
+        let mut header = name.to_vec();
+        while header.len() < 16 {
+            header.push(b' ');
+        }
+        header.push_all(format!("{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+                                0, 0, 0, 644, size).as_bytes());
+        header
+    }
+
+    #[test]
+    fn deterministic() {
+        let obj = elf_object(&["foo"], &[]);
+        let members = [Member { name: "foo.o", data: &obj }];
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        write_archive(&mut a, ArchiveKind::Gnu, &members, true).unwrap();
+        write_archive(&mut b, ArchiveKind::Gnu, &members, true).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn invalid_archives() {
+        assert!(ArchiveReader::new(b"not an archive").is_err());
+        assert!(ArchiveReader::new(b"!<thin>\n").is_err());
+        assert!(ArchiveReader::new(b"!<arch>\nfoo.o/").is_err());
+    }
+}
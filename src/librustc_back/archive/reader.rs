@@ -0,0 +1,263 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing of GNU and BSD style archives.
+//!
+//! The symbol table is only parsed when asked for, so an archive whose table
+//! can't be understood (such as the second linker member of an MSVC `lib.exe`
+//! archive) can still have its members read.
+
+use std::io;
+use std::str;
+
+use super::{ArchiveKind, Member, MAGIC, HEADER_SIZE};
+
+/// An entry in an archive's symbol table.
+pub struct Symbol<'a> {
+    pub name: &'a [u8],
+    /// The index of the member which defines the symbol.
+    pub member: usize,
+}
+
+/// The parsed contents of an archive held in memory.
+pub struct ArchiveReader<'a> {
+    kind: ArchiveKind,
+    members: Vec<Member<'a>>,
+    /// The offset of each member's header, which is how the symbol table
+    /// refers to them.
+    offsets: Vec<usize>,
+    symbol_table: Option<SymbolTable<'a>>,
+}
+
+#[derive(Clone, Copy)]
+enum SymbolTable<'a> {
+    Gnu(&'a [u8]),
+    Gnu64(&'a [u8]),
+    Bsd(&'a [u8]),
+}
+
+impl<'a> ArchiveReader<'a> {
+    pub fn new(data: &'a [u8]) -> io::Result<ArchiveReader<'a>> {
+        if data.starts_with(b"!<thin>\n") {
+            return Err(invalid("thin archives are not supported"))
+        }
+        if !data.starts_with(MAGIC) {
+            return Err(invalid("not an archive"))
+        }
+
+        let mut kind = None;
+        let mut members = Vec::new();
+        let mut offsets = Vec::new();
+        let mut long_names: &[u8] = &[];
+        let mut symbol_table = None;
+
+        let mut pos = MAGIC.len();
+        while pos < data.len() {
+            if data[pos] == b'\n' {
+                // Stray padding, which some tools leave at the end.
+                pos += 1;
+                continue
+            }
+            if data.len() - pos < HEADER_SIZE {
+                return Err(invalid("truncated archive member header"))
+            }
+            let header = &data[pos..pos + HEADER_SIZE];
+            if &header[58..] != b"`\n" {
+                return Err(invalid("invalid archive member header"))
+            }
+            let size = try!(parse_decimal(&header[48..58]));
+            let start = pos + HEADER_SIZE;
+            if data.len() - start < size {
+                return Err(invalid("truncated archive member"))
+            }
+            let mut body = &data[start..start + size];
+            let raw_name = trim_right(&header[..16], b' ');
+
+            let name = if raw_name == b"/" {
+                // MSVC archives have a second `/` member with a symbol table
+                // in a different layout, which is skipped.
+                kind = Some(ArchiveKind::Gnu);
+                if symbol_table.is_none() {
+                    symbol_table = Some(SymbolTable::Gnu(body));
+                }
+                None
+            } else if raw_name == b"/SYM64/" {
+                kind = Some(ArchiveKind::Gnu);
+                symbol_table = Some(SymbolTable::Gnu64(body));
+                None
+            } else if raw_name == b"//" {
+                kind = Some(ArchiveKind::Gnu);
+                long_names = body;
+                None
+            } else if raw_name.starts_with(b"#1/") {
+                // BSD long names are stored at the start of the data.
+                kind = Some(ArchiveKind::Bsd);
+                let len = try!(parse_decimal(&raw_name[3..]));
+                if len > body.len() {
+                    return Err(invalid("archive member name out of bounds"))
+                }
+                let name = trim_right(&body[..len], 0);
+                body = &body[len..];
+                Some(name)
+            } else if raw_name.len() > 1 && raw_name[0] == b'/' {
+                // A GNU long name, as an offset into the `//` member.
+                kind = Some(ArchiveKind::Gnu);
+                let off = try!(parse_decimal(&raw_name[1..]));
+                if off > long_names.len() {
+                    return Err(invalid("archive member name out of bounds"))
+                }
+                let rest = &long_names[off..];
+                let end = rest.iter().position(|&b| b == b'\n')
+                              .unwrap_or(rest.len());
+                Some(trim_right(&rest[..end], b'/'))
+            } else if raw_name.ends_with(b"/") {
+                kind = Some(ArchiveKind::Gnu);
+                Some(&raw_name[..raw_name.len() - 1])
+            } else {
+                if kind.is_none() {
+                    kind = Some(ArchiveKind::Bsd);
+                }
+                Some(raw_name)
+            };
+
+            match name {
+                Some(name) if name.starts_with(b"__.SYMDEF") => {
+                    symbol_table = Some(SymbolTable::Bsd(body));
+                }
+                Some(name) => {
+                    // Members we can't name can't be extracted or replaced,
+                    // so they are left out like `ar` used to leave them out.
+                    if let Ok(name) = str::from_utf8(name) {
+                        members.push(Member { name: name, data: body });
+                        offsets.push(pos);
+                    }
+                }
+                None => {}
+            }
+
+            pos = start + size;
+            pos += pos & 1;
+        }
+
+        Ok(ArchiveReader {
+            kind: kind.unwrap_or(ArchiveKind::Gnu),
+            members: members,
+            offsets: offsets,
+            symbol_table: symbol_table,
+        })
+    }
+
+    /// The format the archive is in. Archives which give no indication either
+    /// way are considered to be GNU archives.
+    pub fn kind(&self) -> ArchiveKind { self.kind }
+
+    /// The members of the archive, in order, not including the symbol table
+    /// or any other special members.
+    pub fn members(&self) -> &[Member<'a>] { &self.members }
+
+    /// Whether the archive has a symbol table.
+    pub fn has_symbol_table(&self) -> bool { self.symbol_table.is_some() }
+
+    /// Parses the archive's symbol table, which is empty if there is none.
+    pub fn symbols(&self) -> io::Result<Vec<Symbol<'a>>> {
+        match self.symbol_table {
+            Some(table) => parse_symbol_table(table, &self.offsets),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+fn parse_symbol_table<'a>(table: SymbolTable<'a>,
+                          offsets: &[usize]) -> io::Result<Vec<Symbol<'a>>> {
+    // Returns the member index for a header offset, skipping entries which
+    // don't point at a member.
+    let member = |off: u64| offsets.binary_search(&(off as usize)).ok();
+    let mut symbols = Vec::new();
+
+    match table {
+        SymbolTable::Gnu(data) | SymbolTable::Gnu64(data) => {
+            let width = match table { SymbolTable::Gnu64(..) => 8, _ => 4 };
+            let count = try!(read_uint(data, 0, width, true)) as usize;
+            let names_start = match count.checked_add(1)
+                                         .and_then(|n| n.checked_mul(width)) {
+                Some(n) if n <= data.len() => n,
+                _ => return Err(invalid("invalid archive symbol table")),
+            };
+            let mut names = &data[names_start..];
+            for i in 0..count {
+                let off = try!(read_uint(data, (i + 1) * width, width, true));
+                let len = match names.iter().position(|&b| b == 0) {
+                    Some(len) => len,
+                    None => return Err(invalid("invalid archive symbol table")),
+                };
+                if let Some(member) = member(off) {
+                    symbols.push(Symbol { name: &names[..len], member: member });
+                }
+                names = &names[len + 1..];
+            }
+        }
+        SymbolTable::Bsd(data) => {
+            let ranlib_size = try!(read_uint(data, 0, 4, false)) as usize;
+            let strings_start = match ranlib_size.checked_add(8) {
+                Some(n) if n <= data.len() => n,
+                _ => return Err(invalid("invalid archive symbol table")),
+            };
+            let strings_size = try!(read_uint(data, ranlib_size + 4, 4, false)) as usize;
+            if data.len() - strings_start < strings_size {
+                return Err(invalid("invalid archive symbol table"))
+            }
+            let strings = &data[strings_start..strings_start + strings_size];
+            for i in 0..ranlib_size / 8 {
+                let strx = try!(read_uint(data, 4 + i * 8, 4, false)) as usize;
+                let off = try!(read_uint(data, 8 + i * 8, 4, false));
+                if strx >= strings.len() {
+                    return Err(invalid("invalid archive symbol table"))
+                }
+                let name = &strings[strx..];
+                let len = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+                if let Some(member) = member(off) {
+                    symbols.push(Symbol { name: &name[..len], member: member });
+                }
+            }
+        }
+    }
+    Ok(symbols)
+}
+
+fn read_uint(data: &[u8], off: usize, width: usize, big_endian: bool) -> io::Result<u64> {
+    if data.len() < off || data.len() - off < width {
+        return Err(invalid("invalid archive symbol table"))
+    }
+    let bytes = &data[off..off + width];
+    let mut n = 0;
+    for i in 0..width {
+        let b = if big_endian {bytes[i]} else {bytes[width - 1 - i]};
+        n = n << 8 | b as u64;
+    }
+    Ok(n)
+}
+
+fn parse_decimal(field: &[u8]) -> io::Result<usize> {
+    let field = trim_right(field, b' ');
+    str::from_utf8(field).ok().and_then(|s| s.parse().ok()).ok_or_else(|| {
+        invalid("invalid number in archive member header")
+    })
+}
+
+fn trim_right(mut s: &[u8], pad: u8) -> &[u8] {
+    while s.last() == Some(&pad) {
+        s = &s[..s.len() - 1];
+    }
+    s
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
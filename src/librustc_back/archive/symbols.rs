@@ -0,0 +1,256 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Extraction of the symbols an object file defines, for an archive's symbol
+//! table.
+//!
+//! This understands just enough of ELF, Mach-O and COFF to walk their symbol
+//! tables. Anything else, or anything malformed, is treated as defining no
+//! symbols, which is what `ar` does for members it doesn't recognize.
+
+macro_rules! try_opt {
+    ($e:expr) => (match $e { Some(e) => e, None => return None })
+}
+
+/// Returns the names of the external symbols defined by the object file
+/// `data`, in the order they appear in its symbol table.
+pub fn defined_symbols(data: &[u8]) -> Vec<&[u8]> {
+    let symbols = if data.starts_with(b"\x7fELF") {
+        elf_symbols(data)
+    } else if is_mach_o(data) {
+        mach_o_symbols(data)
+    } else if is_coff(data) {
+        coff_symbols(data)
+    } else {
+        None
+    };
+    symbols.unwrap_or(Vec::new())
+}
+
+/// Bounds-checked reads of integers at a given offset.
+#[derive(Clone, Copy)]
+struct Bytes<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Bytes<'a> {
+    fn slice(&self, off: usize, len: usize) -> Option<&'a [u8]> {
+        match off.checked_add(len) {
+            Some(end) if end <= self.data.len() => Some(&self.data[off..end]),
+            _ => None,
+        }
+    }
+
+    fn uint(&self, off: usize, len: usize) -> Option<u64> {
+        self.slice(off, len).map(|bytes| {
+            let mut n = 0;
+            for i in 0..len {
+                let b = if self.big_endian {bytes[i]} else {bytes[len - 1 - i]};
+                n = n << 8 | b as u64;
+            }
+            n
+        })
+    }
+
+    fn u8(&self, off: usize) -> Option<u8> { self.uint(off, 1).map(|n| n as u8) }
+    fn u16(&self, off: usize) -> Option<u16> { self.uint(off, 2).map(|n| n as u16) }
+    fn u32(&self, off: usize) -> Option<u32> { self.uint(off, 4).map(|n| n as u32) }
+    fn u64(&self, off: usize) -> Option<u64> { self.uint(off, 8) }
+
+    /// Reads a nul-terminated string.
+    fn cstr(&self, off: usize) -> Option<&'a [u8]> {
+        if off > self.data.len() {
+            return None
+        }
+        let rest = &self.data[off..];
+        rest.iter().position(|&b| b == 0).map(|len| &rest[..len])
+    }
+}
+
+fn elf_symbols(data: &[u8]) -> Option<Vec<&[u8]>> {
+    const SHT_SYMTAB: u32 = 2;
+    const SHN_UNDEF: u16 = 0;
+    const STB_GLOBAL: u8 = 1;
+    const STB_WEAK: u8 = 2;
+    const STB_GNU_UNIQUE: u8 = 10;
+
+    let is64 = match data.get(4) {
+        Some(&1) => false,
+        Some(&2) => true,
+        _ => return None,
+    };
+    let b = Bytes { data: data, big_endian: data.get(5) == Some(&2) };
+    let word = |off: usize| if is64 { b.u64(off) } else { b.u32(off).map(|n| n as u64) };
+
+    let shoff = try_opt!(word(if is64 {0x28} else {0x20})) as usize;
+    let shentsize = try_opt!(b.u16(if is64 {0x3a} else {0x2e})) as usize;
+    let shnum = try_opt!(b.u16(if is64 {0x3c} else {0x30})) as usize;
+    let section = |i: usize| shoff + i * shentsize;
+    // Returns the offset and size of a section's contents.
+    let contents = |sh: usize| -> Option<(usize, usize)> {
+        let off = try_opt!(word(sh + if is64 {0x18} else {0x10}));
+        let size = try_opt!(word(sh + if is64 {0x20} else {0x14}));
+        Some((off as usize, size as usize))
+    };
+
+    let mut symbols = Vec::new();
+    for i in 0..shnum {
+        let sh = section(i);
+        if try_opt!(b.u32(sh + 4)) != SHT_SYMTAB {
+            continue
+        }
+        let (symoff, symsize) = try_opt!(contents(sh));
+        let entsize = try_opt!(word(sh + if is64 {0x38} else {0x24})) as usize;
+        let strtab = try_opt!(b.u32(sh + if is64 {0x28} else {0x18})) as usize;
+        let (stroff, strsize) = try_opt!(contents(section(strtab)));
+        let strings = Bytes {
+            data: try_opt!(b.slice(stroff, strsize)),
+            big_endian: b.big_endian,
+        };
+        if entsize == 0 {
+            return None
+        }
+
+        for sym in (symoff..symoff + symsize / entsize * entsize).step_by(entsize) {
+            let name = try_opt!(b.u32(sym)) as usize;
+            let info = try_opt!(b.u8(sym + if is64 {4} else {12}));
+            let shndx = try_opt!(b.u16(sym + if is64 {6} else {14}));
+            match info >> 4 {
+                STB_GLOBAL | STB_WEAK | STB_GNU_UNIQUE if shndx != SHN_UNDEF => {
+                    symbols.push(try_opt!(strings.cstr(name)));
+                }
+                _ => {}
+            }
+        }
+    }
+    Some(symbols)
+}
+
+const MH_MAGIC: u32 = 0xfeedface;
+const MH_MAGIC_64: u32 = 0xfeedfacf;
+
+fn is_mach_o(data: &[u8]) -> bool {
+    let le = Bytes { data: data, big_endian: false };
+    match le.u32(0) {
+        Some(MH_MAGIC) | Some(MH_MAGIC_64) => true,
+        Some(n) => n.swap_bytes() == MH_MAGIC || n.swap_bytes() == MH_MAGIC_64,
+        None => false,
+    }
+}
+
+fn mach_o_symbols(data: &[u8]) -> Option<Vec<&[u8]>> {
+    const LC_SYMTAB: u32 = 2;
+    const N_STAB: u8 = 0xe0;
+    const N_TYPE: u8 = 0x0e;
+    const N_EXT: u8 = 0x01;
+    const N_UNDF: u8 = 0x00;
+
+    let le = Bytes { data: data, big_endian: false };
+    let magic = try_opt!(le.u32(0));
+    let b = Bytes {
+        data: data,
+        big_endian: magic != MH_MAGIC && magic != MH_MAGIC_64,
+    };
+    let is64 = try_opt!(b.u32(0)) == MH_MAGIC_64;
+    let ncmds = try_opt!(b.u32(16)) as usize;
+
+    let mut cmd = if is64 {32} else {28};
+    for _ in 0..ncmds {
+        let cmdsize = try_opt!(b.u32(cmd + 4)) as usize;
+        if try_opt!(b.u32(cmd)) != LC_SYMTAB {
+            if cmdsize == 0 {
+                return None
+            }
+            cmd += cmdsize;
+            continue
+        }
+
+        let symoff = try_opt!(b.u32(cmd + 8)) as usize;
+        let nsyms = try_opt!(b.u32(cmd + 12)) as usize;
+        let stroff = try_opt!(b.u32(cmd + 16)) as usize;
+        let strsize = try_opt!(b.u32(cmd + 20)) as usize;
+        let strings = Bytes {
+            data: try_opt!(b.slice(stroff, strsize)),
+            big_endian: b.big_endian,
+        };
+        let nlist_size = if is64 {16} else {12};
+
+        let mut symbols = Vec::new();
+        for i in 0..nsyms {
+            let sym = symoff + i * nlist_size;
+            let strx = try_opt!(b.u32(sym)) as usize;
+            let ty = try_opt!(b.u8(sym + 4));
+            if ty & N_STAB == 0 && ty & N_EXT != 0 && ty & N_TYPE != N_UNDF {
+                symbols.push(try_opt!(strings.cstr(strx)));
+            }
+        }
+        return Some(symbols)
+    }
+    Some(Vec::new())
+}
+
+fn is_coff(data: &[u8]) -> bool {
+    const MACHINES: [u16; 4] = [
+        0x014c, // IMAGE_FILE_MACHINE_I386
+        0x8664, // IMAGE_FILE_MACHINE_AMD64
+        0x01c4, // IMAGE_FILE_MACHINE_ARMNT
+        0xaa64, // IMAGE_FILE_MACHINE_ARM64
+    ];
+    let b = Bytes { data: data, big_endian: false };
+    b.u16(0).map_or(false, |machine| MACHINES.contains(&machine))
+}
+
+fn coff_symbols(data: &[u8]) -> Option<Vec<&[u8]>> {
+    const SYMBOL_SIZE: usize = 18;
+    const IMAGE_SYM_CLASS_EXTERNAL: u8 = 2;
+    const IMAGE_SYM_UNDEFINED: i16 = 0;
+    const IMAGE_SYM_DEBUG: i16 = -2;
+
+    let b = Bytes { data: data, big_endian: false };
+    let symoff = try_opt!(b.u32(8)) as usize;
+    let nsyms = try_opt!(b.u32(12)) as usize;
+    // The string table directly follows the symbol table, and offsets into
+    // it include its leading size field.
+    let stroff = symoff + nsyms * SYMBOL_SIZE;
+    if stroff > data.len() {
+        return None
+    }
+    let strings = Bytes { data: &data[stroff..], big_endian: false };
+
+    let mut symbols = Vec::new();
+    let mut i = 0;
+    while i < nsyms {
+        let sym = symoff + i * SYMBOL_SIZE;
+        let value = try_opt!(b.u32(sym + 8));
+        let section = try_opt!(b.u16(sym + 12)) as i16;
+        let class = try_opt!(b.u8(sym + 16));
+        let naux = try_opt!(b.u8(sym + 17)) as usize;
+
+        // Undefined symbols with a value are common symbols, which are
+        // defined by whichever object provides them.
+        let defined = section != IMAGE_SYM_DEBUG &&
+                      (section != IMAGE_SYM_UNDEFINED || value != 0);
+        if class == IMAGE_SYM_CLASS_EXTERNAL && defined {
+            // Short names are stored inline, padded with nuls, and long
+            // names as an offset into the string table.
+            let name = if try_opt!(b.u32(sym)) == 0 {
+                try_opt!(strings.cstr(try_opt!(b.u32(sym + 4)) as usize))
+            } else {
+                let name = try_opt!(b.slice(sym, 8));
+                let len = name.iter().position(|&b| b == 0).unwrap_or(8);
+                &name[..len]
+            };
+            symbols.push(name);
+        }
+        i += 1 + naux;
+    }
+    Some(symbols)
+}
@@ -0,0 +1,224 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Writing of GNU and BSD style archives.
+//!
+//! Archives are written deterministically: every member gets a zero
+//! timestamp, uid and gid, and a mode of 644, so building the same inputs
+//! twice produces identical bytes.
+
+use std::io::prelude::*;
+use std::io;
+
+use super::{ArchiveKind, Member, MAGIC, HEADER_SIZE};
+use super::symbols::defined_symbols;
+
+/// Writes `members` out as an archive of the given kind, preceded by a symbol
+/// table of the symbols they define if `symbols` is set.
+pub fn write_archive<W: Write>(w: &mut W, kind: ArchiveKind, members: &[Member],
+                               symbols: bool) -> io::Result<()> {
+    match kind {
+        ArchiveKind::Gnu => write_gnu(w, members, symbols),
+        ArchiveKind::Bsd => write_bsd(w, members, symbols),
+    }
+}
+
+fn write_gnu<W: Write>(w: &mut W, members: &[Member],
+                       symbols: bool) -> io::Result<()> {
+    // Names which don't fit in the header, along with the trailing `/`, go
+    // in the `//` member and are referred to by their offset in it.
+    let mut long_names = Vec::new();
+    let names = members.iter().map(|m| {
+        if m.name.len() < 16 && !m.name.contains("/") {
+            format!("{}/", m.name)
+        } else {
+            let name = format!("/{}", long_names.len());
+            long_names.push_all(m.name.as_bytes());
+            long_names.push_all(b"/\n");
+            name
+        }
+    }).collect::<Vec<_>>();
+
+    let table = if symbols {
+        Some(symbol_table(members))
+    } else {
+        None
+    };
+
+    // The symbol table refers to members by the offset of their header, so
+    // lay everything out before writing anything.
+    let mut pos = MAGIC.len() as u64;
+    let mut wide = false;
+    if let Some(ref table) = table {
+        // Try the 32-bit table first and only fall back to the 64-bit one if
+        // some member ends up beyond 4GB.
+        let names_len = table.iter().map(|&(name, _)| name.len() + 1)
+                             .fold(0, |a, b| a + b);
+        let size32 = 4 + 4 * table.len() + names_len;
+        let end = gnu_layout(pos + member_size(size32), &long_names, members)
+                      .last().cloned().unwrap_or(0);
+        wide = end > 0xffffffff;
+        let size = if wide { 8 + 8 * table.len() + names_len } else { size32 };
+        pos += member_size(size);
+    }
+    let offsets = gnu_layout(pos, &long_names, members);
+
+    try!(w.write_all(MAGIC));
+    if let Some(ref table) = table {
+        let width = if wide {8} else {4};
+        let mut data = Vec::new();
+        push_uint(&mut data, table.len() as u64, width, true);
+        for &(_, member) in table {
+            push_uint(&mut data, offsets[member], width, true);
+        }
+        for &(name, _) in table {
+            data.push_all(name);
+            data.push(0);
+        }
+        try!(write_member(w, if wide {"/SYM64/"} else {"/"}, &data));
+    }
+    if !long_names.is_empty() {
+        try!(write_member(w, "//", &long_names));
+    }
+    for (member, name) in members.iter().zip(names.iter()) {
+        try!(write_member(w, name, member.data));
+    }
+    Ok(())
+}
+
+/// Returns the offset of each member's header, given that the first special
+/// member after the symbol table starts at `pos`.
+fn gnu_layout(mut pos: u64, long_names: &[u8], members: &[Member]) -> Vec<u64> {
+    if !long_names.is_empty() {
+        pos += member_size(long_names.len());
+    }
+    members.iter().map(|m| {
+        let offset = pos;
+        pos += member_size(m.data.len());
+        offset
+    }).collect()
+}
+
+fn write_bsd<W: Write>(w: &mut W, members: &[Member],
+                       symbols: bool) -> io::Result<()> {
+    let table = if symbols {
+        Some(symbol_table(members))
+    } else {
+        None
+    };
+
+    let mut pos = MAGIC.len() as u64;
+    let mut strings = Vec::new();
+    let mut symdef_name = Vec::new();
+    if let Some(ref table) = table {
+        for &(name, _) in table {
+            strings.push_all(name);
+            strings.push(0);
+        }
+        while strings.len() % 4 != 0 {
+            strings.push(0);
+        }
+        symdef_name = bsd_name("__.SYMDEF", pos);
+        let size = symdef_name.len() + 4 + 8 * table.len() + 4 + strings.len();
+        pos += member_size(size);
+    }
+    let mut names = Vec::new();
+    let mut offsets = Vec::new();
+    for member in members {
+        let name = bsd_name(member.name, pos);
+        offsets.push(pos);
+        pos += member_size(name.len() + member.data.len());
+        names.push(name);
+    }
+    if pos > 0xffffffff {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  "archive is too large for a BSD symbol table"))
+    }
+
+    try!(w.write_all(MAGIC));
+    if let Some(ref table) = table {
+        let mut data = Vec::new();
+        push_uint(&mut data, 8 * table.len() as u64, 4, false);
+        let mut strx = 0;
+        for &(name, member) in table {
+            push_uint(&mut data, strx, 4, false);
+            push_uint(&mut data, offsets[member], 4, false);
+            strx += name.len() as u64 + 1;
+        }
+        push_uint(&mut data, strings.len() as u64, 4, false);
+        data.push_all(&strings);
+        try!(write_bsd_member(w, &symdef_name, &data));
+    }
+    for (member, name) in members.iter().zip(names.iter()) {
+        try!(write_bsd_member(w, name, member.data));
+    }
+    Ok(())
+}
+
+/// Every BSD name is written as `#1/len`, with the name itself at the start
+/// of the member's data. It's padded with nuls so that the contents of a
+/// member whose header is at `pos` start 8-byte aligned, as ld64 expects.
+fn bsd_name(name: &str, pos: u64) -> Vec<u8> {
+    let mut padded = name.as_bytes().to_vec();
+    padded.push(0);
+    while (pos as usize + HEADER_SIZE + padded.len()) % 8 != 0 {
+        padded.push(0);
+    }
+    padded
+}
+
+/// Collects the symbols defined by each member, along with the index of the
+/// member defining them.
+fn symbol_table<'a>(members: &[Member<'a>]) -> Vec<(&'a [u8], usize)> {
+    let mut table = Vec::new();
+    for (i, member) in members.iter().enumerate() {
+        for name in defined_symbols(member.data) {
+            table.push((name, i));
+        }
+    }
+    table
+}
+
+/// The space a member with `len` bytes of data takes up in the archive.
+fn member_size(len: usize) -> u64 {
+    let size = (HEADER_SIZE + len) as u64;
+    size + (size & 1)
+}
+
+fn write_member<W: Write>(w: &mut W, name: &str, data: &[u8]) -> io::Result<()> {
+    try!(write_header(w, name, data.len()));
+    try!(w.write_all(data));
+    if data.len() % 2 != 0 {
+        try!(w.write_all(b"\n"));
+    }
+    Ok(())
+}
+
+fn write_bsd_member<W: Write>(w: &mut W, name: &[u8], data: &[u8]) -> io::Result<()> {
+    let len = name.len() + data.len();
+    try!(write_header(w, &format!("#1/{}", name.len()), len));
+    try!(w.write_all(name));
+    try!(w.write_all(data));
+    if len % 2 != 0 {
+        try!(w.write_all(b"\n"));
+    }
+    Ok(())
+}
+
+fn write_header<W: Write>(w: &mut W, name: &str, size: usize) -> io::Result<()> {
+    write!(w, "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n", name, 0, 0, 0, 644, size)
+}
+
+fn push_uint(buf: &mut Vec<u8>, n: u64, width: usize, big_endian: bool) {
+    for i in 0..width {
+        let shift = if big_endian { 8 * (width - 1 - i) } else { 8 * i };
+        buf.push((n >> shift) as u8);
+    }
+}
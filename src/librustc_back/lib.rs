@@ -47,7 +47,6 @@
 extern crate syntax;
 extern crate libc;
 extern crate serialize;
-extern crate rustc_llvm;
 #[macro_use] extern crate log;
 
 pub mod abi;
@@ -100,6 +100,30 @@ impl Svh {
             attr.node.value.hash(&mut state);
         }
 
+        Svh::from_hasher(state)
+    }
+
+    /// Calculates the hash of a single item, in the same way as the crate as
+    /// a whole. Incremental compilation uses this to find out which items
+    /// have changed since the previous session.
+    pub fn calculate_item(item: &ast::Item) -> Svh {
+        let mut state = SipHasher::new();
+
+        {
+            let mut visit = svh_visitor::make(&mut state);
+            visit::Visitor::visit_item(&mut visit, item);
+        }
+
+        // Attributes such as `#[inline]` affect the code generated for the
+        // item, so they need to be part of its hash.
+        for attr in &item.attrs {
+            attr.node.value.hash(&mut state);
+        }
+
+        Svh::from_hasher(state)
+    }
+
+    fn from_hasher(state: SipHasher) -> Svh {
         let hash = state.finish();
         return Svh {
             hash: (0..64).step_by(4).map(|i| hex(hash >> i)).collect()
@@ -460,7 +460,7 @@ pub fn phase_2_configure_and_expand(sess: &Session,
     });
 
     let Registry { syntax_exts, lint_passes, lint_groups,
-                   llvm_passes, attributes, .. } = registry;
+                   llvm_passes, attributes, custom_derives, .. } = registry;
 
     {
         let mut ls = sess.lint_store.borrow_mut();
@@ -486,8 +486,8 @@ pub fn phase_2_configure_and_expand(sess: &Session,
     // Abort if there are errors from lint processing or a plugin registrar.
     sess.abort_if_errors();
 
-    krate = time(time_passes, "expansion", (krate, macros, syntax_exts),
-        |(krate, macros, syntax_exts)| {
+    krate = time(time_passes, "expansion", (krate, macros, syntax_exts, custom_derives),
+        |(krate, macros, syntax_exts, custom_derives)| {
             // Windows dlls do not have rpaths, so they don't know how to find their
             // dependencies. It's up to us to tell the system where to find all the
             // dependent dlls. Note that this uses cfg!(windows) as opposed to
@@ -507,6 +507,7 @@ pub fn phase_2_configure_and_expand(sess: &Session,
                 features: Some(&features),
                 recursion_limit: sess.recursion_limit.get(),
                 trace_mac: sess.opts.debugging_opts.trace_macros,
+                custom_derives: custom_derives,
             };
             let ret = syntax::ext::expand::expand_crate(&sess.parse_sess,
                                               cfg,
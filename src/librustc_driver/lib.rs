@@ -374,7 +374,8 @@ impl<'a> CompilerCalls<'a> for RustcDefaultCalls {
             control.after_llvm.stop = Compilation::Stop;
         }
 
-        if sess.opts.debugging_opts.save_analysis {
+        if sess.opts.debugging_opts.save_analysis ||
+           sess.opts.debugging_opts.save_analysis_json {
             control.after_analysis.callback = box |state| {
                 time(state.session.time_passes(),
                      "save analysis", (),
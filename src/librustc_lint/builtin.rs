@@ -197,6 +197,8 @@ impl LintPass for TypeLimits {
             },
             ast::ExprLit(ref lit) => {
                 match cx.tcx.expr_ty(e).sty {
+                    // literals are at most 64 bits wide, so they always fit
+                    ty::TyInt(ast::TyI128) | ty::TyUint(ast::TyU128) => (),
                     ty::TyInt(t) => {
                         match lit.node {
                             ast::LitInt(v, ast::SignedIntLit(_, ast::Plus)) |
@@ -291,7 +293,9 @@ impl LintPass for TypeLimits {
                 ast::TyI8 =>    (i8::MIN  as i64, i8::MAX  as i64),
                 ast::TyI16 =>   (i16::MIN as i64, i16::MAX as i64),
                 ast::TyI32 =>   (i32::MIN as i64, i32::MAX as i64),
-                ast::TyI64 =>   (i64::MIN,        i64::MAX)
+                ast::TyI64 =>   (i64::MIN,        i64::MAX),
+                // saturated, the callers deal with 128-bit types themselves
+                ast::TyI128 =>  (i64::MIN,        i64::MAX)
             }
         }
 
@@ -301,7 +305,9 @@ impl LintPass for TypeLimits {
                 ast::TyU8 =>    (u8::MIN   as u64, u8::MAX   as u64),
                 ast::TyU16 =>   (u16::MIN  as u64, u16::MAX  as u64),
                 ast::TyU32 =>   (u32::MIN  as u64, u32::MAX  as u64),
-                ast::TyU64 =>   (u64::MIN,         u64::MAX)
+                ast::TyU64 =>   (u64::MIN,         u64::MAX),
+                // saturated, see `int_ty_range`
+                ast::TyU128 =>  (u64::MIN,         u64::MAX)
             }
         }
 
@@ -318,7 +324,8 @@ impl LintPass for TypeLimits {
                 ast::TyI8 =>    i8::BITS  as u64,
                 ast::TyI16 =>   i16::BITS as u64,
                 ast::TyI32 =>   i32::BITS as u64,
-                ast::TyI64 =>   i64::BITS as u64
+                ast::TyI64 =>   i64::BITS as u64,
+                ast::TyI128 =>  128
             }
         }
 
@@ -328,7 +335,8 @@ impl LintPass for TypeLimits {
                 ast::TyU8 =>    u8::BITS  as u64,
                 ast::TyU16 =>   u16::BITS as u64,
                 ast::TyU32 =>   u32::BITS as u64,
-                ast::TyU64 =>   u64::BITS as u64
+                ast::TyU64 =>   u64::BITS as u64,
+                ast::TyU128 =>  128
             }
         }
 
@@ -347,6 +355,8 @@ impl LintPass for TypeLimits {
                 binop
             };
             match tcx.expr_ty(expr).sty {
+                // no 64-bit literal can be at the limits of a 128-bit type
+                ty::TyInt(ast::TyI128) | ty::TyUint(ast::TyU128) => true,
                 ty::TyInt(int_ty) => {
                     let (min, max) = int_ty_range(int_ty);
                     let lit_val: i64 = match lit.node {
@@ -21,12 +21,12 @@ variable declarations and expression statements.
 
 Here is an example that demonstrates the error:
 
-```
+```compile_fail,E0154
 fn f() {
     // Variable declaration before import
     let x = 0;
     use std::io::Read;
-    ...
+    // ...
 }
 ```
 
@@ -39,7 +39,6 @@ Here is the previous example again, with the correct order:
 fn f() {
     use std::io::Read;
     let x = 0;
-    ...
 }
 ```
 
@@ -55,7 +54,7 @@ items under a new local name.
 
 An example of this error:
 
-```
+```compile_fail,E0251
 use foo::baz;
 use bar::*; // error, do `use foo::baz as quux` instead on the previous line
 
@@ -77,7 +76,7 @@ items under a new local name.
 
 An example of this error:
 
-```
+```compile_fail,E0252
 use foo::baz;
 use bar::baz; // error, do `use bar::baz as quux` instead
 
@@ -93,13 +92,80 @@ mod bar {
 ```
 "##,
 
+E0253: r##"
+Attempt was made to import an unimportable value. This can happen when trying
+to import a method from a trait. An example of this error:
+
+```compile_fail,E0253
+mod foo {
+    pub trait MyTrait {
+        fn do_something();
+    }
+}
+
+use foo::MyTrait::do_something;
+
+fn main() {}
+```
+
+It's invalid to directly import methods belonging to a trait or concrete type.
+Import the trait instead and call the method through it:
+
+```
+mod foo {
+    pub trait MyTrait {
+        fn do_something();
+    }
+}
+
+use foo::MyTrait;
+
+fn main() {}
+```
+"##,
+
+E0254: r##"
+Attempt was made to import an item whereas an extern crate with this name has
+already been imported. For example:
+
+```compile_fail,E0254
+extern crate core;
+
+mod foo {
+    pub trait core {
+        fn do_something();
+    }
+}
+
+use foo::core; // error, do `use foo::core as other_name` instead
+
+fn main() {}
+```
+
+To fix this, rename the import:
+
+```
+extern crate core;
+
+mod foo {
+    pub trait core {
+        fn do_something();
+    }
+}
+
+use foo::core as other_name;
+
+fn main() {}
+```
+"##,
+
 E0255: r##"
 You can't import a value whose name is the same as another value defined in the
 module.
 
 An example of this error:
 
-```
+```compile_fail,E0255
 use bar::foo; // error, do `use bar::foo as baz` instead
 
 fn foo() {}
@@ -118,7 +184,7 @@ the same as another type or submodule defined in the module.
 
 An example of this error:
 
-```
+```compile_fail,E0256
 use foo::Bar; // error
 
 type Bar = u32;
@@ -137,9 +203,11 @@ has been imported into the current module.
 
 Wrong example:
 
-```
-extern crate a;
-extern crate crate_a as a;
+```compile_fail,E0259
+extern crate core;
+extern crate std as core;
+
+fn main() {}
 ```
 
 The solution is to choose a different name that doesn't conflict with any
@@ -148,8 +216,10 @@ external crate imported into the current module.
 Correct example:
 
 ```
-extern crate a;
-extern crate crate_a as other_name;
+extern crate core;
+extern crate std as other_name;
+
+fn main() {}
 ```
 "##,
 
@@ -158,10 +228,12 @@ The name for an item declaration conflicts with an external crate's name.
 
 For instance,
 
-```
-extern crate abc;
+```compile_fail,E0260
+extern crate core;
+
+struct core;
 
-struct abc;
+fn main() {}
 ```
 
 There are two possible solutions:
@@ -169,17 +241,21 @@ There are two possible solutions:
 Solution #1: Rename the item.
 
 ```
-extern crate abc;
+extern crate core;
 
 struct xyz;
+
+fn main() {}
 ```
 
 Solution #2: Import the crate with a different name.
 
 ```
-extern crate abc as xyz;
+extern crate core as xyz;
+
+struct core;
 
-struct abc;
+fn main() {}
 ```
 
 See the Declaration Statements section of the reference for more information
@@ -197,6 +273,74 @@ See the Types section of the reference for more information about the primitive
 types:
 
 http://doc.rust-lang.org/reference.html#types
+"##,
+
+E0364: r##"
+Private items cannot be publicly re-exported. This error indicates that you
+attempted to `pub use` a function or other value which is not itself public.
+Example of erroneous code:
+
+```compile_fail,E0364
+mod foo {
+    fn helper() {}
+}
+
+pub use foo::helper; // error: `helper` is private
+
+fn main() {}
+```
+
+The solution to this problem is to ensure that the items that you are
+re-exporting are themselves marked with `pub`:
+
+```
+mod foo {
+    pub fn helper() {}
+}
+
+pub use foo::helper; // ok!
+
+fn main() {}
+```
+
+See the 'Use Declarations' section of the reference for more information on
+this topic:
+
+http://doc.rust-lang.org/reference.html#use-declarations
+"##,
+
+E0365: r##"
+Private modules and types cannot be publicly re-exported. This error indicates
+that you attempted to `pub use` a module or type which is not itself public.
+Example of erroneous code:
+
+```compile_fail,E0365
+mod foo {
+    mod bar {}
+}
+
+pub use foo::bar; // error: `bar` is private
+
+fn main() {}
+```
+
+The solution to this problem is to ensure that the module or type that you are
+re-exporting is itself marked with `pub`:
+
+```
+mod foo {
+    pub mod bar {}
+}
+
+pub use foo::bar; // ok!
+
+fn main() {}
+```
+
+See the 'Use Declarations' section of the reference for more information on
+this topic:
+
+http://doc.rust-lang.org/reference.html#use-declarations
 "##
 
 }
@@ -204,10 +348,6 @@ http://doc.rust-lang.org/reference.html#types
 register_diagnostics! {
     E0157,
     E0153,
-    E0253, // not directly importable
-    E0254, // import conflicts with imported crate in this module
     E0257,
-    E0258,
-    E0364, // item is private
-    E0365  // item is private
+    E0258
 }
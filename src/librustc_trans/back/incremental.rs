@@ -0,0 +1,492 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reuse of LLVM object files between sessions, enabled with
+//! `-Z incremental=<dir>`.
+//!
+//! Codegen units whose items are unchanged since the previous session are
+//! neither translated to LLVM IR nor run through LLVM again: their object
+//! files and bitcode are copied from the cache instead. Type checking and the
+//! other analysis passes still run over the whole crate, as building the
+//! dependency graph relies on their results.
+//!
+//! Every top-level item of the crate (that is, everything but modules) is
+//! hashed with the same machinery as the crate's SVH, and the items it
+//! depends on are gathered from the paths resolved and the methods called
+//! within it. The hashes and this dependency graph are saved in the cache
+//! directory at the end of each session. An item is *dirty* if its hash
+//! differs from the one saved last time, or if anything it depends on,
+//! directly or transitively, is dirty. Impls are treated as dependencies of
+//! the types and traits named in their headers, since they can be reached
+//! through those without naming the impl itself.
+//!
+//! In incremental mode modules are assigned to codegen units by a hash of
+//! their path, rather than to whichever unit is smallest, so a unit holds the
+//! same items from one session to the next. A unit is reused if none of the
+//! items in it are dirty, it holds exactly the same items as before, and
+//! its outputs from last time are in the cache. Only object files and
+//! bitcode are cached, so nothing is reused if anything else is to be
+//! emitted, or with LTO.
+//!
+//! Anything else which affects code generation, such as the optimization
+//! level or the upstream crates, goes into a fingerprint, and if that changes
+//! nothing is reused.
+//!
+//! For objects from different sessions to link against each other, the
+//! symbols of the crate's items are never internalized in this mode, as a
+//! unit translated now may need a symbol that no unit referenced when a
+//! cached object was produced. Symbol names normally include the crate's SVH,
+//! which changes whenever anything in the crate does, so in this mode it is
+//! left out of them. See `symbol_hash` in `back::link` for what takes its
+//! place.
+
+use back::svh::Svh;
+use middle::def;
+use middle::ty;
+use session::config::{self, OutputFilenames};
+use session::Session;
+use util::nodemap::{NodeMap, NodeSet};
+
+use serialize::json;
+use syntax::ast;
+use syntax::ast_util::{IdVisitor, IdVisitingOperation};
+use syntax::codemap::Span;
+use syntax::parse::token;
+use syntax::print::pprust;
+use syntax::visit::Visitor;
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, PathExt};
+use std::hash::{Hash, Hasher, SipHasher};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// The extensions of the outputs of each codegen unit which are cached.
+const CACHED_OUTPUTS: [&'static str; 2] = ["o", "bc"];
+
+/// What's saved in the cache directory from one session to the next.
+#[derive(RustcEncodable, RustcDecodable)]
+struct Graph {
+    fingerprint: String,
+    items: Vec<ItemData>,
+    units: Vec<UnitData>,
+}
+
+#[derive(RustcEncodable, RustcDecodable)]
+struct ItemData {
+    key: String,
+    hash: String,
+    deps: Vec<String>,
+}
+
+#[derive(RustcEncodable, RustcDecodable, PartialEq)]
+struct UnitData {
+    /// The keys of the items translated into this unit, sorted.
+    items: Vec<String>,
+}
+
+/// The incremental compilation state of the current session.
+pub struct IncrementalState {
+    dir: PathBuf,
+    /// The graph to save once this session's outputs have been produced.
+    graph: Graph,
+    /// Whether each codegen unit is reused from the previous session.
+    reusable: Vec<bool>,
+    /// The top-level items of the reused units, which aren't translated.
+    reused_items: NodeSet,
+    /// The extensions of the outputs each codegen unit has to produce.
+    outputs: Vec<&'static str>,
+}
+
+struct Item<'a> {
+    item: &'a ast::Item,
+    /// A name for the item which stays the same across sessions.
+    key: String,
+    unit: usize,
+}
+
+/// Returns the codegen unit that the items in module `id` are translated into
+/// in incremental mode.
+pub fn codegen_unit(tcx: &ty::ctxt, id: ast::NodeId, units: usize) -> usize {
+    let mut state = SipHasher::new();
+    tcx.map.path_to_string(id).hash(&mut state);
+    (state.finish() % units as u64) as usize
+}
+
+/// Builds the dependency graph for the crate and works out which codegen
+/// units can reuse their outputs from the previous session. This is called
+/// before translation, so that the items of those units can be skipped.
+/// Returns `None` unless incremental compilation is enabled.
+pub fn prepare(tcx: &ty::ctxt, krate: &ast::Crate,
+               crate_name: &str) -> Option<IncrementalState> {
+    let sess = &tcx.sess;
+    let dir = match sess.opts.debugging_opts.incremental {
+        Some(ref dir) => Path::new(dir).join(crate_name),
+        None => return None,
+    };
+    let units = sess.opts.cg.codegen_units;
+    let outputs = unit_outputs(sess);
+
+    let mut items = Vec::new();
+    collect_items(tcx, &krate.module, "", 0, units, &mut items);
+    {
+        let mut seen = HashMap::new();
+        for item in &mut items {
+            let n = seen.entry(item.key.clone()).or_insert(0);
+            if *n > 0 {
+                item.key = format!("{}#{}", item.key, n);
+            }
+            *n += 1;
+        }
+    }
+    let deps = dependencies(tcx, &items);
+
+    let graph = Graph {
+        fingerprint: fingerprint(sess),
+        items: items.iter().zip(deps.iter()).map(|(item, deps)| {
+            let mut deps = deps.iter().map(|&i| items[i].key.clone())
+                               .collect::<Vec<_>>();
+            deps.sort();
+            ItemData {
+                key: item.key.clone(),
+                hash: item_hash(sess, item.item),
+                deps: deps,
+            }
+        }).collect(),
+        units: (0..units).map(|i| {
+            let mut keys = items.iter().filter(|item| item.unit == i)
+                                .map(|item| item.key.clone())
+                                .collect::<Vec<_>>();
+            keys.sort();
+            UnitData { items: keys }
+        }).collect(),
+    };
+
+    let reusable = match (load(&dir), outputs.is_empty()) {
+        (Some(ref prev), false) if prev.fingerprint == graph.fingerprint => {
+            let dirty = dirty_items(&graph, prev);
+            graph.units.iter().enumerate().map(|(i, unit)| {
+                prev.units.get(i) == Some(unit) &&
+                    !unit.items.iter().any(|key| dirty.contains(key)) &&
+                    outputs.iter().all(|ext| {
+                        dir.join(&format!("{}.{}", i, ext)).exists()
+                    })
+            }).collect()
+        }
+        _ => vec![false; units],
+    };
+
+    // Enums are still visited, for the lint translation runs on them.
+    let reused_items = items.iter().filter(|item| {
+        match item.item.node {
+            ast::ItemEnum(..) => false,
+            _ => reusable[item.unit],
+        }
+    }).map(|item| item.item.id).collect();
+
+    Some(IncrementalState {
+        dir: dir,
+        graph: graph,
+        reusable: reusable,
+        reused_items: reused_items,
+        outputs: outputs,
+    })
+}
+
+impl IncrementalState {
+    /// The top-level items which needn't be translated, as the codegen unit
+    /// they belong to is reused.
+    pub fn reused_items(&self) -> &NodeSet {
+        &self.reused_items
+    }
+
+    /// Copies the outputs of codegen unit `unit` from the previous session
+    /// into place if the unit is reused, in which case it wasn't translated
+    /// and its module must be thrown away. Returns whether the unit was
+    /// reused.
+    pub fn restore(&self, sess: &Session, unit: usize,
+                   outputs: &OutputFilenames) -> bool {
+        if !self.reusable[unit] {
+            return false
+        }
+        for ext in &self.outputs {
+            let from = self.dir.join(&format!("{}.{}", unit, ext));
+            let to = outputs.with_extension(&format!("{}.{}", unit, ext));
+            if let Err(e) = fs::copy(&from, &to) {
+                sess.err(&format!("failed to reuse {}: {}", from.display(), e));
+            }
+        }
+        if sess.opts.debugging_opts.incremental_info {
+            sess.note(&format!("reusing codegen unit {} from the previous session",
+                               unit));
+        }
+        true
+    }
+
+    /// Saves this session's dependency graph and the outputs of each codegen
+    /// unit to the cache directory, for the next session to use.
+    pub fn save(&self, sess: &Session, outputs: &OutputFilenames) {
+        let result = fs::create_dir_all(&self.dir).and_then(|()| {
+            for unit in 0..self.graph.units.len() {
+                for ext in &CACHED_OUTPUTS {
+                    let from = outputs.with_extension(&format!("{}.{}", unit, ext));
+                    let to = self.dir.join(&format!("{}.{}", unit, ext));
+                    if from.exists() {
+                        try!(fs::copy(&from, &to));
+                    } else if to.exists() {
+                        try!(fs::remove_file(&to));
+                    }
+                }
+            }
+            let json = json::encode(&self.graph).unwrap();
+            let mut file = try!(File::create(&self.dir.join("graph.json")));
+            file.write_all(json.as_bytes())
+        });
+        if let Err(e) = result {
+            sess.warn(&format!("failed to save incremental compilation state \
+                                to {}: {}", self.dir.display(), e));
+        }
+    }
+}
+
+/// Returns the extensions of the outputs each codegen unit has to produce,
+/// or nothing if some of them aren't kept in the cache.
+fn unit_outputs(sess: &Session) -> Vec<&'static str> {
+    if sess.lto() || sess.opts.cg.save_temps || sess.opts.cg.no_integrated_as {
+        return Vec::new()
+    }
+    let mut obj = false;
+    let mut bc = false;
+    for output_type in &sess.opts.output_types {
+        match *output_type {
+            config::OutputTypeExe => {
+                obj = true;
+                // Rlibs carry their bitcode, see `back::write::run_passes`.
+                bc |= sess.crate_types.borrow().contains(&config::CrateTypeRlib);
+            }
+            config::OutputTypeObject => obj = true,
+            config::OutputTypeBitcode => bc = true,
+            config::OutputTypeDepInfo => {}
+            config::OutputTypeAssembly |
+            config::OutputTypeLlvmAssembly => return Vec::new(),
+        }
+    }
+    CACHED_OUTPUTS.iter().cloned().filter(|&ext| {
+        (ext == "o" && obj) || (ext == "bc" && bc)
+    }).collect()
+}
+
+fn load(dir: &Path) -> Option<Graph> {
+    let mut json = String::new();
+    let read = File::open(&dir.join("graph.json")).and_then(|mut f| {
+        f.read_to_string(&mut json)
+    });
+    match read {
+        Ok(..) => json::decode(&json).ok(),
+        Err(..) => None,
+    }
+}
+
+fn collect_items<'a>(tcx: &ty::ctxt, module: &'a ast::Mod, path: &str,
+                     unit: usize, units: usize, items: &mut Vec<Item<'a>>) {
+    for item in &module.items {
+        if let ast::ItemMod(ref m) = item.node {
+            let path = tcx.map.path_to_string(item.id);
+            let unit = codegen_unit(tcx, item.id, units);
+            collect_items(tcx, m, &path, unit, units, items);
+            continue
+        }
+
+        let name = match item.node {
+            ast::ItemImpl(_, _, _, Some(ref trait_ref), ref ty, _) => {
+                format!("<impl {} for {}>", pprust::path_to_string(&trait_ref.path),
+                        pprust::ty_to_string(ty))
+            }
+            ast::ItemImpl(_, _, _, None, ref ty, _) => {
+                format!("<impl {}>", pprust::ty_to_string(ty))
+            }
+            ast::ItemUse(..) => "<use>".to_string(),
+            ast::ItemForeignMod(..) => "<extern>".to_string(),
+            _ => token::get_ident(item.ident).to_string(),
+        };
+        items.push(Item {
+            item: &**item,
+            key: if path.is_empty() {name} else {format!("{}::{}", path, name)},
+            unit: unit,
+        });
+    }
+}
+
+/// Records which item each node belongs to.
+struct Owners<'a> {
+    map: &'a mut NodeMap<usize>,
+    owner: usize,
+}
+
+impl<'a> IdVisitingOperation for Owners<'a> {
+    fn visit_id(&mut self, id: ast::NodeId) {
+        self.map.insert(id, self.owner);
+    }
+}
+
+/// Returns the indices of the items each item depends on.
+fn dependencies(tcx: &ty::ctxt, items: &[Item]) -> Vec<HashSet<usize>> {
+    let mut owners = NodeMap();
+    for (i, item) in items.iter().enumerate() {
+        let mut op = Owners { map: &mut owners, owner: i };
+        IdVisitor {
+            operation: &mut op,
+            pass_through_items: true,
+            visited_outermost: false,
+        }.visit_item(item.item);
+    }
+    let owner_of = |did: ast::DefId| {
+        if did.krate == ast::LOCAL_CRATE {
+            owners.get(&did.node).cloned()
+        } else {
+            None
+        }
+    };
+
+    let mut deps = vec![HashSet::new(); items.len()];
+    {
+        let mut add = |from: Option<usize>, to: Option<usize>| {
+            if let (Some(from), Some(to)) = (from, to) {
+                if from != to {
+                    deps[from].insert(to);
+                }
+            }
+        };
+
+        for (&id, resolution) in tcx.def_map.borrow().iter() {
+            if resolution.depth != 0 {
+                continue
+            }
+            let did = match resolution.base_def {
+                def::DefPrimTy(..) | def::DefSelfTy(..) => continue,
+                def => def.def_id(),
+            };
+            add(owners.get(&id).cloned(), owner_of(did));
+        }
+
+        for (call, callee) in tcx.tables.borrow().method_map.iter() {
+            let did = match callee.origin {
+                ty::MethodStatic(did) | ty::MethodStaticClosure(did) => did,
+                ty::MethodTypeParam(ref param) => param.trait_ref.def_id,
+                ty::MethodTraitObject(ref object) => object.trait_ref.def_id,
+            };
+            add(owners.get(&call.expr_id).cloned(), owner_of(did));
+        }
+
+        // The types and traits named in an impl's header depend on the impl.
+        for (i, item) in items.iter().enumerate() {
+            if let ast::ItemImpl(_, _, _, ref trait_ref, ref ty, _) = item.item.node {
+                let mut header = NodeMap();
+                {
+                    let mut op = Owners { map: &mut header, owner: i };
+                    let mut visitor = IdVisitor {
+                        operation: &mut op,
+                        pass_through_items: true,
+                        visited_outermost: false,
+                    };
+                    visitor.visit_ty(ty);
+                    if let Some(ref trait_ref) = *trait_ref {
+                        visitor.visit_trait_ref(trait_ref);
+                    }
+                }
+                let def_map = tcx.def_map.borrow();
+                for id in header.keys() {
+                    if let Some(resolution) = def_map.get(id) {
+                        match resolution.base_def {
+                            def::DefPrimTy(..) | def::DefSelfTy(..) => {}
+                            def => add(owner_of(def.def_id()), Some(i)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    deps
+}
+
+fn item_hash(sess: &Session, item: &ast::Item) -> String {
+    let hash = Svh::calculate_item(item).as_str().to_string();
+    if sess.opts.debuginfo == config::NoDebugInfo {
+        return hash
+    }
+    // Debuginfo records where the item is, so moving it around has to count
+    // as a change too.
+    format!("{}@{}", hash, span_position(sess, item.span))
+}
+
+fn span_position(sess: &Session, span: Span) -> String {
+    let lo = sess.codemap().lookup_char_pos(span.lo);
+    let hi = sess.codemap().lookup_char_pos(span.hi);
+    format!("{}:{}:{}-{}:{}", lo.file.name, lo.line, lo.col.0, hi.line, hi.col.0)
+}
+
+/// Returns the keys of the items which have changed since `prev` was saved,
+/// along with everything depending on them.
+fn dirty_items(graph: &Graph, prev: &Graph) -> HashSet<String> {
+    let prev_hashes = prev.items.iter().map(|item| (&item.key, &item.hash))
+                                .collect::<HashMap<_, _>>();
+    let mut dirty = graph.items.iter().filter(|item| {
+        prev_hashes.get(&item.key) != Some(&&item.hash)
+    }).map(|item| item.key.clone()).collect::<HashSet<_>>();
+
+    // Propagate dirtiness to dependents until nothing changes.
+    loop {
+        let newly_dirty = graph.items.iter().filter(|item| {
+            !dirty.contains(&item.key) &&
+                item.deps.iter().any(|dep| dirty.contains(dep))
+        }).map(|item| item.key.clone()).collect::<Vec<_>>();
+        if newly_dirty.is_empty() {
+            return dirty
+        }
+        dirty.extend(newly_dirty);
+    }
+}
+
+/// Hashes everything besides the crate's own items which affects the code
+/// generated for it.
+fn fingerprint(sess: &Session) -> String {
+    let mut state = SipHasher::new();
+    option_env!("CFG_VERSION").hash(&mut state);
+    sess.target.target.llvm_target.hash(&mut state);
+    (sess.opts.optimize as usize).hash(&mut state);
+    (sess.opts.debuginfo as usize).hash(&mut state);
+    sess.opts.debug_assertions.hash(&mut state);
+    sess.crate_types.borrow().hash(&mut state);
+
+    let cg = &sess.opts.cg;
+    cg.codegen_units.hash(&mut state);
+    cg.metadata.hash(&mut state);
+    cg.target_cpu.hash(&mut state);
+    cg.target_feature.hash(&mut state);
+    cg.relocation_model.hash(&mut state);
+    cg.code_model.hash(&mut state);
+    cg.passes.hash(&mut state);
+    cg.llvm_args.hash(&mut state);
+    cg.no_prepopulate_passes.hash(&mut state);
+    cg.no_vectorize_loops.hash(&mut state);
+    cg.no_vectorize_slp.hash(&mut state);
+    cg.no_redzone.hash(&mut state);
+    cg.debug_assertions.hash(&mut state);
+
+    let mut upstream = Vec::new();
+    sess.cstore.iter_crate_data(|_, data| {
+        upstream.push(format!("{}-{}", data.name, data.hash()));
+    });
+    upstream.sort();
+    upstream.hash(&mut state);
+
+    format!("{:016x}", state.finish())
+}
@@ -8,7 +8,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::archive::{Archive, ArchiveBuilder, ArchiveConfig, METADATA_FILENAME};
+use super::archive::{Archive, ArchiveBuilder, ArchiveConfig, ArchiveKind};
+use super::archive::METADATA_FILENAME;
 use super::linker::{Linker, GnuLinker, MsvcLinker};
 use super::rpath::RPathConfig;
 use super::rpath;
@@ -204,7 +205,13 @@ fn symbol_hash<'tcx>(tcx: &ty::ctxt<'tcx>,
     symbol_hasher.reset();
     symbol_hasher.input_str(&link_meta.crate_name);
     symbol_hasher.input_str("-");
-    symbol_hasher.input_str(link_meta.crate_hash.as_str());
+    // The crate hash changes whenever anything in the crate does, which would
+    // rename every symbol and leave incremental compilation nothing to reuse.
+    // In that mode only the crate name and the `-C metadata` below, which is
+    // required then, tell different builds of the crate apart.
+    if tcx.sess.opts.debugging_opts.incremental.is_none() {
+        symbol_hasher.input_str(link_meta.crate_hash.as_str());
+    }
     for meta in tcx.sess.crate_metadata.borrow().iter() {
         symbol_hasher.input_str(&meta[..]);
     }
@@ -365,12 +372,6 @@ pub fn get_cc_prog(sess: &Session) -> String {
     }
 }
 
-pub fn get_ar_prog(sess: &Session) -> String {
-    sess.opts.cg.ar.clone().unwrap_or_else(|| {
-        sess.target.target.options.ar.clone()
-    })
-}
-
 fn command_path(sess: &Session) -> OsString {
     // The compiler's sysroot often has some bundled tools, so add it to the
     // PATH for the child.
@@ -547,6 +548,21 @@ fn archive_search_paths(sess: &Session) -> Vec<PathBuf> {
     return search;
 }
 
+fn archive_config<'a>(sess: &'a Session, output: &Path) -> ArchiveConfig<'a> {
+    ArchiveConfig {
+        handler: &sess.diagnostic().handler,
+        dst: output.to_path_buf(),
+        lib_search_paths: archive_search_paths(sess),
+        slib_prefix: sess.target.target.options.staticlib_prefix.clone(),
+        slib_suffix: sess.target.target.options.staticlib_suffix.clone(),
+        kind: if sess.target.target.options.is_like_osx {
+            ArchiveKind::Bsd
+        } else {
+            ArchiveKind::Gnu
+        },
+    }
+}
+
 // Create an 'rlib'
 //
 // An rlib in its current incarnation is essentially a renamed .a file. The
@@ -558,17 +574,7 @@ fn link_rlib<'a>(sess: &'a Session,
                  obj_filename: &Path,
                  out_filename: &Path) -> ArchiveBuilder<'a> {
     info!("preparing rlib from {:?} to {:?}", obj_filename, out_filename);
-    let handler = &sess.diagnostic().handler;
-    let config = ArchiveConfig {
-        handler: handler,
-        dst: out_filename.to_path_buf(),
-        lib_search_paths: archive_search_paths(sess),
-        slib_prefix: sess.target.target.options.staticlib_prefix.clone(),
-        slib_suffix: sess.target.target.options.staticlib_suffix.clone(),
-        ar_prog: get_ar_prog(sess),
-        command_path: command_path(sess),
-    };
-    let mut ab = ArchiveBuilder::create(config);
+    let mut ab = ArchiveBuilder::create(archive_config(sess, out_filename));
     ab.add_file(obj_filename).unwrap();
 
     for &(ref l, kind) in sess.cstore.get_used_libraries().borrow().iter() {
@@ -578,18 +584,10 @@ fn link_rlib<'a>(sess: &'a Session,
         }
     }
 
-    // After adding all files to the archive, we need to update the
-    // symbol table of the archive.
+    // The archive's symbol table is built from whichever members turn out
+    // to be object files, so it's fine to add the non-object files below.
     ab.update_symbols();
 
-    let mut ab = match sess.target.target.options.is_like_osx {
-        // For OSX/iOS, we must be careful to update symbols only when adding
-        // object files.  We're about to start adding non-object files, so run
-        // `ar` now to process the object files.
-        true => ab.build().extend(),
-        false => ab,
-    };
-
     // Note that it is important that we add all of our non-object "magical
     // files" *after* all of the object files in the archive. The reason for
     // this is as follows:
@@ -684,13 +682,6 @@ fn link_rlib<'a>(sess: &'a Session,
                     remove(sess, &bc_filename);
                 }
             }
-
-            // After adding all files to the archive, we need to update the
-            // symbol table of the archive. This currently dies on OSX (see
-            // #11162), and isn't necessary there anyway
-            if !sess.target.target.options.is_like_osx {
-                ab.update_symbols();
-            }
         }
 
         None => {}
@@ -746,11 +737,7 @@ fn write_rlib_bytecode_object_v1(writer: &mut Write,
 // link in the metadata object file (and also don't prepare the archive with a
 // metadata file).
 fn link_staticlib(sess: &Session, obj_filename: &Path, out_filename: &Path) {
-    let ab = link_rlib(sess, None, obj_filename, out_filename);
-    let mut ab = match sess.target.target.options.is_like_osx {
-        true => ab.build().extend(),
-        false => ab,
-    };
+    let mut ab = link_rlib(sess, None, obj_filename, out_filename);
     if sess.target.target.options.morestack {
         ab.add_native_library("morestack").unwrap();
     }
@@ -1184,17 +1171,7 @@ fn add_upstream_rust_crates(cmd: &mut Linker, sess: &Session,
                                              for LTO: {}", dst.display(), e));
                     }
                 }
-                let handler = &sess.diagnostic().handler;
-                let config = ArchiveConfig {
-                    handler: handler,
-                    dst: dst.clone(),
-                    lib_search_paths: archive_search_paths(sess),
-                    slib_prefix: sess.target.target.options.staticlib_prefix.clone(),
-                    slib_suffix: sess.target.target.options.staticlib_suffix.clone(),
-                    ar_prog: get_ar_prog(sess),
-                    command_path: command_path(sess),
-                };
-                let mut archive = Archive::open(config);
+                let mut archive = Archive::open(archive_config(sess, &dst));
                 archive.remove_file(&format!("{}.o", name));
                 let files = archive.files();
                 if files.iter().any(|s| s.ends_with(".o")) {
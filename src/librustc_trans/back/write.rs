@@ -664,6 +664,18 @@ pub fn run_passes(sess: &Session,
     }
 
     for (index, mtrans) in trans.modules.iter().enumerate() {
+        // With incremental compilation, codegen units which haven't changed
+        // since the previous session weren't translated, and reuse its
+        // outputs instead of being run through LLVM.
+        if let Some(ref state) = trans.incremental {
+            if state.restore(sess, index, crate_output) {
+                unsafe {
+                    llvm::LLVMDisposeModule(mtrans.llmod);
+                    llvm::LLVMContextDispose(mtrans.llcx);
+                }
+                continue
+            }
+        }
         let work = build_work_item(sess,
                                    *mtrans,
                                    modules_config.clone(),
@@ -819,6 +831,10 @@ pub fn run_passes(sess: &Session,
     }
     let user_wants_bitcode = user_wants_bitcode;
 
+    if let Some(ref state) = trans.incremental {
+        state.save(sess, crate_output);
+    }
+
     // Clean up unwanted temporary files.
 
     // We create the following files by default:
@@ -59,6 +59,8 @@ extern crate rustc_llvm as llvm;
 #[macro_use] extern crate log;
 #[macro_use] extern crate syntax;
 
+extern crate serialize as rustc_serialize; // used by deriving
+
 pub use rustc::session;
 pub use rustc::metadata;
 pub use rustc::middle;
@@ -78,6 +80,7 @@ pub mod back {
     pub use rustc_back::x86;
     pub use rustc_back::x86_64;
 
+    pub mod incremental;
     pub mod linker;
     pub mod link;
     pub mod lto;
@@ -23,12 +23,13 @@
 //!
 //! SpanUtils is used to manipulate spans. In particular, to extract sub-spans
 //! from spans (e.g., the span for `bar` from the above example path).
-//! Recorder is used for recording the output in csv format. FmtStrs separates
-//! the format of the output away from extracting it from the compiler.
+//! Recorder is used for recording the output in csv format, or collecting it
+//! to be written as JSON (see the `json` module). FmtStrs separates the format
+//! of the output away from extracting it from the compiler.
 //! DumpCsvVisitor walks the AST and processes it.
 
 
-use super::{escape, generated_code, recorder, SaveContext, PathCollector};
+use super::{escape, generated_code, json, recorder, SaveContext, PathCollector};
 
 use session::Session;
 
@@ -77,7 +78,8 @@ pub struct DumpCsvVisitor<'l, 'tcx: 'l> {
 impl <'l, 'tcx> DumpCsvVisitor<'l, 'tcx> {
     pub fn new(tcx: &'l ty::ctxt<'tcx>,
                analysis: &'l ty::CrateAnalysis,
-               output_file: Box<File>) -> DumpCsvVisitor<'l, 'tcx> {
+               output_file: Box<File>,
+               json: Option<json::Analysis>) -> DumpCsvVisitor<'l, 'tcx> {
         let span_utils = SpanUtils {
             sess: &tcx.sess,
             err_count: Cell::new(0)
@@ -91,6 +93,7 @@ impl <'l, 'tcx> DumpCsvVisitor<'l, 'tcx> {
             fmt: FmtStrs::new(box Recorder {
                                 out: output_file,
                                 dump_spans: false,
+                                json: json,
                               }, span_utils),
             cur_scope: 0
         }
@@ -116,6 +119,10 @@ impl <'l, 'tcx> DumpCsvVisitor<'l, 'tcx> {
         self.fmt.recorder.record("end_external_crates\n");
     }
 
+    pub fn finish(&mut self) {
+        self.fmt.recorder.finish();
+    }
+
     // Return all non-empty prefixes of a path.
     // For each prefix, we return the span for the last segment in the prefix and
     // a str representation of the entire prefix.
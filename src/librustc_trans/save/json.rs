@@ -0,0 +1,218 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The schema for save-analysis output in JSON, written by
+//! `-Z save-analysis-json`.
+//!
+//! Unlike the CSV output, this format is versioned: any change to the
+//! meaning of existing fields, or the removal of a field, bumps
+//! `FORMAT_VERSION`. New fields may be added without a version bump, so
+//! consumers should ignore fields they don't know about.
+//!
+//! Entities are identified by an `Id` made of a crate number and an index
+//! within that crate. Crate number 0 is the crate being dumped; the others
+//! are listed in the prelude's `external_crates` along with their name and
+//! disambiguator. The index of an item from an external crate is the id it
+//! was given in that crate's own dump, so ids can be resolved by finding the
+//! dump with a matching crate name and disambiguator.
+
+use session::Session;
+
+use syntax::ast::{self, NodeId, DefId};
+use syntax::codemap::{Span, ExpnFormat};
+
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(RustcEncodable)]
+pub struct Analysis {
+    pub format_version: u32,
+    pub prelude: Prelude,
+    pub defs: Vec<Def>,
+    pub refs: Vec<Ref>,
+    pub impls: Vec<Impl>,
+    pub imports: Vec<Import>,
+    pub macro_uses: Vec<MacroUse>,
+}
+
+#[derive(RustcEncodable)]
+pub struct Prelude {
+    pub crate_name: String,
+    /// Distinguishes crates with the same name from one another.
+    pub disambiguator: String,
+    pub external_crates: Vec<ExternalCrate>,
+}
+
+#[derive(RustcEncodable)]
+pub struct ExternalCrate {
+    pub num: u32,
+    pub name: String,
+    pub disambiguator: String,
+}
+
+#[derive(RustcEncodable, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Id {
+    pub krate: u32,
+    pub index: u32,
+}
+
+/// A span in the source, with lines and columns starting from 1 and the end
+/// exclusive. Columns count characters, not bytes.
+#[derive(RustcEncodable, Clone, PartialEq, Eq, Debug)]
+pub struct SpanData {
+    pub file_name: String,
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub line_start: u32,
+    pub line_end: u32,
+    pub column_start: u32,
+    pub column_end: u32,
+}
+
+/// The definition of a variable, function, type, module or other named entity.
+#[derive(RustcEncodable)]
+pub struct Def {
+    /// One of the row names used by the CSV output, e.g. `function` or
+    /// `struct`.
+    pub kind: String,
+    pub id: Id,
+    pub span: SpanData,
+    pub name: String,
+    pub qualname: String,
+    /// Kind-specific: the type of a variable, the definition of a type or the
+    /// file a module is defined in.
+    pub value: String,
+    /// The enclosing item or module, if any.
+    pub parent: Option<Id>,
+    /// For methods in impls of traits, the declaration in the trait.
+    pub decl_id: Option<Id>,
+}
+
+/// A use of a definition, for example a call, a path or a type.
+#[derive(RustcEncodable)]
+pub struct Ref {
+    pub kind: String,
+    pub span: SpanData,
+    /// The definition referred to. This is missing for prefixes of paths
+    /// whose definition isn't recorded by the compiler.
+    pub ref_id: Option<Id>,
+    /// For method calls, the declaration of the method in its trait.
+    pub decl_id: Option<Id>,
+    pub qualname: String,
+}
+
+/// An impl, or a trait's supertrait.
+#[derive(RustcEncodable)]
+pub struct Impl {
+    /// `impl`, or `inheritance` for a supertrait bound.
+    pub kind: String,
+    /// The impl itself; missing for supertraits.
+    pub id: Option<Id>,
+    pub span: SpanData,
+    /// The type being implemented for, or the trait with a supertrait.
+    pub self_id: Option<Id>,
+    /// The trait being implemented, or the supertrait.
+    pub trait_id: Option<Id>,
+}
+
+/// A `use` item or `extern crate`.
+#[derive(RustcEncodable)]
+pub struct Import {
+    pub kind: String,
+    pub id: Id,
+    pub span: SpanData,
+    pub name: String,
+    /// The definition imported; for `extern crate` this is the root module
+    /// of the crate.
+    pub ref_id: Option<Id>,
+    /// The names imported by a glob, or the location of an external crate.
+    pub value: String,
+    pub parent: Id,
+}
+
+/// An invocation of a macro or syntax extension written in the crate.
+#[derive(RustcEncodable)]
+pub struct MacroUse {
+    pub name: String,
+    /// `macro_bang` or `macro_attribute`.
+    pub kind: String,
+    pub span: SpanData,
+    /// The definition of the macro, if it has one.
+    pub callee_span: Option<SpanData>,
+}
+
+impl Analysis {
+    pub fn new(crate_name: &str, disambiguator: &str) -> Analysis {
+        Analysis {
+            format_version: FORMAT_VERSION,
+            prelude: Prelude {
+                crate_name: crate_name.to_string(),
+                disambiguator: disambiguator.to_string(),
+                external_crates: Vec::new(),
+            },
+            defs: Vec::new(),
+            refs: Vec::new(),
+            impls: Vec::new(),
+            imports: Vec::new(),
+            macro_uses: Vec::new(),
+        }
+    }
+
+    /// Records every macro invocation which appears in the source of the
+    /// crate. Invocations nested in the arguments of other macros aren't
+    /// included.
+    pub fn record_macro_uses(&mut self, sess: &Session) {
+        let macro_uses = &mut self.macro_uses;
+        sess.codemap().with_expansions(|expansions| {
+            for info in expansions {
+                if super::generated_code(info.call_site) {
+                    continue
+                }
+                let kind = match info.callee.format {
+                    ExpnFormat::MacroBang => "macro_bang",
+                    ExpnFormat::MacroAttribute => "macro_attribute",
+                    ExpnFormat::CompilerExpansion => continue,
+                };
+                macro_uses.push(MacroUse {
+                    name: info.callee.name.clone(),
+                    kind: kind.to_string(),
+                    span: SpanData::new(sess, info.call_site),
+                    callee_span: info.callee.span.map(|sp| SpanData::new(sess, sp)),
+                });
+            }
+        });
+    }
+}
+
+impl Id {
+    pub fn local(id: NodeId) -> Id {
+        Id { krate: ast::LOCAL_CRATE, index: id }
+    }
+
+    pub fn from_def_id(id: DefId) -> Id {
+        Id { krate: id.krate, index: id.node }
+    }
+}
+
+impl SpanData {
+    pub fn new(sess: &Session, span: Span) -> SpanData {
+        let codemap = sess.codemap();
+        let lo = codemap.lookup_char_pos(span.lo);
+        let hi = codemap.lookup_char_pos(span.hi);
+        SpanData {
+            file_name: lo.file.name.clone(),
+            byte_start: codemap.lookup_byte_offset(span.lo).pos.0,
+            byte_end: codemap.lookup_byte_offset(span.hi).pos.0,
+            line_start: lo.line as u32,
+            line_end: hi.line as u32,
+            column_start: lo.col.0 as u32 + 1,
+            column_end: hi.col.0 as u32 + 1,
+        }
+    }
+}
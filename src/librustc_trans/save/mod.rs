@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use back::svh::Svh;
 use middle::ty;
 use middle::def;
 
@@ -28,6 +29,7 @@ use self::span_utils::SpanUtils;
 
 mod span_utils;
 mod recorder;
+mod json;
 
 mod dump_csv;
 
@@ -155,6 +157,7 @@ impl<'l, 'tcx: 'l> SaveContext<'l, 'tcx> {
         self.tcx.sess.cstore.iter_crate_data(|n, cmd| {
             result.push(CrateData { name: cmd.name.clone(), number: n });
         });
+        result.sort_by(|a, b| a.number.cmp(&b.number));
 
         result
     }
@@ -485,6 +488,19 @@ pub fn process_crate(tcx: &ty::ctxt,
     }
 
     assert!(analysis.glob_map.is_some());
+    let opts = &tcx.sess.opts.debugging_opts;
+    if opts.save_analysis {
+        dump_csv(tcx, analysis, odir);
+    }
+    if opts.save_analysis_json {
+        dump_json(tcx, analysis, odir);
+    }
+}
+
+fn dump_csv(tcx: &ty::ctxt,
+            analysis: &ty::CrateAnalysis,
+            odir: Option<&Path>) {
+    let krate = tcx.map.krate();
     let cratename = match attr::find_crate_name(&krate.attrs) {
         Some(name) => name.to_string(),
         None => {
@@ -496,7 +512,7 @@ pub fn process_crate(tcx: &ty::ctxt,
     info!("Dumping crate {}", cratename);
 
     // find a path to dump our data to
-    let mut root_path = match env::var_os("DXR_RUST_TEMP_FOLDER") {
+    let root_path = match env::var_os("DXR_RUST_TEMP_FOLDER") {
         Some(val) => PathBuf::from(val),
         None => match odir {
             Some(val) => val.join("dxr"),
@@ -504,7 +520,44 @@ pub fn process_crate(tcx: &ty::ctxt,
         },
     };
 
-    if let Err(e) = fs::create_dir_all(&root_path) {
+    let output_file = create_output_file(tcx, &root_path, &format!("{}.csv", cratename));
+    let mut visitor = dump_csv::DumpCsvVisitor::new(tcx, analysis, output_file, None);
+
+    visitor.dump_crate_info(&cratename, krate);
+    visit::walk_crate(&mut visitor, krate);
+}
+
+// Unlike the CSV, the JSON output always goes to
+// `<out-dir>/save-analysis/<crate-name><extra-filename>.json`, so that tools
+// can find the analysis for any crate they have the output directory of.
+fn dump_json(tcx: &ty::ctxt,
+             analysis: &ty::CrateAnalysis,
+             odir: Option<&Path>) {
+    let krate = tcx.map.krate();
+    let cratename = &analysis.name;
+    info!("Dumping crate {} as JSON", cratename);
+
+    let root_path = match odir {
+        Some(val) => val.join("save-analysis"),
+        None => PathBuf::from("save-analysis"),
+    };
+    let out_name = format!("{}{}.json", cratename, tcx.sess.opts.cg.extra_filename);
+    let output_file = create_output_file(tcx, &root_path, &out_name);
+
+    // The same hash that ends up in the crate's metadata, which is what
+    // dependent crates know it by.
+    let svh = Svh::calculate(&tcx.sess.opts.cg.metadata, krate);
+    let mut json = json::Analysis::new(cratename, svh.as_str());
+    json.record_macro_uses(&tcx.sess);
+
+    let mut visitor = dump_csv::DumpCsvVisitor::new(tcx, analysis, output_file, Some(json));
+    visitor.dump_crate_info(cratename, krate);
+    visit::walk_crate(&mut visitor, krate);
+    visitor.finish();
+}
+
+fn create_output_file(tcx: &ty::ctxt, root_path: &Path, out_name: &str) -> Box<File> {
+    if let Err(e) = fs::create_dir_all(root_path) {
         tcx.sess.err(&format!("Could not create directory {}: {}",
                               root_path.display(), e));
     }
@@ -515,22 +568,14 @@ pub fn process_crate(tcx: &ty::ctxt,
     }
 
     // Create output file.
-    let mut out_name = cratename.clone();
-    out_name.push_str(".csv");
-    root_path.push(&out_name);
-    let output_file = match File::create(&root_path) {
+    let path = root_path.join(out_name);
+    match File::create(&path) {
         Ok(f) => box f,
         Err(e) => {
-            let disp = root_path.display();
+            let disp = path.display();
             tcx.sess.fatal(&format!("Could not open {}: {}", disp, e));
         }
-    };
-    root_path.pop();
-
-    let mut visitor = dump_csv::DumpCsvVisitor::new(tcx, analysis, output_file);
-
-    visitor.dump_crate_info(&cratename, krate);
-    visit::walk_crate(&mut visitor, krate);
+    }
 }
 
 // Utility functions for the module.
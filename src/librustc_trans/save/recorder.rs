@@ -11,8 +11,11 @@
 pub use self::Row::*;
 
 use super::escape;
+use super::json::{self, Id, SpanData};
 use super::span_utils::SpanUtils;
 
+use serialize::json as json_encoder;
+
 use std::io::Write;
 
 use syntax::ast;
@@ -25,10 +28,15 @@ pub struct Recorder {
     // output file
     pub out: Box<Write+'static>,
     pub dump_spans: bool,
+    // the analysis collected so far, if writing JSON rather than CSV
+    pub json: Option<json::Analysis>,
 }
 
 impl Recorder {
     pub fn record(&mut self, info: &str) {
+        if self.json.is_some() {
+            return;
+        }
         match write!(self.out, "{}", info) {
             Err(_) => error!("Error writing output '{}'", info),
             _ => (),
@@ -45,6 +53,17 @@ impl Recorder {
                              kind, su.extent_str(span), escape(su.snippet(span)));
         self.record(&result[..]);
     }
+
+    // Writes out the JSON analysis, if there is one. CSV is written as it is
+    // recorded.
+    pub fn finish(&mut self) {
+        if let Some(ref analysis) = self.json {
+            let result = json_encoder::encode(analysis).unwrap();
+            if let Err(e) = self.out.write_all(result.as_bytes()) {
+                error!("Error writing output: {}", e);
+            }
+        }
+    }
 }
 
 pub struct FmtStrs<'a> {
@@ -254,6 +273,87 @@ impl<'a> FmtStrs<'a> {
         }
     }
 
+    // Adds an entry to the JSON analysis if one is being collected. `f` is
+    // given the span and the source text of `sub_span`; like CSV, nothing is
+    // recorded without a sub-span.
+    fn record_json<F>(&mut self, sub_span: Option<Span>, f: F) where
+        F: FnOnce(&mut json::Analysis, SpanData, String),
+    {
+        let sub_span = match sub_span {
+            Some(sub_span) => sub_span,
+            None => return,
+        };
+        if self.recorder.json.is_none() {
+            return;
+        }
+        let span = SpanData::new(self.span.sess, sub_span);
+        let text = self.span.snippet(sub_span);
+        f(self.recorder.json.as_mut().unwrap(), span, text);
+    }
+
+    fn json_def(&mut self,
+                kind: Row,
+                sub_span: Option<Span>,
+                id: NodeId,
+                qualname: &str,
+                value: &str,
+                parent: Option<NodeId>,
+                decl_id: Option<DefId>) {
+        let (label, _, _, _) = FmtStrs::lookup_row(kind);
+        self.record_json(sub_span, |json, span, name| {
+            json.defs.push(json::Def {
+                kind: String::from(label),
+                id: Id::local(id),
+                span: span,
+                name: name,
+                qualname: String::from(qualname),
+                value: String::from(value),
+                parent: parent.map(Id::local),
+                decl_id: decl_id.map(Id::from_def_id),
+            });
+        });
+    }
+
+    fn json_ref(&mut self,
+                kind: Row,
+                sub_span: Option<Span>,
+                ref_id: Option<DefId>,
+                decl_id: Option<DefId>,
+                qualname: &str) {
+        let (label, _, _, _) = FmtStrs::lookup_row(kind);
+        self.record_json(sub_span, |json, span, _| {
+            json.refs.push(json::Ref {
+                kind: String::from(label),
+                span: span,
+                ref_id: ref_id.map(Id::from_def_id),
+                decl_id: decl_id.map(Id::from_def_id),
+                qualname: String::from(qualname),
+            });
+        });
+    }
+
+    fn json_import(&mut self,
+                   kind: Row,
+                   sub_span: Option<Span>,
+                   id: NodeId,
+                   name: &str,
+                   ref_id: Option<DefId>,
+                   value: &str,
+                   parent: NodeId) {
+        let (label, _, _, _) = FmtStrs::lookup_row(kind);
+        self.record_json(sub_span, |json, span, _| {
+            json.imports.push(json::Import {
+                kind: String::from(label),
+                id: Id::local(id),
+                span: span,
+                name: String::from(name),
+                ref_id: ref_id.map(Id::from_def_id),
+                value: String::from(value),
+                parent: Id::local(parent),
+            });
+        });
+    }
+
     pub fn variable_str(&mut self,
                         span: Span,
                         sub_span: Option<Span>,
@@ -272,6 +372,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, qualname, value, typ, 0));
+        self.json_def(Variable, sub_span, id, &qualname, typ, None, None);
     }
 
     // formal parameters
@@ -289,6 +390,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, qualname, "", typ, 0));
+        self.json_def(Variable, sub_span, id, &qualname, typ, None, None);
     }
 
     // value is the initialising expression of the static if it is not mut, otherwise "".
@@ -305,6 +407,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, qualname, value, typ, scope_id));
+        self.json_def(Variable, sub_span, id, qualname, typ, Some(scope_id), None);
     }
 
     pub fn field_str(&mut self,
@@ -319,6 +422,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, qualname, "", typ, scope_id));
+        self.json_def(Variable, sub_span, id, qualname, typ, Some(scope_id), None);
     }
 
     pub fn enum_str(&mut self,
@@ -332,6 +436,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, scope_id, value));
+        self.json_def(Enum, sub_span, id, name, value, Some(scope_id), None);
     }
 
     pub fn tuple_variant_str(&mut self,
@@ -347,6 +452,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, qualname, typ, val, scope_id));
+        self.json_def(Variant, sub_span, id, qualname, val, Some(scope_id), None);
     }
 
     pub fn struct_variant_str(&mut self,
@@ -362,6 +468,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, ctor_id, name, typ, val, scope_id));
+        self.json_def(VariantStruct, sub_span, id, name, val, Some(scope_id), None);
     }
 
     pub fn fn_str(&mut self,
@@ -374,6 +481,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, "", "", scope_id));
+        self.json_def(Function, sub_span, id, name, "", Some(scope_id), None);
     }
 
     pub fn method_str(&mut self,
@@ -391,6 +499,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               values);
+        self.json_def(Function, sub_span, id, name, "", Some(scope_id), decl_id);
     }
 
     pub fn method_decl_str(&mut self,
@@ -403,6 +512,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, scope_id));
+        self.json_def(MethodDecl, sub_span, id, name, "", Some(scope_id), None);
     }
 
     pub fn struct_str(&mut self,
@@ -417,6 +527,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, ctor_id, name, scope_id, value));
+        self.json_def(Struct, sub_span, id, name, value, Some(scope_id), None);
     }
 
     pub fn trait_str(&mut self,
@@ -430,6 +541,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, scope_id, value));
+        self.json_def(Trait, sub_span, id, name, value, Some(scope_id), None);
     }
 
     pub fn impl_str(&mut self,
@@ -439,6 +551,15 @@ impl<'a> FmtStrs<'a> {
                     ref_id: Option<DefId>,
                     trait_id: Option<DefId>,
                     scope_id: NodeId) {
+        self.record_json(sub_span, |json, span, _| {
+            json.impls.push(json::Impl {
+                kind: String::from("impl"),
+                id: Some(Id::local(id)),
+                span: span,
+                self_id: ref_id.map(Id::from_def_id),
+                trait_id: trait_id.map(Id::from_def_id),
+            });
+        });
         let ref_id = ref_id.unwrap_or(ZERO_DEF_ID);
         let trait_id = trait_id.unwrap_or(ZERO_DEF_ID);
         self.check_and_record(Impl,
@@ -463,6 +584,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, parent, filename));
+        self.json_def(Module, sub_span, id, name, filename, Some(parent), None);
     }
 
     pub fn use_alias_str(&mut self,
@@ -480,6 +602,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, mod_node, mod_crate, name, parent));
+        self.json_import(UseAlias, sub_span, id, name, mod_id, "", parent);
     }
 
     pub fn use_glob_str(&mut self,
@@ -492,6 +615,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, values, parent));
+        self.json_import(UseGlob, sub_span, id, "", None, values, parent);
     }
 
     pub fn extern_crate_str(&mut self,
@@ -506,6 +630,8 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, name, loc, cnum, parent));
+        let root = DefId { krate: cnum, node: ast::CRATE_NODE_ID };
+        self.json_import(ExternCrate, sub_span, id, name, Some(root), loc, parent);
     }
 
     pub fn inherit_str(&mut self,
@@ -513,6 +639,15 @@ impl<'a> FmtStrs<'a> {
                        sub_span: Option<Span>,
                        base_id: DefId,
                        deriv_id: NodeId) {
+        self.record_json(sub_span, |json, span, _| {
+            json.impls.push(json::Impl {
+                kind: String::from("inheritance"),
+                id: None,
+                span: span,
+                self_id: Some(Id::local(deriv_id)),
+                trait_id: Some(Id::from_def_id(base_id)),
+            });
+        });
         self.check_and_record(Inheritance,
                               span,
                               sub_span,
@@ -531,6 +666,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id.node, id.krate, "", scope_id));
+        self.json_ref(FnCall, sub_span, Some(id), None, "");
     }
 
     pub fn meth_call_str(&mut self,
@@ -539,6 +675,7 @@ impl<'a> FmtStrs<'a> {
                          defid: Option<DefId>,
                          declid: Option<DefId>,
                          scope_id: NodeId) {
+        self.json_ref(MethodCall, sub_span, defid, declid, "");
         let (dfn, dfk) = match defid {
             Some(defid) => (defid.node, defid.krate),
             None => (0, 0)
@@ -562,6 +699,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(0, 0, qualname, parent));
+        self.json_ref(ModRef, Some(sub_span), None, None, qualname);
     }
 
     pub fn typedef_str(&mut self,
@@ -574,6 +712,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id, qualname, value));
+        self.json_def(Typedef, sub_span, id, qualname, value, None, None);
     }
 
     pub fn crate_str(&mut self,
@@ -589,6 +728,13 @@ impl<'a> FmtStrs<'a> {
                               span: Span,
                               name: &str,
                               num: ast::CrateNum) {
+        if let Some(ref mut json) = self.recorder.json {
+            json.prelude.external_crates.push(json::ExternalCrate {
+                num: num,
+                name: String::from(name),
+                disambiguator: self.span.sess.cstore.get_crate_hash(num).as_str().to_string(),
+            });
+        }
         let lo_loc = self.span.sess.codemap().lookup_char_pos(span.lo);
         self.record_without_span(ExternalCrate,
                                  svec!(name, num, lo_loc.file.name),
@@ -603,6 +749,7 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(0, 0, qualname, 0));
+        self.json_ref(TypeRef, Some(sub_span), None, None, qualname);
     }
 
     // A slightly generic function for a reference to an item of any kind.
@@ -616,5 +763,6 @@ impl<'a> FmtStrs<'a> {
                               span,
                               sub_span,
                               svec!(id.node, id.krate, "", scope_id));
+        self.json_ref(kind, sub_span, Some(id), None, "");
     }
 }
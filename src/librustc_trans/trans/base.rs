@@ -30,7 +30,7 @@ use super::CrateTranslation;
 use super::ModuleTranslation;
 
 use back::link::mangle_exported_name;
-use back::{link, abi};
+use back::{link, abi, incremental};
 use lint;
 use llvm::{BasicBlockRef, Linkage, ValueRef, Vector, get_param};
 use llvm;
@@ -82,7 +82,7 @@ use trans::type_of::*;
 use trans::value::Value;
 use util::common::indenter;
 use util::sha2::Sha256;
-use util::nodemap::NodeMap;
+use util::nodemap::{NodeMap, NodeSet};
 
 use arena::TypedArena;
 use libc::c_uint;
@@ -583,7 +583,7 @@ fn cast_shift_rhs<F, G>(op: ast::BinOp_,
 }
 
 pub fn llty_and_min_for_signed_ty<'blk, 'tcx>(cx: Block<'blk, 'tcx>,
-                                              val_t: Ty<'tcx>) -> (Type, u64) {
+                                              val_t: Ty<'tcx>) -> (Type, ValueRef) {
     match val_t.sty {
         ty::TyInt(t) => {
             let llty = Type::int_from_ty(cx.ccx(), t);
@@ -594,8 +594,14 @@ pub fn llty_and_min_for_signed_ty<'blk, 'tcx>(cx: Block<'blk, 'tcx>,
                 ast::TyI16 => i16::MIN as u64,
                 ast::TyI32 => i32::MIN as u64,
                 ast::TyI64 => i64::MIN as u64,
+                // i128::MIN doesn't fit in a u64, so build it as `1 << 127`
+                ast::TyI128 => {
+                    let one = C_integral(llty, 1, false);
+                    let shift = C_integral(llty, 127, false);
+                    return (llty, unsafe { llvm::LLVMConstShl(one, shift) });
+                }
             };
-            (llty, min)
+            (llty, C_integral(llty, min, true))
         }
         _ => unreachable!(),
     }
@@ -656,10 +662,9 @@ pub fn fail_if_zero_or_overflows<'blk, 'tcx>(
     if is_signed {
         let (llty, min) = llty_and_min_for_signed_ty(cx, rhs_t);
         let minus_one = ICmp(bcx, llvm::IntEQ, rhs,
-                             C_integral(llty, !0, false), debug_loc);
+                             C_integral(llty, !0, true), debug_loc);
         with_cond(bcx, minus_one, |bcx| {
-            let is_min = ICmp(bcx, llvm::IntEQ, lhs,
-                              C_integral(llty, min, true), debug_loc);
+            let is_min = ICmp(bcx, llvm::IntEQ, lhs, min, debug_loc);
             with_cond(bcx, is_min, |bcx| {
                 controlflow::trans_fail(bcx,
                                         call_info,
@@ -2011,6 +2016,12 @@ pub fn update_linkage(ccx: &CrateContext,
 pub fn trans_item(ccx: &CrateContext, item: &ast::Item) {
     let _icx = push_ctxt("trans_item");
 
+    // The codegen unit this item belongs to is reused from the previous
+    // incremental session.
+    if ccx.reused_items().contains(&item.id) {
+        return
+    }
+
     let from_external = ccx.external_srcs().borrow().contains_key(&item.id);
 
     match item.node {
@@ -2059,7 +2070,15 @@ pub fn trans_item(ccx: &CrateContext, item: &ast::Item) {
                          item.id);
       }
       ast::ItemMod(ref m) => {
-        trans_mod(&ccx.rotate(), m);
+        if ccx.sess().opts.debugging_opts.incremental.is_some() {
+            // Incremental compilation needs modules to end up in the same
+            // codegen unit from one session to the next.
+            let units = ccx.sess().opts.cg.codegen_units;
+            let unit = incremental::codegen_unit(ccx.tcx(), item.id, units);
+            trans_mod(&ccx.shared().get_ccx(unit), m);
+        } else {
+            trans_mod(&ccx.rotate(), m);
+        }
       }
       ast::ItemEnum(ref enum_definition, ref gens) => {
         if gens.ty_params.is_empty() {
@@ -2696,6 +2715,14 @@ pub fn trans_crate(tcx: &ty::ctxt, analysis: ty::CrateAnalysis) -> CrateTranslat
 
     let link_meta = link::build_link_meta(&tcx.sess, krate, name);
 
+    // With incremental compilation, work out which codegen units can be
+    // reused before translating anything, so that their items are skipped.
+    let incremental = incremental::prepare(tcx, krate, &link_meta.crate_name);
+    let reused_items = match incremental {
+        Some(ref state) => state.reused_items().clone(),
+        None => NodeSet(),
+    };
+
     let codegen_units = tcx.sess.opts.cg.codegen_units;
     let shared_ccx = SharedCrateContext::new(&link_meta.crate_name,
                                              codegen_units,
@@ -2704,6 +2731,7 @@ pub fn trans_crate(tcx: &ty::ctxt, analysis: ty::CrateAnalysis) -> CrateTranslat
                                              Sha256::new(),
                                              link_meta.clone(),
                                              reachable,
+                                             reused_items,
                                              check_overflow,
                                              check_dropflag);
 
@@ -2760,7 +2788,7 @@ pub fn trans_crate(tcx: &ty::ctxt, analysis: ty::CrateAnalysis) -> CrateTranslat
 
     let modules = shared_ccx.iter()
         .map(|ccx| ModuleTranslation { llcx: ccx.llcx(), llmod: ccx.llmod() })
-        .collect();
+        .collect::<Vec<_>>();
 
     let mut reachable: Vec<String> = shared_ccx.reachable().iter().filter_map(|id| {
         shared_ccx.item_symbols().borrow().get(id).map(|s| s.to_string())
@@ -2791,7 +2819,13 @@ pub fn trans_crate(tcx: &ty::ctxt, analysis: ty::CrateAnalysis) -> CrateTranslat
     reachable.push("rust_eh_personality_catch".to_string());
 
     if codegen_units > 1 {
-        internalize_symbols(&shared_ccx, &reachable.iter().cloned().collect());
+        let mut keep = reachable.iter().cloned().collect::<HashSet<_>>();
+        if incremental.is_some() {
+            // Objects reused from a previous session may refer to any of the
+            // crate's items, and objects produced now may be reused later.
+            keep.extend(shared_ccx.item_symbols().borrow().values().cloned());
+        }
+        internalize_symbols(&shared_ccx, &keep);
     }
 
     let metadata_module = ModuleTranslation {
@@ -2809,5 +2843,6 @@ pub fn trans_crate(tcx: &ty::ctxt, analysis: ty::CrateAnalysis) -> CrateTranslat
         reachable: reachable,
         crate_formats: formats,
         no_builtins: no_builtins,
+        incremental: incremental,
     }
 }
@@ -50,17 +50,20 @@ pub fn const_lit(cx: &CrateContext, e: &ast::Expr, lit: &ast::Lit)
     match lit.node {
         ast::LitByte(b) => C_integral(Type::uint_from_ty(cx, ast::TyU8), b as u64, false),
         ast::LitChar(i) => C_integral(Type::char(cx), i as u64, false),
-        ast::LitInt(i, ast::SignedIntLit(t, _)) => {
-            C_integral(Type::int_from_ty(cx, t), i, true)
+        // The lexer hands us the literal's magnitude, so only sign-extend
+        // values which are already negative. Sign-extending a large positive
+        // value would be wrong for `i128`, and makes no difference otherwise.
+        ast::LitInt(i, ast::SignedIntLit(t, sign)) => {
+            C_integral(Type::int_from_ty(cx, t), i, sign == ast::Minus)
         }
         ast::LitInt(u, ast::UnsignedIntLit(t)) => {
             C_integral(Type::uint_from_ty(cx, t), u, false)
         }
-        ast::LitInt(i, ast::UnsuffixedIntLit(_)) => {
+        ast::LitInt(i, ast::UnsuffixedIntLit(sign)) => {
             let lit_int_ty = cx.tcx().node_id_to_type(e.id);
             match lit_int_ty.sty {
                 ty::TyInt(t) => {
-                    C_integral(Type::int_from_ty(cx, t), i as u64, true)
+                    C_integral(Type::int_from_ty(cx, t), i as u64, sign == ast::Minus)
                 }
                 ty::TyUint(t) => {
                     C_integral(Type::uint_from_ty(cx, t), i as u64, false)
@@ -392,6 +395,9 @@ fn check_unary_expr_validity(cx: &CrateContext, e: &ast::Expr, t: Ty,
         if let ast::ExprLit(_) = inner_e.node { return; }
 
         let result = match t.sty {
+            // 128-bit constants don't fit in what `const_to_opt_int` returns;
+            // LLVM folds them correctly, there just isn't an overflow check.
+            ty::TyInt(ast::TyI128) | ty::TyUint(ast::TyU128) => return,
             ty::TyInt(int_type) => {
                 let input = match const_to_opt_int(te) {
                     Some(v) => v,
@@ -423,6 +429,8 @@ fn check_binary_expr_validity(cx: &CrateContext, e: &ast::Expr, t: Ty,
     let b = if let ast::ExprBinary(b, _, _) = e.node { b } else { return };
 
     let result = match t.sty {
+        // See `check_unary_expr_validity`.
+        ty::TyInt(ast::TyI128) | ty::TyUint(ast::TyU128) => return,
         ty::TyInt(int_type) => {
             let (lhs, rhs) = match (const_to_opt_int(te1),
                                     const_to_opt_int(te2)) {
@@ -48,11 +48,13 @@ pub fn push_debuginfo_type_name<'a, 'tcx>(cx: &CrateContext<'a, 'tcx>,
         ty::TyInt(ast::TyI16)   => output.push_str("i16"),
         ty::TyInt(ast::TyI32)   => output.push_str("i32"),
         ty::TyInt(ast::TyI64)   => output.push_str("i64"),
+        ty::TyInt(ast::TyI128)  => output.push_str("i128"),
         ty::TyUint(ast::TyUs)    => output.push_str("usize"),
         ty::TyUint(ast::TyU8)   => output.push_str("u8"),
         ty::TyUint(ast::TyU16)  => output.push_str("u16"),
         ty::TyUint(ast::TyU32)  => output.push_str("u32"),
         ty::TyUint(ast::TyU64)  => output.push_str("u64"),
+        ty::TyUint(ast::TyU128) => output.push_str("u128"),
         ty::TyFloat(ast::TyF32) => output.push_str("f32"),
         ty::TyFloat(ast::TyF64) => output.push_str("f64"),
         ty::TyStruct(def_id, substs) |
@@ -1640,9 +1640,8 @@ fn trans_unary<'blk, 'tcx>(bcx: Block<'blk, 'tcx>,
                     let is_signed = un_ty.is_signed();
                     let result = Neg(bcx, val, debug_loc);
                     let bcx = if bcx.ccx().check_overflow() && is_signed {
-                        let (llty, min) = base::llty_and_min_for_signed_ty(bcx, un_ty);
-                        let is_min = ICmp(bcx, llvm::IntEQ, val,
-                                          C_integral(llty, min, true), debug_loc);
+                        let (_, min) = base::llty_and_min_for_signed_ty(bcx, un_ty);
+                        let is_min = ICmp(bcx, llvm::IntEQ, val, min, debug_loc);
                         with_cond(bcx, is_min, |bcx| {
                             let msg = InternedString::new(
                                 "attempted to negate with overflow");
@@ -2391,11 +2390,13 @@ impl OverflowOpViaIntrinsic {
                 TyInt(TyI16) => "llvm.sadd.with.overflow.i16",
                 TyInt(TyI32) => "llvm.sadd.with.overflow.i32",
                 TyInt(TyI64) => "llvm.sadd.with.overflow.i64",
+                TyInt(TyI128) => "llvm.sadd.with.overflow.i128",
 
                 TyUint(TyU8) => "llvm.uadd.with.overflow.i8",
                 TyUint(TyU16) => "llvm.uadd.with.overflow.i16",
                 TyUint(TyU32) => "llvm.uadd.with.overflow.i32",
                 TyUint(TyU64) => "llvm.uadd.with.overflow.i64",
+                TyUint(TyU128) => "llvm.uadd.with.overflow.i128",
 
                 _ => unreachable!(),
             },
@@ -2404,11 +2405,13 @@ impl OverflowOpViaIntrinsic {
                 TyInt(TyI16) => "llvm.ssub.with.overflow.i16",
                 TyInt(TyI32) => "llvm.ssub.with.overflow.i32",
                 TyInt(TyI64) => "llvm.ssub.with.overflow.i64",
+                TyInt(TyI128) => "llvm.ssub.with.overflow.i128",
 
                 TyUint(TyU8) => "llvm.usub.with.overflow.i8",
                 TyUint(TyU16) => "llvm.usub.with.overflow.i16",
                 TyUint(TyU32) => "llvm.usub.with.overflow.i32",
                 TyUint(TyU64) => "llvm.usub.with.overflow.i64",
+                TyUint(TyU128) => "llvm.usub.with.overflow.i128",
 
                 _ => unreachable!(),
             },
@@ -2417,11 +2420,13 @@ impl OverflowOpViaIntrinsic {
                 TyInt(TyI16) => "llvm.smul.with.overflow.i16",
                 TyInt(TyI32) => "llvm.smul.with.overflow.i32",
                 TyInt(TyI64) => "llvm.smul.with.overflow.i64",
+                TyInt(TyI128) => "llvm.smul.with.overflow.i128",
 
                 TyUint(TyU8) => "llvm.umul.with.overflow.i8",
                 TyUint(TyU16) => "llvm.umul.with.overflow.i16",
                 TyUint(TyU32) => "llvm.umul.with.overflow.i32",
                 TyUint(TyU64) => "llvm.umul.with.overflow.i64",
+                TyUint(TyU128) => "llvm.umul.with.overflow.i128",
 
                 _ => unreachable!(),
             },
@@ -79,9 +79,11 @@ pub fn get_simple_intrinsic(ccx: &CrateContext, item: &ast::ForeignItem) -> Opti
         "ctpop16" => "llvm.ctpop.i16",
         "ctpop32" => "llvm.ctpop.i32",
         "ctpop64" => "llvm.ctpop.i64",
+        "ctpop128" => "llvm.ctpop.i128",
         "bswap16" => "llvm.bswap.i16",
         "bswap32" => "llvm.bswap.i32",
         "bswap64" => "llvm.bswap.i64",
+        "bswap128" => "llvm.bswap.i128",
         "assume" => "llvm.assume",
         _ => return None
     };
@@ -545,6 +547,10 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                                "llvm.ctlz.i64",
                                                llargs[0],
                                                call_debug_location),
+        (_, "ctlz128") => count_zeros_intrinsic(bcx,
+                                                "llvm.ctlz.i128",
+                                                llargs[0],
+                                                call_debug_location),
         (_, "cttz8") => count_zeros_intrinsic(bcx,
                                               "llvm.cttz.i8",
                                               llargs[0],
@@ -561,6 +567,10 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                                "llvm.cttz.i64",
                                                llargs[0],
                                                call_debug_location),
+        (_, "cttz128") => count_zeros_intrinsic(bcx,
+                                                "llvm.cttz.i128",
+                                                llargs[0],
+                                                call_debug_location),
 
         (_, "i8_add_with_overflow") =>
             with_overflow_intrinsic(bcx,
@@ -590,6 +600,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "i128_add_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.sadd.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
 
         (_, "u8_add_with_overflow") =>
             with_overflow_intrinsic(bcx,
@@ -619,6 +636,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "u128_add_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.uadd.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
         (_, "i8_sub_with_overflow") =>
             with_overflow_intrinsic(bcx,
                                     "llvm.ssub.with.overflow.i8",
@@ -647,6 +671,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "i128_sub_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.ssub.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
         (_, "u8_sub_with_overflow") =>
             with_overflow_intrinsic(bcx,
                                     "llvm.usub.with.overflow.i8",
@@ -675,6 +706,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "u128_sub_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.usub.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
         (_, "i8_mul_with_overflow") =>
             with_overflow_intrinsic(bcx,
                                     "llvm.smul.with.overflow.i8",
@@ -703,6 +741,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "i128_mul_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.smul.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
         (_, "u8_mul_with_overflow") =>
             with_overflow_intrinsic(bcx,
                                     "llvm.umul.with.overflow.i8",
@@ -731,6 +776,13 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                                     llargs[0],
                                     llargs[1],
                                     call_debug_location),
+        (_, "u128_mul_with_overflow") =>
+            with_overflow_intrinsic(bcx,
+                                    "llvm.umul.with.overflow.i128",
+                                    ret_ty,
+                                    llargs[0],
+                                    llargs[1],
+                                    call_debug_location),
 
         (_, "unchecked_udiv") => UDiv(bcx, llargs[0], llargs[1], call_debug_location),
         (_, "unchecked_sdiv") => SDiv(bcx, llargs[0], llargs[1], call_debug_location),
@@ -764,26 +816,39 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
         }
 
         // This requires that atomic intrinsics follow a specific naming pattern:
-        // "atomic_<operation>[_<ordering>]", and no ordering means SeqCst
+        // "atomic_<operation>[_<ordering>]", and no ordering means SeqCst.
+        // Compare-exchange may additionally name its failure ordering, as in
+        // "atomic_cxchg[_<ordering>]_fail<ordering>".
         (_, name) if name.starts_with("atomic_") => {
             let split: Vec<&str> = name.split('_').collect();
             assert!(split.len() >= 2, "Atomic intrinsic not correct format");
 
-            let order = if split.len() == 2 {
-                llvm::SequentiallyConsistent
-            } else {
-                match split[2] {
-                    "unordered" => llvm::Unordered,
-                    "relaxed" => llvm::Monotonic,
-                    "acq"     => llvm::Acquire,
-                    "rel"     => llvm::Release,
-                    "acqrel"  => llvm::AcquireRelease,
-                    _ => ccx.sess().fatal("unknown ordering in atomic intrinsic")
-                }
+            let is_cxchg = split[1] == "cxchg" || split[1] == "cxchgweak";
+            let parse_order = |order: &str| match order {
+                "unordered" => llvm::Unordered,
+                "relaxed" => llvm::Monotonic,
+                "acq"     => llvm::Acquire,
+                "rel"     => llvm::Release,
+                "acqrel"  => llvm::AcquireRelease,
+                _ => ccx.sess().fatal("unknown ordering in atomic intrinsic")
+            };
+            let parse_failure_order = |order: &str| match order {
+                "failrelaxed" if is_cxchg => llvm::Monotonic,
+                "failacq" if is_cxchg => llvm::Acquire,
+                _ => ccx.sess().fatal("unknown failure ordering in atomic intrinsic")
+            };
+
+            let (order, failure_order) = match split.len() {
+                2 => (llvm::SequentiallyConsistent, None),
+                3 if split[2].starts_with("fail") =>
+                    (llvm::SequentiallyConsistent, Some(parse_failure_order(split[2]))),
+                3 => (parse_order(split[2]), None),
+                4 => (parse_order(split[2]), Some(parse_failure_order(split[3]))),
+                _ => ccx.sess().fatal("Atomic intrinsic not correct format")
             };
 
             match split[1] {
-                "cxchg" => {
+                "cxchg" | "cxchgweak" => {
                     // See include/llvm/IR/Instructions.h for their implementation
                     // of this, I assume that it's good enough for us to use for
                     // now.
@@ -800,14 +865,33 @@ pub fn trans_intrinsic_call<'a, 'blk, 'tcx>(mut bcx: Block<'blk, 'tcx>,
                         llvm::SequentiallyConsistent =>
                             llvm::SequentiallyConsistent
                     };
+                    let failure_order = failure_order.unwrap_or(strongest_failure_ordering);
+                    let weak = if split[1] == "cxchgweak" { llvm::True } else { llvm::False };
 
                     let tp_ty = *substs.types.get(FnSpace, 0);
                     let ptr = to_arg_ty_ptr(bcx, llargs[0], tp_ty);
                     let cmp = from_arg_ty(bcx, llargs[1], tp_ty);
                     let src = from_arg_ty(bcx, llargs[2], tp_ty);
                     let res = AtomicCmpXchg(bcx, ptr, cmp, src, order,
-                                            strongest_failure_ordering);
-                    ExtractValue(bcx, res, 0)
+                                            failure_order, weak);
+                    if split[1] == "cxchg" {
+                        ExtractValue(bcx, res, 0)
+                    } else {
+                        // A weak exchange can fail spuriously, so return the
+                        // success flag alongside the previous value.
+                        let result = ExtractValue(bcx, res, 0);
+                        let success = ZExt(bcx, ExtractValue(bcx, res, 1), Type::bool(ccx));
+                        let ret = C_undef(type_of::type_of(ccx, ret_ty));
+                        let ret = InsertValue(bcx, ret, result, 0);
+                        let ret = InsertValue(bcx, ret, success, 1);
+                        if !arg_is_indirect(ccx, ret_ty) {
+                            let tmp = alloc_ty(bcx, ret_ty, "tmp");
+                            Store(bcx, ret, tmp);
+                            load_ty(bcx, tmp, ret_ty)
+                        } else {
+                            ret
+                        }
+                    }
                 }
 
                 "load" => {
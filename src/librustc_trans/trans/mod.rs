@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use back::incremental::IncrementalState;
 use llvm::{ContextRef, ModuleRef};
 use metadata::common::LinkMeta;
 use middle::dependency_format;
@@ -76,4 +77,5 @@ pub struct CrateTranslation {
     pub reachable: Vec<String>,
     pub crate_formats: dependency_format::Dependencies,
     pub no_builtins: bool,
+    pub incremental: Option<IncrementalState>,
 }
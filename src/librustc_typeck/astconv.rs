@@ -68,6 +68,7 @@ use std::iter::repeat;
 use std::slice;
 use syntax::{abi, ast, ast_util};
 use syntax::codemap::{Span, Pos};
+use syntax::feature_gate::emit_feature_err;
 use syntax::parse::token;
 use syntax::print::pprust;
 
@@ -1456,6 +1457,16 @@ fn base_def_to_ty<'tcx>(this: &AstConv<'tcx>,
             opt_self_ty.expect("missing T in <T>::a::b::c")
         }
         def::DefPrimTy(prim_ty) => {
+            match prim_ty {
+                ast::TyInt(ast::TyI128) | ast::TyUint(ast::TyU128)
+                        if !tcx.sess.features.borrow().i128_type => {
+                    emit_feature_err(&tcx.sess.parse_sess.span_diagnostic,
+                                     "i128_type",
+                                     span,
+                                     "128-bit integers are not stable");
+                }
+                _ => {}
+            }
             prim_ty_to_ty(tcx, base_segments, prim_ty)
         }
         _ => {
@@ -4122,7 +4122,7 @@ pub fn check_enum_variants<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                 ast::TyU8 => disr as u8 as Disr == disr,
                 ast::TyU16 => disr as u16 as Disr == disr,
                 ast::TyU32 => disr as u32 as Disr == disr,
-                ast::TyU64 => disr as u64 as Disr == disr,
+                ast::TyU64 | ast::TyU128 => disr as u64 as Disr == disr,
                 ast::TyUs => uint_in_range(ccx, ccx.tcx.sess.target.uint_type, disr)
             }
         }
@@ -4131,7 +4131,7 @@ pub fn check_enum_variants<'a,'tcx>(ccx: &CrateCtxt<'a,'tcx>,
                 ast::TyI8 => disr as i8 as Disr == disr,
                 ast::TyI16 => disr as i16 as Disr == disr,
                 ast::TyI32 => disr as i32 as Disr == disr,
-                ast::TyI64 => disr as i64 as Disr == disr,
+                ast::TyI64 | ast::TyI128 => disr as i64 as Disr == disr,
                 ast::TyIs => int_in_range(ccx, ccx.tcx.sess.target.int_type, disr)
             }
         }
@@ -4867,6 +4867,10 @@ pub fn check_intrinsic_type(ccx: &CrateCtxt, it: &ast::ForeignItem) {
                                 param(ccx, 0),
                                 param(ccx, 0)),
                         param(ccx, 0)),
+            "cxchgweak" => (1, vec!(tcx.mk_mut_ptr(param(ccx, 0)),
+                                    param(ccx, 0),
+                                    param(ccx, 0)),
+                            tcx.mk_tup(vec!(param(ccx, 0), tcx.types.bool))),
             "load" => (1, vec!(tcx.mk_imm_ptr(param(ccx, 0))),
                        param(ccx, 0)),
             "store" => (1, vec!(tcx.mk_mut_ptr(param(ccx, 0)), param(ccx, 0)),
@@ -5044,17 +5048,21 @@ pub fn check_intrinsic_type(ccx: &CrateCtxt, it: &ast::ForeignItem) {
             "ctpop16"      => (0, vec!( tcx.types.u16 ), tcx.types.u16),
             "ctpop32"      => (0, vec!( tcx.types.u32 ), tcx.types.u32),
             "ctpop64"      => (0, vec!( tcx.types.u64 ), tcx.types.u64),
+            "ctpop128"     => (0, vec!( tcx.types.u128 ), tcx.types.u128),
             "ctlz8"        => (0, vec!( tcx.types.u8  ), tcx.types.u8),
             "ctlz16"       => (0, vec!( tcx.types.u16 ), tcx.types.u16),
             "ctlz32"       => (0, vec!( tcx.types.u32 ), tcx.types.u32),
             "ctlz64"       => (0, vec!( tcx.types.u64 ), tcx.types.u64),
+            "ctlz128"      => (0, vec!( tcx.types.u128 ), tcx.types.u128),
             "cttz8"        => (0, vec!( tcx.types.u8  ), tcx.types.u8),
             "cttz16"       => (0, vec!( tcx.types.u16 ), tcx.types.u16),
             "cttz32"       => (0, vec!( tcx.types.u32 ), tcx.types.u32),
             "cttz64"       => (0, vec!( tcx.types.u64 ), tcx.types.u64),
+            "cttz128"      => (0, vec!( tcx.types.u128 ), tcx.types.u128),
             "bswap16"      => (0, vec!( tcx.types.u16 ), tcx.types.u16),
             "bswap32"      => (0, vec!( tcx.types.u32 ), tcx.types.u32),
             "bswap64"      => (0, vec!( tcx.types.u64 ), tcx.types.u64),
+            "bswap128"     => (0, vec!( tcx.types.u128 ), tcx.types.u128),
 
             "volatile_load" =>
                 (1, vec!( tcx.mk_imm_ptr(param(ccx, 0)) ), param(ccx, 0)),
@@ -5077,6 +5085,10 @@ pub fn check_intrinsic_type(ccx: &CrateCtxt, it: &ast::ForeignItem) {
                 (0, vec!(tcx.types.i64, tcx.types.i64),
                 tcx.mk_tup(vec!(tcx.types.i64, tcx.types.bool))),
 
+            "i128_add_with_overflow" | "i128_sub_with_overflow" | "i128_mul_with_overflow" =>
+                (0, vec!(tcx.types.i128, tcx.types.i128),
+                tcx.mk_tup(vec!(tcx.types.i128, tcx.types.bool))),
+
             "u8_add_with_overflow" | "u8_sub_with_overflow" | "u8_mul_with_overflow" =>
                 (0, vec!(tcx.types.u8, tcx.types.u8),
                 tcx.mk_tup(vec!(tcx.types.u8, tcx.types.bool))),
@@ -5093,6 +5105,10 @@ pub fn check_intrinsic_type(ccx: &CrateCtxt, it: &ast::ForeignItem) {
                 (0, vec!(tcx.types.u64, tcx.types.u64),
                 tcx.mk_tup(vec!(tcx.types.u64, tcx.types.bool))),
 
+            "u128_add_with_overflow" | "u128_sub_with_overflow" | "u128_mul_with_overflow" =>
+                (0, vec!(tcx.types.u128, tcx.types.u128),
+                tcx.mk_tup(vec!(tcx.types.u128, tcx.types.bool))),
+
             "unchecked_udiv" | "unchecked_sdiv" | "unchecked_urem" | "unchecked_srem" =>
                 (1, vec![param(ccx, 0), param(ccx, 0)], param(ccx, 0)),
 
@@ -19,27 +19,42 @@ extract an incorrect number of fields from a variant.
 
 ```
 enum Fruit {
-    Apple(String, String)
-    Pear(u32)
+    Apple(String, String),
+    Pear(u32),
 }
 ```
 
 Here the `Apple` variant has two fields, and should be matched against like so:
 
 ```
+enum Fruit {
+    Apple(String, String),
+    Pear(u32),
+}
+
+let x = Fruit::Apple(String::new(), String::new());
+
 // Correct.
 match x {
-    Apple(a, b) => ...
+    Fruit::Apple(a, b) => {},
+    _ => {}
 }
 ```
 
 Matching with the wrong number of fields has no sensible interpretation:
 
-```
+```compile_fail,E0023
+enum Fruit {
+    Apple(String, String),
+    Pear(u32),
+}
+
+let x = Fruit::Apple(String::new(), String::new());
+
 // Incorrect.
 match x {
-    Apple(a) => ...,
-    Apple(a, b, c) => ...
+    Fruit::Apple(a) => {},
+    Fruit::Apple(a, b, c) => {},
 }
 ```
 
@@ -51,7 +66,7 @@ E0024: r##"
 This error indicates that a pattern attempted to extract the fields of an enum
 variant with no fields. Here's a tiny example of this error:
 
-```
+```compile_fail,E0024
 // This enum has two variants.
 enum Number {
     // This variant has no fields.
@@ -60,10 +75,12 @@ enum Number {
     One(u32)
 }
 
-// Assuming x is a Number we can pattern match on its contents.
+let x = Number::Zero;
+
+// We can pattern match on the contents of x.
 match x {
-    Zero(inside) => ...,
-    One(inside) => ...
+    Number::Zero(inside) => {},
+    Number::One(inside) => {},
 }
 ```
 
@@ -73,14 +90,43 @@ the enum.
 "##,
 
 E0025: r##"
-Each field of a struct can only be bound once in a pattern. Each occurrence of a
-field name binds the value of that field, so to fix this error you will have to
-remove or alter the duplicate uses of the field name. Perhaps you misspelt
-another field name?
+Each field of a struct can only be bound once in a pattern. Erroneous code
+example:
+
+```compile_fail,E0025
+struct Foo {
+    a: u8,
+    b: u8,
+}
+
+fn main(){
+    let x = Foo { a:1, b:2 };
+
+    let Foo { a: x, a: y } = x;
+    // error: field `a` bound multiple times in the pattern
+}
+```
+
+Each occurrence of a field name binds the value of that field, so to fix this
+error you will have to remove or alter the duplicate uses of the field name.
+Perhaps you misspelled another field name? Example:
+
+```
+struct Foo {
+    a: u8,
+    b: u8,
+}
+
+fn main(){
+    let x = Foo { a:1, b:2 };
+
+    let Foo { a: x, b: y } = x; // ok!
+}
+```
 "##,
 
 E0026: r##"
-This error indicates that a struct pattern attempted to extract a non-existant
+This error indicates that a struct pattern attempted to extract a non-existent
 field from a struct. Struct fields are identified by the name used before the
 colon `:` so struct patterns should resemble the declaration of the struct type
 being matched.
@@ -94,22 +140,40 @@ struct Thing {
 
 let thing = Thing { x: 1, y: 2 };
 match thing {
-    Thing { x: xfield, y: yfield } => ...
+    Thing { x: xfield, y: yfield } => {}
 }
 ```
 
 If you are using shorthand field patterns but want to refer to the struct field
 by a different name, you should rename it explicitly.
 
-```
-// Change this:
+Change this:
+
+```compile_fail,E0026
+struct Thing {
+    x: u32,
+    y: u32
+}
+
+let thing = Thing { x: 0, y: 0 };
+
 match thing {
-    Thing { x, z } => ...
+    Thing { x, z } => {}
+}
+```
+
+To this:
+
+```
+struct Thing {
+    x: u32,
+    y: u32
 }
 
-// To this:
+let thing = Thing { x: 0, y: 0 };
+
 match thing {
-    Thing { x, y: z } => ...
+    Thing { x, y: z } => {}
 }
 ```
 "##,
@@ -121,7 +185,7 @@ definition is mentioned in the pattern, or use `..` to ignore unwanted fields.
 
 For example:
 
-```
+```compile_fail,E0027
 struct Dog {
     name: String,
     age: u32
@@ -131,17 +195,27 @@ let d = Dog { name: "Rusty".to_string(), age: 8 };
 
 // This is incorrect.
 match d {
-    Dog { age: x } => ...
+    Dog { age: x } => {}
+}
+```
+
+This is correct (explicit):
+
+```
+struct Dog {
+    name: String,
+    age: u32
 }
 
-// This is correct (explicit).
+let d = Dog { name: "Rusty".to_string(), age: 8 };
+
 match d {
-    Dog { name: n, age: x } => ...
+    Dog { name: n, age: x } => {}
 }
 
 // This is also correct (ignore unused fields).
 match d {
-    Dog { age: x, .. } => ...
+    Dog { age: x, .. } => {}
 }
 ```
 "##,
@@ -153,18 +227,25 @@ compile-time, and is unable to evaluate arbitrary comparison functions. If you
 want to capture values of an orderable type between two end-points, you can use
 a guard.
 
-```
+```compile_fail,E0029
+let string = "salutations !";
+
 // The ordering relation for strings can't be evaluated at compile time,
 // so this doesn't work:
 match string {
-    "hello" ... "world" => ...
-    _ => ...
+    "hello" ... "world" => {}
+    _ => {}
 }
+```
+
+This is a more general version, using a guard:
+
+```
+let string = "salutations !";
 
-// This is a more general version, using a guard:
 match string {
-    s if s >= "hello" && s <= "world" => ...
-    _ => ...
+    s if s >= "hello" && s <= "world" => {}
+    _ => {}
 }
 ```
 "##,
@@ -177,12 +258,13 @@ range.
 
 For example:
 
-```
+```compile_fail,E0030
 match 5u32 {
     // This range is ok, albeit pointless.
-    1 ... 1 => ...
+    1 ... 1 => {}
     // This range is empty, and the compiler can tell.
-    1000 ... 5 => ...
+    1000 ... 5 => {}
+    _ => {}
 }
 ```
 "##,
@@ -194,8 +276,15 @@ size of trait implementors isn't fixed, this type has no compile-time size.
 Therefore, all accesses to trait types must be through pointers. If you
 encounter this error you should try to avoid dereferencing the pointer.
 
-```
-let trait_obj: &SomeTrait = ...;
+```compile_fail,E0033
+trait SomeTrait {
+    fn method_one(&self) {}
+    fn method_two(&self) {}
+}
+
+impl<T> SomeTrait for T {}
+
+let trait_obj: &SomeTrait = &"some_value";
 
 // This tries to implicitly dereference to create an unsized local variable.
 let &invalid = trait_obj;
@@ -215,7 +304,7 @@ E0034: r##"
 The compiler doesn't know what method to call because more than one method
 has the same prototype. Example:
 
-```
+```compile_fail,E0034
 struct Test;
 
 trait Trait1 {
@@ -277,7 +366,7 @@ fn main() {
 E0035: r##"
 You tried to give a type parameter where it wasn't needed. Bad example:
 
-```
+```compile_fail,E0035
 struct Test;
 
 impl Test {
@@ -312,7 +401,7 @@ E0036: r##"
 This error occurrs when you pass too many or not enough type parameters to
 a method. Example:
 
-```
+```compile_fail,E0036
 struct Test;
 
 impl Test {
@@ -350,7 +439,7 @@ fn main() {
 
 Please note on the last example that we could have called `method` like this:
 
-```
+```ignore
 x.method(v);
 ```
 "##,
@@ -362,7 +451,7 @@ out of scope.
 
 Here's an example of this error:
 
-```
+```compile_fail,E0040
 struct Foo {
     x: i32,
 }
@@ -383,7 +472,7 @@ fn main() {
 E0044: r##"
 You can't use type parameters on foreign items. Example of erroneous code:
 
-```
+```compile_fail,E0044
 extern { fn some_func<T>(x: T); }
 ```
 
@@ -401,17 +490,15 @@ Rust only supports variadic parameters for interoperability with C code in its
 FFI. As such, variadic parameters can only be used with functions which are
 using the C ABI. Examples of erroneous code:
 
-```
+```compile_fail,E0045
+#![feature(unboxed_closures)]
+
 extern "rust-call" { fn foo(x: u8, ...); }
-// or
-fn foo(x: u8, ...) {}
 ```
 
 To fix such code, put them in an extern "C" block:
 
 ```
-extern "C" fn foo (x: u8, ...);
-// or:
 extern "C" {
     fn foo (x: u8, ...);
 }
@@ -432,7 +519,7 @@ has the wrong number of type parameters.
 For example, the trait below has a method `foo` with a type parameter `T`,
 but the implementation of `foo` for the type `Bar` is missing this parameter:
 
-```
+```compile_fail,E0049
 trait Foo {
     fn foo<T: Default>(x: T) -> Self;
 }
@@ -455,7 +542,7 @@ For example, the trait below has a method `foo` with two function parameters
 (`&self` and `u8`), but the implementation of `foo` for the type `Bar` omits
 the `u8` parameter:
 
-```
+```compile_fail,E0050
 trait Foo {
     fn foo(&self, x: u8) -> bool;
 }
@@ -476,7 +563,7 @@ and the trait definition.
 
 Here are a couple examples of this error:
 
-```
+```compile_fail,E0053
 trait Foo {
     fn foo(x: u16);
     fn bar(&self);
@@ -498,14 +585,18 @@ E0054: r##"
 It is not allowed to cast to a bool. If you are trying to cast a numeric type
 to a bool, you can compare it with zero instead:
 
+```compile_fail,E0054
+let x = 5;
+
+// Not allowed, won't compile
+let x_is_nonzero = x as bool;
+```
+
 ```
 let x = 5;
 
 // Ok
 let x_is_nonzero = x != 0;
-
-// Not allowed, won't compile
-let x_is_nonzero = x as bool;
 ```
 "##,
 
@@ -517,7 +608,7 @@ recursion limit (which can be set via the `recursion_limit` attribute).
 
 For a somewhat artificial example:
 
-```
+```compile_fail,E0055
 #![recursion_limit="2"]
 
 struct Foo;
@@ -547,8 +638,8 @@ function must match its definition.
 
 An example using a closure:
 
-```
-let f = |x| x * 3;
+```compile_fail,E0057
+let f = |x: u32| x * 3;
 let a = f();        // invalid, too few parameters
 let b = f(4);       // this works!
 let c = f(2, 3);    // invalid, too many parameters
@@ -573,13 +664,17 @@ implemented by closures.
 The most likely source of this error is using angle-bracket notation without
 wrapping the function argument type into a tuple, for example:
 
-```
+```compile_fail,E0059
+#![feature(unboxed_closures)]
+
 fn foo<F: Fn<i32>>(f: F) -> F::Output { f(3) }
 ```
 
 It can be fixed by adjusting the trait bound like this:
 
 ```
+#![feature(unboxed_closures)]
+
 fn foo<F: Fn<(i32,)>>(f: F) -> F::Output { f(3) }
 ```
 
@@ -593,8 +688,7 @@ takes a minimum number of arguments. For example, consider C's variadic `printf`
 function:
 
 ```
-extern crate libc;
-use libc::{ c_char, c_int };
+use std::os::raw::{c_char, c_int};
 
 extern "C" {
     fn printf(_: *const c_char, ...) -> c_int;
@@ -605,6 +699,8 @@ Using this declaration, it must be called with at least one argument, so
 simply calling `printf()` is illegal. But the following uses are allowed:
 
 ```
+# use std::os::raw::{c_char, c_int};
+# extern "C" { fn printf(_: *const c_char, ...) -> c_int; }
 unsafe {
     use std::ffi::CString;
 
@@ -660,7 +756,8 @@ item paths (ie, namespaced variables), dereferences, indexing expressions,
 and field references.
 
 Let's start with some bad examples:
-```
+
+```compile_fail,E0067
 use std::collections::LinkedList;
 
 // Bad: assignment to non-lvalue expression
@@ -671,8 +768,10 @@ LinkedList::new() += 1;
 fn some_func(i: &mut i32) {
     i += 12; // Error : '+=' operation cannot be applied on a reference !
 }
+```
 
 And now some good examples:
+
 ```
 let mut i : i32 = 0;
 
@@ -683,7 +782,6 @@ i += 12; // Good !
 fn some_func(i: &mut i32) {
     *i += 12; // Good !
 }
-
 ```
 "##,
 
@@ -691,7 +789,7 @@ E0069: r##"
 The compiler found a function whose body contains a `return;` statement but
 whose return type is not `()`. An example of this is:
 
-```
+```compile_fail,E0069
 // error
 fn foo() -> u8 {
     return;
@@ -712,11 +810,8 @@ More details can be found here:
 https://doc.rust-lang.org/reference.html#lvalues,-rvalues-and-temporaries
 
 Now, we can go further. Here are some bad examples:
-```
-struct SomeStruct {
-    x: i32,
-    y: i32
-}
+
+```compile_fail,E0070
 const SOME_CONST : i32 = 12;
 
 fn some_other_func() {}
@@ -725,8 +820,6 @@ fn some_function() {
     SOME_CONST = 14; // error : a constant value cannot be changed!
     1 = 3; // error : 1 isn't a valid lvalue!
     some_other_func() = 4; // error : we can't assign value to a function!
-    SomeStruct.x = 12; // error : SomeStruct a structure name but it is used
-                       // like a variable!
 }
 ```
 
@@ -753,14 +846,11 @@ E0071: r##"
 You tried to use a structure initialization with a non-structure type.
 Example of erroneous code:
 
-```
-enum Foo { FirstValue };
+```compile_fail,E0071
+enum Foo { FirstValue }
 
 let u = Foo::FirstValue { value: 0i32 }; // error: Foo::FirstValue
                                          // isn't a structure!
-// or even simpler, if the structure wasn't defined at all:
-let u = RandomName { random_field: 0i32 }; // error: RandomName
-                                           // isn't a structure!
 ```
 
 To fix this, please check:
@@ -795,7 +885,7 @@ the pointer the size of the type would need to be unbounded.
 
 Consider the following erroneous definition of a type for a list of bytes:
 
-```
+```compile_fail,E0072
 // error, illegal recursive struct type
 struct ListNode {
     head: u8,
@@ -831,7 +921,7 @@ first instance of `Foo` could be made to initialize another instance!
 
 Here's an example of a struct that has this problem:
 
-```
+```compile_fail,E0073
 struct Foo { x: Box<Foo> } // error
 ```
 
@@ -844,22 +934,109 @@ struct Foo { x: Option<Box<Foo>> }
 Now it's possible to create at least one instance of `Foo`: `Foo { x: None }`.
 "##,
 
+E0074: r##"
+The `#[simd]` attribute can only be applied to non-generic structs, because the
+compiler needs to know the concrete element type and lane count to lay the
+vector out. Erroneous code example:
+
+```compile_fail,E0074
+#![feature(simd)]
+
+#[simd]
+struct Bad<T>(T, T, T);
+```
+
+Use a concrete element type instead:
+
+```
+#![feature(simd)]
+
+#[simd]
+struct Good(u32, u32, u32);
+```
+"##,
+
+E0075: r##"
+The `#[simd]` attribute can only be applied to structs with at least one field.
+Erroneous code example:
+
+```compile_fail,E0075
+#![feature(simd)]
+
+#[simd]
+struct Bad;
+```
+
+Add the lanes of the vector as fields:
+
+```
+#![feature(simd)]
+
+#[simd]
+struct Good(u32);
+```
+"##,
+
+E0076: r##"
+All the fields of a `#[simd]` struct must have the same type. Erroneous code
+example:
+
+```compile_fail,E0076
+#![feature(simd)]
+
+#[simd]
+struct Bad(u16, u32, u32);
+```
+
+Give every field the same type:
+
+```
+#![feature(simd)]
+
+#[simd]
+struct Good(u32, u32, u32);
+```
+"##,
+
+E0077: r##"
+The fields of a `#[simd]` struct must be of a machine type, that is a primitive
+integer or floating-point type. Erroneous code example:
+
+```compile_fail,E0077
+#![feature(simd)]
+
+#[simd]
+struct Bad(String);
+```
+
+Use a machine type for the fields instead:
+
+```
+#![feature(simd)]
+
+#[simd]
+struct Good(u32, u32, u32);
+```
+"##,
+
 E0081: r##"
 Enum discriminants are used to differentiate enum variants stored in memory.
 This error indicates that the same value was used for two or more variants,
 making them impossible to tell apart.
 
-```
-// Good.
+```compile_fail,E0081
+// Bad.
 enum Enum {
-    P,
+    P = 3,
     X = 3,
     Y = 5
 }
+```
 
-// Bad.
+```
+// Good.
 enum Enum {
-    P = 3,
+    P,
     X = 3,
     Y = 5
 }
@@ -869,7 +1046,7 @@ Note that variants without a manually specified discriminant are numbered from
 top to bottom starting from 0, so clashes can occur with seemingly unrelated
 variants.
 
-```
+```compile_fail,E0081
 enum Bad {
     X,
     Y = 0
@@ -886,7 +1063,7 @@ adding the `repr` attribute to the enum declaration. This error indicates that
 an integer literal given as a discriminant is not a member of the discriminant
 type. For example:
 
-```
+```compile_fail
 #[repr(u8)]
 enum Thing {
     A = 1024,
@@ -922,7 +1099,7 @@ enum Empty {}
 E0087: r##"
 Too many type parameters were supplied for a function. For example:
 
-```
+```compile_fail,E0087
 fn foo<T>() {}
 
 fn main() {
@@ -937,7 +1114,7 @@ parameters.
 E0088: r##"
 You gave too many lifetime parameters. Erroneous code example:
 
-```
+```compile_fail,E0088
 fn f() {}
 
 fn main() {
@@ -982,7 +1159,7 @@ fn main() {
 E0089: r##"
 Not enough type parameters were supplied for a function. For example:
 
-```
+```compile_fail,E0089
 fn foo<T, U>() {}
 
 fn main() {
@@ -993,7 +1170,7 @@ fn main() {
 Note that if a function takes multiple type parameters but you want the compiler
 to infer some of them, you can use type placeholders:
 
-```
+```compile_fail,E0089
 fn foo<T, U>(x: T) {}
 
 fn main() {
@@ -1004,21 +1181,45 @@ fn main() {
 ```
 "##,
 
+E0090: r##"
+You gave too few lifetime parameters when calling a function explicitly. If
+you give any lifetime parameters at all, you must give all of them. Erroneous
+code example:
+
+```compile_fail,E0090
+fn foo<'a: 'b, 'b: 'a>() {}
+
+fn main() {
+    foo::<'static>(); // error, expected 2 lifetime parameters
+}
+```
+
+Please check you give the right number of lifetime parameters:
+
+```
+fn foo<'a: 'b, 'b: 'a>() {}
+
+fn main() {
+    foo::<'static, 'static>();
+}
+```
+"##,
+
 E0091: r##"
 You gave an unnecessary type parameter in a type alias. Erroneous code
 example:
 
-```
+```compile_fail,E0091
 type Foo<T> = u32; // error: type parameter `T` is unused
 // or:
-type Foo<A,B> = Box<A>; // error: type parameter `B` is unused
+type Foo2<A, B> = Box<A>; // error: type parameter `B` is unused
 ```
 
 Please check you didn't write too many type parameters. Example:
 
 ```
 type Foo = u32; // ok!
-type Foo<A> = Box<A>; // ok!
+type Foo2<A> = Box<A>; // ok!
 ```
 "##,
 
@@ -1026,7 +1227,7 @@ E0092: r##"
 You tried to declare an undefined atomic operation function.
 Erroneous code example:
 
-```
+```compile_fail,E0092
 #![feature(intrinsics)]
 
 extern "rust-intrinsic" {
@@ -1051,7 +1252,7 @@ extern "rust-intrinsic" {
 E0093: r##"
 You declared an unknown intrinsic function. Erroneous code example:
 
-```
+```compile_fail,E0093
 #![feature(intrinsics)]
 
 extern "rust-intrinsic" {
@@ -1088,7 +1289,7 @@ E0094: r##"
 You gave an invalid number of type parameters to an intrinsic function.
 Erroneous code example:
 
-```
+```compile_fail,E0094
 #![feature(intrinsics)]
 
 extern "rust-intrinsic" {
@@ -1114,7 +1315,7 @@ E0101: r##"
 You hit this error because the compiler the compiler lacks information
 to determine a type for this expression. Erroneous code example:
 
-```
+```compile_fail
 fn main() {
     let x = |_| {}; // error: cannot determine a type for this expression
 }
@@ -1136,6 +1337,39 @@ fn main() {
 ```
 "##,
 
+E0102: r##"
+The compiler could not determine the type of a local variable, because nothing
+in the function constrains it. Erroneous code example:
+
+```compile_fail
+let x = []; // error, the element type of `x` cannot be determined
+```
+
+Give the variable an explicit type to fix this:
+
+```
+let x: [u8; 0] = [];
+```
+"##,
+
+E0103: r##"
+The compiler could not determine the type of a variable bound by a pattern,
+for example in a `let` statement or a function argument that destructures a
+value. Like E0102, this happens when nothing constrains the type of the
+binding; adding a type annotation to the pattern fixes it:
+
+```
+let (x, y): (Vec<u8>, u32) = (vec![], 1);
+```
+"##,
+
+E0104: r##"
+The compiler could not infer the lifetime with which a closure captures one of
+the variables it refers to. Making the types of the closure's arguments and
+return value explicit, or moving the closure out of the expression it is
+nested in, usually gives the compiler enough information to resolve it.
+"##,
+
 E0106: r##"
 This error indicates that a lifetime is missing from a type. If it is an error
 inside a function signature, the problem may be with failing to adhere to the
@@ -1143,16 +1377,18 @@ lifetime elision rules (see below).
 
 Here are some simple examples of where you'll run into this error:
 
-```
+```compile_fail,E0106
 struct Foo { x: &bool }        // error
-struct Foo<'a> { x: &'a bool } // correct
-
-enum Bar { A(u8), B(&bool), }        // error
-enum Bar<'a> { A(u8), B(&'a bool), } // correct
+enum Bar { A(u8), B(&bool), }  // error
+type MyStr = &str;             // error
+```
 
-type MyStr = &str;        // error
-type MyStr<'a> = &'a str; //correct
+They are fixed by declaring the lifetime:
 
+```
+struct Foo<'a> { x: &'a bool }        // correct
+enum Bar<'a> { A(u8), B(&'a bool), }  // correct
+type MyStr<'a> = &'a str;             // correct
 ```
 
 Lifetime elision is a special, limited kind of inference for lifetimes in
@@ -1172,15 +1408,15 @@ same as the lifetime on `&self` or `&mut self`.
 
 Here are some examples of elision errors:
 
-```
+```compile_fail,E0106
 // error, no input lifetimes
-fn foo() -> &str { ... }
+fn foo() -> &str { "" }
 
 // error, `x` and `y` have distinct lifetimes inferred
-fn bar(x: &str, y: &str) -> &str { ... }
+fn bar(x: &str, y: &str) -> &str { x }
 
 // error, `y`'s lifetime is inferred to be distinct from `x`'s
-fn baz<'a>(x: &'a str, y: &str) -> &str { ... }
+fn baz<'a>(x: &'a str, y: &str) -> &str { x }
 ```
 
 [book-le]: http://doc.rust-lang.org/nightly/book/lifetimes.html#lifetime-elision
@@ -1192,7 +1428,7 @@ for a type (like a struct or enum) or trait.
 
 Some basic examples include:
 
-```
+```compile_fail,E0107
 struct Foo<'a>(&'a str);
 enum Bar { A, B, C }
 
@@ -1205,7 +1441,7 @@ struct Baz<'a> {
 Here's an example that is currently an error, but may work in a future version
 of Rust:
 
-```
+```compile_fail,E0107
 struct Foo<'a>(&'a str);
 
 trait Quux { }
@@ -1223,8 +1459,8 @@ You can only define an inherent implementation for a type in the same crate
 where the type was defined. For example, an `impl` block as below is not allowed
 since `Vec` is defined in the standard library:
 
-```
-impl Vec<u8> { ... } // error
+```compile_fail,E0116
+impl Vec<u8> { } // error
 ```
 
 To fix this problem, you can do either of these things:
@@ -1237,10 +1473,10 @@ To fix this problem, you can do either of these things:
 Note that using the `type` keyword does not work here because `type` only
 introduces a type alias:
 
-```
+```compile_fail,E0116
 type Bytes = Vec<u8>;
 
-impl Bytes { ... } // error, same as above
+impl Bytes { } // error, same as above
 ```
 "##,
 
@@ -1249,7 +1485,7 @@ You got this error because because you tried to implement a foreign
 trait for a foreign type (with maybe a foreign type parameter). Erroneous
 code example:
 
-```
+```compile_fail,E0117
 impl Drop for u32 {}
 ```
 
@@ -1261,6 +1497,7 @@ pub struct Foo; // you define your type in your crate
 
 impl Drop for Foo { // and you can implement the trait on it!
     // code of trait implementation here
+    fn drop(&mut self) {}
 }
 
 trait Bar { // or define your trait in your crate
@@ -1280,11 +1517,42 @@ impl From<Foo> for i32 { // or you use a type from your crate as
 ```
 "##,
 
+E0118: r##"
+You tried to write an inherent implementation for something that is not a
+struct, an enum or a trait object; the compiler has no type to attach the
+methods to. Erroneous code example:
+
+```compile_fail,E0118
+impl<T> T { // error: no base type found for inherent implementation
+    fn get_state(&self) -> String {
+        String::new()
+    }
+}
+```
+
+To fix this, define a trait and implement it for the types you want instead:
+
+```
+trait MyTrait {
+    fn get_state(&self) -> String;
+}
+
+impl<T> MyTrait for T {
+    fn get_state(&self) -> String {
+        String::new()
+    }
+}
+```
+
+Alternatively, define a new type wrapping the type and write an inherent
+implementation for that new type.
+"##,
+
 E0119: r##"
 There are conflicting trait implementations for the same type.
 Erroneous code example:
 
-```
+```compile_fail,E0119
 trait MyTrait {
     fn get(&self) -> usize;
 }
@@ -1305,7 +1573,7 @@ impl MyTrait for Foo { // error: conflicting implementations for trait
 
 When you write:
 
-```
+```ignore
 impl<T> MyTrait for T {
     fn get(&self) -> usize { 0 }
 }
@@ -1334,40 +1602,132 @@ fn main() {
 ```
 "##,
 
+E0120: r##"
+An attempt was made to implement `Drop` on a trait object or a reference, which
+is not allowed: only structs and enums can implement `Drop`. Erroneous code
+example:
+
+```compile_fail,E0120
+trait MyTrait {}
+
+impl Drop for MyTrait {
+    fn drop(&mut self) {}
+}
+```
+
+A workaround for this problem is to wrap the trait up in a struct, and
+implement `Drop` on that:
+
+```
+trait MyTrait {}
+
+struct MyWrapper<T: MyTrait> { foo: T }
+
+impl <T: MyTrait> Drop for MyWrapper<T> {
+    fn drop(&mut self) {}
+}
+```
+"##,
+
 E0121: r##"
 In order to be consistent with Rust's lack of global type inference, type
 placeholders are disallowed by design in item signatures.
 
 Examples of this error include:
 
-```
+```compile_fail,E0121
 fn foo() -> _ { 5 } // error, explicitly write out the return type instead
 
 static BAR: _ = "test"; // error, explicitly write out the type instead
 ```
 "##,
 
-E0124: r##"
-You declared two fields of a struct with the same name. Erroneous code
-example:
+E0122: r##"
+This warning indicates that a type alias was declared with trait bounds on its
+type parameters. Those bounds are not enforced yet, so they have no effect and
+can be misleading:
 
 ```
-struct Foo {
-    field1: i32,
-    field1: i32 // error: field is already declared
-}
+type Foo<T: Clone> = Vec<T>; // warning, the bound is not enforced
 ```
 
-Please verify that the field names have been correctly spelled. Example:
+The bounds should be removed from the type alias and placed on the items that
+actually rely on them instead:
 
 ```
-struct Foo {
-    field1: i32,
+type Foo<T> = Vec<T>;
+
+fn bar<T: Clone>(x: Foo<T>) -> Foo<T> {
+    x.clone()
+}
+```
+"##,
+
+E0124: r##"
+You declared two fields of a struct with the same name. Erroneous code
+example:
+
+```compile_fail,E0124
+struct Foo {
+    field1: i32,
+    field1: i32 // error: field is already declared
+}
+```
+
+Please verify that the field names have been correctly spelled. Example:
+
+```
+struct Foo {
+    field1: i32,
     field2: i32 // ok!
 }
 ```
 "##,
 
+E0128: r##"
+Type parameter defaults can only use parameters that occur before them.
+Erroneous code example:
+
+```compile_fail,E0128
+struct Foo<T=U, U=()> {
+    field1: T,
+    filed2: U,
+}
+// error: type parameters with a default cannot use forward declared
+// identifiers
+```
+
+Since type parameters are evaluated in-order, you may be able to fix this issue
+by doing:
+
+```
+struct Foo<U=(), T=U> {
+    field1: T,
+    filed2: U,
+}
+```
+"##,
+
+E0130: r##"
+You declared a pattern as an argument in a foreign function declaration.
+Erroneous code example:
+
+```compile_fail,E0130
+extern {
+    fn foo((a, b): (u32, u32)); // error: patterns aren't allowed in foreign
+                                //        function declarations
+}
+```
+
+Please replace the pattern argument with a regular one:
+
+```
+extern {
+    fn foo(s: (u32, u32)); // ok!
+}
+```
+"##,
+
 E0131: r##"
 It is not possible to define `main` with type parameters, or even with function
 parameters. When `main` is present, it must take no arguments and return `()`.
@@ -1377,17 +1737,91 @@ E0132: r##"
 It is not possible to declare type parameters on a function that has the `start`
 attribute. Such a function must have the following type signature:
 
-```
+```ignore
 fn(isize, *const *const u8) -> isize
 ```
 "##,
 
+E0159: r##"
+You tried to use a trait as a struct constructor. Erroneous code example:
+
+```compile_fail,E0159
+trait TraitNotAStruct {}
+
+TraitNotAStruct { value: 0 }; // error: use of trait `TraitNotAStruct` as a
+                              //        struct constructor
+```
+
+Please verify you used the correct name, or define the struct you meant to
+construct:
+
+```
+struct Foo { value: i32 }
+
+Foo { value: 0 }; // ok!
+```
+"##,
+
+E0163: r##"
+This error means that an attempt was made to match an enum variant as a
+struct type when the variant isn't a struct type:
+
+```compile_fail,E0163
+enum Foo { B(u32) }
+
+fn bar(foo: Foo) -> u32 {
+    match foo {
+        Foo::B{i} => i, // error, `Foo::B` is not a struct variant
+    }
+}
+```
+
+Try using `()` instead:
+
+```
+enum Foo { B(u32) }
+
+fn bar(foo: Foo) -> u32 {
+    match foo {
+        Foo::B(i) => i,
+    }
+}
+```
+"##,
+
+E0164: r##"
+This error means that an attempt was made to match a struct type enum
+variant as a non-struct type:
+
+```compile_fail,E0164
+enum Foo { B { i: u32 } }
+
+fn bar(foo: Foo) -> u32 {
+    match foo {
+        Foo::B(i) => i, // error, `Foo::B` is a struct variant
+    }
+}
+```
+
+Try using `{}` instead:
+
+```
+enum Foo { B { i: u32 } }
+
+fn bar(foo: Foo) -> u32 {
+    match foo {
+        Foo::B{i} => i,
+    }
+}
+```
+"##,
+
 E0166: r##"
 This error means that the compiler found a return expression in a function
 marked as diverging. A function diverges if it has `!` in the place of the
 return type in its signature. For example:
 
-```
+```compile_fail,E0166
 fn foo() -> ! { return; } // error
 ```
 
@@ -1396,13 +1830,76 @@ return, for example with a `loop` that never breaks or a call to another
 diverging function (such as `panic!()`).
 "##,
 
+E0167: r##"
+The parenthesized form of type parameters, as in `Foo(A) -> B`, always supplies
+two type parameters: a tuple of the argument types and the return type. It was
+used with an item that takes fewer than two type parameters. This form is meant
+for the `Fn` family of traits; use angle brackets (`Foo<A>`) otherwise.
+"##,
+
+E0168: r##"
+This error means that a trait was used in a struct pattern. Only structs and
+struct-like enum variants can be matched with the `Name { .. }` syntax.
+Erroneous code example:
+
+```compile_fail,E0168
+trait Foo {}
+
+fn bar(x: u32) {
+    match x {
+        Foo { .. } => {} // error: use of trait `Foo` in a struct pattern
+    }
+}
+```
+
+Please check the name used in the pattern refers to a struct or an enum variant.
+"##,
+
+E0172: r##"
+This error means that something which is not a trait was used on the left-hand
+side of `+` in a type. Only a trait can be combined with bounds this way.
+Erroneous code example:
+
+```compile_fail,E0172
+fn foo(bar: i32+std::fmt::Display) {} // error: expected a reference to a trait
+```
+
+Make sure the type before the `+` is a trait, and use a trait object:
+
+```
+fn foo(bar: Box<std::fmt::Display + Send>) {}
+```
+"##,
+
+E0174: r##"
+This error occurs because of the explicit use of unboxed closure methods
+that are an experimental feature in current Rust. Erroneous code example:
+
+```compile_fail,E0174
+fn foo<F: Fn(&str)>(mut f: F) {
+    f.call(("test",)); // error, explicit use of unboxed closure method `call`
+}
+```
+
+Call the closure with the function call syntax instead:
+
+```
+fn foo<F: Fn(&str)>(f: F) {
+    f("test"); // ok!
+}
+```
+
+Explicit use of the unboxed closure methods requires
+`#![feature(unboxed_closures)]`.
+"##,
+
 E0178: r##"
 In types, the `+` type operator has low precedence, so it is often necessary
 to use parentheses.
 
 For example:
 
-```
+```compile_fail,E0178
 trait Foo {}
 
 struct Bar<'a> {
@@ -1418,6 +1915,58 @@ More details can be found in [RFC 438].
 [RFC 438]: https://github.com/rust-lang/rfcs/pull/438
 "##,
 
+E0182: r##"
+Associated type bindings, as in `Foo<Item=u32>`, are only allowed in type
+paths; they were used in an expression path. Erroneous code example:
+
+```compile_fail,E0182
+let x = Vec::<Item=u32>::new(); // error: unexpected binding of associated
+                                //        item in expression path
+```
+
+Give the type parameters without the binding instead:
+
+```
+let x = Vec::<u32>::new();
+```
+"##,
+
+E0183: r##"
+Manual implementations of the `Fn`, `FnMut` and `FnOnce` traits are
+experimental. Erroneous code example:
+
+```compile_fail
+struct MyClosure {
+    foo: i32
+}
+
+impl FnOnce<()> for MyClosure {  // error
+    type Output = ();
+    extern "rust-call" fn call_once(self, args: ()) -> () {
+        println!("{}", self.foo);
+    }
+}
+```
+
+Add `#![feature(unboxed_closures)]` to the crate attributes to implement these
+traits by hand:
+
+```
+#![feature(unboxed_closures)]
+
+struct MyClosure {
+    foo: i32
+}
+
+impl FnOnce<()> for MyClosure {  // ok!
+    type Output = ();
+    extern "rust-call" fn call_once(self, args: ()) -> () {
+        println!("{}", self.foo);
+    }
+}
+```
+"##,
+
 E0184: r##"
 Explicitly implementing both Drop and Copy for a type is currently disallowed.
 This feature can make some sense in theory, but the current implementation is
@@ -1434,7 +1983,7 @@ take a `self` parameter).
 
 Here's an example of this error:
 
-```
+```compile_fail,E0185
 trait Foo {
     fn foo();
 }
@@ -1446,6 +1995,7 @@ impl Foo for Bar {
     // the trait
     fn foo(&self) {}
 }
+```
 "##,
 
 E0186: r##"
@@ -1455,7 +2005,7 @@ to be static.
 
 Here's an example of this error:
 
-```
+```compile_fail,E0186
 trait Foo {
     fn foo(&self);
 }
@@ -1467,6 +2017,32 @@ impl Foo for Bar {
     // the impl
     fn foo() {}
 }
+```
+"##,
+
+E0191: r##"
+Trait objects need to have all associated types specified. Erroneous code
+example:
+
+```compile_fail,E0191
+trait Trait {
+    type Bar;
+}
+
+type Foo = Trait; // error: the value of the associated type `Bar` (from
+                  //        the trait `Trait`) must be specified
+```
+
+Please verify you specified all associated types of the trait and that you
+used the right trait:
+
+```
+trait Trait {
+    type Bar;
+}
+
+type Foo = Trait<Bar=i32>; // ok!
+```
 "##,
 
 E0192: r##"
@@ -1475,11 +2051,57 @@ information see the [opt-in builtin traits RFC](https://github.com/rust-lang/
 rfcs/blob/master/text/0019-opt-in-builtin-traits.md).
 "##,
 
+E0193: r##"
+`where` clauses must use generic type parameters: it does not make sense to
+use them otherwise. An example causing this error:
+
+```compile_fail,E0193
+struct Foo<T> where i32: Copy { // error: cannot bound type `i32`, where
+    x: T                        //        clause bounds may only be attached
+}                               //        to types involving type parameters
+```
+
+The `where` clause is always satisfied and can just be removed, or it should
+bound the type parameter it was meant for:
+
+```
+struct Foo<T> where T: Copy {
+    x: T
+}
+```
+"##,
+
+E0194: r##"
+A type parameter was declared which shadows an existing one. An example of this
+error:
+
+```compile_fail,E0194
+struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    fn do_something<T>(&self, x: T) {} // error: type parameter `T` shadows
+                                       //        another type parameter of the
+                                       //        same name
+}
+```
+
+In this example, the `T` of the method shadows the `T` of the impl. Please
+choose another name for the method's type parameter:
+
+```
+struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    fn do_something<U>(&self, x: U) {}
+}
+```
+"##,
+
 E0195: r##"
 Your method's lifetime parameters do not match the trait declaration.
 Erroneous code example:
 
-```
+```compile_fail,E0195
 trait Trait {
     fn bar<'a,'b:'a>(x: &'a str, y: &'b str);
 }
@@ -1512,21 +2134,33 @@ impl Trait for Foo {
 ```
 "##,
 
+E0196: r##"
+The compiler could not determine the type of a closure, because nothing in the
+function constrains the types of its arguments or of its return value.
+Annotating the closure's arguments and return type, as in
+`|x: i32| -> i32 { x + 1 }`, fixes this.
+"##,
+
 E0197: r##"
 Inherent implementations (one that do not implement a trait but provide
 methods associated with a type) are always safe because they are not
 implementing an unsafe trait. Removing the `unsafe` keyword from the inherent
 implementation will resolve this error.
 
-```
+```compile_fail,E0197
 struct Foo;
 
 // this will cause this error
 unsafe impl Foo { }
-// converting it to this will fix it
-impl Foo { }
 ```
 
+Converting it to this will fix it:
+
+```
+struct Foo;
+
+impl Foo { }
+```
 "##,
 
 E0198: r##"
@@ -1535,15 +2169,24 @@ particular trait. Not being able to use a trait is always a safe operation,
 so negative implementations are always safe and never need to be marked as
 unsafe.
 
-```
+```compile_fail,E0198
+#![feature(optin_builtin_traits)]
+
 struct Foo;
 
 // unsafe is unnecessary
-unsafe impl !Clone for Foo { }
-// this will compile
-impl !Clone for Foo { }
+unsafe impl !Send for Foo { }
+```
+
+This will compile:
+
 ```
+#![feature(optin_builtin_traits)]
 
+struct Foo;
+
+impl !Send for Foo { }
+```
 "##,
 
 E0199: r##"
@@ -1551,17 +2194,24 @@ Safe traits should not have unsafe implementations, therefore marking an
 implementation for a safe trait unsafe will cause a compiler error. Removing the
 unsafe marker on the trait noted in the error will resolve this problem.
 
-```
+```compile_fail,E0199
 struct Foo;
 
 trait Bar { }
 
 // this won't compile because Bar is safe
 unsafe impl Bar for Foo { }
-// this will compile
-impl Bar for Foo { }
 ```
 
+This will compile:
+
+```
+struct Foo;
+
+trait Bar { }
+
+impl Bar for Foo { }
+```
 "##,
 
 E0200: r##"
@@ -1569,17 +2219,24 @@ Unsafe traits must have unsafe implementations. This error occurs when an
 implementation for an unsafe trait isn't marked as unsafe. This may be resolved
 by marking the unsafe implementation as unsafe.
 
-```
+```compile_fail,E0200
 struct Foo;
 
 unsafe trait Bar { }
 
 // this won't compile because Bar is unsafe and impl isn't unsafe
 impl Bar for Foo { }
-// this will compile
-unsafe impl Bar for Foo { }
 ```
 
+This will compile:
+
+```
+struct Foo;
+
+unsafe trait Bar { }
+
+unsafe impl Bar for Foo { }
+```
 "##,
 
 E0201: r##"
@@ -1587,7 +2244,7 @@ It is an error to define an associated function more than once.
 
 For example:
 
-```
+```compile_fail,E0201
 struct Foo(u8);
 
 impl Foo {
@@ -1618,12 +2275,31 @@ See [the tracking issue][iss8995] for the status of this implementation.
 [iss8995]: https://github.com/rust-lang/rust/issues/8995
 "##,
 
+E0203: r##"
+A type parameter can only relax the default `Sized` bound once, and `?Sized` is
+the only such relaxed bound. Erroneous code example:
+
+```compile_fail,E0203
+struct Bad<T: ?Sized + ?Sized> { // error: type parameter has more than one
+    x: Box<T>                    //        relaxed default bound
+}
+```
+
+Remove the repeated bound:
+
+```
+struct Good<T: ?Sized> {
+    x: Box<T>
+}
+```
+"##,
+
 E0204: r##"
 An attempt to implement the `Copy` trait for a struct failed because one of the
 fields does not implement `Copy`. To fix this, you must implement `Copy` for the
 mentioned field. Note that this may not be possible, as in the example of
 
-```
+```compile_fail,E0204
 struct Foo {
     foo : Vec<u32>,
 }
@@ -1635,7 +2311,7 @@ This fails because `Vec<T>` does not implement `Copy` for any `T`.
 
 Here's another example that will fail:
 
-```
+```compile_fail,E0204
 #[derive(Copy)]
 struct Foo<'a> {
     ty: &'a mut bool,
@@ -1651,7 +2327,7 @@ An attempt to implement the `Copy` trait for an enum failed because one of the
 variants does not implement `Copy`. To fix this, you must implement `Copy` for
 the mentioned variant. Note that this may not be possible, as in the example of
 
-```
+```compile_fail,E0205
 enum Foo {
     Bar(Vec<u32>),
     Baz,
@@ -1664,7 +2340,7 @@ This fails because `Vec<T>` does not implement `Copy` for any `T`.
 
 Here's another example that will fail:
 
-```
+```compile_fail,E0205
 #[derive(Copy)]
 enum Foo<'a> {
     Bar(&'a mut bool),
@@ -1681,7 +2357,7 @@ You can only implement `Copy` for a struct or enum. Both of the following
 examples will fail, because neither `i32` (primitive type) nor `&'static Bar`
 (reference to `Bar`) is a struct or enum:
 
-```
+```compile_fail,E0206
 type Foo = i32;
 impl Copy for Foo { } // error
 
@@ -1695,7 +2371,7 @@ E0207: r##"
 You declared an unused type parameter when implementing a trait on an object.
 Erroneous code example:
 
-```
+```compile_fail,E0207
 trait MyTrait {
     fn get(&self) -> usize;
 }
@@ -1727,37 +2403,495 @@ impl MyTrait for Foo {
 ```
 "##,
 
-E0211: r##"
-You used an intrinsic function which doesn't correspond to its
-definition. Erroneous code example:
+E0208: r##"
+This error is only emitted for items carrying the internal `#[rustc_variance]`
+attribute. It is used by the compiler's own test suite to print the variance
+computed for the type and lifetime parameters of an item, and is not something
+user code should run into.
+"##,
 
-```
-#![feature(intrinsics)]
+E0210: r##"
+This error indicates a violation of one of Rust's orphan rules for trait
+implementations. The rule concerns the use of type parameters in an
+implementation of a foreign trait (a trait defined in another crate), and
+states that type parameters must be "covered" by a local type. To understand
+what this means, it is perhaps easiest to consider a few examples.
 
-extern "rust-intrinsic" {
-    fn size_of<T>(); // error: intrinsic has wrong type
+If `ForeignTrait` is a trait defined in some external crate `foo`, then the
+following trait `impl` is an error:
+
+```compile_fail,E0210
+use std::fmt;
+
+impl<T> fmt::Display for T { // error: type parameter `T` must be used as the
+                             //        type parameter for some local type
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
 }
 ```
 
-Please check the function definition. Example:
+To work around this, it can be covered with a local type, `MyType`:
 
 ```
-#![feature(intrinsics)]
+use std::fmt;
 
-extern "rust-intrinsic" {
-    fn size_of<T>() -> usize;
+struct MyType<T>(T);
+
+impl<T> fmt::Display for MyType<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
 }
 ```
+
+For information on the design of the orphan rules, see [RFC 1023].
+
+[RFC 1023]: https://github.com/rust-lang/rfcs/pull/1023
 "##,
 
-E0243: r##"
+E0211: r##"
+You used an intrinsic function which doesn't correspond to its
+definition. Erroneous code example:
+
+```compile_fail,E0211
+#![feature(intrinsics)]
+
+extern "rust-intrinsic" {
+    fn size_of<T>(); // error: intrinsic has wrong type
+}
+```
+
+Please check the function definition. Example:
+
+```
+#![feature(intrinsics)]
+
+extern "rust-intrinsic" {
+    fn size_of<T>() -> usize;
+}
+```
+"##,
+
+E0212: r##"
+An associated type was extracted from a higher-ranked trait bound using the
+`T::Name` shorthand. The compiler cannot pick a lifetime for the bound in that
+position. Erroneous code example:
+
+```compile_fail,E0212
+trait Foo<'a> {
+    type A;
+}
+
+fn bar<T: for<'a> Foo<'a>>(x: T::A) {} // error
+```
+
+Name the trait and the lifetime to use explicitly:
+
+```
+trait Foo<'a> {
+    type A;
+}
+
+fn bar<'b, T: for<'a> Foo<'a>>(x: <T as Foo<'b>>::A) {}
+```
+"##,
+
+E0214: r##"
+A generic type was described using parentheses rather than angle brackets. For
+example:
+
+```compile_fail,E0214
+let v: Vec(&str) = vec!["foo"];
+```
+
+This is not currently supported: `v` should be defined as `Vec<&str>`.
+Parentheses are currently only used with generic types when defining parameters
+for `Fn`-family traits.
+"##,
+
+E0215: r##"
+The traits of the `Fn` family were used with angle-bracket notation, which is
+experimental. Erroneous code example:
+
+```compile_fail,E0215
+fn foo<F: Fn<(u32,)>>(f: F) {}
+```
+
+Use the parenthesized notation instead:
+
+```
+fn foo<F: Fn(u32)>(f: F) {}
+```
+
+Angle-bracket notation for these traits requires
+`#![feature(unboxed_closures)]`.
+"##,
+
+E0216: r##"
+Parenthesized notation, as in `Foo(A) -> B`, is only stable for the traits of
+the `Fn` family. Erroneous code example:
+
+```compile_fail,E0216
+trait Foo<A> {}
+
+fn bar<F: Foo(u32)>(f: F) {} // error
+```
+
+Use angle brackets for other traits:
+
+```
+trait Foo<A> {}
+
+fn bar<F: Foo<u32>>(f: F) {}
+```
+"##,
+
+E0220: r##"
+You used an associated type which isn't defined in the trait.
+Erroneous code example:
+
+```compile_fail,E0220
+trait Trait {
+    type Bar;
+}
+
+fn foo<T: Trait>(x: T::Baz) {} // error: associated type `Baz` not found
+                               //        for `T`
+```
+
+Please verify you used the right trait or you didn't misspell the
+associated type name:
+
+```
+trait Trait {
+    type Bar;
+}
+
+fn foo<T: Trait>(x: T::Bar) {} // ok!
+```
+"##,
+
+E0221: r##"
+An attempt was made to retrieve an associated type, but the type was ambiguous
+because several bounds of the type parameter define an associated type with
+that name. For example:
+
+```compile_fail,E0221
+trait T1 {
+    type A;
+}
+
+trait T2 {
+    type A;
+}
+
+fn foo<T: T1 + T2>(x: T::A) {} // error: ambiguous associated type `A`
+```
+
+Specify which trait the associated type comes from:
+
+```
+trait T1 {
+    type A;
+}
+
+trait T2 {
+    type A;
+}
+
+fn foo<T: T1 + T2>(x: <T as T1>::A) {}
+```
+"##,
+
+E0223: r##"
+An attempt was made to retrieve an associated type, but the type was ambiguous.
+For example:
+
+```compile_fail,E0223
+trait MyTrait {type X; }
+
+fn main() {
+    let foo: MyTrait::X;
+}
+```
+
+The problem here is that we're attempting to take the type of X from MyTrait.
+Unfortunately, the type of X is not defined, because it's only made concrete in
+implementations of the trait. A working version of this code might look like:
+
+```
+trait MyTrait {type X; }
+struct MyStruct;
+
+impl MyTrait for MyStruct {
+    type X = u32;
+}
+
+fn main() {
+    let foo: <MyStruct as MyTrait>::X;
+}
+```
+
+This syntax specifies that we want the X type from MyTrait, as made concrete in
+MyStruct. The reason that we cannot simply use `MyStruct::X` is that MyStruct
+might implement two different traits with identically-named associated types.
+This syntax allows disambiguation between the two.
+"##,
+
+E0224: r##"
+A trait object was declared with only builtin traits such as `Send` and `Sync`
+as bounds. A trait object needs at least one non-builtin trait, whose methods
+it can be used through. Erroneous code example:
+
+```compile_fail,E0224
+type Foo = Box<Send>; // error
+```
+
+Add the trait the object is meant to implement:
+
+```
+use std::fmt::Display;
+
+type Foo = Box<Display + Send>; // ok!
+```
+"##,
+
+E0225: r##"
+You attempted to use multiple types as bounds for a closure or trait object.
+Rust does not currently support this. A simple example that causes this error:
+
+```compile_fail,E0225
+fn main() {
+    let _: Box<std::io::Read + std::io::Write>;
+}
+```
+
+Builtin traits are an exception to this rule: it's possible to have bounds of
+one non-builtin type, plus any of:
+- `Send`
+- `Sync`
+- `Copy`
+- `'static`
+
+For example, this compiles correctly:
+
+```
+fn main() {
+    let _: Box<std::io::Read + Send + Sync>;
+}
+```
+"##,
+
+E0226: r##"
+More than one explicit lifetime bound was used on a trait object. Erroneous
+code example:
+
+```compile_fail,E0226
+trait Foo {}
+
+type T<'a, 'b> = Box<Foo + 'a + 'b>; // error
+```
+
+Here `T` is a trait object with two explicit lifetime bounds, `'a` and `'b`.
+Only a single explicit lifetime bound is permitted on trait objects. To fix this
+error, consider removing one of the lifetime bounds:
+
+```
+trait Foo {}
+
+type T<'a> = Box<Foo + 'a>;
+```
+"##,
+
+E0227: r##"
+The compiler could not pick a lifetime bound for a trait object, because the
+supertraits of its trait give it several different lifetime bounds. For
+example, with `trait Foo<'a, 'b>: 'a + 'b {}`, the type `Box<Foo<'x, 'y>>` could
+be bounded by either `'x` or `'y`. Write the bound explicitly, as in
+`Box<Foo<'x, 'y> + 'x>`, to fix this.
+"##,
+
+E0228: r##"
+The lifetime bound for this object type cannot be deduced from context and must
+be specified. Erroneous code example:
+
+```compile_fail,E0228
+trait Trait {}
+
+struct TwoBounds<'a, 'b, T: ?Sized + 'a + 'b> {
+    x: &'a i32,
+    y: &'b i32,
+    z: T,
+}
+
+type Foo<'a, 'b> = TwoBounds<'a, 'b, Trait>;
+```
+
+In this example, the lifetime bound of `Trait` could be either `'a` or `'b`
+since both are bounds of `T`. Specify it explicitly:
+
+```
+trait Trait {}
+
+struct TwoBounds<'a, 'b, T: ?Sized + 'a + 'b> {
+    x: &'a i32,
+    y: &'b i32,
+    z: T,
+}
+
+type Foo<'a, 'b> = TwoBounds<'a, 'b, Trait + 'b>;
+```
+"##,
+
+E0229: r##"
+An associated type binding was done outside of the type parameter declaration
+and `where` clause. Erroneous code example:
+
+```compile_fail,E0229
+pub trait Foo {
+    type A;
+    fn boo(&self) -> <Self as Foo>::A;
+}
+
+struct Bar;
+
+impl Foo for isize {
+    type A = usize;
+    fn boo(&self) -> usize { 42 }
+}
+
+fn baz<I>(x: &<I as Foo<A=Bar>>::A) {}
+// error: associated type bindings are not allowed here
+```
+
+To solve this error, please move the type bindings in the type parameter
+declaration:
+
+```
+# pub trait Foo { type A; }
+# struct Bar;
+fn baz<I: Foo<A=Bar>>(x: &<I as Foo>::A) {} // ok!
+```
+
+or in the `where` clause:
+
+```
+# pub trait Foo { type A; }
+# struct Bar;
+fn baz<I>(x: &<I as Foo>::A) where I: Foo<A=Bar> {}
+```
+"##,
+
+E0230: r##"
+The trait has more type parameters specified than appear in its definition.
+Erroneous code example:
+
+```compile_fail,E0230
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented = "Trait error on `{Self}` with `<{A},{B}>`"]
+// error: there is no type parameter B on trait BadAnnotation
+trait BadAnnotation<A> {}
+```
+
+As you can see, the `rustc_on_unimplemented` attribute refers to the type
+parameter `B`, which does not exist on the trait. Only `Self` and the type
+parameters of the trait can be referred to:
+
+```
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented = "Trait error on `{Self}` with `<{A}>`"]
+trait GoodAnnotation<A> {}
+```
+"##,
+
+E0231: r##"
+The attribute must have a value. Erroneous code example:
+
+```compile_fail,E0231
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented = "Trait error on `{Self}` with `<{A},{}>`"]
+// error: only named substitution parameters are allowed
+trait BadAnnotation<A> {}
+```
+
+The `rustc_on_unimplemented` message can only refer to type parameters by name,
+so positional substitutions such as `{}` or `{0}` are not allowed:
+
+```
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented = "Trait error on `{Self}` with `<{A}>`"]
+trait GoodAnnotation<A> {}
+```
+"##,
+
+E0232: r##"
+The `rustc_on_unimplemented` attribute was given without a message. Erroneous
+code example:
+
+```compile_fail,E0232
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented]
+// error: this attribute must have a value
+trait BadAnnotation {}
+```
+
+Give the message to print when the trait is not implemented:
+
+```
+#![feature(on_unimplemented)]
+
+#[rustc_on_unimplemented = "the trait `GoodAnnotation` is not implemented"]
+trait GoodAnnotation {}
+```
+"##,
+
+E0235: r##"
+The path used in a struct literal names a struct type which does not match the
+type the literal was checked as, for example because of conflicting type
+parameters. Check the type parameters given in the path of the struct literal.
+"##,
+
+E0236: r##"
+The range syntax `a..b` and `..b` is implemented using structs marked as lang
+items in the standard library. This error means the crate uses that syntax
+while the corresponding lang item is missing, which can only happen in crates
+built without the standard library (`#![no_std]` without `core`).
+"##,
+
+E0237: r##"
+The full range syntax `..` is implemented using a struct marked as a lang item
+in the standard library. This error means the crate uses that syntax while the
+lang item is missing, which can only happen in crates built without the
+standard library (`#![no_std]` without `core`). See also E0236.
+"##,
+
+E0238: r##"
+Parenthesized parameters, as in `Foo(A) -> B`, were used in an expression path
+on something which is not a trait. This notation is only meaningful for the
+traits of the `Fn` family; use angle brackets (`Foo::<A>`) to give the type
+parameters of other items.
+"##,
+
+E0242: r##"
+This is an internal compiler error: the compiler could not find the definition
+a path refers to. It should never happen in practice; please report a bug
+including the code that triggered it.
+"##,
+
+E0243: r##"
 This error indicates that not enough type parameters were found in a type or
 trait.
 
 For example, the `Foo` struct below is defined to be generic in `T`, but the
 type parameter is missing in the definition of `Bar`:
 
-```
+```compile_fail,E0243
 struct Foo<T> { x: T }
 
 struct Bar { x: Foo }
@@ -1771,23 +2905,91 @@ trait.
 For example, the `Foo` struct below has no type parameters, but is supplied
 with two in the definition of `Bar`:
 
-```
+```compile_fail,E0244
 struct Foo { x: bool }
 
 struct Bar<S, T> { x: Foo<S, T> }
 ```
 "##,
 
+E0245: r##"
+Something which is not a trait was used where a trait was expected, for example
+in a trait implementation or in a bound. Erroneous code example:
+
+```compile_fail
+struct Foo;
+
+impl Foo for u32 {} // error: `Foo` is not a trait
+```
+
+Please verify that the name refers to a trait, or define the trait:
+
+```
+trait Foo {}
+
+impl Foo for u32 {} // ok!
+```
+"##,
+
+E0247: r##"
+A module name was used as a type. Erroneous code example:
+
+```compile_fail
+mod MyMod {
+    pub mod MySubMod {}
+}
+
+fn main() {
+    let foo: MyMod::MySubMod; // error
+}
+```
+
+Please verify you didn't misspell the type name or that you imported it. Modules
+can contain types, but are not types themselves:
+
+```
+mod MyMod {
+    pub mod MySubMod {
+        pub struct MyType;
+    }
+}
+
+fn main() {
+    let foo: MyMod::MySubMod::MyType; // ok!
+}
+```
+"##,
+
+E0248: r##"
+This error indicates an attempt to use a value where a type is expected. For
+example:
+
+```compile_fail,E0248
+enum Foo {
+    Bar(u32)
+}
+
+fn do_something(x: Foo::Bar) { }
+```
+
+In this example, we're attempting to take a type of `Foo::Bar` in the
+do_something function. This is not legal: `Foo::Bar` is a value of type `Foo`,
+not a distinct static type. Likewise, it's not legal to attempt to
+`impl Foo::Bar`: instead, you must `impl Foo` and then pattern match to specify
+behavior for specific enum variants.
+"##,
+
 E0249: r##"
 This error indicates a constant expression for the array length was found, but
 it was not an integer (signed or unsigned) expression.
 
 Some examples of code that produces this error are:
 
-```
+```compile_fail
 const A: [u32; "hello"] = []; // error
 const B: [u32; true] = []; // error
 const C: [u32; 0.0] = []; // error
+```
 "##,
 
 E0250: r##"
@@ -1796,7 +2998,7 @@ size array type.
 
 Some examples of this error are:
 
-```
+```compile_fail
 // divide by zero in the length expression
 const A: [u32; 1/0] = [];
 
@@ -1816,11 +3018,173 @@ defined. For more information see the [opt-in builtin traits RFC](https://github
 .com/rust-lang/rfcs/blob/master/text/0019-opt-in-builtin-traits.md).
 "##,
 
-E0322: r##"
-The `Sized` trait is a special trait built-in to the compiler for types with a
-constant size known at compile-time. This trait is automatically implemented
-for types as needed by the compiler, and it is currently disallowed to
-explicitly implement it for a type.
+E0320: r##"
+The compiler overflowed while computing the drop-check rules of a type. This
+happens with recursive types whose recursion goes through ever larger type
+arguments, such as `enum A<T> { B, C(T, Box<A<(T, T)>>) }`: checking what
+dropping a value of the type involves never terminates. Restructure the type so
+that its recursive occurrences use the same type arguments.
+"##,
+
+E0321: r##"
+A cross-crate opt-out trait was implemented on something which wasn't a struct
+or enum type. Erroneous code example:
+
+```compile_fail,E0321
+#![feature(optin_builtin_traits)]
+
+struct Foo;
+
+impl !Sync for &'static Foo {}
+// error: cross-crate traits with a default impl, like `core::marker::Sync`,
+//        can only be implemented for a struct/enum type, not
+//        `&'static Foo`
+```
+
+Only structs and enums are permitted to impl Send, Sync, and other opt-out
+traits, and the struct or enum must be local to the current crate. So, for
+example, `impl !Sync for Foo {}` is allowed.
+"##,
+
+E0322: r##"
+The `Sized` trait is a special trait built-in to the compiler for types with a
+constant size known at compile-time. This trait is automatically implemented
+for types as needed by the compiler, and it is currently disallowed to
+explicitly implement it for a type.
+"##,
+
+E0323: r##"
+An associated const was implemented when another trait item was expected.
+Erroneous code example:
+
+```compile_fail,E0323
+#![feature(associated_consts)]
+
+trait Foo {
+    type N;
+}
+
+struct Bar;
+
+impl Foo for Bar {
+    const N : u32 = 0;
+    // error: item `N` is an associated const, which doesn't match its
+    //        trait `<Bar as Foo>`
+}
+```
+
+Please verify that the associated const wasn't misspelled and the correct trait
+was implemented. Example:
+
+```
+struct Bar;
+
+trait Foo {
+    type N;
+}
+
+impl Foo for Bar {
+    type N = u32; // ok!
+}
+```
+
+Or:
+
+```
+#![feature(associated_consts)]
+
+struct Bar;
+
+trait Foo {
+    const N : u32;
+}
+
+impl Foo for Bar {
+    const N : u32 = 0; // ok!
+}
+```
+"##,
+
+E0324: r##"
+A method was implemented when another trait item was expected. Erroneous
+code example:
+
+```compile_fail,E0324
+#![feature(associated_consts)]
+
+struct Bar;
+
+trait Foo {
+    const N : u32;
+
+    fn M();
+}
+
+impl Foo for Bar {
+    fn N() {}
+    // error: item `N` is an associated method, which doesn't match its
+    //        trait `<Bar as Foo>`
+}
+```
+
+To fix this error, please verify that the method name wasn't misspelled and
+verify that you are indeed implementing the correct trait items. Example:
+
+```
+#![feature(associated_consts)]
+
+struct Bar;
+
+trait Foo {
+    const N : u32;
+
+    fn M();
+}
+
+impl Foo for Bar {
+    const N : u32 = 0;
+
+    fn M() {} // ok!
+}
+```
+"##,
+
+E0325: r##"
+An associated type was implemented when another trait item was expected.
+Erroneous code example:
+
+```compile_fail,E0325
+#![feature(associated_consts)]
+
+struct Bar;
+
+trait Foo {
+    const N : u32;
+}
+
+impl Foo for Bar {
+    type N = u32;
+    // error: item `N` is an associated type, which doesn't match its
+    //        trait `<Bar as Foo>`
+}
+```
+
+Please verify that the associated type name wasn't misspelled and your
+implementation corresponds to the trait definition. Example:
+
+```
+#![feature(associated_consts)]
+
+struct Bar;
+
+trait Foo {
+    const N : u32;
+}
+
+impl Foo for Bar {
+    const N : u32 = 0; // ok!
+}
+```
 "##,
 
 E0326: r##"
@@ -1829,7 +3193,9 @@ types in the trait definition. This error indicates that there was a mismatch.
 
 Here's an example of this error:
 
-```
+```compile_fail,E0326
+#![feature(associated_consts)]
+
 trait Foo {
     const BAR: bool;
 }
@@ -1846,7 +3212,7 @@ E0327: r##"
 You cannot use associated items other than constant items as patterns. This
 includes method items. Example of erroneous code:
 
-```
+```compile_fail,E0327
 enum B {}
 
 impl B {
@@ -1878,11 +3244,161 @@ fn main() {
 ```
 "##,
 
+E0328: r##"
+The `Unsize` trait should not be implemented directly. All implementations of
+`Unsize` are provided automatically by the compiler. Erroneous code example:
+
+```compile_fail,E0328
+#![feature(unsize)]
+
+use std::marker::Unsize;
+
+pub struct MyType;
+
+impl<T> Unsize<T> for MyType {}
+```
+
+If you are defining your own smart pointer type and would like to enable
+conversion from a sized to an unsized type with the DST coercion system
+([RFC 982]), use `CoerceUnsized` instead:
+
+```
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+pub struct MyType<T: ?Sized> {
+    field_with_unsized_type: T,
+}
+
+impl<T, U> CoerceUnsized<MyType<U>> for MyType<T>
+    where T: CoerceUnsized<U> {}
+```
+
+[RFC 982]: https://github.com/rust-lang/rfcs/pull/982
+"##,
+
+E0329: r##"
+An attempt was made to access an associated constant through either a generic
+type parameter or `Self`. This is not supported yet. An example causing this
+error is shown below:
+
+```compile_fail,E0329
+#![feature(associated_consts)]
+
+trait Foo {
+    const BAR: f64;
+}
+
+struct MyStruct;
+
+impl Foo for MyStruct {
+    const BAR: f64 = 0f64;
+}
+
+fn get_bar_bad<F: Foo>(t: F) -> f64 {
+    F::BAR
+}
+```
+
+Currently, the value of `BAR` for a particular type can only be accessed
+through a concrete type, as shown below:
+
+```
+#![feature(associated_consts)]
+
+trait Foo {
+    const BAR: f64;
+}
+
+struct MyStruct;
+
+impl Foo for MyStruct {
+    const BAR: f64 = 0f64;
+}
+
+fn get_bar_good() -> f64 {
+    <MyStruct as Foo>::BAR
+}
+```
+"##,
+
+E0366: r##"
+An attempt was made to implement `Drop` on a concrete specialization of a
+generic type. An example is shown below:
+
+```compile_fail,E0366
+struct Foo<T> {
+    t: T
+}
+
+impl Drop for Foo<u32> {
+    fn drop(&mut self) {}
+}
+```
+
+This code is not legal: it is not possible to specialize `Drop` to a subset of
+implementations of a generic type. One workaround for this is to wrap the
+generic type, as shown below:
+
+```
+struct Foo<T> {
+    t: T
+}
+
+struct Bar {
+    t: Foo<u32>
+}
+
+impl Drop for Bar {
+    fn drop(&mut self) {}
+}
+```
+"##,
+
+E0367: r##"
+An attempt was made to implement `Drop` on a specialization of a generic type.
+An example is shown below:
+
+```compile_fail,E0367
+trait Foo {}
+
+struct MyStruct<T> {
+    t: T
+}
+
+impl<T: Foo> Drop for MyStruct<T> {
+    fn drop(&mut self) {}
+}
+```
+
+This code is not legal: it is not possible to specialize `Drop` to a subset of
+implementations of a generic type. In order for this code to work, `MyStruct`
+must also require that `T` implements `Foo`. Alternatively, another option is
+to wrap the generic type in another that specializes appropriately:
+
+```
+trait Foo {}
+
+struct MyStruct<T> {
+    t: T
+}
+
+struct MyStructWrapper<T: Foo> {
+    t: MyStruct<T>
+}
+
+impl<T: Foo> Drop for MyStructWrapper<T> {
+    fn drop(&mut self) {}
+}
+```
+"##,
+
 E0368: r##"
 This error indicates that a binary assignment operator like `+=` or `^=` was
 applied to the wrong types. For example:
 
-```
+```compile_fail,E0368
 let mut x: u16 = 5;
 x ^= true; // error, `^=` cannot be applied to types `u16` and `bool`
 x += ();   // error, `+=` cannot be applied to types `u16` and `()`
@@ -1892,7 +3408,7 @@ Another problem you might be facing is this: suppose you've overloaded the `+`
 operator for some type `Foo` by implementing the `std::ops::Add` trait for
 `Foo`, but you find that using `+=` does not work, as in this example:
 
-```
+```compile_fail,E0368
 use std::ops::Add;
 
 struct Foo(u32);
@@ -1918,6 +3434,31 @@ to change this.
 [RFC 953]: https://github.com/rust-lang/rfcs/pull/953
 "##,
 
+E0369: r##"
+A binary operation was attempted on a type which doesn't support it.
+Erroneous code example:
+
+```compile_fail,E0369
+let x = 12f32; // error: binary operation `<<` cannot be applied to
+               //        type `f32`
+
+x << 2;
+```
+
+To fix this error, please check that this type implements this binary
+operation. Example:
+
+```
+let x = 12u32; // the `u32` type does implement it:
+               // https://doc.rust-lang.org/stable/std/ops/trait.Shl.html
+
+x << 2; // ok!
+```
+
+It is also possible to overload most operators for your own type by
+implementing traits from `std::ops`.
+"##,
+
 E0371: r##"
 When `Trait2` is a subtrait of `Trait1` (for example, when `Trait2` has a
 definition like `trait Trait2: Trait1 { ... }`), it is not allowed to implement
@@ -1926,7 +3467,7 @@ definition, so it is not useful to do this.
 
 Example:
 
-```
+```compile_fail,E0371
 trait Foo { fn foo(&self) { } }
 trait Bar: Foo { }
 trait Baz: Bar { }
@@ -1946,6 +3487,207 @@ Trait2 { ... }`) does not work if the trait is not object-safe. Please see the
 [RFC 255]: https://github.com/rust-lang/rfcs/pull/255
 "##,
 
+E0374: r##"
+A struct without a field containing an unsized type cannot implement
+`CoerceUnsized`. An [unsized type] is any type that the compiler doesn't know
+the length or alignment of at compile time. Any struct containing an unsized
+type is also unsized.
+
+[unsized type]: https://doc.rust-lang.org/book/unsized-types.html
+
+Example of erroneous code:
+
+```compile_fail,E0374
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized> {
+    a: i32,
+}
+
+// error: Struct `Foo` has no unsized fields that need `CoerceUnsized`.
+impl<T, U> CoerceUnsized<Foo<U>> for Foo<T>
+    where T: CoerceUnsized<U> {}
+```
+
+`CoerceUnsized` is used to coerce one struct containing an unsized type
+into another struct containing a different unsized type. If the struct
+doesn't have any fields of unsized types then you don't need explicit
+coercion to get the types you want. To fix this you can either
+not try to implement `CoerceUnsized` or you can add a field that is
+unsized to the struct.
+
+Example:
+
+```
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+// We don't need to impl `CoerceUnsized` here.
+struct Foo {
+    a: i32,
+}
+
+// We add the unsized type field to the struct.
+struct Bar<T: ?Sized> {
+    a: i32,
+    b: T,
+}
+
+// The struct has an unsized field so we can implement
+// `CoerceUnsized` for it.
+impl<T, U> CoerceUnsized<Bar<U>> for Bar<T>
+    where T: CoerceUnsized<U> {}
+```
+
+Note that `CoerceUnsized` is mainly used by smart pointers like `Box`, `Rc`
+and `Arc` to be able to mark that they can coerce unsized types that they
+are pointing at.
+"##,
+
+E0375: r##"
+A struct with more than one field containing an unsized type cannot implement
+`CoerceUnsized`. This only occurs when you are trying to coerce one of the
+types in your struct to another type in the struct. In this case we try to
+impl `CoerceUnsized` from `T` to `U` which are both types that the struct
+takes. An [unsized type] is any type that the compiler doesn't know the length
+or alignment of at compile time. Any struct containing an unsized type is also
+unsized.
+
+Example of erroneous code:
+
+```compile_fail,E0375
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized, U: ?Sized> {
+    a: i32,
+    b: T,
+    c: U,
+}
+
+// error: Struct `Foo` has more than one unsized field.
+impl<T, U> CoerceUnsized<Foo<U, T>> for Foo<T, U> {}
+```
+
+`CoerceUnsized` only allows for coercion from a structure with a single
+unsized type field to another struct with a single unsized type field.
+In fact Rust only allows for a struct to have one unsized type in a struct
+and that unsized type must be the last field in the struct. So having two
+unsized types in a single struct is not allowed by the compiler. To fix this
+use only one field containing an unsized type in the struct and then use
+multiple structs to manage each unsized type field you need.
+
+Example:
+
+```
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized> {
+    a: i32,
+    b: T,
+}
+
+impl <T, U> CoerceUnsized<Foo<U>> for Foo<T>
+    where T: CoerceUnsized<U> {}
+```
+
+[unsized type]: https://doc.rust-lang.org/book/unsized-types.html
+"##,
+
+E0376: r##"
+The type you are trying to impl `CoerceUnsized` for is not a struct.
+`CoerceUnsized` can only be implemented for a struct. Unsized types are
+already able to be coerced without an implementation of `CoerceUnsized`
+whereas a struct containing an unsized type needs to know the unsized type
+field it's containing is able to be coerced. An [unsized type] is any type
+that the compiler doesn't know the length or alignment of at compile time.
+Any struct containing an unsized type is also unsized.
+
+[unsized type]: https://doc.rust-lang.org/book/unsized-types.html
+
+Example of erroneous code:
+
+```compile_fail,E0376
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized> {
+    a: T,
+}
+
+// error: The target type is not a struct.
+impl<T, U> CoerceUnsized<U> for Foo<T> {}
+```
+
+The `CoerceUnsized` trait takes a struct type. Make sure the type you are
+providing to `CoerceUnsized` is a struct with only the last field containing an
+unsized type.
+
+Example:
+
+```
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T> {
+    a: T,
+}
+
+// The `Foo<U>` is a struct so `CoerceUnsized` can be implemented.
+impl<T, U> CoerceUnsized<Foo<U>> for Foo<T> where T: CoerceUnsized<U> {}
+```
+
+Note that in Rust, structs can only contain an unsized type if the field
+containing the unsized type is the last and only unsized type field in the
+struct.
+"##,
+
+E0377: r##"
+`CoerceUnsized` was implemented for a coercion between two different structs.
+It may only be implemented between two instances of the same struct, which
+differ in the type of a single field. Erroneous code example:
+
+```compile_fail,E0377
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized> {
+    a: T,
+}
+
+struct Bar<T: ?Sized> {
+    a: T,
+}
+
+// error: the trait `CoerceUnsized` may only be implemented for a coercion
+//        between structures with the same definition
+impl<T, U> CoerceUnsized<Bar<U>> for Foo<T> where T: CoerceUnsized<U> {}
+```
+
+Implement the coercion between two instances of the same struct instead:
+
+```
+#![feature(coerce_unsized)]
+
+use std::ops::CoerceUnsized;
+
+struct Foo<T: ?Sized> {
+    a: T,
+}
+
+impl<T, U> CoerceUnsized<Foo<U>> for Foo<T> where T: CoerceUnsized<U> {}
+```
+"##,
+
 E0379: r##"
 Trait methods cannot be declared `const` by design. For more information, see
 [RFC 911].
@@ -1957,115 +3699,174 @@ E0380: r##"
 Default impls are only allowed for traits with no methods or associated items.
 For more information see the [opt-in builtin traits RFC](https://github.com/rust
 -lang/rfcs/blob/master/text/0019-opt-in-builtin-traits.md).
+"##,
+
+E0390: r##"
+You tried to implement methods for a primitive type. Erroneous code example:
+
+```compile_fail,E0390
+struct Foo {
+    x: i32
+}
+
+impl *mut Foo {}
+// error: only a single inherent implementation marked with
+//        `#[lang = "mut_ptr"]` is allowed for the `*mut T` primitive
+```
+
+This isn't allowed, but using a trait to implement a method is a good solution.
+Example:
+
+```
+struct Foo {
+    x: i32
+}
+
+trait Bar {
+    fn bar();
+}
+
+impl Bar for *mut Foo {
+    fn bar() {} // ok!
+}
+```
+"##,
+
+E0391: r##"
+This error indicates that some types or traits depend on each other
+and therefore cannot be constructed.
+
+The following example contains a circular dependency between two traits:
+
+```compile_fail,E0391
+trait FirstTrait : SecondTrait {
+
+}
+
+trait SecondTrait : FirstTrait {
+
+}
+```
+"##,
+
+E0392: r##"
+This error indicates that a type or lifetime parameter has been declared
+but not actually used. Here is an example that demonstrates the error:
+
+```compile_fail,E0392
+enum Foo<T> {
+    Bar
+}
+```
+
+If the type parameter was included by mistake, this error can be fixed
+by simply removing the type parameter, as shown below:
+
+```
+enum Foo {
+    Bar
+}
+```
+
+Alternatively, if the type parameter was intentionally inserted, it must be
+used. A simple fix is shown below:
+
+```
+enum Foo<T> {
+    Bar(T)
+}
+```
+
+This error may also commonly be found when working with unsafe code. For
+example, when using raw pointers one may wish to specify the lifetime for
+which the pointed-at data is valid. An initial attempt (below) causes this
+error:
+
+```compile_fail,E0392
+struct Foo<'a, T> {
+    x: *const T
+}
+```
+
+We want to express the constraint that Foo should not outlive `'a`, because
+the data pointed to by `T` is only valid for that lifetime. The problem is
+that there are no actual uses of `'a`. It's possible to work around this
+by adding a PhantomData type to the struct, using it to tell the compiler
+to act as if the struct contained a borrowed reference `&'a T`:
+
+```
+use std::marker::PhantomData;
+
+struct Foo<'a, T: 'a> {
+    x: *const T,
+    phantom: PhantomData<&'a T>
+}
+```
+
+PhantomData can also be used to express information about unused type
+parameters. You can read more about it in the API documentation:
+
+https://doc.rust-lang.org/std/marker/struct.PhantomData.html
+"##,
+
+E0393: r##"
+A type parameter which references `Self` in its default value was not
+specified. Example of erroneous code:
+
+```compile_fail,E0393
+trait A<T=Self> {}
+
+fn together_we_will_rule_the_galaxy(son: &A) {}
+// error: the type parameter `T` must be explicitly specified in an
+//        object type because its default value `Self` references the
+//        type `Self`
+```
+
+A trait object is defined over a single, fully-defined trait. With a regular
+default parameter, this parameter can just be substituted in. However, if the
+default parameter is `Self`, the trait changes for each concrete type; i.e.
+`i32` will be expected to implement `A<i32>`, `bool` will be expected to
+implement `A<bool>`, etc... These types will not share an implementation of a
+fully-defined trait; instead they share implementations of a trait with
+different parameters substituted in for each implementation. This is
+irreconcilable with what we need to make a trait object work, and is thus
+disallowed. Making the trait concrete by explicitly specifying the value of the
+defaulted parameter will fix this issue. Fixed example:
+
+```
+trait A<T=Self> {}
+
+fn together_we_will_rule_the_galaxy(son: &A<i32>) {} // Ok!
+```
 "##
 
 }
 
 register_diagnostics! {
     E0068,
-    E0074,
-    E0075,
-    E0076,
-    E0077,
     E0085,
     E0086,
-    E0090,
-    E0102,
-    E0103,
-    E0104,
-    E0118,
-    E0120,
-    E0122,
     E0123,
     E0127,
-    E0128,
     E0129,
-    E0130,
     E0141,
-    E0159,
-    E0163,
-    E0164,
-    E0167,
-    E0168,
-    E0172,
     E0173, // manual implementations of unboxed closure traits are experimental
-    E0174, // explicit use of unboxed closure methods are experimental
-    E0182,
-    E0183,
     E0187, // can't infer the kind of the closure
     E0188, // can not cast a immutable reference to a mutable pointer
     E0189, // deprecated: can only cast a boxed pointer to a boxed object
     E0190, // deprecated: can only cast a &-pointer to an &-object
-    E0191, // value of the associated type must be specified
-    E0193, // cannot bound type where clause bounds may only be attached to types
-           // involving type parameters
-    E0194,
-    E0196, // cannot determine a type for this closure
-    E0203, // type parameter has more than one relaxed default bound,
-           // and only one is supported
-    E0208,
     E0209, // builtin traits can only be implemented on structs or enums
-    E0210, // type parameter is not constrained by any local type
-    E0212, // cannot extract an associated type from a higher-ranked trait bound
     E0213, // associated types are not accepted in this context
-    E0214, // parenthesized parameters may only be used with a trait
-    E0215, // angle-bracket notation is not stable with `Fn`
-    E0216, // parenthetical notation is only stable with `Fn`
     E0217, // ambiguous associated type, defined in multiple supertraits
     E0218, // no associated type defined
     E0219, // associated type defined in higher-ranked supertrait
-    E0220, // associated type not found for type parameter
-    E0221, // ambiguous associated type in bounds
     //E0222, // Error code E0045 (variadic function must have C calling
              // convention) duplicate
-    E0223, // ambiguous associated type
-    E0224, // at least one non-builtin train is required for an object type
-    E0225, // only the builtin traits can be used as closure or object bounds
-    E0226, // only a single explicit lifetime bound is permitted
-    E0227, // ambiguous lifetime bound, explicit lifetime bound required
-    E0228, // explicit lifetime bound required
-    E0229, // associated type bindings are not allowed here
-    E0230, // there is no type parameter on trait
-    E0231, // only named substitution parameters are allowed
-    E0232, // this attribute must have a value
     E0233,
     E0234,
-    E0235, // structure constructor specifies a structure of type but
-    E0236, // no lang item for range syntax
-    E0237, // no lang item for range syntax
-    E0238, // parenthesized parameters may only be used with a trait
     E0239, // `next` method of `Iterator` trait has unexpected type
     E0240,
     E0241,
-    E0242, // internal error looking up a definition
-    E0245, // not a trait
     E0246, // illegal recursive type
-    E0247, // found module name used as a type
-    E0248, // found value name used as a type
-    E0319, // trait impls for defaulted traits allowed just for structs/enums
-    E0320, // recursive overflow during dropck
-    E0321, // extended coherence rules for defaulted traits violated
-    E0323, // implemented an associated const when another trait item expected
-    E0324, // implemented a method when another trait item expected
-    E0325, // implemented an associated type when another trait item expected
-    E0328, // cannot implement Unsize explicitly
-    E0329, // associated const depends on type parameter or Self.
-    E0366, // dropck forbid specialization to concrete type or region
-    E0367, // dropck forbid specialization to predicate not in struct/enum
-    E0369, // binary operation `<op>` cannot be applied to types
-    E0374, // the trait `CoerceUnsized` may only be implemented for a coercion
-           // between structures with one field being coerced, none found
-    E0375, // the trait `CoerceUnsized` may only be implemented for a coercion
-           // between structures with one field being coerced, but multiple
-           // fields need coercions
-    E0376, // the trait `CoerceUnsized` may only be implemented for a coercion
-           // between structures
-    E0377, // the trait `CoerceUnsized` may only be implemented for a coercion
-           // between structures with the same definition
-    E0390, // only a single inherent implementation marked with
-           // `#[lang = \"{}\"]` is allowed for the `{}` primitive
-    E0391, // unsupported cyclic reference between types/traits detected
-    E0392, // parameter `{}` is never used
-    E0393  // the type parameter `{}` must be explicitly specified in an object
-           // type because its default value `{}` references the type `Self`"
+    E0319  // trait impls for defaulted traits allowed just for structs/enums
 }
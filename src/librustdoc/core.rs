@@ -12,7 +12,9 @@ pub use self::MaybeTyped::*;
 use rustc_lint;
 use rustc_driver::driver;
 use rustc::session::{self, config};
-use rustc::middle::{privacy, ty};
+use rustc::middle::{infer, privacy, subst, traits, ty};
+use rustc::middle::subst::Subst;
+use rustc::middle::ty::{ToPolyTraitRef, ToPredicate};
 use rustc::ast_map;
 use rustc::lint;
 use rustc_trans::back::link;
@@ -80,6 +82,19 @@ pub struct CrateAnalysis {
     pub external_typarams: RefCell<Option<HashMap<ast::DefId, String>>>,
     pub inlined: RefCell<Option<HashSet<ast::DefId>>>,
     pub deref_trait_did: Option<ast::DefId>,
+    /// The implicit auto trait impls of the crate's structs and enums.
+    pub auto_impls: HashMap<ast::DefId, Vec<AutoImpl>>,
+}
+
+/// Whether a type implements an auto trait such as `Send`, which the compiler
+/// decides from the types it contains rather than from an impl in the source.
+#[derive(Clone)]
+pub struct AutoImpl {
+    pub trait_: clean::Type,
+    /// Type parameters which must implement the trait as well for the type
+    /// to implement it.
+    pub bounded_params: Vec<String>,
+    pub negative: bool,
 }
 
 pub type Externs = HashMap<String, Vec<String>>;
@@ -161,6 +176,7 @@ pub fn run_core(search_paths: SearchPaths, cfgs: Vec<String>, externs: Externs,
             external_typarams: RefCell::new(None),
             inlined: RefCell::new(None),
             deref_trait_did: None,
+            auto_impls: HashMap::new(),
         };
 
         let krate = {
@@ -169,6 +185,8 @@ pub fn run_core(search_paths: SearchPaths, cfgs: Vec<String>, externs: Externs,
             v.clean(&ctxt)
         };
 
+        analysis.auto_impls = auto_impls(&ctxt, &krate);
+
         let external_paths = ctxt.external_paths.borrow_mut().take();
         *analysis.external_paths.borrow_mut() = external_paths;
         let map = ctxt.external_typarams.borrow_mut().take();
@@ -179,3 +197,126 @@ pub fn run_core(search_paths: SearchPaths, cfgs: Vec<String>, externs: Externs,
         (krate, analysis)
     }).1
 }
+
+/// Works out which of the auto traits known to the crate its structs and
+/// enums implement.
+fn auto_impls(cx: &DocContext, krate: &clean::Crate)
+              -> HashMap<ast::DefId, Vec<AutoImpl>> {
+    let tcx = cx.tcx();
+    let mut types = Vec::new();
+    let mut traits = tcx.lang_items.send_trait().into_iter()
+                        .chain(tcx.lang_items.sync_trait().into_iter())
+                        .collect::<Vec<_>>();
+    if let Some(ref m) = krate.module {
+        collect_auto_impl_items(m, &mut types, &mut traits);
+    }
+
+    types.into_iter().map(|did| {
+        let impls = traits.iter().map(|&trait_did| {
+            auto_impl(cx, did, trait_did)
+        }).collect();
+        (did, impls)
+    }).collect()
+}
+
+fn collect_auto_impl_items(item: &clean::Item, types: &mut Vec<ast::DefId>,
+                           traits: &mut Vec<ast::DefId>) {
+    match item.inner {
+        clean::ModuleItem(ref m) => {
+            for item in &m.items {
+                collect_auto_impl_items(item, types, traits);
+            }
+        }
+        clean::StructItem(..) | clean::EnumItem(..) => {
+            if item.def_id.krate == ast::LOCAL_CRATE {
+                types.push(item.def_id);
+            }
+        }
+        clean::DefaultImplItem(clean::DefaultImpl {
+            trait_: clean::ResolvedPath { did, .. }, ..
+        }) => {
+            if !traits.contains(&did) {
+                traits.push(did);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Checks whether the type `did` implements `trait_did` on its own, or
+/// otherwise whether it does so once some of its type parameters do.
+fn auto_impl(cx: &DocContext, did: ast::DefId, trait_did: ast::DefId) -> AutoImpl {
+    let tcx = cx.tcx();
+    let scheme = tcx.lookup_item_type(did);
+    let predicates = tcx.lookup_predicates(did);
+    let env = tcx.construct_parameter_environment(codemap::DUMMY_SP,
+                                                  &scheme.generics,
+                                                  &predicates,
+                                                  did.node);
+    let ty = scheme.ty.subst(tcx, &env.free_substs);
+    let params = env.free_substs.types.get_slice(subst::TypeSpace).to_vec();
+    let names = scheme.generics.types.get_slice(subst::TypeSpace).iter()
+                      .map(|p| p.name.as_str().to_string())
+                      .collect::<Vec<_>>();
+
+    // Assuming every type parameter implements the trait too, drop the ones
+    // whose bound turns out not to be needed.
+    let with_bounds = |bounded: &[bool]| {
+        let mut bounds = env.caller_bounds.clone();
+        bounds.extend(params.iter().zip(bounded).filter(|&(_, &b)| b)
+                            .map(|(&p, _)| trait_predicate(tcx, trait_did, p)));
+        implements(env.with_caller_bounds(bounds), ty, trait_did)
+    };
+    let mut bounded = vec![true; params.len()];
+    let negative = !with_bounds(&bounded[..]);
+    if !negative {
+        for i in 0..bounded.len() {
+            bounded[i] = false;
+            if !with_bounds(&bounded[..]) {
+                bounded[i] = true;
+            }
+        }
+    }
+
+    // The crate store only knows the paths of external traits.
+    let trait_ = if trait_did.krate == ast::LOCAL_CRATE {
+        let name = tcx.map.get_path_elem(trait_did.node).name();
+        clean::ResolvedPath {
+            path: clean::Path::singleton(name.as_str().to_string()),
+            typarams: None,
+            did: trait_did,
+            is_generic: false,
+        }
+    } else {
+        let trait_ref = ty::TraitRef::new(trait_did,
+                                          tcx.mk_substs(subst::Substs::empty()));
+        match trait_ref.clean(cx) {
+            clean::TraitBound(poly, _) => poly.trait_,
+            clean::RegionBound(..) => unreachable!(),
+        }
+    };
+    AutoImpl {
+        trait_: trait_,
+        bounded_params: names.into_iter().zip(bounded)
+                             .filter(|&(_, b)| b && !negative)
+                             .map(|(name, _)| name).collect(),
+        negative: negative,
+    }
+}
+
+fn implements<'a, 'tcx>(env: ty::ParameterEnvironment<'a, 'tcx>,
+                        ty: ty::Ty<'tcx>, trait_did: ast::DefId) -> bool {
+    let tcx = env.tcx;
+    let infcx = infer::new_infer_ctxt(tcx, &tcx.tables, Some(env), false);
+    let mut selcx = traits::SelectionContext::new(&infcx);
+    let obligation = traits::Obligation::new(traits::ObligationCause::dummy(),
+                                             trait_predicate(tcx, trait_did, ty));
+    selcx.evaluate_obligation(&obligation)
+}
+
+fn trait_predicate<'tcx>(tcx: &ty::ctxt<'tcx>, trait_did: ast::DefId,
+                         ty: ty::Ty<'tcx>) -> ty::Predicate<'tcx> {
+    let substs = subst::Substs::new_trait(Vec::new(), Vec::new(), ty);
+    ty::TraitRef::new(trait_did, tcx.mk_substs(substs))
+        .to_poly_trait_ref().to_predicate()
+}
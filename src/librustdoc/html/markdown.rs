@@ -390,7 +390,8 @@ pub fn find_testable_code(doc: &str, tests: &mut ::test::Collector) {
             let text = lines.collect::<Vec<&str>>().connect("\n");
             tests.add_test(text.to_string(),
                            block_info.should_panic, block_info.no_run,
-                           block_info.ignore, block_info.test_harness);
+                           block_info.ignore, block_info.test_harness,
+                           block_info.compile_fail, block_info.error_codes);
         }
     }
 
@@ -435,6 +436,8 @@ struct LangString {
     ignore: bool,
     rust: bool,
     test_harness: bool,
+    compile_fail: bool,
+    error_codes: Vec<String>,
 }
 
 impl LangString {
@@ -445,6 +448,8 @@ impl LangString {
             ignore: false,
             rust: true,  // NB This used to be `notrust = false`
             test_harness: false,
+            compile_fail: false,
+            error_codes: Vec::new(),
         }
     }
 
@@ -465,6 +470,13 @@ impl LangString {
                 "ignore" => { data.ignore = true; seen_rust_tags = true; },
                 "rust" => { data.rust = true; seen_rust_tags = true; },
                 "test_harness" => { data.test_harness = true; seen_rust_tags = true; }
+                "compile_fail" => { data.compile_fail = true; seen_rust_tags = true; }
+                // an error code which a `compile_fail` test has to report
+                x if x.len() == 5 && x.starts_with("E") &&
+                      x[1..].chars().all(|c| c.is_digit(10)) => {
+                    data.error_codes.push(x.to_string());
+                    seen_rust_tags = true;
+                }
                 _ => { seen_other_tags = true }
             }
         }
@@ -561,28 +573,40 @@ mod tests {
     #[test]
     fn test_lang_string_parse() {
         fn t(s: &str,
-            should_panic: bool, no_run: bool, ignore: bool, rust: bool, test_harness: bool) {
+            should_panic: bool, no_run: bool, ignore: bool, rust: bool, test_harness: bool,
+            compile_fail: bool) {
             assert_eq!(LangString::parse(s), LangString {
                 should_panic: should_panic,
                 no_run: no_run,
                 ignore: ignore,
                 rust: rust,
                 test_harness: test_harness,
+                compile_fail: compile_fail,
+                error_codes: Vec::new(),
             })
         }
 
-        // marker                | should_panic| no_run | ignore | rust | test_harness
-        t("",                      false,        false,   false,   true,  false);
-        t("rust",                  false,        false,   false,   true,  false);
-        t("sh",                    false,        false,   false,   false, false);
-        t("ignore",                false,        false,   true,    true,  false);
-        t("should_panic",          true,         false,   false,   true,  false);
-        t("no_run",                false,        true,    false,   true,  false);
-        t("test_harness",          false,        false,   false,   true,  true);
-        t("{.no_run .example}",    false,        true,    false,   true,  false);
-        t("{.sh .should_panic}",   true,         false,   false,   true,  false);
-        t("{.example .rust}",      false,        false,   false,   true,  false);
-        t("{.test_harness .rust}", false,        false,   false,   true,  true);
+        // marker                | should_panic| no_run | ignore | rust | test_harness | compile_fail
+        t("",                      false,        false,   false,   true,  false,          false);
+        t("rust",                  false,        false,   false,   true,  false,          false);
+        t("sh",                    false,        false,   false,   false, false,          false);
+        t("ignore",                false,        false,   true,    true,  false,          false);
+        t("should_panic",          true,         false,   false,   true,  false,          false);
+        t("no_run",                false,        true,    false,   true,  false,          false);
+        t("test_harness",          false,        false,   false,   true,  true,           false);
+        t("compile_fail",          false,        false,   false,   true,  false,          true);
+        t("{.no_run .example}",    false,        true,    false,   true,  false,          false);
+        t("{.sh .should_panic}",   true,         false,   false,   true,  false,          false);
+        t("{.example .rust}",      false,        false,   false,   true,  false,          false);
+        t("{.test_harness .rust}", false,        false,   false,   true,  true,           false);
+        t("{.compile_fail .rust}", false,        false,   false,   true,  false,          true);
+
+        let parse = LangString::parse;
+        assert_eq!(parse("compile_fail,E0308").error_codes, ["E0308"]);
+        assert_eq!(parse("compile_fail,E0308,E0382").error_codes, ["E0308", "E0382"]);
+        assert!(parse("compile_fail,E0308").compile_fail);
+        assert!(parse("E030").error_codes.is_empty());
+        assert!(!parse("E030").rust);
     }
 
     #[test]
@@ -302,11 +302,18 @@ thread_local!(static CACHE_KEY: RefCell<Arc<Cache>> = Default::default());
 thread_local!(pub static CURRENT_LOCATION_KEY: RefCell<Vec<String>> =
                     RefCell::new(Vec::new()));
 
-/// Generates the documentation for `crate` into the directory `dst`
+/// Generates the documentation for `crate` into the directory `dst`.
+///
+/// `extern_urls` maps the names of external crates to the root URL of their
+/// documentation, overriding any `html_root_url` they were compiled with, and
+/// the search indexes in `merge_indexes` are searched along with the crate's
+/// own.
 pub fn run(mut krate: clean::Crate,
            external_html: &ExternalHtml,
            dst: PathBuf,
-           passes: HashSet<String>) -> io::Result<()> {
+           passes: HashSet<String>,
+           extern_urls: HashMap<String, String>,
+           merge_indexes: Vec<PathBuf>) -> io::Result<()> {
     let src_root = match krate.src.parent() {
         Some(p) => p.to_path_buf(),
         None => PathBuf::new(),
@@ -403,8 +410,8 @@ pub fn run(mut krate: clean::Crate,
 
     // Cache where all our extern crates are located
     for &(n, ref e) in &krate.externs {
-        cache.extern_locations.insert(n, (e.name.clone(),
-                                          extern_location(e, &cx.dst)));
+        let location = extern_location(e, extern_urls.get(&e.name), &cx.dst);
+        cache.extern_locations.insert(n, (e.name.clone(), location));
         let did = ast::DefId { krate: n, node: ast::CRATE_NODE_ID };
         cache.paths.insert(did, (vec![e.name.to_string()], ItemType::Module));
     }
@@ -434,7 +441,8 @@ pub fn run(mut krate: clean::Crate,
     CACHE_KEY.with(|v| *v.borrow_mut() = cache.clone());
     CURRENT_LOCATION_KEY.with(|s| s.borrow_mut().clear());
 
-    try!(write_shared(&cx, &krate, &*cache, index));
+    try!(write_shared(&cx, &krate, &*cache, index, &extern_urls,
+                      &merge_indexes));
     let krate = try!(render_sources(&mut cx, krate));
 
     // And finally render the whole crate's documentation
@@ -542,7 +550,9 @@ fn build_index(krate: &clean::Crate, cache: &mut Cache) -> io::Result<String> {
 fn write_shared(cx: &Context,
                 krate: &clean::Crate,
                 cache: &Cache,
-                search_index: String) -> io::Result<()> {
+                search_index: String,
+                extern_urls: &HashMap<String, String>,
+                merge_indexes: &[PathBuf]) -> io::Result<()> {
     // Write out the shared files. Note that these are shared among all rustdoc
     // docs placed in the output directory, so this needs to be a synchronized
     // operation with respect to all other rustdocs running around.
@@ -593,7 +603,56 @@ fn write_shared(cx: &Context,
 
     // Update the search index
     let dst = cx.dst.join("search-index.js");
-    let all_indexes = try!(collect(&dst, &krate.name, "searchIndex"));
+    let mut all_indexes = try!(collect(&dst, &krate.name, "searchIndex"));
+
+    // Pull in the indexes of any other documentation that should be searched
+    // too. Crates documented into this directory take precedence, and the
+    // entries of the others record where their documentation lives so that
+    // search results can link to them: either the root URL given for the
+    // crate, or else the directory of the merged index relative to this one.
+    for path in merge_indexes {
+        if !path.is_file() {
+            return Err(io::Error::new(io::ErrorKind::NotFound,
+                                      format!("search index `{}` not found",
+                                              path.display())));
+        }
+        let local_root = try!(relative_root(path, &cx.dst));
+        for index in try!(collect(path, &krate.name, "searchIndex")) {
+            let malformed = || {
+                io::Error::new(io::ErrorKind::InvalidData,
+                               format!("malformed search index `{}`: {}",
+                                       path.display(), index))
+            };
+            let name = match index_crate(&index) {
+                Some(name) => name.to_string(),
+                None => return Err(malformed()),
+            };
+            if all_indexes.iter().any(|i| index_crate(i) == Some(&name[..])) {
+                continue
+            }
+            let prefix = format!("searchIndex['{}'] = {{", name);
+            if !index.starts_with(&prefix) {
+                return Err(malformed())
+            }
+            let root = match extern_urls.get(&name) {
+                Some(url) => root_url(url),
+                None => match local_root {
+                    Some(ref root) => root.clone(),
+                    None => {
+                        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                            format!("no path from `{}` to the documentation \
+                                     of `{}` in `{}`, pass \
+                                     --extern-html-root-url {}=URL",
+                                    cx.dst.display(), name, path.display(),
+                                    name)))
+                    }
+                },
+            };
+            all_indexes.push(format!("{}\"root\":{},{}", prefix, root.to_json(),
+                                     &index[prefix.len()..]));
+        }
+    }
+
     let mut w = try!(File::create(&dst));
     try!(writeln!(&mut w, "var searchIndex = {{}};"));
     try!(writeln!(&mut w, "{}", search_index));
@@ -727,9 +786,59 @@ fn clean_srcpath<F>(src_root: &Path, p: &Path, keep_filename: bool, mut f: F) wh
     }
 }
 
+/// Returns the name of the crate that a `searchIndex['name'] = ...` line of a
+/// search index is for.
+fn index_crate(line: &str) -> Option<&str> {
+    let prefix = "searchIndex['";
+    if !line.starts_with(prefix) {
+        return None
+    }
+    line[prefix.len()..].find("']").map(|end| {
+        &line[prefix.len()..prefix.len() + end]
+    })
+}
+
+/// Normalizes the root URL of some documentation to end with a `/`.
+fn root_url(url: &str) -> String {
+    if url.ends_with("/") {
+        url.to_string()
+    } else {
+        format!("{}/", url)
+    }
+}
+
+/// Returns the URL of the directory containing the search index `index`
+/// relative to the output directory `dst`, if there is one (there's none
+/// between different drives on Windows).
+fn relative_root(index: &Path, dst: &Path) -> io::Result<Option<String>> {
+    let index = try!(fs::canonicalize(index));
+    let dst = try!(fs::canonicalize(dst));
+    let from: Vec<_> = dst.components().collect();
+    let to: Vec<_> = index.parent().unwrap().components().collect();
+    let common = from.iter().zip(&to).take_while(|&(a, b)| a == b).count();
+    if common == 0 {
+        return Ok(None)
+    }
+    let mut url = String::new();
+    for _ in &from[common..] {
+        url.push_str("../");
+    }
+    for component in &to[common..] {
+        url.push_str(&component.as_os_str().to_string_lossy());
+        url.push('/');
+    }
+    Ok(Some(url))
+}
+
 /// Attempts to find where an external crate is located, given that we're
 /// rendering in to the specified source destination.
-fn extern_location(e: &clean::ExternalCrate, dst: &Path) -> ExternalLocation {
+fn extern_location(e: &clean::ExternalCrate, extern_url: Option<&String>,
+                   dst: &Path) -> ExternalLocation {
+    // A root URL given on the command line overrides everything else
+    if let Some(url) = extern_url {
+        return Remote(root_url(url));
+    }
+
     // See if there's documentation generated into the local directory
     let local_location = dst.join(&e.name);
     if local_location.is_dir() {
@@ -745,10 +854,7 @@ fn extern_location(e: &clean::ExternalCrate, dst: &Path) -> ExternalLocation {
                     match *attr {
                         clean::NameValue(ref x, ref s)
                                 if "html_root_url" == *x => {
-                            if s.ends_with("/") {
-                                return Remote(s.to_string());
-                            }
-                            return Remote(format!("{}/", s));
+                            return Remote(root_url(s));
                         }
                         _ => {}
                     }
@@ -535,26 +535,28 @@
                     shown.push(item);
                     name = item.name;
                     type = itemTypes[item.ty];
+                    // crates from a merged search index may live elsewhere
+                    var root = item.root;
 
                     if (type === 'mod') {
                         displayPath = item.path + '::';
-                        href = rootPath + item.path.replace(/::/g, '/') + '/' +
+                        href = root + item.path.replace(/::/g, '/') + '/' +
                                name + '/index.html';
                     } else if (type === 'static' || type === 'reexport') {
                         displayPath = item.path + '::';
-                        href = rootPath + item.path.replace(/::/g, '/') +
+                        href = root + item.path.replace(/::/g, '/') +
                                '/index.html';
                     } else if (item.parent !== undefined) {
                         var myparent = item.parent;
                         var anchor = '#' + type + '.' + name;
                         displayPath = item.path + '::' + myparent.name + '::';
-                        href = rootPath + item.path.replace(/::/g, '/') +
+                        href = root + item.path.replace(/::/g, '/') +
                                '/' + itemTypes[myparent.ty] +
                                '.' + myparent.name +
                                '.html' + anchor;
                     } else {
                         displayPath = item.path + '::';
-                        href = rootPath + item.path.replace(/::/g, '/') +
+                        href = root + item.path.replace(/::/g, '/') +
                                '/' + type + '.' + name + '.html';
                     }
 
@@ -636,6 +638,20 @@
             return -1;
         }
 
+        // The URL of the documentation of `crate` relative to this page. Crates
+        // from a merged search index record it either as an absolute URL or
+        // relative to the output directory (see `--merge-search-index`).
+        function crateRoot(crate) {
+            var root = rawSearchIndex[crate].root;
+            if (!root) {
+                return rootPath;
+            }
+            if (/^([a-z][a-z0-9+.-]*:|\/)/i.test(root)) {
+                return root;
+            }
+            return rootPath + root;
+        }
+
         function buildIndex(rawSearchIndex) {
             searchIndex = [];
             var searchWords = [];
@@ -652,6 +668,9 @@
                 // an array of [(Number) item type,
                 //              (String) name]
                 var paths = rawSearchIndex[crate].paths;
+                // the root URL of the crate's documentation, which may live
+                // somewhere else (see `--merge-search-index`)
+                var root = crateRoot(crate);
 
                 // convert `paths` into an object form
                 var len = paths.length;
@@ -672,7 +691,8 @@
                     var rawRow = items[i];
                     var row = {crate: crate, ty: rawRow[0], name: rawRow[1],
                                path: rawRow[2] || lastPath, desc: rawRow[3],
-                               parent: paths[rawRow[4]], type: rawRow[5]};
+                               parent: paths[rawRow[4]], type: rawRow[5],
+                               root: root};
                     searchIndex.push(row);
                     if (typeof row.name === "string") {
                         var word = row.name.toLowerCase();
@@ -755,7 +775,8 @@
                 }
                 if (rawSearchIndex[crates[i]].items[0]) {
                     var desc = rawSearchIndex[crates[i]].items[0][3];
-                    div.append($('<a>', {'href': '../' + crates[i] + '/index.html',
+                    var root = crateRoot(crates[i]);
+                    div.append($('<a>', {'href': root + crates[i] + '/index.html',
                                          'title': plainSummaryLine(desc),
                                          'class': klass}).text(crates[i]));
                 }
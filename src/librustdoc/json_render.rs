@@ -0,0 +1,1153 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! JSON rendering of the cleaned crate model (`--output-format json`).
+//!
+//! Unlike the `legacy-json` dump, which is just `clean::Crate` run through
+//! `RustcEncodable` and changes shape whenever the clean AST does, this
+//! backend writes a documented format which is versioned separately by
+//! `FORMAT_VERSION`. Consumers should check that field before anything else;
+//! it is bumped whenever a field is removed or changes meaning, while new
+//! fields may be added within a version.
+//!
+//! The top level of the output is an object of the form:
+//!
+//! ```text
+//! {
+//!     "format_version": 1,
+//!     "crate": { "name": "foo", "root": ID, "externs": { CRATENUM: EXTERN } },
+//!     "index": { ID: ITEM, ... },
+//!     "paths": { ID: { "crate": CRATENUM, "path": ["foo", "bar"], "kind": KIND } },
+//!     "reexports": { ID: [["foo", "Bar"], ...] },
+//!     "plugins": { NAME: ..., ... }
+//! }
+//! ```
+//!
+//! An `ID` is a string of the form `"crate:node"` built from the item's
+//! `DefId`, so it can be used to link to items in other crates as well. Items
+//! which don't have a `DefId` of their own (imports and `extern crate`s) or
+//! which are documented more than once (items inlined through several
+//! re-exports) get a `":n"` suffix to keep their ids unique.
+//!
+//! `paths` maps ids to the canonical path of every item which is the direct
+//! child of a module, along with any external item the crate refers to. Items
+//! inlined from other crates are listed under the path they are re-exported
+//! at. `reexports` lists the paths of public `use` declarations, keyed by the
+//! id of the item they resolve to.
+//!
+//! Each `ITEM` in the index looks like:
+//!
+//! ```text
+//! {
+//!     "id": ID,
+//!     "crate": CRATENUM,
+//!     "name": "Bar" | null,
+//!     "kind": KIND,
+//!     "source": { "filename": "src/lib.rs", "begin": [LINE, COL], "end": [LINE, COL] },
+//!     "visibility": "public" | "inherited" | null,
+//!     "docs": "..." | null,
+//!     "attrs": ["inline", "doc(hidden)", ...],
+//!     "stability": { "level": "stable" | "unstable", "feature": ..., "since": ...,
+//!                    "deprecated_since": ..., "reason": ... } | null,
+//!     "inner": { ... }
+//! }
+//! ```
+//!
+//! `KIND` is one of the item kinds used in HTML file names (`struct`, `fn`,
+//! `mod`, `impl`, ...), and `inner` holds the kind-specific details. Child
+//! items (module members, fields, variants, trait and impl items) are stored
+//! in the index themselves and referred to by id. Types, generics, bounds and
+//! where-clauses are written inline as tagged objects with a `"kind"` field.
+//!
+//! The `inner` object of an `impl` item carries `"trait"` (or `null` for
+//! inherent impls), `"for"`, `"negative"` for `impl !Trait for T`, `"blanket"`
+//! when the impl is for a bare type parameter (`impl<T> Trait for T`) and
+//! `"auto"` for the `impl Trait for ..` declarations of auto traits. Structs,
+//! enums and primitives list the impls for them under `"impls"`, and traits
+//! list theirs under `"implementors"`. Impls for references to a type are
+//! listed with the type, and impls for primitive types with the primitive in
+//! whichever crate documents it, as in the HTML output.
+//!
+//! The compiler's implicit impls of auto traits (`Send`, `Sync` and any
+//! trait declared with `impl Trait for ..`) for the crate's structs and enums
+//! are included as well, marked `"synthetic": true`. They have no source and
+//! their id is `"TYPE+TRAIT"` built from the ids of the type and the trait.
+//! `"negative"` is set if the type doesn't implement the trait at all, and
+//! otherwise the `T: Trait` where-predicates say which type parameters must
+//! implement the trait for the type to do so.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::prelude::*;
+use std::io;
+use std::path::PathBuf;
+
+use serialize::json::{Json, ToJson};
+use syntax::ast;
+use syntax::attr;
+
+use clean;
+use core::AutoImpl;
+use doctree;
+use html::item_type::ItemType;
+use plugins;
+
+/// The version of the format written by this module.
+pub const FORMAT_VERSION: u64 = 1;
+
+/// Renders `krate` and the output of `plugins` as JSON into `dst`.
+pub fn run(krate: clean::Crate, plugins: Vec<plugins::PluginJson>,
+           dst: PathBuf) -> io::Result<()> {
+    let analysis = ::ANALYSISKEY.with(|a| a.clone());
+    let auto_impls = match *analysis.borrow() {
+        Some(ref a) => a.auto_impls.clone(),
+        None => HashMap::new(),
+    };
+    let json = render(&krate, plugins, auto_impls);
+    let mut file = try!(File::create(&dst));
+    write!(&mut file, "{}", json)
+}
+
+fn render(krate: &clean::Crate, plugins: Vec<plugins::PluginJson>,
+          auto_impls: HashMap<ast::DefId, Vec<AutoImpl>>) -> Json {
+    // Impls for primitives are listed with the primitive in the closest
+    // crate which documents it.
+    let mut primitive_locations = HashMap::new();
+    for &(n, ref e) in krate.externs.iter().rev() {
+        for &prim in &e.primitives {
+            primitive_locations.insert(prim, n);
+        }
+    }
+    for &prim in &krate.primitives {
+        primitive_locations.insert(prim, ast::LOCAL_CRATE);
+    }
+
+    let mut r = Renderer {
+        krate_name: krate.name.clone(),
+        index: BTreeMap::new(),
+        paths: BTreeMap::new(),
+        reexports: BTreeMap::new(),
+        used_ids: HashSet::new(),
+        stack: Vec::new(),
+        impls: HashMap::new(),
+        implementors: HashMap::new(),
+        auto_impls: auto_impls,
+        primitive_locations: primitive_locations,
+    };
+
+    let root = krate.module.as_ref().map(|m| {
+        let root = r.item(m);
+        r.add_path(root.clone(), ast::LOCAL_CRATE, vec![krate.name.clone()],
+                   ItemType::Module);
+        root
+    });
+    r.link_impls();
+
+    // Paths of external items the crate refers to. Anything which was
+    // documented in this crate already has a path from the walk above.
+    let analysis = ::ANALYSISKEY.with(|a| a.clone());
+    let analysis = analysis.borrow();
+    if let Some(ref a) = *analysis {
+        if let Some(ref paths) = *a.external_paths.borrow() {
+            for (&did, &(ref path, kind)) in paths {
+                let kind = ItemType::from_type_kind(kind);
+                r.paths.entry(def_id(did)).or_insert(path_entry(did.krate,
+                                                                path.clone(),
+                                                                kind));
+            }
+        }
+    }
+
+    let externs = krate.externs.iter().map(|&(cnum, ref e)| {
+        (cnum.to_string(), object(vec![
+            ("name", e.name.to_json()),
+            ("primitives", e.primitives.iter().map(|p| p.to_string())
+                                      .collect::<Vec<_>>().to_json()),
+        ]))
+    }).collect::<BTreeMap<_, _>>();
+    let plugins = plugins.into_iter().filter_map(|p| p).collect();
+
+    object(vec![
+        ("format_version", FORMAT_VERSION.to_json()),
+        ("crate", object(vec![
+            ("name", krate.name.to_json()),
+            ("root", root.to_json()),
+            ("externs", Json::Object(externs)),
+        ])),
+        ("index", Json::Object(r.index)),
+        ("paths", Json::Object(r.paths)),
+        ("reexports", r.reexports.to_json()),
+        ("plugins", Json::Object(plugins)),
+    ])
+}
+
+struct Renderer {
+    krate_name: String,
+    index: BTreeMap<String, Json>,
+    paths: BTreeMap<String, Json>,
+    reexports: BTreeMap<String, Vec<Vec<String>>>,
+    /// Every id handed out so far, including those of items which are still
+    /// being rendered and so aren't in the index yet.
+    used_ids: HashSet<String>,
+    /// Path of the module currently being rendered.
+    stack: Vec<String>,
+    /// Impls keyed by the type they are for, and by the trait they implement.
+    impls: HashMap<ast::DefId, Vec<String>>,
+    implementors: HashMap<ast::DefId, Vec<String>>,
+    auto_impls: HashMap<ast::DefId, Vec<AutoImpl>>,
+    primitive_locations: HashMap<clean::PrimitiveType, ast::CrateNum>,
+}
+
+impl Renderer {
+    /// Renders `item` and all of its children into the index, returning the
+    /// id it was given.
+    fn item(&mut self, item: &clean::Item) -> String {
+        let id = self.fresh_id(item.def_id);
+        let inner = match item.inner {
+            clean::ModuleItem(ref m) => {
+                let name = if m.is_crate {
+                    self.krate_name.clone()
+                } else {
+                    item.name.clone().unwrap_or(String::new())
+                };
+                self.stack.push(name);
+                let mut items = Vec::new();
+                for child in &m.items {
+                    let child_id = self.item(child);
+                    if let Some(ref name) = child.name {
+                        let mut path = self.stack.clone();
+                        path.push(name.clone());
+                        self.add_path(child_id.clone(), child.def_id.krate, path,
+                                      ItemType::from_item(child));
+                    }
+                    items.push(child_id);
+                }
+                self.stack.pop();
+                object(vec![
+                    ("is_crate", m.is_crate.to_json()),
+                    ("items", items.to_json()),
+                ])
+            }
+            clean::ExternCrateItem(ref name, ref path) => {
+                object(vec![
+                    ("name", name.to_json()),
+                    ("path", path.to_json()),
+                ])
+            }
+            clean::ImportItem(ref import) => self.import(item, import),
+            clean::StructItem(ref s) => {
+                self.synthetic_impls(item, &s.generics);
+                object(vec![
+                    ("struct_type", struct_type(s.struct_type).to_json()),
+                    ("generics", s.generics.to_json()),
+                    ("fields", self.items(&s.fields).to_json()),
+                    ("fields_stripped", s.fields_stripped.to_json()),
+                    ("impls", Json::Array(Vec::new())),
+                ])
+            }
+            clean::EnumItem(ref e) => {
+                self.synthetic_impls(item, &e.generics);
+                object(vec![
+                    ("generics", e.generics.to_json()),
+                    ("variants", self.items(&e.variants).to_json()),
+                    ("variants_stripped", e.variants_stripped.to_json()),
+                    ("impls", Json::Array(Vec::new())),
+                ])
+            }
+            clean::VariantItem(ref v) => match v.kind {
+                clean::CLikeVariant => {
+                    object(vec![("variant_kind", "plain".to_json())])
+                }
+                clean::TupleVariant(ref tys) => {
+                    object(vec![
+                        ("variant_kind", "tuple".to_json()),
+                        ("types", tys.to_json()),
+                    ])
+                }
+                clean::StructVariant(ref s) => {
+                    object(vec![
+                        ("variant_kind", "struct".to_json()),
+                        ("fields", self.items(&s.fields).to_json()),
+                        ("fields_stripped", s.fields_stripped.to_json()),
+                    ])
+                }
+            },
+            clean::StructFieldItem(ref f) => {
+                let ty = match *f {
+                    clean::HiddenStructField => None,
+                    clean::TypedStructField(ref ty) => Some(ty),
+                };
+                object(vec![("type", ty.map(|t| t.to_json()).to_json())])
+            }
+            clean::FunctionItem(ref f) | clean::ForeignFunctionItem(ref f) => {
+                object(vec![
+                    ("decl", f.decl.to_json()),
+                    ("generics", f.generics.to_json()),
+                    ("unsafe", (f.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("const", (f.constness == ast::Constness::Const).to_json()),
+                    ("abi", f.abi.to_string().to_json()),
+                ])
+            }
+            clean::TypedefItem(ref t, is_associated) => {
+                object(vec![
+                    ("type", t.type_.to_json()),
+                    ("generics", t.generics.to_json()),
+                    ("associated", is_associated.to_json()),
+                ])
+            }
+            clean::StaticItem(ref s) | clean::ForeignStaticItem(ref s) => {
+                object(vec![
+                    ("type", s.type_.to_json()),
+                    ("mutable", (s.mutability == clean::Mutable).to_json()),
+                    ("expr", s.expr.to_json()),
+                ])
+            }
+            clean::ConstantItem(ref c) => {
+                object(vec![
+                    ("type", c.type_.to_json()),
+                    ("expr", c.expr.to_json()),
+                ])
+            }
+            clean::TraitItem(ref t) => {
+                object(vec![
+                    ("unsafe", (t.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("generics", t.generics.to_json()),
+                    ("bounds", t.bounds.to_json()),
+                    ("items", self.items(&t.items).to_json()),
+                    ("implementors", Json::Array(Vec::new())),
+                ])
+            }
+            clean::ImplItem(ref i) => {
+                if let Some(did) = self.impl_target(&i.for_) {
+                    self.impls.entry(did).or_insert(vec![]).push(id.clone());
+                }
+                if let Some(clean::ResolvedPath { did, .. }) = i.trait_ {
+                    self.implementors.entry(did).or_insert(vec![])
+                        .push(id.clone());
+                }
+                let blanket = match i.for_ {
+                    clean::Generic(..) => true,
+                    _ => false,
+                };
+                object(vec![
+                    ("unsafe", (i.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("generics", i.generics.to_json()),
+                    ("trait", i.trait_.to_json()),
+                    ("for", i.for_.to_json()),
+                    ("items", self.items(&i.items).to_json()),
+                    ("derived", i.derived.to_json()),
+                    ("negative", (i.polarity ==
+                                  Some(clean::ImplPolarity::Negative)).to_json()),
+                    ("blanket", blanket.to_json()),
+                    ("auto", false.to_json()),
+                    ("synthetic", false.to_json()),
+                ])
+            }
+            clean::DefaultImplItem(ref i) => {
+                if let clean::ResolvedPath { did, .. } = i.trait_ {
+                    self.implementors.entry(did).or_insert(vec![])
+                        .push(id.clone());
+                }
+                object(vec![
+                    ("unsafe", (i.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("generics", clean::Generics {
+                        lifetimes: Vec::new(),
+                        type_params: Vec::new(),
+                        where_predicates: Vec::new(),
+                    }.to_json()),
+                    ("trait", i.trait_.to_json()),
+                    ("for", Json::Null),
+                    ("items", Json::Array(Vec::new())),
+                    ("derived", false.to_json()),
+                    ("negative", false.to_json()),
+                    ("blanket", false.to_json()),
+                    ("auto", true.to_json()),
+                    ("synthetic", false.to_json()),
+                ])
+            }
+            clean::TyMethodItem(ref m) => {
+                object(vec![
+                    ("decl", m.decl.to_json()),
+                    ("generics", m.generics.to_json()),
+                    ("self", self_ty(&m.self_)),
+                    ("unsafe", (m.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("const", false.to_json()),
+                    ("abi", m.abi.to_string().to_json()),
+                    ("has_body", false.to_json()),
+                ])
+            }
+            clean::MethodItem(ref m) => {
+                object(vec![
+                    ("decl", m.decl.to_json()),
+                    ("generics", m.generics.to_json()),
+                    ("self", self_ty(&m.self_)),
+                    ("unsafe", (m.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("const", (m.constness == ast::Constness::Const).to_json()),
+                    ("abi", m.abi.to_string().to_json()),
+                    ("has_body", true.to_json()),
+                ])
+            }
+            clean::MacroItem(ref m) => {
+                object(vec![
+                    ("source", m.source.to_json()),
+                    ("imported_from", m.imported_from.to_json()),
+                ])
+            }
+            clean::PrimitiveItem(p) => {
+                object(vec![
+                    ("name", p.to_string().to_json()),
+                    ("impls", Json::Array(Vec::new())),
+                ])
+            }
+            clean::AssociatedConstItem(ref ty, ref default) => {
+                object(vec![
+                    ("type", ty.to_json()),
+                    ("default", default.to_json()),
+                ])
+            }
+            clean::AssociatedTypeItem(ref bounds, ref default) => {
+                object(vec![
+                    ("bounds", bounds.to_json()),
+                    ("default", default.to_json()),
+                ])
+            }
+        };
+
+        let json = object(vec![
+            ("id", id.to_json()),
+            ("crate", item.def_id.krate.to_json()),
+            ("name", item.name.to_json()),
+            ("kind", ItemType::from_item(item).to_static_str().to_json()),
+            ("source", object(vec![
+                ("filename", item.source.filename.to_json()),
+                ("begin", (item.source.loline, item.source.locol).to_json()),
+                ("end", (item.source.hiline, item.source.hicol).to_json()),
+            ])),
+            ("visibility", match item.visibility {
+                Some(ast::Public) => "public".to_json(),
+                Some(ast::Inherited) => "inherited".to_json(),
+                None => Json::Null,
+            }),
+            ("docs", docs(&item.attrs).to_json()),
+            ("attrs", item.attrs.iter().filter_map(|a| match *a {
+                clean::NameValue(ref k, _) if k == "doc" => None,
+                ref a => Some(attr_string(a)),
+            }).collect::<Vec<_>>().to_json()),
+            ("stability", item.stability.to_json()),
+            ("inner", inner),
+        ]);
+        self.index.insert(id.clone(), json);
+        id
+    }
+
+    fn items(&mut self, items: &[clean::Item]) -> Vec<String> {
+        items.iter().map(|i| self.item(i)).collect()
+    }
+
+    fn import(&mut self, item: &clean::Item, import: &clean::Import) -> Json {
+        let public = item.visibility == Some(ast::Public);
+        match *import {
+            clean::SimpleImport(ref name, ref src) => {
+                if public {
+                    self.add_reexport(src.did, name);
+                }
+                object(vec![
+                    ("import_kind", "simple".to_json()),
+                    ("name", name.to_json()),
+                    ("source", path_string(&src.path).to_json()),
+                    ("target", src.did.map(def_id).to_json()),
+                ])
+            }
+            clean::GlobImport(ref src) => {
+                object(vec![
+                    ("import_kind", "glob".to_json()),
+                    ("source", path_string(&src.path).to_json()),
+                    ("target", src.did.map(def_id).to_json()),
+                ])
+            }
+            clean::ImportList(ref src, ref names) => {
+                let names = names.iter().map(|n| {
+                    if public {
+                        self.add_reexport(n.source, &n.name);
+                    }
+                    object(vec![
+                        ("name", n.name.to_json()),
+                        ("target", n.source.map(def_id).to_json()),
+                    ])
+                }).collect();
+                object(vec![
+                    ("import_kind", "list".to_json()),
+                    ("source", path_string(&src.path).to_json()),
+                    ("target", src.did.map(def_id).to_json()),
+                    ("names", Json::Array(names)),
+                ])
+            }
+        }
+    }
+
+    fn add_reexport(&mut self, target: Option<ast::DefId>, name: &str) {
+        if let Some(did) = target {
+            let mut path = self.stack.clone();
+            path.push(name.to_string());
+            self.reexports.entry(def_id(did)).or_insert(vec![]).push(path);
+        }
+    }
+
+    fn add_path(&mut self, id: String, krate: ast::CrateNum,
+                path: Vec<String>, kind: ItemType) {
+        self.paths.insert(id, path_entry(krate, path, kind));
+    }
+
+    /// Renders the implicit auto trait impls of the struct or enum `item`
+    /// into the index.
+    fn synthetic_impls(&mut self, item: &clean::Item, generics: &clean::Generics) {
+        let impls = match self.auto_impls.get(&item.def_id) {
+            Some(impls) => impls.clone(),
+            None => return,
+        };
+        let for_ = clean::ResolvedPath {
+            path: clean::Path {
+                global: false,
+                segments: vec![clean::PathSegment {
+                    name: item.name.clone().unwrap_or(String::new()),
+                    params: clean::PathParameters::AngleBracketed {
+                        lifetimes: generics.lifetimes.clone(),
+                        types: generics.type_params.iter().map(|t| {
+                            clean::Generic(t.name.clone())
+                        }).collect(),
+                        bindings: Vec::new(),
+                    },
+                }],
+            },
+            typarams: None,
+            did: item.def_id,
+            is_generic: false,
+        };
+        for imp in impls {
+            let trait_did = match imp.trait_ {
+                clean::ResolvedPath { did, .. } => did,
+                _ => continue,
+            };
+            let id = format!("{}+{}", def_id(item.def_id), def_id(trait_did));
+            self.used_ids.insert(id.clone());
+            self.impls.entry(item.def_id).or_insert(vec![]).push(id.clone());
+            self.implementors.entry(trait_did).or_insert(vec![]).push(id.clone());
+
+            let mut generics = generics.clone();
+            for name in &imp.bounded_params {
+                generics.where_predicates.push(clean::WherePredicate::BoundPredicate {
+                    ty: clean::Generic(name.clone()),
+                    bounds: vec![clean::TraitBound(clean::PolyTrait {
+                        trait_: imp.trait_.clone(),
+                        lifetimes: Vec::new(),
+                    }, ast::TraitBoundModifier::None)],
+                });
+            }
+            let json = object(vec![
+                ("id", id.to_json()),
+                ("crate", item.def_id.krate.to_json()),
+                ("name", Json::Null),
+                ("kind", ItemType::Impl.to_static_str().to_json()),
+                ("source", Json::Null),
+                ("visibility", Json::Null),
+                ("docs", Json::Null),
+                ("attrs", Json::Array(Vec::new())),
+                ("stability", Json::Null),
+                ("inner", object(vec![
+                    ("unsafe", false.to_json()),
+                    ("generics", generics.to_json()),
+                    ("trait", imp.trait_.to_json()),
+                    ("for", for_.to_json()),
+                    ("items", Json::Array(Vec::new())),
+                    ("derived", false.to_json()),
+                    ("negative", imp.negative.to_json()),
+                    ("blanket", false.to_json()),
+                    ("auto", false.to_json()),
+                    ("synthetic", true.to_json()),
+                ])),
+            ]);
+            self.index.insert(id, json);
+        }
+    }
+
+    /// Returns the `DefId` of the item whose `impls` list an impl for `ty`
+    /// goes into: the type itself for paths and references to them, or the
+    /// primitive in the closest crate which documents it.
+    fn impl_target(&self, ty: &clean::Type) -> Option<ast::DefId> {
+        match *ty {
+            clean::ResolvedPath { did, .. } |
+            clean::BorrowedRef {
+                type_: box clean::ResolvedPath { did, .. }, ..
+            } => Some(did),
+            ref t => t.primitive_type().and_then(|p| {
+                self.primitive_locations.get(&p).map(|&krate| {
+                    ast::DefId { krate: krate, node: p.to_node_id() }
+                })
+            }),
+        }
+    }
+
+    /// Returns a unique id for an item with the given `DefId`.
+    fn fresh_id(&mut self, did: ast::DefId) -> String {
+        let base = def_id(did);
+        let mut id = base.clone();
+        let mut n = 0;
+        while self.used_ids.contains(&id) {
+            n += 1;
+            id = format!("{}:{}", base, n);
+        }
+        self.used_ids.insert(id.clone());
+        id
+    }
+
+    /// Fills in the `impls` and `implementors` lists of the items that the
+    /// impls seen during the walk refer to.
+    fn link_impls(&mut self) {
+        let lists = [("impls", &self.impls), ("implementors", &self.implementors)];
+        for &(field, map) in &lists {
+            for (&did, ids) in map {
+                let item = match self.index.get_mut(&def_id(did)) {
+                    Some(&mut Json::Object(ref mut item)) => item,
+                    _ => continue,
+                };
+                if let Some(&mut Json::Object(ref mut inner)) = item.get_mut("inner") {
+                    if inner.contains_key(field) {
+                        inner.insert(field.to_string(), ids.to_json());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn object(fields: Vec<(&str, Json)>) -> Json {
+    Json::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+fn def_id(did: ast::DefId) -> String {
+    format!("{}:{}", did.krate, did.node)
+}
+
+fn path_entry(krate: ast::CrateNum, path: Vec<String>, kind: ItemType) -> Json {
+    object(vec![
+        ("crate", krate.to_json()),
+        ("path", path.to_json()),
+        ("kind", kind.to_static_str().to_json()),
+    ])
+}
+
+fn docs(attrs: &[clean::Attribute]) -> Option<String> {
+    let docs = attrs.iter().filter_map(|a| match *a {
+        clean::NameValue(ref k, ref v) if k == "doc" => Some(&v[..]),
+        _ => None,
+    }).collect::<Vec<_>>();
+    if docs.is_empty() {
+        None
+    } else {
+        Some(docs.connect("\n"))
+    }
+}
+
+fn attr_string(attr: &clean::Attribute) -> String {
+    match *attr {
+        clean::Word(ref s) => s.clone(),
+        clean::List(ref s, ref list) => {
+            let list = list.iter().map(attr_string).collect::<Vec<_>>();
+            format!("{}({})", s, list.connect(", "))
+        }
+        clean::NameValue(ref k, ref v) => format!("{} = {:?}", k, v),
+    }
+}
+
+fn path_string(path: &clean::Path) -> String {
+    let segments = path.segments.iter().map(|s| &s.name[..]).collect::<Vec<_>>();
+    let joined = segments.connect("::");
+    if path.global { format!("::{}", joined) } else { joined }
+}
+
+fn struct_type(ty: doctree::StructType) -> &'static str {
+    match ty {
+        doctree::Plain => "plain",
+        doctree::Tuple => "tuple",
+        doctree::Newtype => "newtype",
+        doctree::Unit => "unit",
+    }
+}
+
+fn self_ty(s: &clean::SelfTy) -> Json {
+    match *s {
+        clean::SelfStatic => Json::Null,
+        clean::SelfValue => object(vec![("kind", "value".to_json())]),
+        clean::SelfBorrowed(ref lt, mutability) => {
+            object(vec![
+                ("kind", "borrowed".to_json()),
+                ("lifetime", lt.to_json()),
+                ("mutable", (mutability == clean::Mutable).to_json()),
+            ])
+        }
+        clean::SelfExplicit(ref ty) => {
+            object(vec![
+                ("kind", "explicit".to_json()),
+                ("type", ty.to_json()),
+            ])
+        }
+    }
+}
+
+impl ToJson for clean::Stability {
+    fn to_json(&self) -> Json {
+        let non_empty = |s: &String| {
+            if s.is_empty() { Json::Null } else { s.to_json() }
+        };
+        object(vec![
+            ("level", match self.level {
+                attr::Stable => "stable",
+                attr::Unstable => "unstable",
+            }.to_json()),
+            ("feature", non_empty(&self.feature)),
+            ("since", non_empty(&self.since)),
+            ("deprecated_since", non_empty(&self.deprecated_since)),
+            ("reason", non_empty(&self.reason)),
+        ])
+    }
+}
+
+impl ToJson for clean::Lifetime {
+    fn to_json(&self) -> Json {
+        self.get_ref().to_json()
+    }
+}
+
+impl ToJson for clean::Generics {
+    fn to_json(&self) -> Json {
+        object(vec![
+            ("lifetimes", self.lifetimes.to_json()),
+            ("type_params", self.type_params.to_json()),
+            ("where_predicates", self.where_predicates.to_json()),
+        ])
+    }
+}
+
+impl ToJson for clean::TyParam {
+    fn to_json(&self) -> Json {
+        object(vec![
+            ("name", self.name.to_json()),
+            ("id", def_id(self.did).to_json()),
+            ("bounds", self.bounds.to_json()),
+            ("default", self.default.to_json()),
+        ])
+    }
+}
+
+impl ToJson for clean::TyParamBound {
+    fn to_json(&self) -> Json {
+        match *self {
+            clean::RegionBound(ref lt) => {
+                object(vec![
+                    ("kind", "lifetime".to_json()),
+                    ("lifetime", lt.to_json()),
+                ])
+            }
+            clean::TraitBound(ref poly, modifier) => {
+                object(vec![
+                    ("kind", "trait".to_json()),
+                    ("trait", poly.trait_.to_json()),
+                    ("lifetimes", poly.lifetimes.to_json()),
+                    ("maybe", (modifier == ast::TraitBoundModifier::Maybe).to_json()),
+                ])
+            }
+        }
+    }
+}
+
+impl ToJson for clean::WherePredicate {
+    fn to_json(&self) -> Json {
+        match *self {
+            clean::WherePredicate::BoundPredicate { ref ty, ref bounds } => {
+                object(vec![
+                    ("kind", "bound".to_json()),
+                    ("type", ty.to_json()),
+                    ("bounds", bounds.to_json()),
+                ])
+            }
+            clean::WherePredicate::RegionPredicate { ref lifetime, ref bounds } => {
+                object(vec![
+                    ("kind", "region".to_json()),
+                    ("lifetime", lifetime.to_json()),
+                    ("bounds", bounds.to_json()),
+                ])
+            }
+            clean::WherePredicate::EqPredicate { ref lhs, ref rhs } => {
+                object(vec![
+                    ("kind", "eq".to_json()),
+                    ("lhs", lhs.to_json()),
+                    ("rhs", rhs.to_json()),
+                ])
+            }
+        }
+    }
+}
+
+impl ToJson for clean::FnDecl {
+    fn to_json(&self) -> Json {
+        let inputs = self.inputs.values.iter().map(|arg| {
+            object(vec![
+                ("name", arg.name.to_json()),
+                ("type", arg.type_.to_json()),
+            ])
+        }).collect();
+        let output = match self.output {
+            clean::Return(ref ty) => ty.to_json(),
+            clean::DefaultReturn => Json::Null,
+            clean::NoReturn => clean::Bottom.to_json(),
+        };
+        object(vec![
+            ("inputs", Json::Array(inputs)),
+            ("output", output),
+        ])
+    }
+}
+
+impl ToJson for clean::Path {
+    fn to_json(&self) -> Json {
+        let segments = self.segments.iter().map(|s| {
+            let args = match s.params {
+                clean::PathParameters::AngleBracketed {
+                    ref lifetimes, ref types, ref bindings
+                } => {
+                    let bindings = bindings.iter().map(|b| {
+                        object(vec![
+                            ("name", b.name.to_json()),
+                            ("type", b.ty.to_json()),
+                        ])
+                    }).collect();
+                    object(vec![
+                        ("kind", "angle_bracketed".to_json()),
+                        ("lifetimes", lifetimes.to_json()),
+                        ("types", types.to_json()),
+                        ("bindings", Json::Array(bindings)),
+                    ])
+                }
+                clean::PathParameters::Parenthesized { ref inputs, ref output } => {
+                    object(vec![
+                        ("kind", "parenthesized".to_json()),
+                        ("inputs", inputs.to_json()),
+                        ("output", output.to_json()),
+                    ])
+                }
+            };
+            object(vec![
+                ("name", s.name.to_json()),
+                ("args", args),
+            ])
+        }).collect();
+        object(vec![
+            ("global", self.global.to_json()),
+            ("segments", Json::Array(segments)),
+        ])
+    }
+}
+
+impl ToJson for clean::Type {
+    fn to_json(&self) -> Json {
+        match *self {
+            clean::ResolvedPath { ref path, ref typarams, did, is_generic } => {
+                object(vec![
+                    ("kind", "resolved_path".to_json()),
+                    ("name", path_string(path).to_json()),
+                    ("id", def_id(did).to_json()),
+                    ("path", path.to_json()),
+                    ("bounds", typarams.to_json()),
+                    ("is_generic", is_generic.to_json()),
+                ])
+            }
+            clean::Generic(ref name) => {
+                object(vec![
+                    ("kind", "generic".to_json()),
+                    ("name", name.to_json()),
+                ])
+            }
+            clean::Primitive(p) => {
+                object(vec![
+                    ("kind", "primitive".to_json()),
+                    ("name", p.to_string().to_json()),
+                ])
+            }
+            clean::BareFunction(ref f) => {
+                object(vec![
+                    ("kind", "function_pointer".to_json()),
+                    ("unsafe", (f.unsafety == ast::Unsafety::Unsafe).to_json()),
+                    ("generics", f.generics.to_json()),
+                    ("decl", f.decl.to_json()),
+                    ("abi", f.abi.to_json()),
+                ])
+            }
+            clean::Tuple(ref tys) => {
+                object(vec![
+                    ("kind", "tuple".to_json()),
+                    ("types", tys.to_json()),
+                ])
+            }
+            clean::Vector(ref ty) => {
+                object(vec![
+                    ("kind", "slice".to_json()),
+                    ("type", ty.to_json()),
+                ])
+            }
+            clean::FixedVector(ref ty, ref len) => {
+                object(vec![
+                    ("kind", "array".to_json()),
+                    ("type", ty.to_json()),
+                    ("len", len.to_json()),
+                ])
+            }
+            clean::Bottom => object(vec![("kind", "bottom".to_json())]),
+            clean::Unique(ref ty) => {
+                object(vec![
+                    ("kind", "box".to_json()),
+                    ("type", ty.to_json()),
+                ])
+            }
+            clean::RawPointer(mutability, ref ty) => {
+                object(vec![
+                    ("kind", "raw_pointer".to_json()),
+                    ("mutable", (mutability == clean::Mutable).to_json()),
+                    ("type", ty.to_json()),
+                ])
+            }
+            clean::BorrowedRef { ref lifetime, mutability, ref type_ } => {
+                object(vec![
+                    ("kind", "borrowed_ref".to_json()),
+                    ("lifetime", lifetime.to_json()),
+                    ("mutable", (mutability == clean::Mutable).to_json()),
+                    ("type", type_.to_json()),
+                ])
+            }
+            clean::QPath { ref name, ref self_type, ref trait_ } => {
+                object(vec![
+                    ("kind", "qualified_path".to_json()),
+                    ("name", name.to_json()),
+                    ("self_type", self_type.to_json()),
+                    ("trait", trait_.to_json()),
+                ])
+            }
+            clean::Infer => object(vec![("kind", "infer".to_json())]),
+            clean::PolyTraitRef(ref bounds) => {
+                object(vec![
+                    ("kind", "poly_trait_ref".to_json()),
+                    ("bounds", bounds.to_json()),
+                ])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use serialize::json::{Json, ToJson};
+    use syntax::ast;
+    use syntax::ast_util::local_def;
+
+    use clean;
+    use core::AutoImpl;
+    use super::render;
+
+    fn item(name: &str, id: ast::NodeId, inner: clean::ItemEnum) -> clean::Item {
+        clean::Item {
+            source: clean::Span {
+                filename: "lib.rs".to_string(),
+                loline: 1, locol: 0, hiline: 1, hicol: 0,
+            },
+            name: Some(name.to_string()),
+            attrs: vec![clean::NameValue("doc".to_string(), "docs".to_string())],
+            inner: inner,
+            visibility: Some(ast::Public),
+            def_id: local_def(id),
+            stability: None,
+        }
+    }
+
+    fn no_generics() -> clean::Generics {
+        clean::Generics {
+            lifetimes: Vec::new(),
+            type_params: Vec::new(),
+            where_predicates: Vec::new(),
+        }
+    }
+
+    fn trait_path(name: &str, id: ast::NodeId) -> clean::Type {
+        clean::ResolvedPath {
+            path: clean::Path::singleton(name.to_string()),
+            typarams: None,
+            did: local_def(id),
+            is_generic: false,
+        }
+    }
+
+    fn impl_item(id: ast::NodeId, trait_: Option<clean::Type>,
+                 for_: clean::Type) -> clean::Item {
+        let mut imp = item("", id, clean::ImplItem(clean::Impl {
+            unsafety: ast::Unsafety::Normal,
+            generics: no_generics(),
+            trait_: trait_,
+            for_: for_,
+            items: Vec::new(),
+            derived: false,
+            polarity: Some(clean::ImplPolarity::Positive),
+        }));
+        imp.name = None;
+        imp
+    }
+
+    fn render_module(items: Vec<clean::Item>) -> Json {
+        render_crate(items, Vec::new(), HashMap::new())
+    }
+
+    fn render_crate(items: Vec<clean::Item>, primitives: Vec<clean::PrimitiveType>,
+                    auto_impls: HashMap<ast::DefId, Vec<AutoImpl>>) -> Json {
+        let krate = clean::Crate {
+            name: "foo".to_string(),
+            src: PathBuf::from("lib.rs"),
+            module: Some(item("foo", 0, clean::ModuleItem(clean::Module {
+                items: items,
+                is_crate: true,
+            }))),
+            externs: Vec::new(),
+            primitives: primitives,
+            external_traits: HashMap::new(),
+        };
+        render(&krate, Vec::new(), auto_impls)
+    }
+
+    #[test]
+    fn generic_type() {
+        let json = clean::Generic("T".to_string()).to_json();
+        assert_eq!(json.find("kind"), Some(&"generic".to_json()));
+        assert_eq!(json.find("name"), Some(&"T".to_json()));
+    }
+
+    #[test]
+    fn crate_index() {
+        let json = render_module(vec![
+            item("Bar", 1, clean::StructItem(clean::Struct {
+                struct_type: ::doctree::Unit,
+                generics: no_generics(),
+                fields: Vec::new(),
+                fields_stripped: false,
+            })),
+        ]);
+        assert_eq!(json.find("format_version"), Some(&1u64.to_json()));
+        assert_eq!(json.find_path(&["crate", "root"]), Some(&"0:0".to_json()));
+        let bar = json.find_path(&["index", "0:1"]).unwrap();
+        assert_eq!(bar.find("kind"), Some(&"struct".to_json()));
+        assert_eq!(bar.find("docs"), Some(&"docs".to_json()));
+        assert_eq!(json.find_path(&["paths", "0:1", "path"]),
+                   Some(&vec!["foo".to_string(), "Bar".to_string()].to_json()));
+    }
+
+    #[test]
+    fn blanket_impls() {
+        let imp = impl_item(2, Some(trait_path("Baz", 1)),
+                            clean::Generic("T".to_string()));
+        let json = render_module(vec![
+            item("Baz", 1, clean::TraitItem(clean::Trait {
+                unsafety: ast::Unsafety::Normal,
+                items: Vec::new(),
+                generics: no_generics(),
+                bounds: Vec::new(),
+            })),
+            imp,
+        ]);
+        assert_eq!(json.find_path(&["index", "0:2", "inner", "blanket"]),
+                   Some(&true.to_json()));
+        assert_eq!(json.find_path(&["index", "0:1", "inner", "implementors"]),
+                   Some(&vec!["0:2".to_string()].to_json()));
+    }
+
+    #[test]
+    fn primitive_impls() {
+        let prim = clean::PrimitiveType::Str;
+        let mut prim_item = item("str", prim.to_node_id(), clean::PrimitiveItem(prim));
+        prim_item.name = None;
+        let borrowed = clean::BorrowedRef {
+            lifetime: None,
+            mutability: clean::Immutable,
+            type_: box clean::Primitive(prim),
+        };
+        let json = render_crate(vec![
+            prim_item,
+            impl_item(1, None, clean::Primitive(prim)),
+            impl_item(2, None, borrowed),
+        ], vec![prim], HashMap::new());
+        let id = format!("0:{}", prim.to_node_id());
+        assert_eq!(json.find_path(&["index", &id, "inner", "impls"]),
+                   Some(&vec!["0:1".to_string(), "0:2".to_string()].to_json()));
+    }
+
+    #[test]
+    fn synthetic_impls() {
+        let mut generics = no_generics();
+        generics.type_params.push(clean::TyParam {
+            name: "T".to_string(),
+            did: local_def(2),
+            bounds: Vec::new(),
+            default: None,
+        });
+        let mut auto_impls = HashMap::new();
+        auto_impls.insert(local_def(1), vec![
+            AutoImpl {
+                trait_: trait_path("Send", 3),
+                bounded_params: vec!["T".to_string()],
+                negative: false,
+            },
+            AutoImpl {
+                trait_: trait_path("Sync", 4),
+                bounded_params: Vec::new(),
+                negative: true,
+            },
+        ]);
+        let json = render_crate(vec![
+            item("Bar", 1, clean::StructItem(clean::Struct {
+                struct_type: ::doctree::Plain,
+                generics: generics,
+                fields: Vec::new(),
+                fields_stripped: false,
+            })),
+        ], Vec::new(), auto_impls);
+
+        assert_eq!(json.find_path(&["index", "0:1", "inner", "impls"]),
+                   Some(&vec!["0:1+0:3".to_string(), "0:1+0:4".to_string()].to_json()));
+        let send = json.find_path(&["index", "0:1+0:3", "inner"]).unwrap();
+        assert_eq!(send.find("synthetic"), Some(&true.to_json()));
+        assert_eq!(send.find("negative"), Some(&false.to_json()));
+        assert_eq!(send.find_path(&["for", "id"]), Some(&"0:1".to_json()));
+        let preds = send.find_path(&["generics", "where_predicates"]).unwrap();
+        let preds = preds.as_array().unwrap();
+        assert_eq!(preds.len(), 1);
+        assert_eq!(preds[0].find_path(&["type", "name"]), Some(&"T".to_json()));
+        assert_eq!(preds[0].find("bounds").unwrap()[0].find_path(&["trait", "id"]),
+                   Some(&"0:3".to_json()));
+        assert_eq!(json.find_path(&["index", "0:1+0:4", "inner", "negative"]),
+                   Some(&true.to_json()));
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        let import = item("", 0, clean::ExternCrateItem("bar".to_string(), None));
+        let json = render_module(vec![import.clone(), import]);
+        let index = json.find("index").unwrap().as_object().unwrap();
+        assert!(index.contains_key("0:0"));
+        assert!(index.contains_key("0:0:1"));
+        assert!(index.contains_key("0:0:2"));
+    }
+}
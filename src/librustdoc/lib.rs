@@ -23,10 +23,12 @@
 #![feature(box_patterns)]
 #![feature(box_syntax)]
 #![feature(dynamic_lib)]
+#![feature(fs_canonicalize)]
 #![feature(libc)]
 #![feature(owned_ascii_ext)]
 #![feature(path_ext)]
 #![feature(path_relative_from)]
+#![feature(rt)]
 #![feature(rustc_private)]
 #![feature(set_stdio)]
 #![feature(slice_extras)]
@@ -79,6 +81,7 @@ pub mod clean;
 pub mod core;
 pub mod doctree;
 pub mod fold;
+pub mod json_render;
 pub mod html {
     pub mod highlight;
     pub mod escape;
@@ -144,15 +147,22 @@ pub fn opts() -> Vec<getopts::OptGroup> {
         optflag("V", "version", "print rustdoc's version"),
         optflag("v", "verbose", "use verbose output"),
         optopt("r", "input-format", "the input type of the specified file",
-               "[rust|json]"),
+               "[rust|legacy-json]"),
         optopt("w", "output-format", "the output type to write",
-               "[html|json]"),
+               "[html|json|legacy-json]"),
         optopt("o", "output", "where to place the output", "PATH"),
         optopt("", "crate-name", "specify the name of this crate", "NAME"),
         optmulti("L", "library-path", "directory to add to crate search path",
                  "DIR"),
         optmulti("", "cfg", "pass a --cfg to rustc", ""),
         optmulti("", "extern", "pass an --extern to rustc", "NAME=PATH"),
+        optmulti("", "extern-html-root-url",
+                 "base URL to use for links to the documentation of a dependency",
+                 "NAME=URL"),
+        optmulti("", "merge-search-index",
+                 "search-index.js of other documentation to include in the \
+                  search of this one",
+                 "PATH"),
         optmulti("", "plugin-path", "directory to load plugins from", "DIR"),
         optmulti("", "passes", "list of passes to also run, you might want \
                                 to pass it multiple times; a value of `list` \
@@ -240,6 +250,17 @@ pub fn main_args(args: &[String]) -> isize {
         }
     };
 
+    let extern_urls = match parse_extern_html_roots(&matches) {
+        Ok(urls) => urls,
+        Err(err) => {
+            println!("{}", err);
+            return 1;
+        }
+    };
+    let merge_indexes: Vec<PathBuf> = matches.opt_strs("merge-search-index")
+                                             .into_iter().map(PathBuf::from)
+                                             .collect();
+
     let test_args = matches.opt_strs("test-args");
     let test_args: Vec<String> = test_args.iter()
                                           .flat_map(|s| s.split_whitespace())
@@ -288,12 +309,20 @@ pub fn main_args(args: &[String]) -> isize {
         Some("html") | None => {
             match html::render::run(krate, &external_html,
                                     output.unwrap_or(PathBuf::from("doc")),
-                                    passes.into_iter().collect()) {
+                                    passes.into_iter().collect(),
+                                    extern_urls, merge_indexes) {
                 Ok(()) => {}
                 Err(e) => panic!("failed to generate documentation: {}", e),
             }
         }
         Some("json") => {
+            match json_render::run(krate, json_plugins,
+                                   output.unwrap_or(PathBuf::from("doc.json"))) {
+                Ok(()) => {}
+                Err(e) => panic!("failed to write json: {}", e),
+            }
+        }
+        Some("legacy-json") => {
             match json_output(krate, json_plugins,
                               output.unwrap_or(PathBuf::from("doc.json"))) {
                 Ok(()) => {}
@@ -316,7 +345,9 @@ fn acquire_input(input: &str,
                  matches: &getopts::Matches) -> Result<Output, String> {
     match matches.opt_str("r").as_ref().map(|s| &**s) {
         Some("rust") => Ok(rust_input(input, externs, matches)),
-        Some("json") => json_input(input),
+        Some("legacy-json") => json_input(input),
+        Some("json") => Err("only `-w legacy-json` output can be read back in, \
+                             not `-w json`".to_string()),
         Some(s) => Err(format!("unknown input format: {}", s)),
         None => {
             if input.ends_with(".json") {
@@ -353,6 +384,27 @@ fn parse_externs(matches: &getopts::Matches) -> Result<core::Externs, String> {
     Ok(externs)
 }
 
+/// Extracts `--extern-html-root-url CRATE=URL` arguments from `matches` and
+/// returns a `HashMap` mapping crate names to the root URL of their
+/// documentation or else an error message.
+fn parse_extern_html_roots(matches: &getopts::Matches)
+                           -> Result<HashMap<String, String>, String> {
+    let mut urls = HashMap::new();
+    for arg in &matches.opt_strs("extern-html-root-url") {
+        let mut parts = arg.splitn(2, '=');
+        let name = parts.next().unwrap();
+        let url = match parts.next() {
+            Some(s) if !name.is_empty() => s,
+            _ => {
+                return Err("--extern-html-root-url value must be of the \
+                            format `foo=http://example.com/`".to_string());
+            }
+        };
+        urls.insert(name.to_string(), url.to_string());
+    }
+    Ok(urls)
+}
+
 /// Interprets the input file as a rust source file, passing it through the
 /// compiler all the way through the analysis passes. The rustdoc output is then
 /// generated from the cleaned AST of the crate.
@@ -478,6 +530,10 @@ fn json_input(input: &str) -> Result<Output, String> {
                     }
                 }
                 Some(..) => return Err("malformed json".to_string()),
+                None if obj.contains_key("format_version") => {
+                    return Err("only `-w legacy-json` output can be read back in, \
+                                not `-w json`".to_string())
+                }
                 None => return Err("expected a schema version".to_string()),
             }
             let krate = match obj.remove(&"crate".to_string()) {
@@ -500,7 +556,8 @@ fn json_input(input: &str) -> Result<Output, String> {
 }
 
 /// Outputs the crate/plugin json as a giant json blob at the specified
-/// destination.
+/// destination. This is the `legacy-json` format, which `json_input` can read
+/// back in; see `json_render` for the documented `json` format.
 fn json_output(krate: clean::Crate, res: Vec<plugins::PluginJson> ,
                dst: PathBuf) -> io::Result<()> {
     // {
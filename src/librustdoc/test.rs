@@ -17,6 +17,7 @@ use std::io::prelude::*;
 use std::io;
 use std::path::PathBuf;
 use std::process::Command;
+use std::rt;
 use std::str;
 use std::sync::{Arc, Mutex};
 
@@ -155,7 +156,7 @@ fn scrape_test_config(krate: &::syntax::ast::Crate) -> TestOptions {
 fn runtest(test: &str, cratename: &str, libs: SearchPaths,
            externs: core::Externs,
            should_panic: bool, no_run: bool, as_test_harness: bool,
-           opts: &TestOptions) {
+           compile_fail: bool, error_codes: Vec<String>, opts: &TestOptions) {
     // the test harness wants its own `main` & top level functions, so
     // never wrap the test in `fn main() { ... }`
     let test = maketest(test, Some(cratename), as_test_harness, opts);
@@ -201,6 +202,7 @@ fn runtest(test: &str, cratename: &str, libs: SearchPaths,
         }
     }
     let data = Arc::new(Mutex::new(Vec::new()));
+    let output = data.clone();
     let emitter = diagnostic::EmitterWriter::new(box Sink(data.clone()), None);
     let old = io::set_panic(box Sink(data.clone()));
     let _bomb = Bomb(data, old.unwrap_or(box io::stdout()));
@@ -221,9 +223,41 @@ fn runtest(test: &str, cratename: &str, libs: SearchPaths,
     let cfg = config::build_configuration(&sess);
     let libdir = sess.target_filesearch(PathKind::All).get_lib_path();
     let mut control = driver::CompileController::basic();
-    if no_run {
+    if no_run || compile_fail {
         control.after_analysis.stop = Compilation::Stop;
     }
+    if compile_fail {
+        // Errors in the example abort the session by panicking with a
+        // `FatalError`. Any other panic is an internal compiler error, which
+        // doesn't count as the expected failure.
+        let res = unsafe {
+            rt::unwind::try(|| {
+                driver::compile_input(sess, cfg, &input, &out, &None, None,
+                                      control);
+            })
+        };
+        match res {
+            Ok(()) => panic!("test compiled successfully when it should have \
+                              failed"),
+            Err(ref e) if e.is::<diagnostic::FatalError>() => {}
+            Err(..) => panic!("test failed to compile due to an internal \
+                               compiler error"),
+        }
+        // Each error code the test names has to be among the errors reported,
+        // which look like `error: message [E0000]`.
+        let missing = {
+            let output = output.lock().unwrap();
+            let output = String::from_utf8_lossy(&output);
+            error_codes.into_iter().filter(|code| {
+                !output.contains(&format!("[{}]", code))
+            }).collect::<Vec<_>>()
+        };
+        if !missing.is_empty() {
+            panic!("test failed to compile, but not with the expected error \
+                    codes: {}", missing.connect(", "));
+        }
+        return
+    }
     driver::compile_input(sess, cfg, &input, &out, &None, None, control);
 
     if no_run { return }
@@ -355,7 +389,8 @@ impl Collector {
 
     pub fn add_test(&mut self, test: String,
                     should_panic: bool, no_run: bool, should_ignore: bool,
-                    as_test_harness: bool) {
+                    as_test_harness: bool, compile_fail: bool,
+                    error_codes: Vec<String>) {
         let name = if self.use_headers {
             let s = self.current_header.as_ref().map(|s| &**s).unwrap_or("");
             format!("{}_{}", s, self.cnt)
@@ -383,6 +418,8 @@ impl Collector {
                         should_panic,
                         no_run,
                         as_test_harness,
+                        compile_fail,
+                        error_codes,
                         &opts);
             }))
         });
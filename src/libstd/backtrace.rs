@@ -0,0 +1,167 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Capturing stack backtraces of the current thread.
+//!
+//! A `Backtrace` records the stack of the thread which captured it, with
+//! each frame resolved to a demangled symbol name and source location where
+//! the platform can provide them. This is the same information which is
+//! printed on panic when `RUST_BACKTRACE` is set, but it can be captured at
+//! any time, stored (for example in an error value) and inspected or
+//! formatted later.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(backtrace)]
+//! use std::backtrace::Backtrace;
+//!
+//! let bt = Backtrace::capture();
+//! for frame in bt.frames() {
+//!     println!("{:?}", frame.symbol_name());
+//! }
+//! println!("{}", bt);
+//! ```
+
+#![unstable(feature = "backtrace", reason = "recently added")]
+
+use prelude::v1::*;
+
+use fmt;
+use path::Path;
+use sys;
+use sys_common::backtrace::Frame;
+
+/// A captured stack backtrace of a thread.
+///
+/// The frames are ordered from the most recent call outwards, so the first
+/// frames belong to the capturing machinery itself.
+#[derive(Clone)]
+pub struct Backtrace {
+    frames: Vec<BacktraceFrame>,
+}
+
+/// A single frame of a `Backtrace`.
+#[derive(Clone)]
+pub struct BacktraceFrame {
+    inner: Frame,
+}
+
+impl Backtrace {
+    /// Captures a backtrace of the current thread.
+    ///
+    /// Symbols and source locations are resolved eagerly, so this can be
+    /// fairly expensive. Platforms without backtrace support return an
+    /// empty backtrace.
+    #[inline(never)] // if we know this is a function call, we can skip it when
+                     // tracing
+    pub fn capture() -> Backtrace {
+        let mut frames = Vec::new();
+        sys::backtrace::trace(&mut |frame| {
+            frames.push(BacktraceFrame { inner: Frame::from_raw(frame) });
+            true
+        });
+        Backtrace { frames: frames }
+    }
+
+    /// Returns the frames of this backtrace, most recent call first.
+    pub fn frames(&self) -> &[BacktraceFrame] {
+        &self.frames
+    }
+}
+
+impl BacktraceFrame {
+    /// Returns the instruction pointer of this frame.
+    pub fn ip(&self) -> usize {
+        self.inner.ip
+    }
+
+    /// Returns the demangled name of the function containing this frame, if
+    /// it could be determined.
+    pub fn symbol_name(&self) -> Option<&str> {
+        self.inner.name.as_ref().map(|s| &s[..])
+    }
+
+    /// Returns the source file this frame's instruction pointer belongs to,
+    /// if debug information is available.
+    ///
+    /// If calls have been inlined into this frame, this is the location of
+    /// the innermost inlined call.
+    pub fn filename(&self) -> Option<&Path> {
+        self.inner.filelines.first().map(|&(ref file, _)| &**file)
+    }
+
+    /// Returns the line number in `filename` of this frame, if debug
+    /// information is available.
+    pub fn lineno(&self) -> Option<u32> {
+        self.inner.filelines.first().map(|&(_, line)| line)
+    }
+}
+
+impl fmt::Debug for Backtrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.frames.iter()).finish()
+    }
+}
+
+impl fmt::Display for Backtrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, frame) in self.frames.iter().enumerate() {
+            try!(write!(f, "  {:2}: {}", i + 1, frame.inner));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BacktraceFrame")
+         .field("ip", &(self.ip() as *const ()))
+         .field("symbol_name", &self.symbol_name())
+         .field("filename", &self.filename())
+         .field("lineno", &self.lineno())
+         .finish()
+    }
+}
+
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use super::*;
+
+    fn _assert_send_sync<T: Send + Sync>() {}
+    fn _assert_backtrace() { _assert_send_sync::<Backtrace>(); }
+
+    #[test]
+    fn capture() {
+        let bt = Backtrace::capture();
+        assert!(!bt.frames().is_empty());
+        assert!(bt.frames().iter().all(|f| f.ip() != 0));
+
+        let display = bt.to_string();
+        assert_eq!(display.lines().filter(|l| l.contains(" - ")).count(),
+                   bt.frames().len());
+        assert!(format!("{:?}", bt).starts_with("[BacktraceFrame {"));
+    }
+
+    #[test]
+    fn clone() {
+        let bt = Backtrace::capture();
+        let bt2 = bt.clone();
+        assert_eq!(bt.to_string(), bt2.to_string());
+    }
+}
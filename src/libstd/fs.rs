@@ -22,9 +22,12 @@ use core::prelude::*;
 use fmt;
 use ffi::OsString;
 use io::{self, Error, ErrorKind, SeekFrom, Seek, Read, Write};
+use marker::PhantomData;
+use ops::{Deref, DerefMut};
 use path::{Path, PathBuf};
 use sys::fs as fs_imp;
 use sys_common::{AsInnerMut, FromInner, AsInner};
+use usize;
 use vec::Vec;
 
 /// A reference to an open file on the filesystem.
@@ -302,6 +305,143 @@ impl File {
     pub fn metadata(&self) -> io::Result<Metadata> {
         self.inner.file_attr().map(Metadata)
     }
+
+    /// Acquires a shared lock on the file, blocking until it can be taken.
+    ///
+    /// Any number of handles may hold a shared lock at once, but none may
+    /// while another holds an exclusive lock. Locks belong to the open file,
+    /// so they are shared with handles made by `try_clone`, and released when
+    /// the last of them is closed or `unlock` is called.
+    ///
+    /// On Unix this is an advisory `flock` lock, which only affects others
+    /// who also take locks. On Windows the lock is mandatory and also
+    /// prevents conflicting reads and writes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::open("index"));
+    /// try!(f.lock_shared());
+    /// // read from the file knowing no one is writing to it
+    /// try!(f.unlock());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", reason = "recently added")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Acquires an exclusive lock on the file, blocking until it can be
+    /// taken.
+    ///
+    /// No other handle may hold any lock on the file at the same time. See
+    /// `lock_shared` for how locks behave.
+    #[unstable(feature = "file_lock", reason = "recently added")]
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.inner.lock_exclusive()
+    }
+
+    /// Attempts to acquire an exclusive lock on the file without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds a lock on the file.
+    #[unstable(feature = "file_lock", reason = "recently added")]
+    pub fn try_lock(&self) -> io::Result<bool> {
+        self.inner.try_lock_exclusive()
+    }
+
+    /// Attempts to acquire a shared lock on the file without blocking.
+    ///
+    /// Returns `Ok(false)` if another handle holds an exclusive lock on the
+    /// file.
+    #[unstable(feature = "file_lock", reason = "recently added")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Releases the lock held on the file, if any.
+    #[unstable(feature = "file_lock", reason = "recently added")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
+
+    /// Maps the contents of the file into memory for reading.
+    ///
+    /// The whole file, as long as it is when this is called, is mapped, and
+    /// the mapping stays valid while the file is open.
+    ///
+    /// # Safety
+    ///
+    /// The map is a `&[u8]` whose contents are only as stable as the file
+    /// underneath it, which nothing stops from changing. While the map is
+    /// alive the caller must make sure that:
+    ///
+    /// * the file isn't truncated, whether through this `File` (for example
+    ///   with `set_len`), another handle or another process. Accessing the
+    ///   part which was cut off raises `SIGBUS` on Unix.
+    /// * the mapped part of the file isn't written to, whether through this
+    ///   `File`, a mutable map of it or anything else, as that would change
+    ///   bytes which are borrowed immutably.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_mmap)]
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::open("index"));
+    /// // Nothing else changes the index while it's mapped.
+    /// let map = try!(unsafe { f.map() });
+    /// println!("first byte: {:?}", map.first());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_mmap", reason = "recently added")]
+    pub unsafe fn map(&self) -> io::Result<Mmap> {
+        let len = try!(self.map_len());
+        fs_imp::Mmap::new(&self.inner, len, false).map(|inner| {
+            Mmap { inner: inner, file: PhantomData }
+        })
+    }
+
+    /// Maps the contents of the file into memory for reading and writing.
+    ///
+    /// The file must have been opened for both reading and writing. Writes
+    /// to the map are written back to the file, and are visible to others
+    /// who map it.
+    ///
+    /// The map borrows this `File` mutably, so it can't be mapped again or
+    /// resized through it while the map is alive.
+    ///
+    /// # Safety
+    ///
+    /// The map is a `&mut [u8]`, which must not be aliased, over a file which
+    /// can still be reached in other ways. While the map is alive the caller
+    /// must make sure that the file isn't truncated, mapped or accessed
+    /// through other handles to it, including ones made with `try_clone`,
+    /// or by other processes. Truncating it raises `SIGBUS` on Unix when the
+    /// part which was cut off is accessed.
+    #[unstable(feature = "file_mmap", reason = "recently added")]
+    pub unsafe fn map_mut(&mut self) -> io::Result<MmapMut> {
+        let len = try!(self.map_len());
+        fs_imp::Mmap::new(&self.inner, len, true).map(|inner| {
+            MmapMut { inner: inner, file: PhantomData }
+        })
+    }
+
+    fn map_len(&self) -> io::Result<usize> {
+        let len = try!(self.metadata()).len();
+        if len > usize::MAX as u64 {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                                  "file is too large to map into memory"))
+        }
+        Ok(len as usize)
+    }
 }
 
 impl AsInner<fs_imp::File> for File {
@@ -358,6 +498,68 @@ impl<'a> Seek for &'a File {
     }
 }
 
+/// A read-only memory map of a file's contents.
+///
+/// This structure is created by the `map` method on `File`, and derefs to
+/// the mapped bytes. It borrows the `File` it was made from.
+#[unstable(feature = "file_mmap", reason = "recently added")]
+pub struct Mmap<'a> {
+    inner: fs_imp::Mmap,
+    file: PhantomData<&'a File>,
+}
+
+#[unstable(feature = "file_mmap", reason = "recently added")]
+impl<'a> Deref for Mmap<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] { self.inner.as_slice() }
+}
+
+impl<'a> fmt::Debug for Mmap<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Mmap").field("len", &self.len()).finish()
+    }
+}
+
+/// A memory map of a file's contents which can be written to.
+///
+/// This structure is created by the `map_mut` method on `File`, and derefs
+/// to the mapped bytes. It mutably borrows the `File` it was made from.
+#[unstable(feature = "file_mmap", reason = "recently added")]
+pub struct MmapMut<'a> {
+    inner: fs_imp::Mmap,
+    file: PhantomData<&'a mut File>,
+}
+
+impl<'a> MmapMut<'a> {
+    /// Writes any modified parts of the map back to the file, returning once
+    /// they have reached the disk.
+    ///
+    /// Modifications are written back eventually even if this isn't called.
+    #[unstable(feature = "file_mmap", reason = "recently added")]
+    pub fn flush(&self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[unstable(feature = "file_mmap", reason = "recently added")]
+impl<'a> Deref for MmapMut<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] { self.inner.as_slice() }
+}
+
+#[unstable(feature = "file_mmap", reason = "recently added")]
+impl<'a> DerefMut for MmapMut<'a> {
+    fn deref_mut(&mut self) -> &mut [u8] { self.inner.as_mut_slice() }
+}
+
+impl<'a> fmt::Debug for MmapMut<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MmapMut").field("len", &self.len()).finish()
+    }
+}
+
 impl OpenOptions {
     /// Creates a blank net set of options ready for configuration.
     ///
@@ -2156,4 +2358,61 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn file_lock() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("lock");
+        let f1 = check!(File::create(&path));
+        let f2 = check!(File::open(&path));
+
+        check!(f1.lock_shared());
+        assert!(check!(f2.try_lock_shared()));
+        assert!(!check!(f2.try_lock()));
+        check!(f2.unlock());
+
+        check!(f1.unlock());
+        assert!(check!(f2.try_lock()));
+        assert!(!check!(f1.try_lock_shared()));
+        assert!(!check!(f1.try_lock()));
+        check!(f2.unlock());
+
+        check!(f1.lock_exclusive());
+        assert!(!check!(f2.try_lock_shared()));
+        drop(f1);
+        assert!(check!(f2.try_lock_shared()));
+        check!(f2.unlock());
+        // Unlocking without holding a lock is fine.
+        check!(f2.unlock());
+    }
+
+    #[test]
+    fn file_map() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("map");
+        check!(check!(File::create(&path)).write_all(b"hello world"));
+
+        let f = check!(File::open(&path));
+        let map = check!(unsafe { f.map() });
+        assert_eq!(&map[..], b"hello world");
+        drop(map);
+
+        let mut f = check!(OpenOptions::new().read(true).write(true).open(&path));
+        {
+            let mut map = check!(unsafe { f.map_mut() });
+            map[0] = b'j';
+            check!(map.flush());
+        }
+        let mut contents = String::new();
+        check!(check!(File::open(&path)).read_to_string(&mut contents));
+        assert_eq!(contents, "jello world");
+    }
+
+    #[test]
+    fn file_map_empty() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("empty");
+        let f = check!(File::create(&path));
+        assert!(check!(unsafe { f.map() }).is_empty());
+    }
 }
@@ -184,6 +184,17 @@ impl TcpStream {
     pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
         self.0.write_timeout()
     }
+
+    /// Moves this TCP stream into or out of nonblocking mode.
+    ///
+    /// In nonblocking mode, `read` and `write` calls which would otherwise
+    /// have to wait return an error of kind `WouldBlock` instead. This is
+    /// meant to be combined with a way of waiting for the socket to become
+    /// ready, such as `std::os::unix::poll`.
+    #[unstable(feature = "net_nonblocking", reason = "recently added")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -271,6 +282,17 @@ impl TcpListener {
     pub fn incoming(&self) -> Incoming {
         Incoming { listener: self }
     }
+
+    /// Moves this TCP listener into or out of nonblocking mode.
+    ///
+    /// In nonblocking mode, `accept` returns an error of kind `WouldBlock`
+    /// when there is no connection waiting to be accepted. Whether the
+    /// streams it returns inherit the mode differs between platforms, so set
+    /// it on them explicitly.
+    #[unstable(feature = "net_nonblocking", reason = "recently added")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -969,4 +991,32 @@ mod tests {
         assert!(wait > Duration::from_millis(400));
         assert!(wait < Duration::from_millis(1600));
     }
+
+    #[test]
+    fn nonblocking() {
+        let addr = next_test_ip4();
+        let listener = t!(TcpListener::bind(&addr));
+        t!(listener.set_nonblocking(true));
+        match listener.accept() {
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(..) => panic!("unexpected success"),
+        }
+
+        let mut stream = t!(TcpStream::connect(&("localhost", addr.port())));
+        t!(stream.set_nonblocking(true));
+        let mut buf = [0; 10];
+        match stream.read(&mut buf) {
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(..) => panic!("unexpected success"),
+        }
+
+        t!(stream.set_nonblocking(false));
+        t!(stream.set_read_timeout(Some(Duration::from_millis(1000))));
+        t!(listener.set_nonblocking(false));
+        let mut other_end = t!(listener.accept()).0;
+        t!(other_end.write_all(b"hello"));
+        assert_eq!(t!(stream.read(&mut buf)), 5);
+    }
 }
@@ -164,6 +164,15 @@ impl UdpSocket {
     pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
         self.0.write_timeout()
     }
+
+    /// Moves this UDP socket into or out of nonblocking mode.
+    ///
+    /// In nonblocking mode, `recv_from` and `send_to` calls which would
+    /// otherwise have to wait return an error of kind `WouldBlock` instead.
+    #[unstable(feature = "net_nonblocking", reason = "recently added")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
 }
 
 impl AsInner<net_imp::UdpSocket> for UdpSocket {
@@ -0,0 +1,42 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Panic support in the standard library
+//!
+//! When a thread panics, the process-wide panic hook is run before the
+//! thread starts unwinding. The default hook prints the familiar
+//! "thread '...' panicked at ..." message (and a backtrace if
+//! `RUST_BACKTRACE` is set) to standard error. `set_hook` replaces it with a
+//! custom one, for example to route panics into a logger:
+//!
+//! ```
+//! #![feature(panic_hook)]
+//! use std::panic::{self, PanicInfo};
+//!
+//! panic::set_hook(Box::new(|info: &PanicInfo| {
+//!     let msg = match info.payload().downcast_ref::<&'static str>() {
+//!         Some(s) => *s,
+//!         None => match info.payload().downcast_ref::<String>() {
+//!             Some(s) => &s[..],
+//!             None => "Box<Any>",
+//!         },
+//!     };
+//!     println!("{} panicked at {}: {}",
+//!              info.thread_name().unwrap_or("<unnamed>"),
+//!              info.location(), msg);
+//! }));
+//!
+//! // Restore the default hook.
+//! let _ = panic::take_hook();
+//! ```
+
+#![unstable(feature = "panic_hook", reason = "recently added")]
+
+pub use panicking::{set_hook, take_hook, PanicInfo, Location};
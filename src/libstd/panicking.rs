@@ -13,9 +13,13 @@ use io::prelude::*;
 
 use any::Any;
 use cell::RefCell;
+use fmt;
+use mem;
 use rt::{backtrace, unwind};
 use sys::stdio::Stderr;
+use sys_common::rwlock::RWLock;
 use sys_common::thread_info;
+use thread;
 
 thread_local! {
     pub static LOCAL_STDERR: RefCell<Option<Box<Write + Send>>> = {
@@ -23,17 +27,146 @@ thread_local! {
     }
 }
 
-pub fn on_panic(obj: &(Any+Send), file: &'static str, line: u32) {
-    let msg = match obj.downcast_ref::<&'static str>() {
+#[derive(Copy, Clone)]
+enum Hook {
+    Default,
+    Custom(*mut (Fn(&PanicInfo) + 'static + Sync + Send)),
+}
+
+static HOOK_LOCK: RWLock = RWLock::new();
+static mut HOOK: Hook = Hook::Default;
+
+/// Registers a custom panic hook, replacing any that was previously
+/// registered.
+///
+/// The panic hook is invoked when a thread panics, but before it starts
+/// unwinding the stack. The default hook prints a message to standard error
+/// and generates a backtrace if requested, but this behavior can be customized
+/// with the `set_hook` and `take_hook` functions.
+///
+/// The hook is provided with a `PanicInfo` struct which contains information
+/// about the origin of the panic, including the payload passed to `panic!`,
+/// the source code location from which the panic originated and the name of
+/// the panicking thread.
+///
+/// The panic hook is a global resource.
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+#[unstable(feature = "panic_hook", reason = "recently added")]
+pub fn set_hook(hook: Box<Fn(&PanicInfo) + 'static + Sync + Send>) {
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    unsafe {
+        HOOK_LOCK.write();
+        let old = mem::replace(&mut HOOK, Hook::Custom(Box::into_raw(hook)));
+        HOOK_LOCK.write_unlock();
+
+        if let Hook::Custom(ptr) = old {
+            drop(Box::from_raw(ptr));
+        }
+    }
+}
+
+/// Unregisters the current panic hook, returning it.
+///
+/// If no custom hook is registered, the default hook will be returned.
+///
+/// # Panics
+///
+/// Panics if called from a panicking thread.
+#[unstable(feature = "panic_hook", reason = "recently added")]
+pub fn take_hook() -> Box<Fn(&PanicInfo) + 'static + Sync + Send> {
+    if thread::panicking() {
+        panic!("cannot modify the panic hook from a panicking thread");
+    }
+
+    unsafe {
+        HOOK_LOCK.write();
+        let hook = mem::replace(&mut HOOK, Hook::Default);
+        HOOK_LOCK.write_unlock();
+
+        match hook {
+            Hook::Default => Box::new(default_hook),
+            Hook::Custom(ptr) => Box::from_raw(ptr),
+        }
+    }
+}
+
+/// A struct providing information about a panic.
+#[unstable(feature = "panic_hook", reason = "recently added")]
+pub struct PanicInfo<'a> {
+    payload: &'a (Any + Send),
+    location: Location<'a>,
+    thread_name: Option<&'a str>,
+}
+
+impl<'a> PanicInfo<'a> {
+    /// Returns the payload associated with the panic.
+    ///
+    /// This will commonly, but not always, be a `&'static str` or `String`.
+    #[unstable(feature = "panic_hook", reason = "recently added")]
+    pub fn payload(&self) -> &(Any + Send) {
+        self.payload
+    }
+
+    /// Returns information about the location from which the panic
+    /// originated.
+    #[unstable(feature = "panic_hook", reason = "recently added")]
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Returns the name of the panicking thread, if it has one.
+    #[unstable(feature = "panic_hook", reason = "recently added")]
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name
+    }
+}
+
+/// A struct containing information about the location of a panic.
+#[unstable(feature = "panic_hook", reason = "recently added")]
+#[derive(Debug)]
+pub struct Location<'a> {
+    file: &'a str,
+    line: u32,
+}
+
+impl<'a> Location<'a> {
+    /// Returns the name of the source file from which the panic originated.
+    #[unstable(feature = "panic_hook", reason = "recently added")]
+    pub fn file(&self) -> &str {
+        self.file
+    }
+
+    /// Returns the line number from which the panic originated.
+    #[unstable(feature = "panic_hook", reason = "recently added")]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+#[unstable(feature = "panic_hook", reason = "recently added")]
+impl<'a> fmt::Display for Location<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+fn default_hook(info: &PanicInfo) {
+    let msg = match info.payload.downcast_ref::<&'static str>() {
         Some(s) => *s,
-        None => match obj.downcast_ref::<String>() {
+        None => match info.payload.downcast_ref::<String>() {
             Some(s) => &s[..],
             None => "Box<Any>",
         }
     };
     let mut err = Stderr::new().ok();
-    let thread = thread_info::current_thread();
-    let name = thread.as_ref().and_then(|t| t.name()).unwrap_or("<unnamed>");
+    let name = info.thread_name.unwrap_or("<unnamed>");
+    let (file, line) = (info.location.file, info.location.line);
     let prev = LOCAL_STDERR.with(|s| s.borrow_mut().take());
     match (prev, err.as_mut()) {
         (Some(mut stderr), _) => {
@@ -68,3 +201,21 @@ pub fn on_panic(obj: &(Any+Send), file: &'static str, line: u32) {
         _ => {}
     }
 }
+
+pub fn on_panic(obj: &(Any+Send), file: &'static str, line: u32) {
+    let thread = thread_info::current_thread();
+    let info = PanicInfo {
+        payload: obj,
+        location: Location { file: file, line: line },
+        thread_name: thread.as_ref().and_then(|t| t.name()),
+    };
+
+    unsafe {
+        HOOK_LOCK.read();
+        match HOOK {
+            Hook::Default => default_hook(&info),
+            Hook::Custom(ptr) => (*ptr)(&info),
+        }
+        HOOK_LOCK.read_unlock();
+    }
+}
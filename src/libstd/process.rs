@@ -464,12 +464,7 @@ impl Child {
             // signals will only fail once the child has been reaped. For this
             // reason, if the process hasn't exited yet, then we attempt to collect
             // their status with WNOHANG.
-            if p.status.is_none() {
-                match p.handle.try_wait() {
-                    Some(status) => { p.status = Some(status); }
-                    None => {}
-                }
-            }
+            let _ = p.try_wait();
         }
         #[cfg(windows)] fn collect_status(_p: &mut Child) {}
 
@@ -515,6 +510,25 @@ impl Child {
         }
     }
 
+    /// Checks whether the child has exited, without blocking.
+    ///
+    /// Returns `Ok(None)` if the child is still running. Once the child has
+    /// exited its status is returned, both from this call and from any later
+    /// call to `try_wait` or `wait`.
+    ///
+    /// Unlike `wait`, this does not close the child's stdin.
+    #[unstable(feature = "process_try_wait", reason = "recently added")]
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        match self.status {
+            Some(code) => Ok(Some(ExitStatus(code))),
+            None => {
+                let status = try!(self.handle.try_wait());
+                self.status = status;
+                Ok(status.map(ExitStatus))
+            }
+        }
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning a `Output`
     /// instance.
@@ -694,6 +708,134 @@ mod tests {
         assert!(Command::new("/bin/ls").uid(0).gid(0).spawn().is_err());
     }
 
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn try_wait_works() {
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("read line")
+                            .stdin(Stdio::piped())
+                            .spawn().unwrap();
+        assert!(p.try_wait().unwrap().is_none());
+        drop(p.stdin.take());
+        let status = p.wait().unwrap();
+        assert!(status.success());
+        assert_eq!(p.try_wait().unwrap(), Some(status));
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn signal_works() {
+        use os::unix::prelude::*;
+        use libc;
+
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("read line")
+                            .stdin(Stdio::piped())
+                            .spawn().unwrap();
+        p.signal(libc::SIGTERM).unwrap();
+        assert_eq!(p.wait().unwrap().signal(), Some(libc::SIGTERM));
+        assert_eq!(p.signal(libc::SIGTERM).unwrap_err().kind(),
+                   ErrorKind::InvalidInput);
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn process_group_works() {
+        use os::unix::prelude::*;
+
+        // `$$` is the shell's pid, and `ps -o pgid=` its process group.
+        let mut cmd = Command::new("/bin/sh");
+        cmd.arg("-c").arg("test $(ps -o pgid= -p $$) -eq $$ && echo ok")
+           .process_group(0)
+           .stdout(Stdio::piped());
+        assert_eq!(run_output(cmd), "ok\n");
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn session_leader_works() {
+        use os::unix::prelude::*;
+
+        let mut cmd = Command::new("/bin/sh");
+        cmd.arg("-c").arg("test $(ps -o sid= -p $$) -eq $$ && echo ok")
+           .session_leader(true)
+           .stdout(Stdio::piped());
+        assert_eq!(run_output(cmd), "ok\n");
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn session_leader_and_process_group_conflict() {
+        use os::unix::prelude::*;
+
+        match Command::new("true").session_leader(true).process_group(0).spawn() {
+            Ok(..) => panic!("spawned a session leader into a process group"),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+        }
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn fd_works() {
+        use os::unix::prelude::*;
+        use sys::pipe::anon_pipe;
+
+        let (read, write) = anon_pipe().unwrap();
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("echo foo >&7")
+                            .fd(7, write.raw())
+                            .spawn().unwrap();
+        assert!(p.wait().unwrap().success());
+        drop(write);
+
+        let mut buf = [0; 5];
+        assert_eq!(read.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf[..4], b"foo\n");
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn fd_does_not_hide_spawn_errors() {
+        use os::unix::prelude::*;
+        use sys::pipe::anon_pipe;
+
+        // Whichever descriptor the child reports exec errors through, one of
+        // these lands on its number.
+        let (_read, write) = anon_pipe().unwrap();
+        let mut cmd = Command::new("nonexistent-command-for-fd-test");
+        for fd in 3..64 {
+            cmd.fd(fd, write.raw());
+        }
+        match cmd.spawn() {
+            Ok(..) => panic!("spawned a nonexistent command"),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        }
+    }
+
+    #[cfg(all(unix, not(target_os="android")))]
+    #[test]
+    fn before_exec_works() {
+        use os::unix::prelude::*;
+        use io;
+        use libc;
+
+        let mut cmd = Command::new("/bin/sh");
+        cmd.arg("-c").arg("pwd")
+           .before_exec(|| {
+               let ret = unsafe { libc::chdir(b"/\0".as_ptr() as *const _) };
+               if ret == 0 { Ok(()) } else { Err(io::Error::last_os_error()) }
+           })
+           .stdout(Stdio::piped());
+        assert_eq!(run_output(cmd), "/\n");
+
+        match Command::new("/bin/sh")
+                      .before_exec(|| Err(io::Error::from_raw_os_error(libc::EPERM)))
+                      .spawn() {
+            Ok(..) => panic!(),
+            Err(e) => assert_eq!(e.raw_os_error(), Some(libc::EPERM)),
+        }
+    }
+
     #[cfg(not(target_os="android"))]
     #[test]
     fn test_process_status() {
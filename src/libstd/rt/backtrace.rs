@@ -17,7 +17,7 @@ use prelude::v1::*;
 use env;
 use sync::atomic::{self, Ordering};
 
-pub use sys::backtrace::write;
+pub use sys_common::backtrace::write;
 
 // For now logging is turned off by default, and this function checks to see
 // whether the magical environment variable is present to see if it's turned on.
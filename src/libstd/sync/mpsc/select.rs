@@ -64,6 +64,8 @@ use core::mem;
 use core::ptr;
 use core::usize;
 
+use time::{Duration, Instant};
+
 use sync::mpsc::{Receiver, RecvError};
 use sync::mpsc::blocking::{self, SignalToken};
 
@@ -163,11 +165,23 @@ impl Select {
     /// event could either be that data is available or the corresponding
     /// channel has been closed.
     pub fn wait(&self) -> usize {
-        self.wait2(true)
+        self.wait2(true, None).unwrap()
     }
 
-    /// Helper method for skipping the preflight checks during testing
-    fn wait2(&self, do_preflight_checks: bool) -> usize {
+    /// Waits for an event on this receiver set, giving up once `timeout` has
+    /// elapsed.
+    ///
+    /// Returns `Some(id)` for the handle which became ready, in the same manner
+    /// as `wait`, or `None` if no receiver became ready before the timeout.
+    #[unstable(feature = "mpsc_recv_timeout", reason = "recently added")]
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<usize> {
+        self.wait2(true, Some(Instant::now() + timeout))
+    }
+
+    /// Helper method for skipping the preflight checks during testing, and
+    /// for optionally giving up at a deadline.
+    fn wait2(&self, do_preflight_checks: bool,
+             deadline: Option<Instant>) -> Option<usize> {
         // Note that this is currently an inefficient implementation. We in
         // theory have knowledge about all receivers in the set ahead of time,
         // so this method shouldn't really have to iterate over all of them yet
@@ -193,7 +207,7 @@ impl Select {
             if do_preflight_checks {
                 for handle in self.iter() {
                     if (*handle).packet.can_recv() {
-                        return (*handle).id();
+                        return Some((*handle).id());
                     }
                 }
             }
@@ -212,15 +226,20 @@ impl Select {
                         for handle in self.iter().take(i) {
                             (*handle).packet.abort_selection();
                         }
-                        return (*handle).id;
+                        return Some((*handle).id);
                     }
                 }
             }
 
-            // Stage 3: no messages available, actually block
-            wait_token.wait();
+            // Stage 3: no messages available, actually block (possibly only
+            // until the deadline)
+            match deadline {
+                Some(deadline) => { wait_token.wait_max_until(deadline); }
+                None => wait_token.wait(),
+            }
 
-            // Stage 4: there *must* be message available; find it.
+            // Stage 4: unless we timed out, there *must* be message available;
+            // find it.
             //
             // Abort the selection process on each receiver. If the abort
             // process returns `true`, then that means that the receiver is
@@ -250,9 +269,12 @@ impl Select {
                 }
             }
 
-            // We must have found a ready receiver
-            assert!(ready_id != usize::MAX);
-            return ready_id;
+            // We must have found a ready receiver, unless we gave up waiting
+            if ready_id == usize::MAX {
+                assert!(deadline.is_some());
+                return None;
+            }
+            return Some(ready_id);
         }
     }
 
@@ -357,6 +379,7 @@ mod tests {
 
     use thread;
     use sync::mpsc::*;
+    use time::Duration;
 
     // Don't use the libstd version so we can pull in the right Select structure
     // (std::comm points at the wrong one)
@@ -607,7 +630,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -618,7 +641,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -629,7 +652,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -639,7 +662,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -651,7 +674,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -664,7 +687,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -762,4 +785,23 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn wait_timeout() {
+        let (tx1, rx1) = channel::<i32>();
+        let (_tx2, rx2) = channel::<i32>();
+        let s = Select::new();
+        let mut h1 = s.handle(&rx1);
+        let mut h2 = s.handle(&rx2);
+        unsafe { h1.add(); }
+        unsafe { h2.add(); }
+        assert_eq!(s.wait_timeout(Duration::from_millis(10)), None);
+
+        let _t = thread::spawn(move|| {
+            for _ in 0..100 { thread::yield_now() }
+            tx1.send(1).unwrap();
+        });
+        assert_eq!(s.wait_timeout(Duration::from_secs(10)), Some(h1.id()));
+        assert_eq!(h1.recv().unwrap(), 1);
+    }
 }
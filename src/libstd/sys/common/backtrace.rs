@@ -11,7 +11,12 @@
 use prelude::v1::*;
 use io::prelude::*;
 
+use fmt;
 use io;
+use path::PathBuf;
+use str;
+use sync::StaticMutex;
+use sys::backtrace::trace;
 
 #[cfg(target_pointer_width = "64")]
 pub const HEX_WIDTH: usize = 18;
@@ -19,6 +24,125 @@ pub const HEX_WIDTH: usize = 18;
 #[cfg(target_pointer_width = "32")]
 pub const HEX_WIDTH: usize = 10;
 
+// Don't print ginormous backtraces
+const MAX_FRAMES: usize = 100;
+
+/// How many source locations of a frame are looked up at most. In the
+/// presence of inlining there can be several.
+pub const FILELINE_SIZE: usize = 32;
+
+/// A frame of the current thread's stack, as it is handed out while the
+/// stack is being walked.
+///
+/// Everything in it is borrowed from the platform's own buffers, so nothing
+/// has to be allocated to look at it. Printing a backtrace on panic relies on
+/// this, as the panic may well be due to running out of memory.
+pub struct RawFrame<'a> {
+    pub ip: usize,
+    /// The raw symbol name of the enclosing function, if it could be found.
+    pub name: Option<&'a [u8]>,
+    /// Source locations for `ip`, innermost inlined call first. If there are
+    /// `FILELINE_SIZE` of them there may have been more.
+    pub filelines: &'a [(&'a [u8], u32)],
+}
+
+/// A single frame of a stack trace, resolved as far as the platform allows.
+#[derive(Clone)]
+pub struct Frame {
+    /// The instruction pointer of this frame.
+    pub ip: usize,
+    /// The demangled name of the enclosing function, if it could be found.
+    pub name: Option<String>,
+    /// Source locations for `ip`, innermost inlined call first.
+    pub filelines: Vec<(PathBuf, u32)>,
+}
+
+impl Frame {
+    /// Copies out a frame seen while walking the stack, demangling its
+    /// symbol name.
+    pub fn from_raw(raw: &RawFrame) -> Frame {
+        let name = raw.name.map(|bytes| {
+            let mut ret = Vec::new();
+            // writing to a `Vec` can't fail
+            let _ = demangle(&mut ret, &String::from_utf8_lossy(bytes));
+            String::from_utf8_lossy(&ret).into_owned()
+        });
+        let filelines = raw.filelines.iter().map(|&(file, line)| {
+            (PathBuf::from(String::from_utf8_lossy(file).into_owned()), line)
+        }).collect();
+        Frame { ip: raw.ip, name: name, filelines: filelines }
+    }
+}
+
+// prints "{ip} - {name}" followed by one "at {file}:{line}" line per
+// location, lined up under the name.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{:#1$x} - ", self.ip, HEX_WIDTH));
+        match self.name {
+            Some(ref name) => try!(f.write_str(name)),
+            None => try!(f.write_str("<unknown>")),
+        }
+        try!(f.write_str("\n"));
+        for &(ref file, line) in &self.filelines {
+            try!(write!(f, "      {:3$}at {}:{}\n", "", file.display(), line,
+                        HEX_WIDTH));
+        }
+        Ok(())
+    }
+}
+
+/// Prints a backtrace of the current thread to `w`.
+///
+/// Each frame is printed as soon as it has been found, and nothing is
+/// allocated on the way, so this works even if the heap is unusable.
+#[inline(never)] // if we know this is a function call, we can skip it when
+                 // tracing
+pub fn write(w: &mut Write) -> io::Result<()> {
+    // Tracing is already serialized by the platform, but holding a lock for
+    // the whole write keeps the output of simultaneous panics from getting
+    // interleaved.
+    static LOCK: StaticMutex = StaticMutex::new();
+    let _g = LOCK.lock();
+
+    try!(writeln!(w, "stack backtrace:"));
+    let mut idx = 0;
+    let mut res = Ok(());
+    trace(&mut |frame| {
+        idx += 1;
+        if idx > MAX_FRAMES {
+            res = w.write_all(b" ... <frames omitted>\n");
+            return false
+        }
+        // Once we hit an error, stop trying to print more frames
+        res = output(w, idx, frame);
+        res.is_ok()
+    });
+    res
+}
+
+fn output(w: &mut Write, idx: usize, frame: &RawFrame) -> io::Result<()> {
+    try!(write!(w, "  {:2}: {:#2$x} - ", idx, frame.ip, HEX_WIDTH));
+    match frame.name {
+        Some(bytes) => match str::from_utf8(bytes) {
+            Ok(s) => try!(demangle(w, s)),
+            Err(..) => try!(w.write_all(bytes)),
+        },
+        None => try!(w.write_all(b"<unknown>")),
+    }
+    try!(w.write_all(b"\n"));
+    for (i, &(file, line)) in frame.filelines.iter().enumerate() {
+        let file = str::from_utf8(file).unwrap_or("<unknown>");
+        // prior line: "  ##: {:2$} - func"
+        try!(write!(w, "      {:3$}at {}:{}", "", file, line, HEX_WIDTH));
+        if i == FILELINE_SIZE - 1 {
+            try!(w.write_all(b" <... and possibly more>"));
+        }
+        try!(w.write_all(b"\n"));
+    }
+    Ok(())
+}
+
 // All rust symbols are in theory lists of "::"-separated identifiers. Some
 // assemblers, however, can't handle these characters in symbol names. To get
 // around this, we use C++-style mangling. The mangling method is:
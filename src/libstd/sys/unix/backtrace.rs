@@ -84,16 +84,12 @@
 /// all unix platforms we support right now, so it at least gets the job done.
 
 use prelude::v1::*;
-use io::prelude::*;
 
 use ffi::CStr;
-use io;
 use libc;
-use mem;
-use str;
 use sync::StaticMutex;
 
-use sys_common::backtrace::*;
+use sys_common::backtrace::RawFrame;
 
 /// As always - iOS on arm uses SjLj exceptions and
 /// _Unwind_Backtrace is even not available there. Still,
@@ -106,8 +102,8 @@ use sys_common::backtrace::*;
 /// only viable option.
 #[cfg(all(target_os = "ios", target_arch = "arm"))]
 #[inline(never)]
-pub fn write(w: &mut Write) -> io::Result<()> {
-    use result;
+pub fn trace(f: &mut FnMut(&RawFrame) -> bool) {
+    use mem;
 
     extern {
         fn backtrace(buf: *mut *mut libc::c_void,
@@ -120,27 +116,25 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     static LOCK: StaticMutex = StaticMutex::new();
     let _g = LOCK.lock();
 
-    try!(writeln!(w, "stack backtrace:"));
     // 100 lines should be enough
     const SIZE: usize = 100;
     let mut buf: [*mut libc::c_void; SIZE] = unsafe {mem::zeroed()};
     let cnt = unsafe { backtrace(buf.as_mut_ptr(), SIZE as libc::c_int) as usize};
 
-    // skipping the first one as it is write itself
-    let iter = (1..cnt).map(|i| {
-        print(w, i as isize, buf[i], buf[i])
-    });
-    result::fold(iter, (), |_, _| ())
+    // skipping the first one as it is trace itself
+    for &ip in buf.iter().take(cnt).skip(1) {
+        if !resolve(ip, ip, f) { break }
+    }
 }
 
+/// Walks the stack of the current thread, handing each frame to `f` until it
+/// returns `false`.
 #[cfg(not(all(target_os = "ios", target_arch = "arm")))]
 #[inline(never)] // if we know this is a function call, we can skip it when
                  // tracing
-pub fn write(w: &mut Write) -> io::Result<()> {
+pub fn trace(f: &mut FnMut(&RawFrame) -> bool) {
     struct Context<'a> {
-        idx: isize,
-        writer: &'a mut (Write+'a),
-        last_error: Option<io::Error>,
+        f: &'a mut (FnMut(&RawFrame) -> bool + 'a),
     }
 
     // When using libbacktrace, we use some necessary global state, so we
@@ -151,25 +145,15 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     static LOCK: StaticMutex = StaticMutex::new();
     let _g = LOCK.lock();
 
-    try!(writeln!(w, "stack backtrace:"));
-
-    let mut cx = Context { writer: w, last_error: None, idx: 0 };
-    return match unsafe {
+    let mut cx = Context { f: f };
+    unsafe {
         uw::_Unwind_Backtrace(trace_fn,
-                              &mut cx as *mut Context as *mut libc::c_void)
-    } {
-        uw::_URC_NO_REASON => {
-            match cx.last_error {
-                Some(err) => Err(err),
-                None => Ok(())
-            }
-        }
-        _ => Ok(()),
-    };
+                              &mut cx as *mut Context as *mut libc::c_void);
+    }
 
     extern fn trace_fn(ctx: *mut uw::_Unwind_Context,
                        arg: *mut libc::c_void) -> uw::_Unwind_Reason_Code {
-        let cx: &mut Context = unsafe { mem::transmute(arg) };
+        let cx = unsafe { &mut *(arg as *mut Context) };
         let mut ip_before_insn = 0;
         let mut ip = unsafe {
             uw::_Unwind_GetIPInfo(ctx, &mut ip_before_insn) as *mut libc::c_void
@@ -196,34 +180,18 @@ pub fn write(w: &mut Write) -> io::Result<()> {
             unsafe { uw::_Unwind_FindEnclosingFunction(ip) }
         };
 
-        // Don't print out the first few frames (they're not user frames)
-        cx.idx += 1;
-        if cx.idx <= 0 { return uw::_URC_NO_REASON }
-        // Don't print ginormous backtraces
-        if cx.idx > 100 {
-            match write!(cx.writer, " ... <frames omitted>\n") {
-                Ok(()) => {}
-                Err(e) => { cx.last_error = Some(e); }
-            }
-            return uw::_URC_FAILURE
-        }
-
-        // Once we hit an error, stop trying to print more frames
-        if cx.last_error.is_some() { return uw::_URC_FAILURE }
-
-        match print(cx.writer, cx.idx, ip, symaddr) {
-            Ok(()) => {}
-            Err(e) => { cx.last_error = Some(e); }
+        if resolve(ip, symaddr, cx.f) {
+            // keep going
+            uw::_URC_NO_REASON
+        } else {
+            uw::_URC_FAILURE
         }
-
-        // keep going
-        return uw::_URC_NO_REASON
     }
 }
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
-fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
-         _symaddr: *mut libc::c_void) -> io::Result<()> {
+fn resolve(addr: *mut libc::c_void, _symaddr: *mut libc::c_void,
+           f: &mut FnMut(&RawFrame) -> bool) -> bool {
     use intrinsics;
     #[repr(C)]
     struct Dl_info {
@@ -238,21 +206,24 @@ fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
     }
 
     let mut info: Dl_info = unsafe { intrinsics::init() };
-    if unsafe { dladdr(addr, &mut info) == 0 } {
-        output(w, idx,addr, None)
+    let name = if unsafe { dladdr(addr, &mut info) == 0 } ||
+                  info.dli_sname.is_null() {
+        None
     } else {
-        output(w, idx, addr, Some(unsafe {
-            CStr::from_ptr(info.dli_sname).to_bytes()
-        }))
-    }
+        Some(unsafe { CStr::from_ptr(info.dli_sname).to_bytes() })
+    };
+    f(&RawFrame { ip: addr as usize, name: name, filelines: &[] })
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
-fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
-         symaddr: *mut libc::c_void) -> io::Result<()> {
+fn resolve(addr: *mut libc::c_void, symaddr: *mut libc::c_void,
+           f: &mut FnMut(&RawFrame) -> bool) -> bool {
     use env;
     use os::unix::prelude::*;
     use ptr;
+    use sys_common::backtrace::FILELINE_SIZE;
+
+    type FileLine = (*const libc::c_char, libc::c_int);
 
     ////////////////////////////////////////////////////////////////////////
     // libbacktrace.h API
@@ -300,8 +271,6 @@ fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
     // helper callbacks
     ////////////////////////////////////////////////////////////////////////
 
-    type FileLine = (*const libc::c_char, libc::c_int);
-
     extern fn error_cb(_data: *mut libc::c_void, _msg: *const libc::c_char,
                        _errnum: libc::c_int) {
         // do nothing for now
@@ -392,11 +361,10 @@ fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
     // translation
     ////////////////////////////////////////////////////////////////////////
 
-    // backtrace errors are currently swept under the rug, only I/O
-    // errors are reported
+    // backtrace errors are currently swept under the rug
     let state = unsafe { init_state() };
     if state.is_null() {
-        return output(w, idx, addr, None)
+        return f(&RawFrame { ip: addr as usize, name: None, filelines: &[] })
     }
     let mut data = ptr::null();
     let data_addr = &mut data as *mut *const libc::c_char;
@@ -405,16 +373,15 @@ fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
                           syminfo_cb, error_cb,
                           data_addr as *mut libc::c_void)
     };
-    if ret == 0 || data.is_null() {
-        try!(output(w, idx, addr, None));
+    let name = if ret == 0 || data.is_null() {
+        None
     } else {
-        try!(output(w, idx, addr, Some(unsafe { CStr::from_ptr(data).to_bytes() })));
-    }
+        Some(unsafe { CStr::from_ptr(data).to_bytes() })
+    };
 
     // pcinfo may return an arbitrary number of file:line pairs,
     // in the order of stack trace (i.e. inlined calls first).
     // in order to avoid allocation, we stack-allocate a fixed size of entries.
-    const FILELINE_SIZE: usize = 32;
     let mut fileline_buf = [(ptr::null(), -1); FILELINE_SIZE];
     let ret;
     let fileline_count;
@@ -428,38 +395,19 @@ fn print(w: &mut Write, idx: isize, addr: *mut libc::c_void,
         };
         fileline_count = FILELINE_SIZE - fileline_win.len();
     }
+
+    let no_file: &[u8] = &[];
+    let mut filelines = [(no_file, 0); FILELINE_SIZE];
+    let mut count = 0;
     if ret == 0 {
-        for (i, &(file, line)) in fileline_buf[..fileline_count].iter().enumerate() {
+        for &(file, line) in &fileline_buf[..fileline_count] {
             if file.is_null() { continue; } // just to be sure
-            let file = unsafe { CStr::from_ptr(file).to_bytes() };
-            try!(output_fileline(w, file, line, i == FILELINE_SIZE - 1));
+            filelines[count] = (unsafe { CStr::from_ptr(file).to_bytes() },
+                                line as u32);
+            count += 1;
         }
     }
-
-    Ok(())
-}
-
-// Finally, after all that work above, we can emit a symbol.
-fn output(w: &mut Write, idx: isize, addr: *mut libc::c_void,
-          s: Option<&[u8]>) -> io::Result<()> {
-    try!(write!(w, "  {:2}: {:2$?} - ", idx, addr, HEX_WIDTH));
-    match s.and_then(|s| str::from_utf8(s).ok()) {
-        Some(string) => try!(demangle(w, string)),
-        None => try!(write!(w, "<unknown>")),
-    }
-    w.write_all(&['\n' as u8])
-}
-
-#[allow(dead_code)]
-fn output_fileline(w: &mut Write, file: &[u8], line: libc::c_int,
-                   more: bool) -> io::Result<()> {
-    let file = str::from_utf8(file).unwrap_or("<unknown>");
-    // prior line: "  ##: {:2$} - func"
-    try!(write!(w, "      {:3$}at {}:{}", "", file, line, HEX_WIDTH));
-    if more {
-        try!(write!(w, " <... and possibly more>"));
-    }
-    w.write_all(&['\n' as u8])
+    f(&RawFrame { ip: addr as usize, name: name, filelines: &filelines[..count] })
 }
 
 /// Unwind library interface used for backtraces
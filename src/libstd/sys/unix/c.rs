@@ -26,6 +26,7 @@
 
 pub use self::signal_os::{sigaction, siginfo, sigset_t, sigaltstack};
 pub use self::signal_os::{SA_ONSTACK, SA_SIGINFO, SIGBUS, SIGSTKSZ, SIG_SETMASK};
+pub use self::socket_os::{msghdr, cmsghdr, cmsg_len_t, cmsg_align};
 
 use libc;
 
@@ -51,6 +52,15 @@ pub const FIOCLEX: libc::c_ulong = 0x5451;
               target_arch = "powerpc")))]
 pub const FIOCLEX: libc::c_ulong = 0x6601;
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const F_DUPFD_CLOEXEC: libc::c_int = 1030;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub const F_DUPFD_CLOEXEC: libc::c_int = 67;
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+pub const F_DUPFD_CLOEXEC: libc::c_int = 17;
+#[cfg(any(target_os = "bitrig", target_os = "openbsd"))]
+pub const F_DUPFD_CLOEXEC: libc::c_int = 10;
+
 pub const WNOHANG: libc::c_int = 1;
 
 #[cfg(target_os = "linux")]
@@ -155,6 +165,159 @@ extern {
                      ptr: *const libc::c_void) -> libc::c_int;
     pub fn realpath(pathname: *const libc::c_char, resolved: *mut libc::c_char)
                     -> *mut libc::c_char;
+    pub fn flock(fd: libc::c_int, operation: libc::c_int) -> libc::c_int;
+
+    pub fn socketpair(domain: libc::c_int, ty: libc::c_int, protocol: libc::c_int,
+                      sv: *mut libc::c_int) -> libc::c_int;
+    pub fn sendmsg(sockfd: libc::c_int, msg: *const msghdr,
+                   flags: libc::c_int) -> libc::ssize_t;
+    pub fn recvmsg(sockfd: libc::c_int, msg: *mut msghdr,
+                   flags: libc::c_int) -> libc::ssize_t;
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn getpeereid(socket: libc::c_int, euid: *mut libc::uid_t,
+                      egid: *mut libc::gid_t) -> libc::c_int;
+}
+
+#[repr(C)]
+pub struct iovec {
+    pub iov_base: *mut libc::c_void,
+    pub iov_len: libc::size_t,
+}
+
+pub const SCM_RIGHTS: libc::c_int = 1;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const MSG_CMSG_CLOEXEC: libc::c_int = 0x40000000;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ucred {
+    pub pid: libc::pid_t,
+    pub uid: libc::uid_t,
+    pub gid: libc::gid_t,
+}
+
+#[cfg(all(any(target_os = "linux", target_os = "android"),
+          any(target_arch = "mips", target_arch = "mipsel")))]
+pub const SO_PEERCRED: libc::c_int = 18;
+#[cfg(all(any(target_os = "linux", target_os = "android"),
+          target_arch = "powerpc"))]
+pub const SO_PEERCRED: libc::c_int = 21;
+#[cfg(all(any(target_os = "linux", target_os = "android"),
+          not(any(target_arch = "mips",
+                  target_arch = "mipsel",
+                  target_arch = "powerpc"))))]
+pub const SO_PEERCRED: libc::c_int = 17;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use self::epoll::*;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod epoll {
+    use libc;
+
+    pub const EPOLL_CTL_ADD: libc::c_int = 1;
+    pub const EPOLL_CTL_DEL: libc::c_int = 2;
+    pub const EPOLL_CTL_MOD: libc::c_int = 3;
+
+    pub const EPOLLIN: u32 = 0x001;
+    pub const EPOLLPRI: u32 = 0x002;
+    pub const EPOLLOUT: u32 = 0x004;
+    pub const EPOLLERR: u32 = 0x008;
+    pub const EPOLLHUP: u32 = 0x010;
+    pub const EPOLLRDHUP: u32 = 0x2000;
+
+    // The kernel packs this structure on x86_64 only.
+    #[repr(C)]
+    #[cfg_attr(target_arch = "x86_64", repr(packed))]
+    #[derive(Clone, Copy)]
+    pub struct epoll_event {
+        pub events: u32,
+        pub data: u64,
+    }
+
+    extern {
+        pub fn epoll_create(size: libc::c_int) -> libc::c_int;
+        pub fn epoll_ctl(epfd: libc::c_int, op: libc::c_int, fd: libc::c_int,
+                         event: *mut epoll_event) -> libc::c_int;
+        pub fn epoll_wait(epfd: libc::c_int, events: *mut epoll_event,
+                          maxevents: libc::c_int,
+                          timeout: libc::c_int) -> libc::c_int;
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod socket_os {
+    use libc;
+
+    // The type of the lengths of control messages and their buffers.
+    pub type cmsg_len_t = libc::size_t;
+
+    #[repr(C)]
+    pub struct msghdr {
+        pub msg_name: *mut libc::c_void,
+        pub msg_namelen: libc::socklen_t,
+        pub msg_iov: *mut super::iovec,
+        pub msg_iovlen: libc::size_t,
+        pub msg_control: *mut libc::c_void,
+        pub msg_controllen: cmsg_len_t,
+        pub msg_flags: libc::c_int,
+    }
+
+    #[repr(C)]
+    pub struct cmsghdr {
+        pub cmsg_len: cmsg_len_t,
+        pub cmsg_level: libc::c_int,
+        pub cmsg_type: libc::c_int,
+    }
+
+    // Control messages are aligned to a `size_t`.
+    pub fn cmsg_align(len: usize) -> usize {
+        let align = ::mem::size_of::<libc::size_t>();
+        (len + align - 1) & !(align - 1)
+    }
+}
+
+#[cfg(any(target_os = "macos",
+          target_os = "ios",
+          target_os = "freebsd",
+          target_os = "dragonfly",
+          target_os = "bitrig",
+          target_os = "openbsd"))]
+mod socket_os {
+    use libc;
+
+    pub type cmsg_len_t = libc::socklen_t;
+
+    #[repr(C)]
+    pub struct msghdr {
+        pub msg_name: *mut libc::c_void,
+        pub msg_namelen: libc::socklen_t,
+        pub msg_iov: *mut super::iovec,
+        pub msg_iovlen: libc::c_int,
+        pub msg_control: *mut libc::c_void,
+        pub msg_controllen: cmsg_len_t,
+        pub msg_flags: libc::c_int,
+    }
+
+    #[repr(C)]
+    pub struct cmsghdr {
+        pub cmsg_len: cmsg_len_t,
+        pub cmsg_level: libc::c_int,
+        pub cmsg_type: libc::c_int,
+    }
+
+    // Darwin aligns control messages to 4 bytes, the BSDs to a `long`.
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    pub fn cmsg_align(len: usize) -> usize {
+        (len + 3) & !3
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    pub fn cmsg_align(len: usize) -> usize {
+        let align = ::mem::size_of::<libc::c_long>();
+        (len + align - 1) & !(align - 1)
+    }
 }
 
 // Ugh. This is only available as an inline until Android API 21.
@@ -32,6 +32,9 @@
 pub mod io;
 pub mod ffi;
 pub mod fs;
+pub mod net;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub mod poll;
 pub mod process;
 pub mod raw;
 
@@ -50,4 +53,6 @@ pub mod prelude {
     pub use super::fs::{DirEntryExt};
     #[doc(no_inline)] #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::process::{CommandExt, ExitStatusExt};
+    #[doc(no_inline)]
+    pub use super::process::ChildExt;
 }
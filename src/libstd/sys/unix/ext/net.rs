@@ -0,0 +1,1352 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Unix domain sockets.
+//!
+//! # Example
+//!
+//! ```no_run
+//! #![feature(unix_socket)]
+//!
+//! use std::io::prelude::*;
+//! use std::os::unix::net::UnixStream;
+//!
+//! let mut stream = UnixStream::connect("/run/foo.sock").unwrap();
+//! stream.write_all(b"hello world").unwrap();
+//! let mut response = String::new();
+//! stream.read_to_string(&mut response).unwrap();
+//! println!("{}", response);
+//! ```
+
+#![unstable(feature = "unix_socket", reason = "newly added")]
+
+use prelude::v1::*;
+use io::prelude::*;
+
+use ffi::OsStr;
+use fmt;
+use io;
+use libc::{self, c_int, c_void, socklen_t};
+use mem;
+use net::Shutdown;
+use os::unix::ffi::OsStrExt;
+use os::unix::io::{RawFd, AsRawFd, FromRawFd};
+use os::unix::raw;
+use path::Path;
+use ptr;
+use sys::c;
+use sys::net::Socket;
+use sys::{cvt, cvt_r};
+use sys_common::{AsInner, FromInner};
+use time::Duration;
+
+fn sun_path_offset() -> usize {
+    unsafe {
+        // Work with an actual instance of the type since using a null pointer
+        // is UB.
+        let addr: libc::sockaddr_un = mem::uninitialized();
+        let base = &addr as *const _ as usize;
+        let path = &addr.sun_path as *const _ as usize;
+        path - base
+    }
+}
+
+unsafe fn sockaddr_un(path: &[u8]) -> io::Result<(libc::sockaddr_un, socklen_t)> {
+    let mut addr: libc::sockaddr_un = mem::zeroed();
+    addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+
+    if path.contains(&0) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  "paths may not contain interior null bytes"));
+    }
+    // The path needs to be followed by a nul.
+    if path.len() >= addr.sun_path.len() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  "path must be shorter than SUN_LEN"));
+    }
+    for (dst, src) in addr.sun_path.iter_mut().zip(path.iter()) {
+        *dst = *src as libc::c_char;
+    }
+
+    let mut len = sun_path_offset() + path.len();
+    if !path.is_empty() {
+        len += 1;
+    }
+    Ok((addr, len as socklen_t))
+}
+
+enum AddressKind<'a> {
+    Unnamed,
+    Pathname(&'a Path),
+    Abstract(&'a [u8]),
+}
+
+/// An address associated with a Unix socket.
+#[derive(Clone)]
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct SocketAddr {
+    addr: libc::sockaddr_un,
+    len: socklen_t,
+}
+
+impl SocketAddr {
+    fn new<F>(f: F) -> io::Result<SocketAddr>
+        where F: FnOnce(*mut libc::sockaddr, *mut socklen_t) -> c_int
+    {
+        unsafe {
+            let mut addr: libc::sockaddr_un = mem::zeroed();
+            let mut len = mem::size_of::<libc::sockaddr_un>() as socklen_t;
+            try!(cvt(f(&mut addr as *mut _ as *mut _, &mut len)));
+            SocketAddr::from_parts(addr, len)
+        }
+    }
+
+    fn from_parts(addr: libc::sockaddr_un, mut len: socklen_t) -> io::Result<SocketAddr> {
+        if len == 0 {
+            // Linux returns a zero length address for datagrams sent from
+            // unnamed sockets.
+            len = sun_path_offset() as socklen_t;
+        } else if addr.sun_family != libc::AF_UNIX as libc::sa_family_t {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "file descriptor did not correspond to a Unix socket"));
+        }
+
+        Ok(SocketAddr {
+            addr: addr,
+            len: len,
+        })
+    }
+
+    /// Creates an address referring to the given path in the filesystem.
+    ///
+    /// This fails if the path is too long, or contains a nul byte.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn from_pathname<P: AsRef<Path>>(path: P) -> io::Result<SocketAddr> {
+        unsafe {
+            let (addr, len) = try!(sockaddr_un(path.as_ref().as_os_str().as_bytes()));
+            Ok(SocketAddr { addr: addr, len: len })
+        }
+    }
+
+    /// Creates an address in the abstract namespace, which isn't tied to the
+    /// filesystem. Unlike a path, the name may contain nul bytes.
+    ///
+    /// This fails if the name is too long.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn from_abstract_name(name: &[u8]) -> io::Result<SocketAddr> {
+        unsafe {
+            let mut addr: libc::sockaddr_un = mem::zeroed();
+            addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+            // The name goes after a leading nul, and isn't nul terminated.
+            if name.len() >= addr.sun_path.len() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                          "name must be shorter than SUN_LEN"));
+            }
+            for (dst, src) in addr.sun_path[1..].iter_mut().zip(name.iter()) {
+                *dst = *src as libc::c_char;
+            }
+            let len = sun_path_offset() + 1 + name.len();
+            Ok(SocketAddr { addr: addr, len: len as socklen_t })
+        }
+    }
+
+    /// Returns true if and only if the address is unnamed.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn is_unnamed(&self) -> bool {
+        if let AddressKind::Unnamed = self.address() {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the contents of this address if it is a `pathname` address.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn as_pathname(&self) -> Option<&Path> {
+        if let AddressKind::Pathname(path) = self.address() {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of this address if it is in the abstract namespace.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn as_abstract_name(&self) -> Option<&[u8]> {
+        if let AddressKind::Abstract(name) = self.address() {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
+    fn address<'a>(&'a self) -> AddressKind<'a> {
+        let len = self.len as usize - sun_path_offset();
+        let path = unsafe { mem::transmute::<&[libc::c_char], &[u8]>(&self.addr.sun_path) };
+
+        // OSX seems to return a len of 16 and a zeroed sun_path for unnamed
+        // addresses, and has no abstract namespace.
+        if len == 0 || (cfg!(not(any(target_os = "linux", target_os = "android"))) &&
+                        self.addr.sun_path[0] == 0) {
+            AddressKind::Unnamed
+        } else if self.addr.sun_path[0] == 0 {
+            AddressKind::Abstract(&path[1..len])
+        } else {
+            // The length may or may not include the trailing nul.
+            let path = &path[..len];
+            let end = path.iter().position(|&b| b == 0).unwrap_or(path.len());
+            AddressKind::Pathname(Path::new(OsStr::from_bytes(&path[..end])))
+        }
+    }
+}
+
+impl fmt::Debug for SocketAddr {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.address() {
+            AddressKind::Unnamed => write!(fmt, "(unnamed)"),
+            AddressKind::Abstract(name) => {
+                write!(fmt, "{:?} (abstract)", String::from_utf8_lossy(name))
+            }
+            AddressKind::Pathname(path) => write!(fmt, "{:?} (pathname)", path),
+        }
+    }
+}
+
+/// The credentials of the process on the other end of a Unix socket, as of
+/// the time the connection was made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct UCred {
+    /// The effective user id of the peer.
+    pub uid: raw::uid_t,
+    /// The effective group id of the peer.
+    pub gid: raw::gid_t,
+    /// The process id of the peer, on platforms which provide it.
+    pub pid: Option<raw::pid_t>,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn peer_cred(sock: &Socket) -> io::Result<UCred> {
+    use sys_common::net::getsockopt;
+
+    let cred: c::ucred = try!(getsockopt(sock, libc::SOL_SOCKET, c::SO_PEERCRED));
+    Ok(UCred {
+        uid: cred.uid,
+        gid: cred.gid,
+        pid: Some(cred.pid),
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn peer_cred(sock: &Socket) -> io::Result<UCred> {
+    let mut uid = 0;
+    let mut gid = 0;
+    try!(cvt(unsafe { c::getpeereid(*sock.as_inner(), &mut uid, &mut gid) }));
+    Ok(UCred {
+        uid: uid,
+        gid: gid,
+        pid: None,
+    })
+}
+
+// Equivalents of the `CMSG_*` macros for walking control messages.
+fn cmsg_len(len: usize) -> usize {
+    c::cmsg_align(mem::size_of::<c::cmsghdr>()) + len
+}
+
+fn cmsg_space(len: usize) -> usize {
+    c::cmsg_align(mem::size_of::<c::cmsghdr>()) + c::cmsg_align(len)
+}
+
+unsafe fn cmsg_data(cmsg: *const c::cmsghdr) -> *const u8 {
+    (cmsg as *const u8).offset(cmsg_len(0) as isize)
+}
+
+// A buffer for control messages, suitably aligned for a `cmsghdr`.
+fn cmsg_buffer(fds: usize) -> Vec<usize> {
+    let space = cmsg_space(fds * mem::size_of::<RawFd>());
+    let words = (space + mem::size_of::<usize>() - 1) / mem::size_of::<usize>();
+    (0..words).map(|_| 0).collect()
+}
+
+// Sends `buf` along with the file descriptors `fds` as `SCM_RIGHTS`.
+fn send_msg(sock: &Socket, buf: &[u8], fds: &[RawFd],
+            dst: Option<&SocketAddr>) -> io::Result<usize> {
+    let mut control = cmsg_buffer(fds.len());
+    unsafe {
+        let mut iov = c::iovec {
+            iov_base: buf.as_ptr() as *mut c_void,
+            iov_len: buf.len() as libc::size_t,
+        };
+        let mut msg: c::msghdr = mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        if let Some(dst) = dst {
+            msg.msg_name = &dst.addr as *const _ as *mut c_void;
+            msg.msg_namelen = dst.len;
+        }
+        if !fds.is_empty() {
+            let len = fds.len() * mem::size_of::<RawFd>();
+            let cmsg = control.as_mut_ptr() as *mut c::cmsghdr;
+            (*cmsg).cmsg_len = cmsg_len(len) as c::cmsg_len_t;
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = c::SCM_RIGHTS;
+            ptr::copy_nonoverlapping(fds.as_ptr(),
+                                     cmsg_data(cmsg) as *mut RawFd,
+                                     fds.len());
+            msg.msg_control = control.as_mut_ptr() as *mut c_void;
+            msg.msg_controllen = cmsg_space(len) as c::cmsg_len_t;
+        }
+        let ret = try!(cvt(c::sendmsg(*sock.as_inner(), &msg, 0)));
+        Ok(ret as usize)
+    }
+}
+
+// Received descriptors are made close-on-exec atomically where the platform
+// supports it, and straight after the `recvmsg` call otherwise.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const RECV_FLAGS: c_int = c::MSG_CMSG_CLOEXEC;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const RECV_FLAGS: c_int = 0;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn set_cloexec(_fd: RawFd) {}
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn set_cloexec(fd: RawFd) {
+    c::ioctl(fd, c::FIOCLEX);
+}
+
+// Receives into `buf`, storing any file descriptors that came with the data
+// in `fds`. Returns the number of bytes and descriptors received, and the
+// address of the sender. Descriptors beyond those which fit in `fds` are
+// closed.
+fn recv_msg(sock: &Socket, buf: &mut [u8],
+            fds: &mut [RawFd]) -> io::Result<(usize, usize, SocketAddr)> {
+    let mut control = cmsg_buffer(fds.len());
+    unsafe {
+        let mut iov = c::iovec {
+            iov_base: buf.as_mut_ptr() as *mut c_void,
+            iov_len: buf.len() as libc::size_t,
+        };
+        let mut addr: libc::sockaddr_un = mem::zeroed();
+        let mut msg: c::msghdr = mem::zeroed();
+        msg.msg_name = &mut addr as *mut _ as *mut c_void;
+        msg.msg_namelen = mem::size_of::<libc::sockaddr_un>() as socklen_t;
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        if !fds.is_empty() {
+            msg.msg_control = control.as_mut_ptr() as *mut c_void;
+            msg.msg_controllen = (control.len() * mem::size_of::<usize>()) as c::cmsg_len_t;
+        }
+        let n = try!(cvt_r(|| c::recvmsg(*sock.as_inner(), &mut msg, RECV_FLAGS)));
+
+        let mut received = 0;
+        let start = control.as_ptr() as usize;
+        let end = start + msg.msg_controllen as usize;
+        let mut pos = start;
+        while pos + cmsg_len(0) <= end {
+            let cmsg = pos as *const c::cmsghdr;
+            let len = (*cmsg).cmsg_len as usize;
+            if len < cmsg_len(0) || pos + len > end {
+                break
+            }
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET &&
+               (*cmsg).cmsg_type == c::SCM_RIGHTS {
+                let data = cmsg_data(cmsg) as *const RawFd;
+                for i in 0..(len - cmsg_len(0)) / mem::size_of::<RawFd>() {
+                    let fd = *data.offset(i as isize);
+                    if received < fds.len() {
+                        set_cloexec(fd);
+                        fds[received] = fd;
+                        received += 1;
+                    } else {
+                        libc::close(fd);
+                    }
+                }
+            }
+            pos += c::cmsg_align(len);
+        }
+
+        let addr = try!(SocketAddr::from_parts(addr, msg.msg_namelen));
+        Ok((n as usize, received, addr))
+    }
+}
+
+/// A Unix stream socket.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket)]
+///
+/// use std::os::unix::net::UnixStream;
+/// use std::io::prelude::*;
+///
+/// let mut stream = UnixStream::connect("/path/to/my/socket").unwrap();
+/// stream.write_all(b"hello world").unwrap();
+/// let mut response = String::new();
+/// stream.read_to_string(&mut response).unwrap();
+/// println!("{}", response);
+/// ```
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct UnixStream(Socket);
+
+impl fmt::Debug for UnixStream {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixStream");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixStream {
+    /// Connects to the socket named by `path`.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixStream> {
+        UnixStream::connect_addr(&try!(SocketAddr::from_pathname(path)))
+    }
+
+    /// Connects to the socket at `addr`, which may be in the abstract
+    /// namespace.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn connect_addr(addr: &SocketAddr) -> io::Result<UnixStream> {
+        let inner = try!(Socket::new_raw(libc::AF_UNIX, libc::SOCK_STREAM));
+        try!(cvt_r(|| unsafe {
+            libc::connect(*inner.as_inner(), &addr.addr as *const _ as *const _, addr.len)
+        }));
+        Ok(UnixStream(inner))
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixStream`s which are connected to each other.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn pair() -> io::Result<(UnixStream, UnixStream)> {
+        let (a, b) = try!(Socket::new_pair(libc::AF_UNIX, libc::SOCK_STREAM));
+        Ok((UnixStream(a), UnixStream(b)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixStream` is a reference to the same stream that this
+    /// object references. Both handles will read and write the same stream of
+    /// data, and options set on one stream will be propogated to the other
+    /// stream.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn try_clone(&self) -> io::Result<UnixStream> {
+        self.0.duplicate().map(UnixStream)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns the credentials of the process which created the other end
+    /// of this connection.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        peer_cred(&self.0)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is `None`, then `read` calls will block
+    /// indefinitely. It is an error to pass the zero `Duration` to this
+    /// method.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is `None`, then `write` calls will block
+    /// indefinitely. It is an error to pass the zero `Duration` to this
+    /// method.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of `Shutdown`).
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        shutdown(&self.0, how)
+    }
+
+    /// Writes `buf` to the socket, passing along the file descriptors in
+    /// `fds`.
+    ///
+    /// The peer receives duplicates of the descriptors, which stay open
+    /// until it closes them, whatever happens to the originals.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[RawFd]) -> io::Result<usize> {
+        send_msg(&self.0, buf, fds, None)
+    }
+
+    /// Reads from the socket into `buf`, storing any file descriptors passed
+    /// along with the data in `fds`.
+    ///
+    /// Returns the number of bytes read and the number of descriptors
+    /// received. The caller takes ownership of the received descriptors,
+    /// which are close-on-exec. Any which don't fit in `fds` are closed.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn recv_with_fds(&self, buf: &mut [u8],
+                         fds: &mut [RawFd]) -> io::Result<(usize, usize)> {
+        recv_msg(&self.0, buf, fds).map(|(n, nfds, _)| (n, nfds))
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl Read for UnixStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(&mut &*self, buf)
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl<'a> Read for &'a UnixStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl Write for UnixStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::Write::write(&mut &*self, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::Write::flush(&mut &*self)
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl<'a> Write for &'a UnixStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl AsRawFd for UnixStream {
+    fn as_raw_fd(&self) -> RawFd {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl FromRawFd for UnixStream {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixStream {
+        UnixStream(Socket::from_inner(fd))
+    }
+}
+
+fn shutdown(sock: &Socket, how: Shutdown) -> io::Result<()> {
+    let how = match how {
+        Shutdown::Read => libc::SHUT_RD,
+        Shutdown::Write => libc::SHUT_WR,
+        Shutdown::Both => libc::consts::os::bsd44::SHUT_RDWR,
+    };
+    try!(cvt(unsafe { libc::shutdown(*sock.as_inner(), how) }));
+    Ok(())
+}
+
+/// A structure representing a Unix domain socket server.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket)]
+///
+/// use std::thread;
+/// use std::os::unix::net::{UnixStream, UnixListener};
+///
+/// fn handle_client(stream: UnixStream) {
+///     // ...
+/// }
+///
+/// let listener = UnixListener::bind("/path/to/the/socket").unwrap();
+///
+/// // accept connections and process them, spawning a new thread for each one
+/// for stream in listener.incoming() {
+///     match stream {
+///         Ok(stream) => {
+///             /* connection succeeded */
+///             thread::spawn(|| handle_client(stream));
+///         }
+///         Err(err) => {
+///             /* connection failed */
+///             break;
+///         }
+///     }
+/// }
+///
+/// // close the listener socket
+/// drop(listener);
+/// ```
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct UnixListener(Socket);
+
+impl fmt::Debug for UnixListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixListener {
+    /// Creates a new `UnixListener` bound to the specified socket.
+    ///
+    /// The socket file is created by this call and isn't removed when the
+    /// listener is dropped; binding fails if it already exists.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixListener> {
+        UnixListener::bind_addr(&try!(SocketAddr::from_pathname(path)))
+    }
+
+    /// Creates a new `UnixListener` bound to `addr`, which may be in the
+    /// abstract namespace.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn bind_addr(addr: &SocketAddr) -> io::Result<UnixListener> {
+        let inner = try!(Socket::new_raw(libc::AF_UNIX, libc::SOCK_STREAM));
+        try!(cvt(unsafe {
+            libc::bind(*inner.as_inner(), &addr.addr as *const _ as *const _, addr.len)
+        }));
+        try!(cvt(unsafe { libc::listen(*inner.as_inner(), 128) }));
+        Ok(UnixListener(inner))
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new Unix connection
+    /// is established. When established, the corersponding `UnixStream` and
+    /// the remote peer's address will be returned.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn accept(&self) -> io::Result<(UnixStream, SocketAddr)> {
+        let mut addr: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&addr) as socklen_t;
+        let sock = try!(self.0.accept(&mut addr as *mut _ as *mut _, &mut len));
+        let addr = try!(SocketAddr::from_parts(addr, len));
+        Ok((UnixStream(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixListener` is a reference to the same socket that this
+    /// object references. Both handles can be used to accept incoming
+    /// connections and options set on one listener will affect the other.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn try_clone(&self) -> io::Result<UnixListener> {
+        self.0.duplicate().map(UnixListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns an iterator over incoming connections.
+    ///
+    /// The iterator will never return `None` and will also not yield the
+    /// peer's `SocketAddr` structure.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn incoming<'a>(&'a self) -> Incoming<'a> {
+        Incoming { listener: self }
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl AsRawFd for UnixListener {
+    fn as_raw_fd(&self) -> RawFd {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl FromRawFd for UnixListener {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixListener {
+        UnixListener(Socket::from_inner(fd))
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl<'a> IntoIterator for &'a UnixListener {
+    type Item = io::Result<UnixStream>;
+    type IntoIter = Incoming<'a>;
+
+    fn into_iter(self) -> Incoming<'a> {
+        self.incoming()
+    }
+}
+
+/// An iterator over incoming connections to a `UnixListener`.
+///
+/// It will never return `None`.
+#[derive(Debug)]
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct Incoming<'a> {
+    listener: &'a UnixListener,
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl<'a> Iterator for Incoming<'a> {
+    type Item = io::Result<UnixStream>;
+
+    fn next(&mut self) -> Option<io::Result<UnixStream>> {
+        Some(self.listener.accept().map(|s| s.0))
+    }
+}
+
+/// A Unix datagram socket.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_socket)]
+///
+/// use std::os::unix::net::UnixDatagram;
+///
+/// let socket = UnixDatagram::bind("/path/to/my/socket").unwrap();
+/// socket.send_to(b"hello world", "/path/to/other/socket").unwrap();
+/// let mut buf = [0; 100];
+/// let (count, address) = socket.recv_from(&mut buf).unwrap();
+/// println!("socket {:?} sent {:?}", address, &buf[..count]);
+/// ```
+#[unstable(feature = "unix_socket", reason = "newly added")]
+pub struct UnixDatagram(Socket);
+
+impl fmt::Debug for UnixDatagram {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixDatagram");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixDatagram {
+    /// Creates a Unix datagram socket bound to the given path.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixDatagram> {
+        UnixDatagram::bind_addr(&try!(SocketAddr::from_pathname(path)))
+    }
+
+    /// Creates a Unix datagram socket bound to `addr`, which may be in the
+    /// abstract namespace.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn bind_addr(addr: &SocketAddr) -> io::Result<UnixDatagram> {
+        let socket = try!(UnixDatagram::unbound());
+        try!(cvt(unsafe {
+            libc::bind(*socket.0.as_inner(), &addr.addr as *const _ as *const _, addr.len)
+        }));
+        Ok(socket)
+    }
+
+    /// Creates a Unix datagram socket which is not bound to any address.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn unbound() -> io::Result<UnixDatagram> {
+        let inner = try!(Socket::new_raw(libc::AF_UNIX, libc::SOCK_DGRAM));
+        Ok(UnixDatagram(inner))
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixDatagrams`s which are connected to each other.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn pair() -> io::Result<(UnixDatagram, UnixDatagram)> {
+        let (a, b) = try!(Socket::new_pair(libc::AF_UNIX, libc::SOCK_DGRAM));
+        Ok((UnixDatagram(a), UnixDatagram(b)))
+    }
+
+    /// Connects the socket to the specified path.
+    ///
+    /// The `send` method may be used to send data to the specified address.
+    /// `recv` and `recv_from` will only receive data from that address.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn connect<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.connect_addr(&try!(SocketAddr::from_pathname(path)))
+    }
+
+    /// Connects the socket to `addr`, which may be in the abstract namespace.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn connect_addr(&self, addr: &SocketAddr) -> io::Result<()> {
+        try!(cvt(unsafe {
+            libc::connect(*self.0.as_inner(), &addr.addr as *const _ as *const _, addr.len)
+        }));
+        Ok(())
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixDatagram` is a reference to the same socket that this
+    /// object references. Both handles can be used to accept incoming
+    /// connections and options set on one side will affect the other.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn try_clone(&self) -> io::Result<UnixDatagram> {
+        self.0.duplicate().map(UnixDatagram)
+    }
+
+    /// Returns the address of this socket.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Returns the address of this socket's peer.
+    ///
+    /// The `connect` method will connect the socket to a peer.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(*self.0.as_inner(), addr, len) })
+    }
+
+    /// Receives data from the socket.
+    ///
+    /// On success, returns the number of bytes read and the address from
+    /// whence the data came.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        let mut count = 0;
+        let addr = try!(SocketAddr::new(|addr, len| {
+            unsafe {
+                count = libc::recvfrom(*self.0.as_inner(),
+                                       buf.as_mut_ptr() as *mut c_void,
+                                       buf.len() as libc::size_t,
+                                       0,
+                                       addr,
+                                       len);
+                if count > 0 {
+                    1
+                } else if count == 0 {
+                    0
+                } else {
+                    -1
+                }
+            }
+        }));
+
+        Ok((count as usize, addr))
+    }
+
+    /// Receives data from the socket.
+    ///
+    /// On success, returns the number of bytes read.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Sends data on the socket to the specified address.
+    ///
+    /// On success, returns the number of bytes written.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send_to<P: AsRef<Path>>(&self, buf: &[u8], path: P) -> io::Result<usize> {
+        self.send_to_addr(buf, &try!(SocketAddr::from_pathname(path)))
+    }
+
+    /// Sends data on the socket to `addr`, which may be in the abstract
+    /// namespace.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send_to_addr(&self, buf: &[u8], addr: &SocketAddr) -> io::Result<usize> {
+        let count = try!(cvt(unsafe {
+            libc::sendto(*self.0.as_inner(),
+                         buf.as_ptr() as *const c_void,
+                         buf.len() as libc::size_t,
+                         0,
+                         &addr.addr as *const _ as *const _,
+                         addr.len)
+        }));
+        Ok(count as usize)
+    }
+
+    /// Sends data on the socket to the socket's peer.
+    ///
+    /// The peer address may be set by the `connect` method, and this method
+    /// will return an error if the socket has not already been connected.
+    ///
+    /// On success, returns the number of bytes written.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    /// Sends `buf` to the socket's peer along with the file descriptors in
+    /// `fds`.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send_with_fds(&self, buf: &[u8], fds: &[RawFd]) -> io::Result<usize> {
+        send_msg(&self.0, buf, fds, None)
+    }
+
+    /// Sends `buf` to `addr` along with the file descriptors in `fds`.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn send_to_with_fds(&self, buf: &[u8], fds: &[RawFd],
+                            addr: &SocketAddr) -> io::Result<usize> {
+        send_msg(&self.0, buf, fds, Some(addr))
+    }
+
+    /// Receives a datagram, storing any file descriptors passed along with it
+    /// in `fds`.
+    ///
+    /// Returns the number of bytes read, the number of descriptors received
+    /// and the address of the sender. The caller takes ownership of the
+    /// received descriptors, which are close-on-exec. Any which don't fit in
+    /// `fds` are closed.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn recv_with_fds(&self, buf: &mut [u8], fds: &mut [RawFd])
+                         -> io::Result<(usize, usize, SocketAddr)> {
+        recv_msg(&self.0, buf, fds)
+    }
+
+    /// Returns the credentials of the process which created the socket's
+    /// peer. Only sockets created by `pair` have credentials.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        peer_cred(&self.0)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is `None`, then `recv` and `recv_from` calls will
+    /// block indefinitely. It is an error to pass the zero `Duration` to this
+    /// method.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is `None`, then `send` and `send_to` calls will
+    /// block indefinitely. It is an error to pass the zero `Duration` to this
+    /// method.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Shut down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of `Shutdown`).
+    #[unstable(feature = "unix_socket", reason = "newly added")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        shutdown(&self.0, how)
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl AsRawFd for UnixDatagram {
+    fn as_raw_fd(&self) -> RawFd {
+        *self.0.as_inner()
+    }
+}
+
+#[unstable(feature = "unix_socket", reason = "newly added")]
+impl FromRawFd for UnixDatagram {
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixDatagram {
+        UnixDatagram(Socket::from_inner(fd))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(deprecated)] //rand
+
+    use prelude::v1::*;
+    use io::prelude::*;
+
+    use env;
+    use fs::{self, File};
+    use io;
+    use os::unix::io::{AsRawFd, FromRawFd};
+    use path::PathBuf;
+    use rand::{self, Rng};
+    use thread;
+    use time::Duration;
+
+    use super::*;
+
+    macro_rules! or_panic {
+        ($e:expr) => {
+            match $e {
+                Ok(e) => e,
+                Err(e) => panic!("{}", e),
+            }
+        }
+    }
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> TempDir {
+            let dir = env::temp_dir().join(&format!("rust-{}", rand::thread_rng().next_u32()));
+            or_panic!(fs::create_dir(&dir));
+            TempDir(dir)
+        }
+
+        fn join(&self, path: &str) -> PathBuf {
+            self.0.join(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            or_panic!(fs::remove_dir_all(&self.0));
+        }
+    }
+
+    #[test]
+    fn basic() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("sock");
+        let msg1 = b"hello";
+        let msg2 = b"world!";
+
+        let listener = or_panic!(UnixListener::bind(&socket_path));
+        let thread = thread::spawn(move || {
+            let mut stream = or_panic!(listener.accept()).0;
+            let mut buf = [0; 5];
+            or_panic!(stream.read(&mut buf));
+            assert_eq!(&msg1[..], &buf[..]);
+            or_panic!(stream.write_all(msg2));
+        });
+
+        let mut stream = or_panic!(UnixStream::connect(&socket_path));
+        assert_eq!(Some(&*socket_path),
+                   stream.peer_addr().unwrap().as_pathname());
+        or_panic!(stream.write_all(msg1));
+        let mut buf = vec![];
+        or_panic!(stream.read_to_end(&mut buf));
+        assert_eq!(&msg2[..], &buf[..]);
+        drop(stream);
+
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn pair() {
+        let msg1 = b"hello";
+        let msg2 = b"world!";
+
+        let (mut s1, mut s2) = or_panic!(UnixStream::pair());
+        let thread = thread::spawn(move || {
+            // s1 must be moved in or the test will hang!
+            let mut buf = [0; 5];
+            or_panic!(s1.read(&mut buf));
+            assert_eq!(&msg1[..], &buf[..]);
+            or_panic!(s1.write_all(msg2));
+        });
+
+        or_panic!(s2.write_all(msg1));
+        let mut buf = vec![];
+        or_panic!(s2.read_to_end(&mut buf));
+        assert_eq!(&msg2[..], &buf[..]);
+        drop(s2);
+
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn try_clone() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("sock");
+        let msg1 = b"hello";
+        let msg2 = b"world";
+
+        let listener = or_panic!(UnixListener::bind(&socket_path));
+        let thread = thread::spawn(move || {
+            let mut stream = or_panic!(listener.accept()).0;
+            or_panic!(stream.write_all(msg1));
+            or_panic!(stream.write_all(msg2));
+        });
+
+        let mut stream = or_panic!(UnixStream::connect(&socket_path));
+        let mut stream2 = or_panic!(stream.try_clone());
+
+        let mut buf = [0; 5];
+        or_panic!(stream.read(&mut buf));
+        assert_eq!(&msg1[..], &buf[..]);
+        or_panic!(stream2.read(&mut buf));
+        assert_eq!(&msg2[..], &buf[..]);
+
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn iter() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("sock");
+
+        let listener = or_panic!(UnixListener::bind(&socket_path));
+        let thread = thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = or_panic!(stream);
+                let mut buf = [0];
+                or_panic!(stream.read(&mut buf));
+            }
+        });
+
+        for _ in 0..2 {
+            let mut stream = or_panic!(UnixStream::connect(&socket_path));
+            or_panic!(stream.write_all(&[0]));
+        }
+
+        thread.join().unwrap();
+    }
+
+    #[test]
+    fn long_path() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("asdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdfasdf\
+                                    sasdfasdfasdasdfasdfasdfadfasdfasdfasdfasdfasdf");
+        match UnixStream::connect(&socket_path) {
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput => {}
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(_) => panic!("unexpected success"),
+        }
+
+        match UnixListener::bind(&socket_path) {
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput => {}
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(_) => panic!("unexpected success"),
+        }
+
+        match UnixDatagram::bind(&socket_path) {
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput => {}
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(_) => panic!("unexpected success"),
+        }
+    }
+
+    #[test]
+    fn timeouts() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("sock");
+
+        let _listener = or_panic!(UnixListener::bind(&socket_path));
+
+        let stream = or_panic!(UnixStream::connect(&socket_path));
+        let dur = Duration::new(15410, 0);
+
+        assert_eq!(None, or_panic!(stream.read_timeout()));
+
+        or_panic!(stream.set_read_timeout(Some(dur)));
+        assert_eq!(Some(dur), or_panic!(stream.read_timeout()));
+
+        assert_eq!(None, or_panic!(stream.write_timeout()));
+
+        or_panic!(stream.set_write_timeout(Some(dur)));
+        assert_eq!(Some(dur), or_panic!(stream.write_timeout()));
+
+        or_panic!(stream.set_read_timeout(None));
+        assert_eq!(None, or_panic!(stream.read_timeout()));
+
+        or_panic!(stream.set_write_timeout(None));
+        assert_eq!(None, or_panic!(stream.write_timeout()));
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        let dir = TempDir::new();
+        let socket_path = dir.join("sock");
+
+        let _listener = or_panic!(UnixListener::bind(&socket_path));
+
+        let mut stream = or_panic!(UnixStream::connect(&socket_path));
+        or_panic!(stream.set_read_timeout(Some(Duration::new(0, 1000))));
+
+        let mut buf = [0; 10];
+        let kind = stream.read(&mut buf).err().expect("expected error").kind();
+        assert!(kind == io::ErrorKind::WouldBlock || kind == io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn test_unix_datagram() {
+        let dir = TempDir::new();
+        let path1 = dir.join("sock1");
+        let path2 = dir.join("sock2");
+
+        let sock1 = or_panic!(UnixDatagram::bind(&path1));
+        let sock2 = or_panic!(UnixDatagram::bind(&path2));
+
+        let msg = b"hello world";
+        or_panic!(sock1.send_to(msg, &path2));
+        let mut buf = [0; 11];
+        let (n, addr) = or_panic!(sock2.recv_from(&mut buf));
+        assert_eq!(n, msg.len());
+        assert_eq!(&msg[..], &buf[..]);
+        assert_eq!(Some(&*path1), addr.as_pathname());
+    }
+
+    #[test]
+    fn test_unnamed_unix_datagram() {
+        let dir = TempDir::new();
+        let path1 = dir.join("sock1");
+
+        let sock1 = or_panic!(UnixDatagram::bind(&path1));
+        let sock2 = or_panic!(UnixDatagram::unbound());
+
+        let msg = b"hello world";
+        or_panic!(sock2.send_to(msg, &path1));
+        let mut buf = [0; 11];
+        let (size, addr) = or_panic!(sock1.recv_from(&mut buf));
+        assert_eq!(size, 11);
+        assert!(addr.is_unnamed());
+        assert_eq!(msg, &buf[..]);
+    }
+
+    #[test]
+    fn test_unix_datagram_pair() {
+        let msg1 = b"hello";
+        let msg2 = b"world!";
+
+        let (s1, s2) = or_panic!(UnixDatagram::pair());
+        or_panic!(s1.send(msg1));
+        let mut buf = [0; 5];
+        or_panic!(s2.recv(&mut buf));
+        assert_eq!(&msg1[..], &buf[..]);
+
+        or_panic!(s2.send(msg2));
+        let mut buf = [0; 6];
+        or_panic!(s1.recv(&mut buf));
+        assert_eq!(&msg2[..], &buf[..]);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn abstract_address() {
+        let name = format!("rust-{}", rand::thread_rng().next_u32());
+        let addr = or_panic!(SocketAddr::from_abstract_name(name.as_bytes()));
+
+        let listener = or_panic!(UnixListener::bind_addr(&addr));
+        let local = or_panic!(listener.local_addr());
+        assert_eq!(Some(name.as_bytes()), local.as_abstract_name());
+        assert!(local.as_pathname().is_none());
+
+        let mut stream = or_panic!(UnixStream::connect_addr(&addr));
+        let mut accepted = or_panic!(listener.accept()).0;
+        or_panic!(stream.write_all(b"hi"));
+        let mut buf = [0; 2];
+        or_panic!(accepted.read(&mut buf));
+        assert_eq!(b"hi", &buf);
+    }
+
+    #[test]
+    fn peer_cred() {
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        let cred1 = or_panic!(s1.peer_cred());
+        let cred2 = or_panic!(s2.peer_cred());
+        assert_eq!(cred1, cred2);
+    }
+
+    #[test]
+    fn pass_fds() {
+        let dir = TempDir::new();
+        let path = dir.join("file");
+        or_panic!(or_panic!(File::create(&path)).write_all(b"passed"));
+        let file = or_panic!(File::open(&path));
+
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        assert_eq!(or_panic!(s1.send_with_fds(b"x", &[file.as_raw_fd()])), 1);
+        drop(file);
+
+        let mut buf = [0; 1];
+        let mut fds = [-1; 2];
+        let (n, nfds) = or_panic!(s2.recv_with_fds(&mut buf, &mut fds));
+        assert_eq!((n, nfds), (1, 1));
+        assert_eq!(&buf, b"x");
+
+        let mut file = unsafe { File::from_raw_fd(fds[0]) };
+        let mut contents = String::new();
+        or_panic!(file.read_to_string(&mut contents));
+        assert_eq!(contents, "passed");
+    }
+
+    #[test]
+    fn pass_fds_datagram() {
+        let (s1, s2) = or_panic!(UnixDatagram::pair());
+        let (a, b) = or_panic!(UnixStream::pair());
+        or_panic!(s1.send_with_fds(b"", &[a.as_raw_fd()]));
+
+        let mut buf = [0; 1];
+        let mut fds = [-1; 1];
+        let (n, nfds, _) = or_panic!(s2.recv_with_fds(&mut buf, &mut fds));
+        assert_eq!((n, nfds), (0, 1));
+
+        // The received descriptor is another handle to `a`.
+        let mut a2 = unsafe { UnixStream::from_raw_fd(fds[0]) };
+        drop(a);
+        or_panic!(a2.write_all(b"y"));
+        let mut b = b;
+        or_panic!(b.read(&mut buf));
+        assert_eq!(&buf, b"y");
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Waiting for readiness on many file descriptors at once.
+//!
+//! A `Poll` holds a set of registered file descriptors, each with a `Token`
+//! chosen by the caller and the kinds of readiness it is interested in. A
+//! call to `poll` waits until at least one of them is ready and reports
+//! which ones are through `Events`.
+//!
+//! Readiness is level-triggered: a descriptor keeps being reported for as
+//! long as it stays ready. Registered descriptors are usually put in
+//! nonblocking mode, so that an operation which turns out not to be ready
+//! after all fails with `ErrorKind::WouldBlock` instead of blocking.
+//!
+//! This is currently only available on Linux and Android, where it is
+//! implemented with `epoll`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! #![feature(unix_poll, net_nonblocking)]
+//!
+//! use std::io::{self, Read};
+//! use std::net::TcpStream;
+//! use std::os::unix::poll::{Poll, Events, Interest, Token};
+//!
+//! let mut stream = TcpStream::connect("127.0.0.1:8080").unwrap();
+//! stream.set_nonblocking(true).unwrap();
+//!
+//! let poll = Poll::new().unwrap();
+//! poll.register(&stream, Token(0), Interest::Read).unwrap();
+//!
+//! let mut events = Events::with_capacity(16);
+//! let mut buf = [0; 1024];
+//! loop {
+//!     poll.poll(&mut events, None).unwrap();
+//!     for event in &events {
+//!         assert_eq!(event.token(), Token(0));
+//!         match stream.read(&mut buf) {
+//!             Ok(0) => return,
+//!             Ok(n) => println!("read {} bytes", n),
+//!             Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {}
+//!             Err(e) => panic!("{}", e),
+//!         }
+//!     }
+//! }
+//! ```
+
+#![unstable(feature = "unix_poll", reason = "recently added")]
+
+use prelude::v1::*;
+
+use cmp;
+use fmt;
+use io;
+use os::unix::io::{AsRawFd, RawFd};
+use sys::c;
+use sys::poll as imp;
+use time::Duration;
+
+/// An identifier for a registered file descriptor, reported back in the
+/// events for that descriptor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub struct Token(pub usize);
+
+/// The kinds of readiness a registered file descriptor is watched for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub enum Interest {
+    /// Readiness for reading, or for accepting a connection.
+    Read,
+    /// Readiness for writing.
+    Write,
+    /// Readiness for either reading or writing.
+    ReadWrite,
+}
+
+impl Interest {
+    fn events(&self) -> u32 {
+        match *self {
+            Interest::Read => c::EPOLLIN | c::EPOLLRDHUP,
+            Interest::Write => c::EPOLLOUT,
+            Interest::ReadWrite => c::EPOLLIN | c::EPOLLRDHUP | c::EPOLLOUT,
+        }
+    }
+}
+
+/// A set of file descriptors to wait on.
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub struct Poll(imp::Epoll);
+
+impl Poll {
+    /// Creates a new `Poll` with nothing registered.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn new() -> io::Result<Poll> {
+        imp::Epoll::new().map(Poll)
+    }
+
+    /// Starts watching `fd` for the readiness in `interest`, reporting it
+    /// with `token`.
+    ///
+    /// A file descriptor can only be registered once. The registration is
+    /// removed automatically once every copy of the descriptor is closed, but
+    /// should be removed with `deregister` before then.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn register<F: AsRawFd + ?Sized>(&self, fd: &F, token: Token,
+                                         interest: Interest) -> io::Result<()> {
+        self.0.add(fd.as_raw_fd(), interest.events(), token.0 as u64)
+    }
+
+    /// Changes the token and interest of a file descriptor which has already
+    /// been registered.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn reregister<F: AsRawFd + ?Sized>(&self, fd: &F, token: Token,
+                                           interest: Interest) -> io::Result<()> {
+        self.0.modify(fd.as_raw_fd(), interest.events(), token.0 as u64)
+    }
+
+    /// Stops watching `fd`.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn deregister<F: AsRawFd + ?Sized>(&self, fd: &F) -> io::Result<()> {
+        self.0.delete(fd.as_raw_fd())
+    }
+
+    /// Waits for at least one registered file descriptor to become ready, and
+    /// fills `events` with as many of the ready ones as it can hold.
+    ///
+    /// If `timeout` is `None` this blocks until something is ready. Otherwise
+    /// it returns after at most about `timeout`, with no events if nothing
+    /// became ready in that time. Returns the number of events received.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn poll(&self, events: &mut Events,
+                timeout: Option<Duration>) -> io::Result<usize> {
+        try!(self.0.wait(&mut events.inner, timeout));
+        Ok(events.len())
+    }
+}
+
+#[unstable(feature = "unix_poll", reason = "recently added")]
+impl AsRawFd for Poll {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.fd().raw()
+    }
+}
+
+impl fmt::Debug for Poll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Poll").field("fd", &self.as_raw_fd()).finish()
+    }
+}
+
+/// A buffer for the events received by `Poll::poll`.
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub struct Events {
+    inner: Vec<c::epoll_event>,
+}
+
+impl Events {
+    /// Creates a buffer with room for up to `capacity` events at a time.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn with_capacity(capacity: usize) -> Events {
+        // epoll_wait refuses to return no events at all.
+        Events { inner: Vec::with_capacity(cmp::max(capacity, 1)) }
+    }
+
+    /// Returns the number of events received by the last call to `poll`.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the last call to `poll` received no events.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the event at `index`, if there is one.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn get(&self, index: usize) -> Option<Event> {
+        self.inner.get(index).map(|event| Event { kind: event.events, data: event.data })
+    }
+
+    /// Returns an iterator over the events received by the last call to
+    /// `poll`.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn iter(&self) -> Iter {
+        Iter { events: self, pos: 0 }
+    }
+}
+
+impl fmt::Debug for Events {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+#[unstable(feature = "unix_poll", reason = "recently added")]
+impl<'a> IntoIterator for &'a Events {
+    type Item = Event;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the events in an `Events`.
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub struct Iter<'a> {
+    events: &'a Events,
+    pos: usize,
+}
+
+#[unstable(feature = "unix_poll", reason = "recently added")]
+impl<'a> Iterator for Iter<'a> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        let event = self.events.get(self.pos);
+        if event.is_some() {
+            self.pos += 1;
+        }
+        event
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.events.len() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+/// The readiness of a registered file descriptor.
+#[derive(Copy, Clone)]
+#[unstable(feature = "unix_poll", reason = "recently added")]
+pub struct Event {
+    kind: u32,
+    data: u64,
+}
+
+impl Event {
+    /// Returns the token the file descriptor was registered with.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn token(&self) -> Token {
+        Token(self.data as usize)
+    }
+
+    /// Returns true if the file descriptor can be read from without
+    /// blocking.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn is_readable(&self) -> bool {
+        self.kind & (c::EPOLLIN | c::EPOLLPRI) != 0
+    }
+
+    /// Returns true if the file descriptor can be written to without
+    /// blocking.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn is_writable(&self) -> bool {
+        self.kind & c::EPOLLOUT != 0
+    }
+
+    /// Returns true if an error is pending on the file descriptor. This is
+    /// reported whatever the registered interest.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn is_error(&self) -> bool {
+        self.kind & c::EPOLLERR != 0
+    }
+
+    /// Returns true if the other end has hung up, or shut down its writing
+    /// half. Reads will then return whatever data is left, followed by
+    /// end-of-file.
+    #[unstable(feature = "unix_poll", reason = "recently added")]
+    pub fn is_hup(&self) -> bool {
+        self.kind & (c::EPOLLHUP | c::EPOLLRDHUP) != 0
+    }
+}
+
+impl fmt::Debug for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Event")
+         .field("token", &self.token())
+         .field("readable", &self.is_readable())
+         .field("writable", &self.is_writable())
+         .field("error", &self.is_error())
+         .field("hup", &self.is_hup())
+         .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+    use io::prelude::*;
+
+    use os::unix::net::UnixStream;
+    use time::Duration;
+
+    use super::*;
+
+    macro_rules! t {
+        ($e:expr) => {
+            match $e {
+                Ok(e) => e,
+                Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+            }
+        }
+    }
+
+    #[test]
+    fn timeout() {
+        let (a, _b) = t!(UnixStream::pair());
+        let poll = t!(Poll::new());
+        t!(poll.register(&a, Token(1), Interest::Read));
+
+        let mut events = Events::with_capacity(4);
+        assert_eq!(t!(poll.poll(&mut events, Some(Duration::from_millis(10)))), 0);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn readable_and_writable() {
+        let (a, mut b) = t!(UnixStream::pair());
+        let poll = t!(Poll::new());
+        t!(poll.register(&a, Token(1), Interest::ReadWrite));
+
+        let mut events = Events::with_capacity(4);
+        assert_eq!(t!(poll.poll(&mut events, None)), 1);
+        let event = events.get(0).unwrap();
+        assert_eq!(event.token(), Token(1));
+        assert!(event.is_writable());
+        assert!(!event.is_readable());
+
+        t!(b.write_all(b"hello"));
+        t!(poll.reregister(&a, Token(2), Interest::Read));
+        assert_eq!(t!(poll.poll(&mut events, None)), 1);
+        let event = events.iter().next().unwrap();
+        assert_eq!(event.token(), Token(2));
+        assert!(event.is_readable());
+        assert!(!event.is_writable());
+
+        drop(b);
+        t!(poll.poll(&mut events, None));
+        assert!(events.get(0).unwrap().is_hup());
+
+        t!(poll.deregister(&a));
+        t!(poll.poll(&mut events, Some(Duration::from_millis(10))));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn many() {
+        let pairs = (0..8).map(|_| t!(UnixStream::pair())).collect::<Vec<_>>();
+        let poll = t!(Poll::new());
+        for (i, &(ref a, _)) in pairs.iter().enumerate() {
+            t!(poll.register(a, Token(i), Interest::Read));
+        }
+        for (_, &(_, ref b)) in pairs.iter().enumerate().filter(|&(i, _)| i % 2 == 0) {
+            t!((&*b).write_all(b"x"));
+        }
+
+        let mut events = Events::with_capacity(8);
+        assert_eq!(t!(poll.poll(&mut events, None)), 4);
+        let mut tokens = events.iter().map(|e| e.token().0).collect::<Vec<_>>();
+        tokens.sort();
+        assert_eq!(tokens, [0, 2, 4, 6]);
+
+        // Only as many events as fit in the buffer are returned.
+        let mut events = Events::with_capacity(3);
+        assert_eq!(t!(poll.poll(&mut events, None)), 3);
+    }
+}
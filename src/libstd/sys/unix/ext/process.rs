@@ -12,9 +12,10 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use os::unix::raw::{uid_t, gid_t};
+use os::unix::raw::{uid_t, gid_t, pid_t};
 use os::unix::io::{FromRawFd, RawFd, AsRawFd};
 use prelude::v1::*;
+use io;
 use process;
 use sys;
 use sys_common::{AsInnerMut, AsInner, FromInner};
@@ -32,6 +33,52 @@ pub trait CommandExt {
     /// the same semantics as the `uid` field.
     #[stable(feature = "rust1", since = "1.0.0")]
     fn gid(&mut self, id: gid_t) -> &mut process::Command;
+
+    /// Starts the child in a new session, of which it is the leader, by
+    /// calling `setsid` in the child. The child is then also the leader of a
+    /// new process group and has no controlling terminal.
+    ///
+    /// This can't be combined with `process_group`, and spawning a command
+    /// which asks for both fails with an `InvalidInput` error.
+    #[unstable(feature = "process_session_leader",
+               reason = "recently added")]
+    fn session_leader(&mut self, on: bool) -> &mut process::Command;
+
+    /// Moves the child into the process group `pgroup` by calling `setpgid`
+    /// in the child. If `pgroup` is 0, the child is made the leader of a new
+    /// process group whose id is the child's pid.
+    ///
+    /// Failure in the `setpgid` call will cause the spawn to fail, as will
+    /// combining this with `session_leader`.
+    #[unstable(feature = "process_group", reason = "recently added")]
+    fn process_group(&mut self, pgroup: pid_t) -> &mut process::Command;
+
+    /// Makes the file descriptor `parent_fd` of this process available in
+    /// the child as `child_fd`.
+    ///
+    /// The descriptors are put in place after the child's stdio has been set
+    /// up, so a `child_fd` of 0, 1 or 2 takes precedence over the `stdin`,
+    /// `stdout` or `stderr` configuration. A `parent_fd` is only borrowed,
+    /// and must remain open until the child has been spawned. Failure to
+    /// duplicate any of the descriptors will cause the spawn to fail.
+    #[unstable(feature = "process_fd", reason = "recently added")]
+    fn fd(&mut self, child_fd: RawFd, parent_fd: RawFd) -> &mut process::Command;
+
+    /// Schedules a closure to be run in the child just before it calls
+    /// `exec`, after all other configuration has been applied. Closures are
+    /// run in the order they were added.
+    ///
+    /// If a closure returns an error, the child exits without calling `exec`
+    /// and the spawn fails with that error.
+    ///
+    /// The closure runs in a copy of this process created by `fork`, in
+    /// which only the calling thread exists. Locks held by other threads at
+    /// the time of the fork are never released, so the closure should
+    /// restrict itself to making system calls; in particular allocating
+    /// memory can deadlock.
+    #[unstable(feature = "process_exec", reason = "recently added")]
+    fn before_exec<F>(&mut self, f: F) -> &mut process::Command
+        where F: Fn() -> io::Result<()> + Send + Sync + 'static;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -45,6 +92,53 @@ impl CommandExt for process::Command {
         self.as_inner_mut().gid = Some(id);
         self
     }
+
+    fn session_leader(&mut self, on: bool) -> &mut process::Command {
+        self.as_inner_mut().detach = on;
+        self
+    }
+
+    fn process_group(&mut self, pgroup: pid_t) -> &mut process::Command {
+        self.as_inner_mut().pgroup = Some(pgroup);
+        self
+    }
+
+    fn fd(&mut self, child_fd: RawFd, parent_fd: RawFd) -> &mut process::Command {
+        self.as_inner_mut().fds.push((child_fd, parent_fd));
+        self
+    }
+
+    fn before_exec<F>(&mut self, f: F) -> &mut process::Command
+        where F: Fn() -> io::Result<()> + Send + Sync + 'static
+    {
+        self.as_inner_mut().closures.push(Box::new(f));
+        self
+    }
+}
+
+/// Unix-specific extensions to `std::process::Child`
+#[unstable(feature = "process_signal", reason = "recently added")]
+pub trait ChildExt {
+    /// Sends the signal `signal` to the child.
+    ///
+    /// This fails with `InvalidInput` if the child is known to have exited,
+    /// as its pid may since have been reused by another process.
+    #[unstable(feature = "process_signal", reason = "recently added")]
+    fn signal(&mut self, signal: i32) -> io::Result<()>;
+}
+
+#[unstable(feature = "process_signal", reason = "recently added")]
+impl ChildExt for process::Child {
+    fn signal(&mut self, signal: i32) -> io::Result<()> {
+        // See `Child::kill` for why the status is collected first.
+        if try!(self.try_wait()).is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid argument: can't signal an exited process",
+            ))
+        }
+        unsafe { self.as_inner().signal(signal) }
+    }
 }
 
 /// Unix-specific extensions to `std::process::ExitStatus`
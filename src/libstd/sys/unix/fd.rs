@@ -53,6 +53,21 @@ impl FileDesc {
         Ok(ret as usize)
     }
 
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        unsafe {
+            let previous = try!(cvt(libc::fcntl(self.fd, libc::F_GETFL)));
+            let new = if nonblocking {
+                previous | libc::O_NONBLOCK
+            } else {
+                previous & !libc::O_NONBLOCK
+            };
+            if new != previous {
+                try!(cvt(libc::fcntl(self.fd, libc::F_SETFL, new)));
+            }
+            Ok(())
+        }
+    }
+
     pub fn set_cloexec(&self) {
         unsafe {
             let ret = c::ioctl(self.fd, c::FIOCLEX);
@@ -19,6 +19,7 @@ use libc::{self, c_int, size_t, off_t, c_char, mode_t};
 use mem;
 use path::{Path, PathBuf};
 use ptr;
+use slice;
 use sync::Arc;
 use sys::fd::FileDesc;
 use sys::platform::raw;
@@ -330,9 +331,108 @@ impl File {
         Ok(n as u64)
     }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_SH)
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_EX)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_SH)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_EX)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_UN)
+    }
+
+    fn flock(&self, operation: c_int) -> io::Result<()> {
+        try!(cvt_r(|| unsafe { c::flock(self.0.raw(), operation) }));
+        Ok(())
+    }
+
+    fn try_flock(&self, operation: c_int) -> io::Result<bool> {
+        match self.flock(operation | libc::LOCK_NB) {
+            Ok(()) => Ok(true),
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn fd(&self) -> &FileDesc { &self.0 }
 }
 
+pub struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    /// Maps the first `len` bytes of `file`, which must have been opened for
+    /// writing if `writable` is set. Writes are shared with the file.
+    pub fn new(file: &File, len: usize, writable: bool) -> io::Result<Mmap> {
+        // mmap refuses empty mappings, so there's nothing to map.
+        if len == 0 {
+            return Ok(Mmap { ptr: ptr::null_mut(), len: 0 })
+        }
+        let prot = if writable {
+            libc::PROT_READ | libc::PROT_WRITE
+        } else {
+            libc::PROT_READ
+        };
+        let ptr = unsafe {
+            libc::mmap(ptr::null_mut(), len as size_t, prot, libc::MAP_SHARED,
+                       file.0.raw(), 0)
+        };
+        if ptr == libc::MAP_FAILED {
+            Err(Error::last_os_error())
+        } else {
+            Ok(Mmap { ptr: ptr, len: len })
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.len == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        if self.len == 0 {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(self.ptr as *mut u8, self.len) }
+        }
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        if self.len != 0 {
+            try!(cvt(unsafe {
+                libc::msync(self.ptr, self.len as size_t, libc::MS_SYNC)
+            }));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            let _ = unsafe { libc::munmap(self.ptr, self.len as size_t) };
+        }
+    }
+}
+
 impl DirBuilder {
     pub fn new() -> DirBuilder {
         DirBuilder { mode: 0o777 }
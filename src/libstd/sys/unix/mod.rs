@@ -39,6 +39,8 @@ pub mod net;
 pub mod os;
 pub mod os_str;
 pub mod pipe;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub mod poll;
 pub mod process;
 pub mod rwlock;
 pub mod stack_overflow;
@@ -0,0 +1,93 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use prelude::v1::*;
+
+use cmp;
+use io;
+use libc::c_int;
+use sys::c;
+use sys::fd::FileDesc;
+use sys::cvt;
+use time::{Duration, Instant};
+use u64;
+
+pub struct Epoll(FileDesc);
+
+impl Epoll {
+    pub fn new() -> io::Result<Epoll> {
+        // The size is ignored by the kernel, but must be positive.
+        let fd = try!(cvt(unsafe { c::epoll_create(1024) }));
+        let fd = FileDesc::new(fd);
+        fd.set_cloexec();
+        Ok(Epoll(fd))
+    }
+
+    pub fn add(&self, fd: c_int, events: u32, data: u64) -> io::Result<()> {
+        self.ctl(c::EPOLL_CTL_ADD, fd, events, data)
+    }
+
+    pub fn modify(&self, fd: c_int, events: u32, data: u64) -> io::Result<()> {
+        self.ctl(c::EPOLL_CTL_MOD, fd, events, data)
+    }
+
+    pub fn delete(&self, fd: c_int) -> io::Result<()> {
+        // The event is ignored, but kernels before 2.6.9 require one anyway.
+        self.ctl(c::EPOLL_CTL_DEL, fd, 0, 0)
+    }
+
+    fn ctl(&self, op: c_int, fd: c_int, events: u32, data: u64) -> io::Result<()> {
+        let mut event = c::epoll_event { events: events, data: data };
+        try!(cvt(unsafe { c::epoll_ctl(self.0.raw(), op, fd, &mut event) }));
+        Ok(())
+    }
+
+    /// Waits for events, filling `events` up to its capacity with the ones
+    /// that are ready.
+    pub fn wait(&self, events: &mut Vec<c::epoll_event>,
+                timeout: Option<Duration>) -> io::Result<()> {
+        let start = Instant::now();
+        unsafe { events.set_len(0) }
+        loop {
+            // If a signal interrupts the wait, only wait for whatever is left
+            // of the timeout when trying again.
+            let ms = match timeout {
+                Some(dur) => {
+                    let left = dur.checked_sub(start.elapsed())
+                                  .unwrap_or(Duration::new(0, 0));
+                    timeout_ms(left)
+                }
+                None => -1,
+            };
+            let n = unsafe {
+                c::epoll_wait(self.0.raw(), events.as_mut_ptr(),
+                              events.capacity() as c_int, ms)
+            };
+            match cvt(n) {
+                Ok(n) => {
+                    unsafe { events.set_len(n as usize) }
+                    return Ok(())
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn fd(&self) -> &FileDesc { &self.0 }
+}
+
+// Rounds up, so that a short timeout doesn't turn into a poll.
+fn timeout_ms(dur: Duration) -> c_int {
+    let ms = dur.secs().checked_mul(1000).and_then(|ms| {
+        ms.checked_add((dur.extra_nanos() as u64 + 999_999) / 1_000_000)
+    }).unwrap_or(u64::MAX);
+    cmp::min(ms, c_int::max_value() as u64) as c_int
+}
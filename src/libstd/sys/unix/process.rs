@@ -11,6 +11,7 @@
 use prelude::v1::*;
 use os::unix::prelude::*;
 
+use cmp;
 use collections::HashMap;
 use env;
 use ffi::{OsString, OsStr, CString, CStr};
@@ -28,7 +29,6 @@ use sys::{self, c, cvt, cvt_r};
 // Command
 ////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Clone)]
 pub struct Command {
     pub program: CString,
     pub args: Vec<CString>,
@@ -36,7 +36,11 @@ pub struct Command {
     pub cwd: Option<CString>,
     pub uid: Option<uid_t>,
     pub gid: Option<gid_t>,
-    pub detach: bool, // not currently exposed in std::process
+    pub detach: bool, // calls setsid() in the child
+    pub pgroup: Option<pid_t>,
+    // (child fd, parent fd) pairs, dup2'd into the child after its stdio
+    pub fds: Vec<(c_int, c_int)>,
+    pub closures: Vec<Box<Fn() -> io::Result<()> + Send + Sync>>,
 }
 
 impl Command {
@@ -49,6 +53,9 @@ impl Command {
             uid: None,
             gid: None,
             detach: false,
+            pgroup: None,
+            fds: Vec::new(),
+            closures: Vec::new(),
         }
     }
 
@@ -133,7 +140,11 @@ const CLOEXEC_MSG_FOOTER: &'static [u8] = b"NOEX";
 
 impl Process {
     pub unsafe fn kill(&self) -> io::Result<()> {
-        try!(cvt(libc::funcs::posix88::signal::kill(self.pid, libc::SIGKILL)));
+        self.signal(libc::SIGKILL)
+    }
+
+    pub unsafe fn signal(&self, signal: c_int) -> io::Result<()> {
+        try!(cvt(libc::funcs::posix88::signal::kill(self.pid, signal)));
         Ok(())
     }
 
@@ -141,18 +152,31 @@ impl Process {
                  in_fd: Stdio,
                  out_fd: Stdio,
                  err_fd: Stdio) -> io::Result<Process> {
+        // A session leader can't be moved to another process group, so the
+        // child's `setpgid` would always fail after `setsid`.
+        if cfg.detach && cfg.pgroup.is_some() {
+            return Err(Error::new(ErrorKind::InvalidInput,
+                                  "a process can't both be a session leader \
+                                   and be moved into a process group"))
+        }
+
         let dirp = cfg.cwd.as_ref().map(|c| c.as_ptr()).unwrap_or(ptr::null());
 
         let (envp, _a, _b) = make_envp(cfg.env.as_ref());
         let (argv, _a) = make_argv(&cfg.program, &cfg.args);
         let (input, output) = try!(sys::pipe::anon_pipe());
 
+        // The child can't allocate, so the space it needs to shuffle the
+        // extra file descriptors around is set aside here.
+        let mut fd_scratch = vec![0; cfg.fds.len()];
+
         let pid = unsafe {
             match libc::fork() {
                 0 => {
                     drop(input);
                     Process::child_after_fork(cfg, output, argv, envp, dirp,
-                                              in_fd, out_fd, err_fd)
+                                              in_fd, out_fd, err_fd,
+                                              &mut fd_scratch)
                 }
                 n if n < 0 => return Err(Error::last_os_error()),
                 n => n,
@@ -236,9 +260,14 @@ impl Process {
                                dirp: *const libc::c_char,
                                in_fd: Stdio,
                                out_fd: Stdio,
-                               err_fd: Stdio) -> ! {
+                               err_fd: Stdio,
+                               fd_scratch: &mut [c_int]) -> ! {
         fn fail(output: &mut AnonPipe) -> ! {
-            let errno = sys::os::errno() as u32;
+            fail_with(output, sys::os::errno())
+        }
+
+        fn fail_with(output: &mut AnonPipe, errno: i32) -> ! {
+            let errno = errno as u32;
             let bytes = [
                 (errno >> 24) as u8,
                 (errno >> 16) as u8,
@@ -279,10 +308,34 @@ impl Process {
             }
         };
 
+        // Move each of the extra file descriptors above every number it
+        // could be asked to occupy, so that installing one can't clobber
+        // another which has yet to be installed, nor can setting up stdio.
+        // These copies aren't close-on-exec, but they're all closed again
+        // once the descriptors are in place. The pipe errors are reported
+        // through is moved out of the way first, keeping it close-on-exec.
+        let min_fd = cfg.fds.iter().fold(libc::STDERR_FILENO, |m, &(dst, _)| {
+            cmp::max(m, dst)
+        }) + 1;
+        let fd = libc::fcntl(output.raw(), c::F_DUPFD_CLOEXEC, min_fd);
+        if fd == -1 { fail(&mut output) }
+        output = AnonPipe::from_fd(fd);
+        for (&(_, src), tmp) in cfg.fds.iter().zip(fd_scratch.iter_mut()) {
+            *tmp = libc::fcntl(src, libc::F_DUPFD, min_fd);
+            if *tmp == -1 { fail(&mut output) }
+        }
+
         if !setup(in_fd, libc::STDIN_FILENO) { fail(&mut output) }
         if !setup(out_fd, libc::STDOUT_FILENO) { fail(&mut output) }
         if !setup(err_fd, libc::STDERR_FILENO) { fail(&mut output) }
 
+        for (&(dst, _), &tmp) in cfg.fds.iter().zip(fd_scratch.iter()) {
+            if cvt_r(|| libc::dup2(tmp, dst)).is_err() { fail(&mut output) }
+        }
+        for &tmp in fd_scratch.iter() {
+            let _ = libc::close(tmp);
+        }
+
         if let Some(u) = cfg.gid {
             if libc::setgid(u as libc::gid_t) != 0 {
                 fail(&mut output);
@@ -308,6 +361,11 @@ impl Process {
             // error, but ignore it anyway.
             let _ = libc::setsid();
         }
+        if let Some(pgroup) = cfg.pgroup {
+            if libc::setpgid(0, pgroup) != 0 {
+                fail(&mut output);
+            }
+        }
         if !dirp.is_null() && libc::chdir(dirp) == -1 {
             fail(&mut output);
         }
@@ -331,6 +389,12 @@ impl Process {
             fail(&mut output);
         }
 
+        for closure in cfg.closures.iter() {
+            if let Err(e) = (**closure)() {
+                fail_with(&mut output, e.raw_os_error().unwrap_or(libc::EINVAL));
+            }
+        }
+
         let _ = libc::execvp(*argv, argv);
         fail(&mut output)
     }
@@ -345,15 +409,14 @@ impl Process {
         Ok(translate_status(status))
     }
 
-    pub fn try_wait(&self) -> Option<ExitStatus> {
+    pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
         let mut status = 0 as c_int;
-        match cvt_r(|| unsafe {
+        match try!(cvt_r(|| unsafe {
             c::waitpid(self.pid, &mut status, c::WNOHANG)
-        }) {
-            Ok(0) => None,
-            Ok(n) if n == self.pid => Some(translate_status(status)),
-            Ok(n) => panic!("unknown pid: {}", n),
-            Err(e) => panic!("unknown waitpid error: {}", e),
+        })) {
+            0 => Ok(None),
+            n if n == self.pid => Ok(Some(translate_status(status))),
+            n => panic!("unknown pid: {}", n),
         }
     }
 }
@@ -25,20 +25,17 @@
 #![allow(dead_code)]
 
 use prelude::v1::*;
-use io::prelude::*;
 
 use dynamic_lib::DynamicLibrary;
 use ffi::CStr;
 use intrinsics;
-use io;
 use libc;
 use mem;
 use path::Path;
 use ptr;
-use str;
 use sync::StaticMutex;
 
-use sys_common::backtrace::*;
+use sys_common::backtrace::RawFrame;
 
 #[allow(non_snake_case)]
 extern "system" {
@@ -292,7 +289,9 @@ impl Drop for Cleanup {
     fn drop(&mut self) { (self.SymCleanup)(self.handle); }
 }
 
-pub fn write(w: &mut Write) -> io::Result<()> {
+/// Walks the stack of the current thread, handing each frame to `f` until it
+/// returns `false`.
+pub fn trace(f: &mut FnMut(&RawFrame) -> bool) {
     // According to windows documentation, all dbghelp functions are
     // single-threaded.
     static LOCK: StaticMutex = StaticMutex::new();
@@ -303,13 +302,13 @@ pub fn write(w: &mut Write) -> io::Result<()> {
     let path = Path::new("dbghelp.dll");
     let lib = match DynamicLibrary::open(Some(&path)) {
         Ok(lib) => lib,
-        Err(..) => return Ok(()),
+        Err(..) => return,
     };
 
     macro_rules! sym{ ($e:expr, $t:ident) => (unsafe {
         match lib.symbol($e) {
             Ok(f) => mem::transmute::<*mut u8, $t>(f),
-            Err(..) => return Ok(())
+            Err(..) => return
         }
     }) }
 
@@ -329,12 +328,10 @@ pub fn write(w: &mut Write) -> io::Result<()> {
 
     // Initialize this process's symbols
     let ret = SymInitialize(process, ptr::null_mut(), libc::TRUE);
-    if ret != libc::TRUE { return Ok(()) }
+    if ret != libc::TRUE { return }
     let _c = Cleanup { handle: process, SymCleanup: SymCleanup };
 
     // And now that we're done with all the setup, do the stack walking!
-    let mut i = 0;
-    try!(write!(w, "stack backtrace:\n"));
     while StackWalk64(image, process, thread, &mut frame, &mut context,
                       ptr::null_mut(),
                       ptr::null_mut(),
@@ -344,8 +341,6 @@ pub fn write(w: &mut Write) -> io::Result<()> {
         if addr == frame.AddrReturn.Offset || addr == 0 ||
            frame.AddrReturn.Offset == 0 { break }
 
-        i += 1;
-        try!(write!(w, "  {:2}: {:#2$x}", i, addr, HEX_WIDTH));
         let mut info: SYMBOL_INFO = unsafe { intrinsics::init() };
         info.MaxNameLen = MAX_SYM_NAME as libc::c_ulong;
         // the struct size in C.  the value is different to
@@ -357,17 +352,14 @@ pub fn write(w: &mut Write) -> io::Result<()> {
         let ret = SymFromAddr(process, addr as u64, &mut displacement,
                               &mut info);
 
-        if ret == libc::TRUE {
-            try!(write!(w, " - "));
+        let name = if ret == libc::TRUE {
             let ptr = info.Name.as_ptr() as *const libc::c_char;
-            let bytes = unsafe { CStr::from_ptr(ptr).to_bytes() };
-            match str::from_utf8(bytes) {
-                Ok(s) => try!(demangle(w, s)),
-                Err(..) => try!(w.write_all(&bytes[..bytes.len()-1])),
-            }
+            Some(unsafe { CStr::from_ptr(ptr).to_bytes() })
+        } else {
+            None
+        };
+        if !f(&RawFrame { ip: addr as usize, name: name, filelines: &[] }) {
+            break
         }
-        try!(w.write_all(&['\n' as u8]));
     }
-
-    Ok(())
 }
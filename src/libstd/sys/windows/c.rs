@@ -51,6 +51,8 @@ pub const WSA_FLAG_OVERLAPPED: libc::DWORD = 0x01;
 pub const WSA_FLAG_NO_HANDLE_INHERIT: libc::DWORD = 0x80;
 
 pub const ERROR_NO_MORE_FILES: libc::DWORD = 18;
+pub const ERROR_LOCK_VIOLATION: libc::DWORD = 33;
+pub const ERROR_NOT_LOCKED: libc::DWORD = 158;
 pub const TOKEN_READ: libc::DWORD = 0x20008;
 pub const FILE_FLAG_OPEN_REPARSE_POINT: libc::DWORD = 0x00200000;
 pub const MAXIMUM_REPARSE_DATA_BUFFER_SIZE: usize = 16 * 1024;
@@ -59,6 +61,9 @@ pub const IO_REPARSE_TAG_SYMLINK: libc::DWORD = 0xa000000c;
 
 pub const SYMBOLIC_LINK_FLAG_DIRECTORY: libc::DWORD = 0x1;
 
+pub const LOCKFILE_FAIL_IMMEDIATELY: libc::DWORD = 0x1;
+pub const LOCKFILE_EXCLUSIVE_LOCK: libc::DWORD = 0x2;
+
 // Note that these are not actually HANDLEs, just values to pass to GetStdHandle
 pub const STD_INPUT_HANDLE: libc::DWORD = -10i32 as libc::DWORD;
 pub const STD_OUTPUT_HANDLE: libc::DWORD = -11i32 as libc::DWORD;
@@ -413,6 +418,19 @@ extern "system" {
     pub fn SetHandleInformation(hObject: libc::HANDLE,
                                 dwMask: libc::DWORD,
                                 dwFlags: libc::DWORD) -> libc::BOOL;
+    pub fn LockFileEx(hFile: libc::HANDLE,
+                      dwFlags: libc::DWORD,
+                      dwReserved: libc::DWORD,
+                      nNumberOfBytesToLockLow: libc::DWORD,
+                      nNumberOfBytesToLockHigh: libc::DWORD,
+                      lpOverlapped: libc::LPOVERLAPPED) -> libc::BOOL;
+    pub fn UnlockFile(hFile: libc::HANDLE,
+                      dwFileOffsetLow: libc::DWORD,
+                      dwFileOffsetHigh: libc::DWORD,
+                      nNumberOfBytesToUnlockLow: libc::DWORD,
+                      nNumberOfBytesToUnlockHigh: libc::DWORD) -> libc::BOOL;
+    pub fn FlushViewOfFile(lpBaseAddress: libc::LPCVOID,
+                           dwNumberOfBytesToFlush: libc::SIZE_T) -> libc::BOOL;
 }
 
 // Functions that aren't available on Windows XP, but we still use them and just
@@ -312,6 +312,47 @@ impl File {
         Ok(newpos as u64)
     }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock(0).map(|_| ())
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.lock(c::LOCKFILE_EXCLUSIVE_LOCK).map(|_| ())
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.lock(c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.lock(c::LOCKFILE_EXCLUSIVE_LOCK | c::LOCKFILE_FAIL_IMMEDIATELY)
+    }
+
+    // Unlocking a file which isn't locked succeeds, as it does with `flock`.
+    pub fn unlock(&self) -> io::Result<()> {
+        match cvt(unsafe {
+            c::UnlockFile(self.handle.raw(), 0, 0, !0, !0)
+        }) {
+            Ok(_) => Ok(()),
+            Err(ref e) if e.raw_os_error() ==
+                          Some(c::ERROR_NOT_LOCKED as i32) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Locks the whole file, which is the closest match to `flock` on Unix.
+    fn lock(&self, flags: libc::DWORD) -> io::Result<bool> {
+        let mut overlapped: libc::OVERLAPPED = unsafe { mem::zeroed() };
+        match cvt(unsafe {
+            c::LockFileEx(self.handle.raw(), flags, 0, !0, !0, &mut overlapped)
+        }) {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.raw_os_error() ==
+                          Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn handle(&self) -> &Handle { &self.handle }
 
     fn is_symlink(&self) -> bool {
@@ -352,6 +393,84 @@ impl File {
     pub fn into_handle(self) -> Handle { self.handle }
 }
 
+pub struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+    // The mapped file, which outlives the map
+    file: libc::HANDLE,
+}
+
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    pub fn new(file: &File, len: usize, writable: bool) -> io::Result<Mmap> {
+        // Empty mappings aren't allowed, so there's nothing to map.
+        if len == 0 {
+            return Ok(Mmap { ptr: ptr::null_mut(), len: 0, file: file.handle.raw() })
+        }
+        let (protect, access) = if writable {
+            (libc::PAGE_READWRITE, libc::FILE_MAP_READ | libc::FILE_MAP_WRITE)
+        } else {
+            (libc::PAGE_READONLY, libc::FILE_MAP_READ)
+        };
+        unsafe {
+            let mapping = libc::CreateFileMappingW(file.handle.raw(),
+                                                   ptr::null_mut(),
+                                                   protect, 0, 0,
+                                                   ptr::null());
+            if mapping.is_null() {
+                return Err(Error::last_os_error())
+            }
+            // The view keeps the mapping object alive once it's created.
+            let mapping = Handle::new(mapping);
+            let ptr = libc::MapViewOfFile(mapping.raw(), access, 0, 0,
+                                          len as libc::SIZE_T);
+            if ptr.is_null() {
+                Err(Error::last_os_error())
+            } else {
+                Ok(Mmap { ptr: ptr, len: len, file: file.handle.raw() })
+            }
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.len == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        if self.len == 0 {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(self.ptr as *mut u8, self.len) }
+        }
+    }
+
+    pub fn flush(&self) -> io::Result<()> {
+        if self.len != 0 {
+            // This only starts writing the view back, the file's buffers
+            // have to be flushed as well to wait for it to reach the disk.
+            try!(cvt(unsafe {
+                c::FlushViewOfFile(self.ptr, self.len as libc::SIZE_T)
+            }));
+            try!(cvt(unsafe { libc::FlushFileBuffers(self.file) }));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            let _ = unsafe { libc::UnmapViewOfFile(self.ptr) };
+        }
+    }
+}
+
 impl FromInner<libc::HANDLE> for File {
     fn from_inner(handle: libc::HANDLE) -> File {
         File { handle: Handle::new(handle) }
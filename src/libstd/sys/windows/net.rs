@@ -137,6 +137,12 @@ impl Socket {
         }
     }
 
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        let mut nonblocking = nonblocking as libc::c_ulong;
+        try!(cvt(unsafe { c::ioctlsocket(self.0, c::FIONBIO, &mut nonblocking) }));
+        Ok(())
+    }
+
     pub fn set_timeout(&self, dur: Option<Duration>, kind: libc::c_int) -> io::Result<()> {
         let timeout = match dur {
             Some(dur) => {
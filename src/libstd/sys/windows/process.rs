@@ -219,6 +219,20 @@ impl Process {
         }
     }
 
+    pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
+        use libc::{STILL_ACTIVE, GetExitCodeProcess};
+
+        unsafe {
+            let mut status = 0;
+            try!(cvt(GetExitCodeProcess(self.handle.raw(), &mut status)));
+            if status == STILL_ACTIVE {
+                Ok(None)
+            } else {
+                Ok(Some(ExitStatus(status as i32)))
+            }
+        }
+    }
+
     pub fn handle(&self) -> &Handle { &self.handle }
 }
 
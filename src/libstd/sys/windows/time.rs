@@ -7,17 +7,43 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
+use prelude::v1::*;
+
+use cmp::Ordering;
+use fmt;
 use libc;
 use ops::Sub;
 use time::Duration;
 use sync::Once;
 
 const NANOS_PER_SEC: u64 = 1_000_000_000;
+const INTERVALS_PER_SEC: u64 = NANOS_PER_SEC / 100;
 
 pub struct SteadyTime {
     t: libc::LARGE_INTEGER,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Instant {
+    t: libc::LARGE_INTEGER,
+}
+
+#[derive(Copy, Clone)]
+pub struct SystemTime {
+    t: libc::FILETIME,
+}
+
+// A FILETIME counts 100 nanosecond intervals since January 1, 1601, so the
+// Unix epoch sits 11644473600 seconds later.
+const INTERVALS_TO_UNIX_EPOCH: u64 = 11_644_473_600 * INTERVALS_PER_SEC;
+
+pub const UNIX_EPOCH: SystemTime = SystemTime {
+    t: libc::FILETIME {
+        dwLowDateTime: INTERVALS_TO_UNIX_EPOCH as u32,
+        dwHighDateTime: (INTERVALS_TO_UNIX_EPOCH >> 32) as u32,
+    },
+};
+
 impl SteadyTime {
     pub fn now() -> SteadyTime {
         let mut t = SteadyTime { t: 0 };
@@ -48,6 +74,138 @@ impl<'a> Sub for &'a SteadyTime {
     }
 }
 
+impl Instant {
+    pub fn now() -> Instant {
+        let mut t = Instant { t: 0 };
+        unsafe { libc::QueryPerformanceCounter(&mut t.t); }
+        t
+    }
+
+    pub fn sub_instant(&self, other: &Instant) -> Duration {
+        // Values which are +- 1 need to be considered as basically the same
+        // units in time due to various measurement oddities, according to
+        // Windows [1]
+        //
+        // [1]:
+        // https://msdn.microsoft.com/en-us/library/windows/desktop
+        //                           /dn553408%28v=vs.85%29.aspx#guidance
+        if other.t > self.t && other.t - self.t == 1 {
+            return Duration::new(0, 0)
+        }
+        let diff = (self.t as u64).checked_sub(other.t as u64)
+                                  .expect("specified instant was later than \
+                                           self");
+        let nanos = mul_div_u64(diff, NANOS_PER_SEC, frequency() as u64);
+        Duration::new(nanos / NANOS_PER_SEC, (nanos % NANOS_PER_SEC) as u32)
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> Instant {
+        let freq = frequency() as u64;
+        let t = other.secs().checked_mul(freq).and_then(|i| {
+            (self.t as u64).checked_add(i)
+        }).and_then(|i| {
+            i.checked_add(mul_div_u64(other.extra_nanos() as u64, freq,
+                                      NANOS_PER_SEC))
+        }).expect("overflow when adding duration to time");
+        Instant { t: t as libc::LARGE_INTEGER }
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> Instant {
+        let freq = frequency() as u64;
+        let t = other.secs().checked_mul(freq).and_then(|i| {
+            (self.t as u64).checked_sub(i)
+        }).and_then(|i| {
+            i.checked_sub(mul_div_u64(other.extra_nanos() as u64, freq,
+                                      NANOS_PER_SEC))
+        }).expect("overflow when subtracting duration from time");
+        Instant { t: t as libc::LARGE_INTEGER }
+    }
+}
+
+impl SystemTime {
+    pub fn now() -> SystemTime {
+        unsafe {
+            let mut t: SystemTime = UNIX_EPOCH;
+            libc::GetSystemTimeAsFileTime(&mut t.t);
+            t
+        }
+    }
+
+    fn from_intervals(intervals: u64) -> SystemTime {
+        SystemTime {
+            t: libc::FILETIME {
+                dwLowDateTime: intervals as u32,
+                dwHighDateTime: (intervals >> 32) as u32,
+            }
+        }
+    }
+
+    fn intervals(&self) -> u64 {
+        (self.t.dwLowDateTime as u64) | ((self.t.dwHighDateTime as u64) << 32)
+    }
+
+    pub fn sub_time(&self, other: &SystemTime) -> Result<Duration, Duration> {
+        let me = self.intervals();
+        let other = other.intervals();
+        if me >= other {
+            Ok(intervals2dur(me - other))
+        } else {
+            Err(intervals2dur(other - me))
+        }
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> SystemTime {
+        let intervals = self.intervals().checked_add(dur2intervals(other))
+                            .expect("overflow when adding duration to time");
+        SystemTime::from_intervals(intervals)
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> SystemTime {
+        let intervals = self.intervals().checked_sub(dur2intervals(other))
+                            .expect("overflow when subtracting from time");
+        SystemTime::from_intervals(intervals)
+    }
+}
+
+impl PartialEq for SystemTime {
+    fn eq(&self, other: &SystemTime) -> bool {
+        self.intervals() == other.intervals()
+    }
+}
+
+impl Eq for SystemTime {}
+
+impl PartialOrd for SystemTime {
+    fn partial_cmp(&self, other: &SystemTime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SystemTime {
+    fn cmp(&self, other: &SystemTime) -> Ordering {
+        self.intervals().cmp(&other.intervals())
+    }
+}
+
+impl fmt::Debug for SystemTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SystemTime")
+         .field("intervals", &self.intervals())
+         .finish()
+    }
+}
+
+fn dur2intervals(d: &Duration) -> u64 {
+    d.secs().checked_mul(INTERVALS_PER_SEC).and_then(|i| {
+        i.checked_add(d.extra_nanos() as u64 / 100)
+    }).expect("overflow when converting duration to intervals")
+}
+
+fn intervals2dur(intervals: u64) -> Duration {
+    Duration::new(intervals / INTERVALS_PER_SEC,
+                  ((intervals % INTERVALS_PER_SEC) * 100) as u32)
+}
+
 // Computes (value*numer)/denom without overflow, as long as both
 // (numer*denom) and the overall result fit into i64 (which is the case
 // for our time conversions).
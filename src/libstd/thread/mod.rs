@@ -89,28 +89,37 @@
 //!
 //! The `spawn` method does not allow the child and parent threads to
 //! share any stack data, since that is not safe in general. However,
-//! `scoped` makes it possible to share the parent's stack by forcing
-//! a join before any relevant stack frames are popped:
+//! `scope` makes it possible to share the parent's stack by joining
+//! every thread spawned within the scope before it returns:
 //!
 //! ```rust
-//! # #![feature(scoped)]
+//! # #![feature(thread_scope)]
 //! use std::thread;
 //!
-//! let guard = thread::scoped(move || {
-//!     // some work here
+//! let mut numbers = vec![1, 2, 3];
+//!
+//! thread::scope(|s| {
+//!     s.spawn(|| {
+//!         // some work with `numbers` here
+//!         println!("length: {}", numbers.len());
+//!     });
+//!
+//!     // do some other work in the meantime
 //! });
 //!
-//! // do some other work in the meantime
-//! let output = guard.join();
+//! // all threads in the scope have been joined, so we may mutate again
+//! numbers.push(4);
 //! ```
 //!
-//! The `scoped` function doesn't return a `Thread` directly; instead,
-//! it returns a *join guard*. The join guard is an RAII-style guard
-//! that will automatically join the child thread (block until it
-//! terminates) when it is dropped. You can join the child thread in
-//! advance by calling the `join` method on the guard, which will also
-//! return the result produced by the thread.  A handle to the thread
-//! itself is available via the `thread` method of the join guard.
+//! Threads spawned on a `Scope` return a `ScopedJoinHandle`, which can
+//! be used to join the child thread in advance and retrieve the result
+//! it produced. A handle to the thread itself is available via the
+//! `thread` method of the join handle.
+//!
+//! The older `scoped` function returns an RAII-style *join guard*
+//! instead, which joins the child thread when it is dropped. It is not
+//! memory safe: if the guard is leaked with `mem::forget`, the child
+//! may outlive the data it borrows.
 //!
 //! ## Configuring threads
 //!
@@ -191,10 +200,12 @@ use prelude::v1::*;
 
 use alloc::boxed::FnBox;
 use any::Any;
-use cell::UnsafeCell;
+use cell::{Cell, RefCell, UnsafeCell};
 use fmt;
 use io;
 use marker::PhantomData;
+use mem;
+use rc::Rc;
 use rt::{self, unwind};
 use sync::{Mutex, Condvar, Arc};
 use sys::thread as imp;
@@ -299,7 +310,7 @@ impl Builder {
                reason = "memory unsafe if destructor is avoided, see #24292")]
     #[deprecated(since = "1.2.0",
                  reason = "this unsafe API is unlikely to ever be stabilized \
-                           in this form")]
+                           in this form, use `Builder::spawn_scoped` instead")]
     pub fn scoped<'a, T, F>(self, f: F) -> io::Result<JoinGuard<'a, T>> where
         T: Send + 'a, F: FnOnce() -> T, F: Send + 'a
     {
@@ -310,6 +321,41 @@ impl Builder {
         }
     }
 
+    /// Spawns a new child thread within the given scope, and returns a
+    /// `ScopedJoinHandle` for it.
+    ///
+    /// The child thread may borrow any data which outlives the scope. It
+    /// is joined before `thread::scope` returns, if it was not already
+    /// joined through its handle. See the `scope` function for details.
+    ///
+    /// # Errors
+    ///
+    /// Unlike the `Scope::spawn` method, this method yields an
+    /// `io::Result` to capture any failure to create the thread at
+    /// the OS level.
+    #[unstable(feature = "thread_scope", reason = "recently added")]
+    pub fn spawn_scoped<'a, F, T>(self, scope: &Scope<'a>, f: F)
+                                  -> io::Result<ScopedJoinHandle<'a, T>> where
+        F: FnOnce() -> T, F: Send + 'a, T: Send + 'a
+    {
+        // The scope joins the thread before any data it borrows for `'a` can
+        // go away, which is what makes this safe.
+        let inner = unsafe { try!(self.spawn_inner(Box::new(f))) };
+        let thread = inner.thread.clone();
+        let inner = Rc::new(RefCell::new(inner));
+        let their_inner = inner.clone();
+        scope.joins.borrow_mut().push(Box::new(move || {
+            let mut inner = their_inner.borrow_mut();
+            inner.wait();
+            inner.panicked()
+        }));
+        Ok(ScopedJoinHandle {
+            inner: inner,
+            thread: thread,
+            _marker: PhantomData,
+        })
+    }
+
     // NB: this function is unsafe as the lifetime parameter of the code to run
     //     in the new thread is not tied into the return value, and the return
     //     value must not outlast that lifetime.
@@ -403,7 +449,7 @@ pub fn spawn<F, T>(f: F) -> JoinHandle<T> where
            reason = "memory unsafe if destructor is avoided, see #24292")]
 #[deprecated(since = "1.2.0",
              reason = "this unsafe API is unlikely to ever be stabilized \
-                       in this form")]
+                       in this form, use `thread::scope` instead")]
 #[allow(deprecated)]
 pub fn scoped<'a, T, F>(f: F) -> JoinGuard<'a, T> where
     T: Send + 'a, F: FnOnce() -> T, F: Send + 'a
@@ -411,6 +457,66 @@ pub fn scoped<'a, T, F>(f: F) -> JoinGuard<'a, T> where
     Builder::new().scoped(f).unwrap()
 }
 
+/// Creates a scope for spawning threads which may borrow non-`'static`
+/// data.
+///
+/// The closure `f` is given a `Scope` on which threads can be spawned with
+/// `Scope::spawn`. Unlike `thread::spawn`, those threads may borrow
+/// anything which outlives the call to `scope`, because every thread spawned
+/// in the scope is joined before `scope` returns, even if `f` panics.
+///
+/// Threads may also be joined early through the `ScopedJoinHandle` returned
+/// by `spawn`, which hands back their result.
+///
+/// # Panics
+///
+/// If any thread spawned in the scope panics and has not been joined
+/// through its handle, `scope` panics once all the threads have been
+/// joined. A panic in `f` itself is propagated after joining the threads.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(thread_scope)]
+/// use std::thread;
+///
+/// let mut a = vec![1, 2, 3];
+/// let mut x = 0;
+///
+/// thread::scope(|s| {
+///     s.spawn(|| {
+///         println!("hello from the first scoped thread");
+///         // We can borrow `a` here.
+///         println!("{:?}", a);
+///     });
+///     let sum = s.spawn(|| {
+///         println!("hello from the second scoped thread");
+///         // We can even mutably borrow `x` here,
+///         // because no other threads are using it.
+///         x += a[0] + a[2];
+///         x
+///     });
+///     assert_eq!(sum.join().unwrap(), 4);
+/// });
+///
+/// // After the scope, we can modify and access our variables again:
+/// a.push(4);
+/// assert_eq!(x, a.len());
+/// ```
+#[unstable(feature = "thread_scope", reason = "recently added")]
+pub fn scope<'a, F, R>(f: F) -> R where F: FnOnce(&Scope<'a>) -> R {
+    let scope = Scope {
+        joins: RefCell::new(Vec::new()),
+        _marker: PhantomData,
+    };
+    // If `f` panics, dropping `scope` joins the threads while unwinding.
+    let ret = f(&scope);
+    if scope.join_all() {
+        panic!("a scoped thread panicked");
+    }
+    ret
+}
+
 /// Gets a handle to the thread that invokes it.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn current() -> Thread {
@@ -665,11 +771,28 @@ struct JoinInner<T> {
 
 impl<T> JoinInner<T> {
     fn join(&mut self) -> Result<T> {
-        self.native.take().unwrap().join();
+        self.wait();
         unsafe {
             (*self.packet.0.get()).take().unwrap()
         }
     }
+
+    // Waits for the thread to finish if it hasn't been joined yet, leaving
+    // its result in the packet.
+    fn wait(&mut self) {
+        if let Some(native) = self.native.take() {
+            native.join();
+        }
+    }
+
+    // Whether the thread panicked and nobody has taken the result yet. Only
+    // meaningful once the thread has been joined.
+    fn panicked(&self) -> bool {
+        match unsafe { &*self.packet.0.get() } {
+            &Some(Err(_)) => true,
+            _ => false,
+        }
+    }
 }
 
 /// An owned permission to join on a thread (block on its termination).
@@ -753,6 +876,87 @@ impl<'a, T: Send + 'a> Drop for JoinGuard<'a, T> {
     }
 }
 
+/// A scope in which threads borrowing non-`'static` data can be spawned.
+///
+/// See the `scope` function for details.
+#[unstable(feature = "thread_scope", reason = "recently added")]
+pub struct Scope<'a> {
+    // Joins a thread spawned in this scope, returning whether it panicked.
+    joins: RefCell<Vec<Box<FnBox() -> bool + 'a>>>,
+    // `'a` must be invariant: if it could be shortened, threads could be
+    // spawned borrowing data which dies before the scope joins them.
+    _marker: PhantomData<Cell<&'a ()>>,
+}
+
+impl<'a> Scope<'a> {
+    /// Spawns a new thread within the scope, returning a `ScopedJoinHandle`
+    /// for it.
+    ///
+    /// The thread may borrow any data which outlives the scope, and is joined
+    /// before `thread::scope` returns if it has not been joined already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread; use `Builder::spawn_scoped`
+    /// to recover from such errors.
+    #[unstable(feature = "thread_scope", reason = "recently added")]
+    pub fn spawn<F, T>(&self, f: F) -> ScopedJoinHandle<'a, T> where
+        F: FnOnce() -> T, F: Send + 'a, T: Send + 'a
+    {
+        Builder::new().spawn_scoped(self, f).unwrap()
+    }
+
+    // Joins every thread which hasn't been joined yet, returning whether any
+    // of them panicked.
+    fn join_all(&self) -> bool {
+        let joins = mem::replace(&mut *self.joins.borrow_mut(), Vec::new());
+        let mut panicked = false;
+        for join in joins {
+            if join() {
+                panicked = true;
+            }
+        }
+        panicked
+    }
+}
+
+#[unstable(feature = "thread_scope", reason = "recently added")]
+impl<'a> Drop for Scope<'a> {
+    fn drop(&mut self) {
+        // Only reached with threads left to join if the scope's closure
+        // panicked, in which case that panic is the one to propagate.
+        self.join_all();
+    }
+}
+
+/// An owned permission to join on a thread spawned within a `Scope`.
+///
+/// The thread is joined when the scope ends if this handle hasn't been used
+/// to join it before then.
+#[unstable(feature = "thread_scope", reason = "recently added")]
+pub struct ScopedJoinHandle<'a, T: 'a> {
+    inner: Rc<RefCell<JoinInner<T>>>,
+    thread: Thread,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: 'a> ScopedJoinHandle<'a, T> {
+    /// Extracts a handle to the underlying thread
+    #[unstable(feature = "thread_scope", reason = "recently added")]
+    pub fn thread(&self) -> &Thread {
+        &self.thread
+    }
+
+    /// Waits for the associated thread to finish.
+    ///
+    /// If the child thread panics, `Err` is returned with the parameter given
+    /// to `panic`, and the panic is not propagated by the scope.
+    #[unstable(feature = "thread_scope", reason = "recently added")]
+    pub fn join(self) -> Result<T> {
+        self.inner.borrow_mut().join()
+    }
+}
+
 fn _assert_sync_and_send() {
     fn _assert_both<T: Send + Sync>() {}
     _assert_both::<JoinHandle<()>>();
@@ -840,6 +1044,91 @@ mod tests {
         let _ = thread::scoped(|| panic!());
     }
 
+    #[test]
+    fn test_scope_borrows() {
+        let mut data = vec![1, 2, 3];
+        let mut sum = 0;
+        thread::scope(|s| {
+            s.spawn(|| {
+                assert_eq!(data.len(), 3);
+            });
+            s.spawn(|| {
+                sum = data.iter().fold(0, |a, b| a + b);
+            });
+        });
+        data.push(4);
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn test_scope_joins_all() {
+        use sync::atomic::{AtomicUsize, Ordering};
+
+        let count = AtomicUsize::new(0);
+        thread::scope(|s| {
+            for _ in 0..10 {
+                s.spawn(|| {
+                    thread::sleep_ms(10);
+                    count.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        });
+        assert_eq!(count.load(Ordering::SeqCst), 10);
+    }
+
+    #[test]
+    fn test_scope_join_handle() {
+        let x = 5;
+        let res = thread::scope(|s| {
+            let handle = Builder::new().name("scoped".to_string()).spawn_scoped(s, || {
+                assert_eq!(thread::current().name(), Some("scoped"));
+                x * 2
+            }).unwrap();
+            assert_eq!(handle.thread().name(), Some("scoped"));
+            handle.join().unwrap()
+        });
+        assert_eq!(res, 10);
+    }
+
+    #[test]
+    fn test_scope_join_handle_escapes() {
+        let handle = thread::scope(|s| s.spawn(|| "done"));
+        assert_eq!(handle.join().unwrap(), "done");
+    }
+
+    #[test]
+    fn test_scope_joined_panic_is_caught() {
+        thread::scope(|s| {
+            assert!(s.spawn(|| panic!()).join().is_err());
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scope_panic() {
+        thread::scope(|s| {
+            s.spawn(|| panic!());
+        });
+    }
+
+    #[test]
+    fn test_scope_closure_panic_joins() {
+        use sync::atomic::{AtomicBool, Ordering};
+
+        static DONE: AtomicBool = AtomicBool::new(false);
+        let res = thread::spawn(|| {
+            thread::scope(|s| {
+                s.spawn(|| {
+                    thread::sleep_ms(10);
+                    DONE.store(true, Ordering::SeqCst);
+                });
+                panic!();
+            })
+        }).join();
+        assert!(res.is_err());
+        assert!(DONE.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn test_spawn_sched() {
         use clone::Clone;
@@ -1319,6 +1319,7 @@ pub enum IntTy {
     TyI16,
     TyI32,
     TyI64,
+    TyI128,
 }
 
 impl fmt::Debug for IntTy {
@@ -1338,6 +1339,7 @@ impl IntTy {
         match *self {
             TyIs | TyI8 => 2,
             TyI16 | TyI32 | TyI64  => 3,
+            TyI128 => 4,
         }
     }
 }
@@ -1349,6 +1351,7 @@ pub enum UintTy {
     TyU16,
     TyU32,
     TyU64,
+    TyU128,
 }
 
 impl UintTy {
@@ -1356,6 +1359,7 @@ impl UintTy {
         match *self {
             TyUs | TyU8 => 2,
             TyU16 | TyU32 | TyU64  => 3,
+            TyU128 => 4,
         }
     }
 }
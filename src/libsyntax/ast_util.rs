@@ -128,7 +128,8 @@ pub fn int_ty_to_string(t: IntTy, val: Option<i64>) -> String {
         TyI8 => "i8",
         TyI16 => "i16",
         TyI32 => "i32",
-        TyI64 => "i64"
+        TyI64 => "i64",
+        TyI128 => "i128"
     };
 
     match val {
@@ -145,7 +146,8 @@ pub fn int_ty_max(t: IntTy) -> u64 {
         TyI8 => 0x80,
         TyI16 => 0x8000,
         TyIs | TyI32 => 0x80000000, // actually ni about TyIs
-        TyI64 => 0x8000000000000000
+        TyI64 => 0x8000000000000000,
+        TyI128 => 0xffffffffffffffff // saturated, literals are parsed as u64
     }
 }
 
@@ -157,7 +159,8 @@ pub fn uint_ty_to_string(t: UintTy, val: Option<u64>) -> String {
         TyU8 => "u8",
         TyU16 => "u16",
         TyU32 => "u32",
-        TyU64 => "u64"
+        TyU64 => "u64",
+        TyU128 => "u128"
     };
 
     match val {
@@ -171,7 +174,7 @@ pub fn uint_ty_max(t: UintTy) -> u64 {
         TyU8 => 0xff,
         TyU16 => 0xffff,
         TyUs | TyU32 => 0xffffffff, // actually ni about TyUs
-        TyU64 => 0xffffffffffffffff
+        TyU64 | TyU128 => 0xffffffffffffffff // saturated for TyU128
     }
 }
 
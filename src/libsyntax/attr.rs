@@ -630,6 +630,7 @@ impl IntType {
             SignedInt(ast::TyI16) | UnsignedInt(ast::TyU16) |
             SignedInt(ast::TyI32) | UnsignedInt(ast::TyU32) |
             SignedInt(ast::TyI64) | UnsignedInt(ast::TyU64) => true,
+            SignedInt(ast::TyI128) | UnsignedInt(ast::TyU128) => false,
             SignedInt(ast::TyIs) | UnsignedInt(ast::TyUs) => false
         }
     }
@@ -937,6 +937,13 @@ impl CodeMap {
         }
     }
 
+    /// Calls `f` with every expansion recorded so far.
+    pub fn with_expansions<T, F>(&self, f: F) -> T where
+        F: FnOnce(&[ExpnInfo]) -> T,
+    {
+        f(&self.expansions.borrow())
+    }
+
     /// Check if a span is "internal" to a macro in which #[unstable]
     /// items can be used (that is, a macro marked with
     /// `#[allow_internal_unstable]`).
@@ -0,0 +1,315 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A JSON emitter for errors.
+//!
+//! This works by converting each diagnostic into a simplified, serializable
+//! representation and writing it out as a single line of JSON. Notes and help
+//! messages which follow an error or warning are attached to it as children,
+//! so each top-level JSON object describes one complete diagnostic.
+//!
+//! The format of the JSON output should be considered *unstable*. For now the
+//! structs at the end of this file (Diagnostic*) specify the error format.
+
+use codemap::{self, CodeMap, COMMAND_LINE_EXPN, Span};
+use diagnostic::{Emitter, Level, RenderSpan, Bug, Fatal, Error, Warning};
+use diagnostic::{FullSpan, EndSpan, Suggestion, FileLine};
+use diagnostics::registry::Registry;
+
+use std::io::{self, Write};
+
+use serialize::json::as_json;
+
+pub struct JsonEmitter {
+    dst: Box<Write + Send>,
+    registry: Option<Registry>,
+    /// The last error or warning emitted, which subsequent notes and help
+    /// messages are attached to. It is written out once the next top-level
+    /// diagnostic arrives or the emitter is dropped.
+    pending: Option<Diagnostic>,
+}
+
+impl JsonEmitter {
+    pub fn stderr(registry: Option<Registry>) -> JsonEmitter {
+        JsonEmitter::new(Box::new(io::stderr()), registry)
+    }
+
+    pub fn new(dst: Box<Write + Send>,
+               registry: Option<Registry>) -> JsonEmitter {
+        JsonEmitter {
+            dst: dst,
+            registry: registry,
+            pending: None,
+        }
+    }
+
+    fn push(&mut self, diag: Diagnostic, lvl: Level) {
+        match lvl {
+            Bug | Fatal => {
+                // The handler is about to unwind, so nothing else will be
+                // attached to this diagnostic.
+                self.flush_pending();
+                self.write(&diag);
+            }
+            Error | Warning => {
+                self.flush_pending();
+                self.pending = Some(diag);
+            }
+            _ => {
+                match self.pending {
+                    Some(ref mut parent) => parent.children.push(diag),
+                    None => self.write(&diag),
+                }
+            }
+        }
+    }
+
+    fn flush_pending(&mut self) {
+        if let Some(diag) = self.pending.take() {
+            self.write(&diag);
+        }
+    }
+
+    fn write(&mut self, diag: &Diagnostic) {
+        if let Err(e) = writeln!(&mut self.dst, "{}", as_json(diag)) {
+            panic!("failed to print diagnostics: {:?}", e);
+        }
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn emit(&mut self,
+            cmsp: Option<(&CodeMap, Span)>,
+            msg: &str,
+            code: Option<&str>,
+            lvl: Level) {
+        let spans = match cmsp {
+            Some((cm, sp)) => DiagnosticSpan::from_render_span(&FullSpan(sp), cm),
+            None => vec![],
+        };
+        let diag = Diagnostic::new(msg, code, lvl, spans, &self.registry);
+        self.push(diag, lvl);
+    }
+
+    fn custom_emit(&mut self,
+                   cm: &CodeMap,
+                   sp: RenderSpan,
+                   msg: &str,
+                   lvl: Level) {
+        let spans = DiagnosticSpan::from_render_span(&sp, cm);
+        let diag = Diagnostic::new(msg, None, lvl, spans, &self.registry);
+        self.push(diag, lvl);
+    }
+}
+
+impl Drop for JsonEmitter {
+    fn drop(&mut self) {
+        self.flush_pending();
+    }
+}
+
+// The following data types are provided just for serialisation.
+
+#[derive(RustcEncodable)]
+struct Diagnostic {
+    /// The primary error message.
+    message: String,
+    code: Option<DiagnosticCode>,
+    /// "error: internal compiler error", "error", "warning", "note", "help".
+    level: String,
+    spans: Vec<DiagnosticSpan>,
+    /// Associated diagnostic messages.
+    children: Vec<Diagnostic>,
+}
+
+#[derive(RustcEncodable)]
+struct DiagnosticSpan {
+    file_name: String,
+    byte_start: u32,
+    byte_end: u32,
+    /// 1-based.
+    line_start: usize,
+    line_end: usize,
+    /// 1-based, character offset.
+    column_start: usize,
+    column_end: usize,
+    /// Is this the span the diagnostic is about, or is it one of the macro
+    /// expansion sites which led to it?
+    is_primary: bool,
+    /// A description of this span, for spans which are not primary.
+    label: Option<String>,
+    /// If the diagnostic carries a suggestion, the text which should replace
+    /// the code covered by this span.
+    suggested_replacement: Option<String>,
+}
+
+#[derive(RustcEncodable)]
+struct DiagnosticCode {
+    /// The code itself.
+    code: String,
+    /// An explanation for the code, as given by `rustc --explain`.
+    explanation: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(msg: &str,
+           code: Option<&str>,
+           lvl: Level,
+           spans: Vec<DiagnosticSpan>,
+           registry: &Option<Registry>)
+           -> Diagnostic {
+        Diagnostic {
+            message: msg.to_string(),
+            code: DiagnosticCode::map_opt_string(code, registry),
+            level: lvl.to_string(),
+            spans: spans,
+            children: vec![],
+        }
+    }
+}
+
+impl DiagnosticSpan {
+    fn from_render_span(rsp: &RenderSpan, cm: &CodeMap) -> Vec<DiagnosticSpan> {
+        let (span, replacement) = match *rsp {
+            FullSpan(sp) | FileLine(sp) => (sp, None),
+            EndSpan(sp) => (Span { lo: sp.hi, hi: sp.hi, expn_id: sp.expn_id }, None),
+            Suggestion(sp, ref s) => (sp, Some(s.clone())),
+        };
+
+        // We cannot check equality directly with COMMAND_LINE_SP
+        // since PartialEq is manually implemented to ignore the ExpnId
+        if span.expn_id == COMMAND_LINE_EXPN {
+            return vec![];
+        }
+
+        let mut spans = vec![DiagnosticSpan::from_span(span, cm, true, None,
+                                                       replacement)];
+        DiagnosticSpan::push_macro_backtrace(&mut spans, span, cm);
+        spans
+    }
+
+    fn from_span(span: Span,
+                 cm: &CodeMap,
+                 is_primary: bool,
+                 label: Option<String>,
+                 suggested_replacement: Option<String>)
+                 -> DiagnosticSpan {
+        let start = cm.lookup_char_pos(span.lo);
+        let end = cm.lookup_char_pos(span.hi);
+        DiagnosticSpan {
+            file_name: start.file.name.clone(),
+            byte_start: cm.lookup_byte_offset(span.lo).pos.0,
+            byte_end: cm.lookup_byte_offset(span.hi).pos.0,
+            line_start: start.line,
+            line_end: end.line,
+            column_start: start.col.0 + 1,
+            column_end: end.col.0 + 1,
+            is_primary: is_primary,
+            label: label,
+            suggested_replacement: suggested_replacement,
+        }
+    }
+
+    /// Records the chain of macro invocations which produced `sp` as
+    /// secondary spans, innermost first.
+    fn push_macro_backtrace(spans: &mut Vec<DiagnosticSpan>, sp: Span, cm: &CodeMap) {
+        let call_site = cm.with_expn_info(sp.expn_id, |expn_info| {
+            expn_info.map(|ei| {
+                let (pre, post) = match ei.callee.format {
+                    codemap::MacroAttribute => ("#[", "]"),
+                    codemap::MacroBang => ("", "!"),
+                    codemap::CompilerExpansion => ("", ""),
+                };
+                let label = format!("in expansion of {}{}{}",
+                                    pre, ei.callee.name, post);
+                (ei.call_site, label)
+            })
+        });
+        if let Some((call_site, label)) = call_site {
+            spans.push(DiagnosticSpan::from_span(call_site, cm, false,
+                                                 Some(label), None));
+            DiagnosticSpan::push_macro_backtrace(spans, call_site, cm);
+        }
+    }
+}
+
+impl DiagnosticCode {
+    fn map_opt_string(s: Option<&str>,
+                      registry: &Option<Registry>)
+                      -> Option<DiagnosticCode> {
+        s.map(|s| {
+            let explanation = registry.as_ref()
+                                      .and_then(|registry| registry.find_description(s));
+
+            DiagnosticCode {
+                code: s.to_string(),
+                explanation: explanation.map(|s| s.to_string()),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::JsonEmitter;
+    use codemap::{mk_sp, CodeMap, BytePos};
+    use diagnostic::{Emitter, Error, Note};
+    use std::io::{self, Write};
+    use std::str::from_utf8;
+    use std::sync::{Arc, Mutex};
+
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+    impl Write for Sink {
+        fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+            Write::write(&mut *self.0.lock().unwrap(), data)
+        }
+        fn flush(&mut self) -> io::Result<()> { Ok(()) }
+    }
+
+    #[test]
+    fn test_notes_are_attached_to_errors() {
+        let data = Arc::new(Mutex::new(Vec::new()));
+        let cm = CodeMap::new();
+        cm.new_filemap("dummy.rs".to_string(),
+                       "fn main() {\n    let x = 1;\n}\n".to_string());
+        {
+            let mut ew = JsonEmitter::new(Box::new(Sink(data.clone())), None);
+            let err = mk_sp(BytePos(16), BytePos(22));
+            let note = mk_sp(BytePos(20), BytePos(21));
+            ew.emit(Some((&cm, err)), "unused binding", Some("E0000"), Error);
+            ew.emit(Some((&cm, note)), "declared here", None, Note);
+        }
+
+        let vec = data.lock().unwrap().clone();
+        let output = from_utf8(&vec).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0],
+                   "{\"message\":\"unused binding\",\
+                     \"code\":{\"code\":\"E0000\",\"explanation\":null},\
+                     \"level\":\"error\",\
+                     \"spans\":[{\"file_name\":\"dummy.rs\",\
+                                 \"byte_start\":16,\"byte_end\":22,\
+                                 \"line_start\":2,\"line_end\":2,\
+                                 \"column_start\":5,\"column_end\":11,\
+                                 \"is_primary\":true,\"label\":null,\
+                                 \"suggested_replacement\":null}],\
+                     \"children\":[{\"message\":\"declared here\",\
+                                    \"code\":null,\
+                                    \"level\":\"note\",\
+                                    \"spans\":[{\"file_name\":\"dummy.rs\",\
+                                                \"byte_start\":20,\"byte_end\":21,\
+                                                \"line_start\":2,\"line_end\":2,\
+                                                \"column_start\":9,\"column_end\":10,\
+                                                \"is_primary\":true,\"label\":null,\
+                                                \"suggested_replacement\":null}],\
+                                    \"children\":[]}]}");
+    }
+}
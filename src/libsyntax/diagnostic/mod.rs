@@ -23,6 +23,8 @@ use std::io;
 use term::{self, WriterWrapper};
 use libc;
 
+pub mod json;
+
 /// maximum number of lines we will print for each error; arbitrary.
 const MAX_LINES: usize = 6;
 
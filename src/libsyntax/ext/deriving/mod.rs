@@ -101,7 +101,11 @@ fn expand_derive(cx: &mut ExtCtxt,
                     }
                 };
 
-                if !(is_builtin_trait(tname) || cx.ecfg.enable_custom_derive()) {
+                // Derives registered by plugins through
+                // `Registry::register_custom_derive` don't need the feature gate.
+                let registered = cx.ecfg.custom_derives.iter().any(|d| *d == **tname);
+                if !(is_builtin_trait(tname) || registered ||
+                     cx.ecfg.enable_custom_derive()) {
                     feature_gate::emit_feature_err(&cx.parse_sess.span_diagnostic,
                                                    "custom_derive",
                                                    titem.span,
@@ -149,7 +153,9 @@ macro_rules! derive_traits {
                        MultiModifier(Box::new(expand_derive)));
         }
 
-        fn is_builtin_trait(name: &str) -> bool {
+        /// Whether `name` is one of the traits which the compiler itself
+        /// knows how to derive.
+        pub fn is_builtin_trait(name: &str) -> bool {
             match name {
                 $( $name )|+ => true,
                 _ => false,
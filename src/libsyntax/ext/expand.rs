@@ -1470,6 +1470,8 @@ pub struct ExpansionConfig<'feat> {
     pub features: Option<&'feat Features>,
     pub recursion_limit: usize,
     pub trace_mac: bool,
+    /// Traits whose `#[derive]` implementations were registered by plugins.
+    pub custom_derives: Vec<String>,
 }
 
 macro_rules! feature_tests {
@@ -1492,6 +1494,7 @@ impl<'feat> ExpansionConfig<'feat> {
             features: None,
             recursion_limit: 64,
             trace_mac: false,
+            custom_derives: Vec::new(),
         }
     }
 
@@ -598,17 +598,19 @@ pub fn integer_lit(s: &str, suffix: Option<&str>, sd: &SpanHandler, sp: Span) ->
             "i16" => ast::SignedIntLit(ast::TyI16, ast::Plus),
             "i32" => ast::SignedIntLit(ast::TyI32, ast::Plus),
             "i64" => ast::SignedIntLit(ast::TyI64, ast::Plus),
+            "i128" => ast::SignedIntLit(ast::TyI128, ast::Plus),
             "usize" => ast::UnsignedIntLit(ast::TyUs),
             "u8"  => ast::UnsignedIntLit(ast::TyU8),
             "u16" => ast::UnsignedIntLit(ast::TyU16),
             "u32" => ast::UnsignedIntLit(ast::TyU32),
             "u64" => ast::UnsignedIntLit(ast::TyU64),
+            "u128" => ast::UnsignedIntLit(ast::TyU128),
             _ => {
                 // i<digits> and u<digits> look like widths, so lets
                 // give an error message along those lines
                 if looks_like_width_suffix(&['i', 'u'], suf) {
                     sd.span_err(sp, &*format!("illegal width `{}` for integer literal; \
-                                              valid widths are 8, 16, 32 and 64",
+                                              valid widths are 8, 16, 32, 64 and 128",
                                               &suf[1..]));
                 } else {
                     sd.span_err(sp, &*format!("illegal suffix `{}` for numeric literal", suf));
@@ -637,6 +639,14 @@ pub fn integer_lit(s: &str, suffix: Option<&str>, sd: &SpanHandler, sp: Span) ->
 
             if !already_errored {
                 sd.span_err(sp, "int literal is too large");
+                match ty {
+                    ast::SignedIntLit(ast::TyI128, _) | ast::UnsignedIntLit(ast::TyU128) => {
+                        sd.fileline_help(sp, "integer literals are limited to 64 bits for now, \
+                                              even for `i128` and `u128`; build wider values \
+                                              with shifts instead");
+                    }
+                    _ => {}
+                }
             }
             0
         }
@@ -41,11 +41,14 @@
 #![feature(fnbox)]
 #![feature(iter_cmp)]
 #![feature(libc)]
+#![feature(mpsc_select)]
 #![feature(rt)]
 #![feature(rustc_private)]
 #![feature(set_stdio)]
 #![feature(slice_extras)]
 #![feature(staged_api)]
+#![feature(thread_sleep)]
+#![feature(time2)]
 
 extern crate getopts;
 extern crate serialize;
@@ -64,6 +67,7 @@ use self::OutputLocation::*;
 use stats::Stats;
 use getopts::{OptGroup, optflag, optopt};
 use serialize::Encodable;
+use serialize::json;
 use std::boxed::FnBox;
 use term::Terminal;
 use term::color::{Color, RED, YELLOW, GREEN, CYAN};
@@ -81,7 +85,7 @@ use std::path::PathBuf;
 use std::sync::mpsc::{channel, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 // to be used by rustc to compile tests in libtest
 pub mod test {
@@ -90,11 +94,18 @@ pub mod test {
              Metric, MetricMap,
              StaticTestFn, StaticTestName, DynTestName, DynTestFn,
              run_test, test_main, test_main_static, filter_tests,
-             parse_opts, StaticBenchFn, ShouldPanic};
+             parse_opts, StaticBenchFn, ShouldPanic, OutputFormat};
 }
 
 pub mod stats;
 
+// The default number of seconds a test may run before the harness warns that
+// it is taking a long time
+const TEST_WARN_TIMEOUT_S: u64 = 60;
+
+// How often the harness wakes up to look for slow or timed out tests
+const TIMEOUT_CHECK_INTERVAL_MS: u64 = 100;
+
 // The name of a test. By convention this follows the rules for rust
 // paths; i.e. it should be a series of identifiers separated by double
 // colons. This way if some test runner wants to arrange the tests
@@ -250,6 +261,12 @@ pub fn test_main(args: &[String], tests: Vec<TestDescAndFn> ) {
             Some(Err(msg)) => panic!("{:?}", msg),
             None => return
         };
+    if opts.list {
+        if let Err(e) = list_tests_console(&opts, tests) {
+            panic!("io error when listing tests: {:?}", e);
+        }
+        return;
+    }
     match run_tests_console(&opts, tests) {
         Ok(true) => {}
         Ok(false) => panic!("Some tests failed"),
@@ -283,27 +300,57 @@ pub enum ColorConfig {
     NeverColor,
 }
 
+/// How the results of a test run are reported on stdout.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum OutputFormat {
+    /// Human readable output, one line per test.
+    Pretty,
+    /// A stream of JSON objects, one event per line.
+    Json,
+    /// A JUnit XML report, written once all tests have finished.
+    Junit,
+}
+
 pub struct TestOpts {
-    pub filter: Option<String>,
+    pub list: bool,
+    pub filters: Vec<String>,
+    pub filter_exact: bool,
+    pub skip: Vec<String>,
+    /// Run the tests in a random order, shuffled with the given seed.
+    pub shuffle: Option<u64>,
+    /// Only run the `index`th of `count` shards, as `(index, count)` with
+    /// `0 <= index < count`.
+    pub shard: Option<(usize, usize)>,
     pub run_ignored: bool,
     pub run_tests: bool,
     pub bench_benchmarks: bool,
     pub logfile: Option<PathBuf>,
     pub nocapture: bool,
     pub color: ColorConfig,
+    pub format: OutputFormat,
+    pub test_timeout: Option<Duration>,
+    pub warn_timeout: Duration,
 }
 
 impl TestOpts {
     #[cfg(test)]
     fn new() -> TestOpts {
         TestOpts {
-            filter: None,
+            list: false,
+            filters: Vec::new(),
+            filter_exact: false,
+            skip: Vec::new(),
+            shuffle: None,
+            shard: None,
             run_ignored: false,
             run_tests: false,
             bench_benchmarks: false,
             logfile: None,
             nocapture: false,
             color: AutoColor,
+            format: OutputFormat::Pretty,
+            test_timeout: None,
+            warn_timeout: Duration::from_secs(TEST_WARN_TIMEOUT_S),
         }
     }
 }
@@ -316,6 +363,16 @@ fn optgroups() -> Vec<getopts::OptGroup> {
       getopts::optflag("", "test", "Run tests and not benchmarks"),
       getopts::optflag("", "bench", "Run benchmarks instead of tests"),
       getopts::optflag("h", "help", "Display this message (longer with --help)"),
+      getopts::optflag("", "list", "List all tests and benchmarks instead of \
+                                    running them"),
+      getopts::optflag("", "exact", "Exactly match filters rather than by substring"),
+      getopts::optmulti("", "skip", "Skip tests whose names contain FILTER (this \
+                                     flag can be used multiple times)", "FILTER"),
+      getopts::optflagopt("", "shuffle", "Run tests in a random order, optionally \
+                                          reproducing a previous order from its \
+                                          seed", "SEED"),
+      getopts::optopt("", "shard", "Split the tests into M shards and only run \
+                                    the Nth of them (1 <= N <= M)", "N/M"),
       getopts::optopt("", "logfile", "Write logs to the specified file instead \
                           of stdout", "PATH"),
       getopts::optflag("", "nocapture", "don't capture stdout/stderr of each \
@@ -323,19 +380,39 @@ fn optgroups() -> Vec<getopts::OptGroup> {
       getopts::optopt("", "color", "Configure coloring of output:
             auto   = colorize if stdout is a tty and tests are run on serially (default);
             always = always colorize output;
-            never  = never colorize output;", "auto|always|never"))
+            never  = never colorize output;", "auto|always|never"),
+      getopts::optopt("", "format", "Configure formatting of output:
+            pretty = print verbose output (default);
+            json   = output a stream of JSON events, one per line;
+            junit  = output a JUnit XML report once all tests have run;",
+                      "pretty|json|junit"),
+      getopts::optopt("", "test-timeout", "Fail tests which run for longer \
+                                            than the given number of seconds",
+                      "SECS"),
+      getopts::optopt("", "warn-timeout", "Warn about tests which run for \
+                                            longer than the given number of \
+                                            seconds (default 60)", "SECS"))
 }
 
 fn usage(binary: &str) {
-    let message = format!("Usage: {} [OPTIONS] [FILTER]", binary);
+    let message = format!("Usage: {} [OPTIONS] [FILTER...]", binary);
     println!(r#"{usage}
 
-The FILTER regex is tested against the name of all tests to run, and
-only those tests that match are run.
+Each FILTER string is tested against the name of all tests to run, and
+only those tests that match at least one of them are run. With --exact
+a test's name has to equal a filter rather than just contain it.
+
+Sharding with --shard is applied after filtering, so running every
+shard from 1/M to M/M with the same filters runs each test exactly once.
 
 By default, all tests are run in parallel. This can be altered with the
 RUST_TEST_THREADS environment variable when running tests (set it to 1).
 
+A warning is printed for tests which are still running after --warn-timeout
+seconds. With --test-timeout such tests fail instead. A test which timed out
+can't be stopped, so it keeps its thread until it finishes and no other test
+is started in its place.
+
 All tests have their standard output and standard error captured by default.
 This can be overridden with the --nocapture flag or the RUST_TEST_NOCAPTURE=1
 environment variable. Logging is not captured by default.
@@ -368,12 +445,34 @@ pub fn parse_opts(args: &[String]) -> Option<OptRes> {
 
     if matches.opt_present("h") { usage(&args[0]); return None; }
 
-    let filter = if !matches.free.is_empty() {
-        Some(matches.free[0].clone())
+    let list = matches.opt_present("list");
+    let filters = matches.free.clone();
+    let filter_exact = matches.opt_present("exact");
+    let skip = matches.opt_strs("skip");
+
+    let shuffle = if matches.opt_present("shuffle") {
+        match matches.opt_str("shuffle") {
+            Some(s) => match s.parse::<u64>() {
+                Ok(seed) => Some(seed),
+                Err(_) => return Some(Err(format!("argument for --shuffle must \
+                                                   be a number (was {})", s))),
+            },
+            None => Some(random_seed()),
+        }
     } else {
         None
     };
 
+    let shard = match matches.opt_str("shard") {
+        None => None,
+        Some(s) => match parse_shard(&s) {
+            Some(shard) => Some(shard),
+            None => return Some(Err(format!("argument for --shard must be of \
+                                             the form N/M with 1 <= N <= M \
+                                             (was {})", s))),
+        },
+    };
+
     let run_ignored = matches.opt_present("ignored");
 
     let logfile = matches.opt_str("logfile");
@@ -398,19 +497,77 @@ pub fn parse_opts(args: &[String]) -> Option<OptRes> {
                                             v))),
     };
 
+    let format = match matches.opt_str("format").as_ref().map(|s| &**s) {
+        Some("pretty") | None => OutputFormat::Pretty,
+        Some("json") => OutputFormat::Json,
+        Some("junit") => OutputFormat::Junit,
+
+        Some(v) => return Some(Err(format!("argument for --format must be \
+                                            pretty, json, or junit (was {})",
+                                            v))),
+    };
+
+    let test_timeout = match matches.opt_str("test-timeout") {
+        None => None,
+        Some(s) => match s.parse::<u64>() {
+            Ok(n) if n > 0 => Some(Duration::from_secs(n)),
+            _ => return Some(Err(format!("argument for --test-timeout must be \
+                                          a positive number of seconds (was {})",
+                                          s))),
+        },
+    };
+
+    let warn_timeout = match matches.opt_str("warn-timeout") {
+        None => Duration::from_secs(TEST_WARN_TIMEOUT_S),
+        Some(s) => match s.parse::<u64>() {
+            Ok(n) if n > 0 => Duration::from_secs(n),
+            _ => return Some(Err(format!("argument for --warn-timeout must be \
+                                          a positive number of seconds (was {})",
+                                          s))),
+        },
+    };
+
     let test_opts = TestOpts {
-        filter: filter,
+        list: list,
+        filters: filters,
+        filter_exact: filter_exact,
+        skip: skip,
+        shuffle: shuffle,
+        shard: shard,
         run_ignored: run_ignored,
         run_tests: run_tests,
         bench_benchmarks: bench_benchmarks,
         logfile: logfile,
         nocapture: nocapture,
         color: color,
+        format: format,
+        test_timeout: test_timeout,
+        warn_timeout: warn_timeout,
     };
 
     Some(Ok(test_opts))
 }
 
+// Parses a `--shard` argument of the form "N/M" into a zero-based index and
+// the number of shards
+fn parse_shard(s: &str) -> Option<(usize, usize)> {
+    let mut parts = s.splitn(2, '/');
+    let index = parts.next().and_then(|n| n.parse::<usize>().ok());
+    let count = parts.next().and_then(|m| m.parse::<usize>().ok());
+    match (index, count) {
+        (Some(n), Some(m)) if 1 <= n && n <= m => Some((n - 1, m)),
+        _ => None,
+    }
+}
+
+// Picks a seed for `--shuffle` when none was given on the command line
+fn random_seed() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)
+                               .unwrap_or(Duration::new(0, 0));
+    now.secs() ^ ((now.extra_nanos() as u64) << 32)
+}
+
 #[derive(Clone, PartialEq)]
 pub struct BenchSamples {
     ns_iter_summ: stats::Summary,
@@ -421,6 +578,7 @@ pub struct BenchSamples {
 pub enum TestResult {
     TrOk,
     TrFailed,
+    TrTimedOut,
     TrIgnored,
     TrMetrics(MetricMap),
     TrBench(BenchSamples),
@@ -437,6 +595,8 @@ struct ConsoleTestState<T> {
     log_out: Option<File>,
     out: OutputLocation<T>,
     use_color: bool,
+    format: OutputFormat,
+    shuffle_seed: Option<u64>,
     total: usize,
     passed: usize,
     failed: usize,
@@ -444,7 +604,13 @@ struct ConsoleTestState<T> {
     measured: usize,
     metrics: MetricMap,
     failures: Vec<(TestDesc, Vec<u8> )> ,
+    timed_out: Vec<TestDesc>,
+    // every result seen so far, only kept around for the JUnit report
+    results: Vec<(TestDesc, TestResult, Vec<u8>)>,
     max_name_len: usize, // number of columns to fill when aligning names
+    warn_timeout: Duration,
+    // the `test foo ... ` text of a test whose result hasn't been printed yet
+    open_line: Option<String>,
 }
 
 impl<T: Write> ConsoleTestState<T> {
@@ -463,6 +629,8 @@ impl<T: Write> ConsoleTestState<T> {
             out: out,
             log_out: log_out,
             use_color: use_color(opts),
+            format: opts.format,
+            shuffle_seed: opts.shuffle,
             total: 0,
             passed: 0,
             failed: 0,
@@ -470,7 +638,11 @@ impl<T: Write> ConsoleTestState<T> {
             measured: 0,
             metrics: MetricMap::new(),
             failures: Vec::new(),
+            timed_out: Vec::new(),
+            results: Vec::new(),
             max_name_len: 0,
+            warn_timeout: opts.warn_timeout,
+            open_line: None,
         })
     }
 
@@ -482,6 +654,10 @@ impl<T: Write> ConsoleTestState<T> {
         self.write_pretty("FAILED", term::color::RED)
     }
 
+    pub fn write_timed_out(&mut self) -> io::Result<()> {
+        self.write_pretty("TIMEOUT", term::color::RED)
+    }
+
     pub fn write_ignored(&mut self) -> io::Result<()> {
         self.write_pretty("ignored", term::color::YELLOW)
     }
@@ -530,20 +706,134 @@ impl<T: Write> ConsoleTestState<T> {
 
     pub fn write_run_start(&mut self, len: usize) -> io::Result<()> {
         self.total = len;
-        let noun = if len != 1 { "tests" } else { "test" };
-        self.write_plain(&format!("\nrunning {} {}\n", len, noun))
+        match self.format {
+            OutputFormat::Pretty => {
+                let noun = if len != 1 { "tests" } else { "test" };
+                try!(self.write_plain(&format!("\nrunning {} {}\n", len, noun)));
+                match self.shuffle_seed {
+                    Some(seed) => self.write_plain(&format!("shuffle seed: {}\n",
+                                                            seed)),
+                    None => Ok(()),
+                }
+            }
+            OutputFormat::Json => {
+                self.write_plain(&format!("{{ \"type\": \"suite\", \
+                                           \"event\": \"started\", \
+                                           \"test_count\": {} }}\n", len))
+            }
+            OutputFormat::Junit => Ok(()),
+        }
     }
 
     pub fn write_test_start(&mut self, test: &TestDesc,
                             align: NamePadding) -> io::Result<()> {
-        let name = test.padded_name(self.max_name_len, align);
-        self.write_plain(&format!("test {} ... ", name))
+        match self.format {
+            OutputFormat::Pretty => {
+                let name = test.padded_name(self.max_name_len, align);
+                let line = format!("test {} ... ", name);
+                try!(self.write_plain(&line));
+                self.open_line = Some(line);
+                Ok(())
+            }
+            OutputFormat::Json => {
+                self.write_plain(&format!("{{ \"type\": \"test\", \
+                                           \"event\": \"started\", \
+                                           \"name\": {} }}\n",
+                                          json_str(test.name.as_slice())))
+            }
+            OutputFormat::Junit => Ok(()),
+        }
+    }
+
+    pub fn write_long_running(&mut self, test: &TestDesc) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Pretty => {
+                // Don't split up a `test foo ... ` line which is waiting for
+                // its result, put the warning on a line of its own and then
+                // start the interrupted line again.
+                let open_line = self.open_line.take();
+                if open_line.is_some() {
+                    try!(self.write_plain("\n"));
+                }
+                try!(self.write_plain(&format!("test {} has been running for over {}\n",
+                                               test.name, self.warn_timeout)));
+                if let Some(line) = open_line {
+                    try!(self.write_plain(&line));
+                    self.open_line = Some(line);
+                }
+                Ok(())
+            }
+            OutputFormat::Json => {
+                self.write_plain(&format!("{{ \"type\": \"test\", \
+                                           \"event\": \"long_running\", \
+                                           \"name\": {}, \"secs\": {} }}\n",
+                                          json_str(test.name.as_slice()),
+                                          self.warn_timeout.secs() as f64 +
+                                          self.warn_timeout.extra_nanos() as f64 / 1e9))
+            }
+            OutputFormat::Junit => Ok(()),
+        }
     }
 
-    pub fn write_result(&mut self, result: &TestResult) -> io::Result<()> {
+    pub fn write_result(&mut self, test: &TestDesc, result: &TestResult,
+                        stdout: &[u8]) -> io::Result<()> {
+        self.open_line = None;
+        match self.format {
+            OutputFormat::Pretty => self.write_pretty_result(result),
+            OutputFormat::Json => self.write_json_result(test, result, stdout),
+            OutputFormat::Junit => {
+                self.results.push((test.clone(), result.clone(), stdout.to_vec()));
+                Ok(())
+            }
+        }
+    }
+
+    fn write_json_result(&mut self, test: &TestDesc, result: &TestResult,
+                         stdout: &[u8]) -> io::Result<()> {
+        let name = json_str(test.name.as_slice());
+        let line = match *result {
+            TrOk | TrFailed | TrTimedOut | TrIgnored => {
+                let event = match *result {
+                    TrOk => "ok",
+                    TrFailed => "failed",
+                    TrTimedOut => "timeout",
+                    _ => "ignored",
+                };
+                let stdout = if stdout.is_empty() {
+                    String::new()
+                } else {
+                    format!(", \"stdout\": {}",
+                            json_str(&String::from_utf8_lossy(stdout)))
+                };
+                format!("{{ \"type\": \"test\", \"event\": \"{}\", \
+                           \"name\": {}{} }}", event, name, stdout)
+            }
+            TrMetrics(ref mm) => {
+                let MetricMap(ref mm) = *mm;
+                let metrics = mm.iter().map(|(k, v)| {
+                    format!("{}: {{ \"value\": {}, \"noise\": {} }}",
+                            json_str(k), v.value, v.noise)
+                }).collect::<Vec<_>>().connect(", ");
+                format!("{{ \"type\": \"metric\", \"name\": {}, \
+                           \"metrics\": {{ {} }} }}", name, metrics)
+            }
+            TrBench(ref bs) => {
+                let median = bs.ns_iter_summ.median as usize;
+                let deviation = (bs.ns_iter_summ.max - bs.ns_iter_summ.min) as usize;
+                format!("{{ \"type\": \"bench\", \"name\": {}, \
+                           \"median\": {}, \"deviation\": {}, \
+                           \"mib_per_second\": {} }}",
+                        name, median, deviation, bs.mb_s)
+            }
+        };
+        self.write_plain(&format!("{}\n", line))
+    }
+
+    fn write_pretty_result(&mut self, result: &TestResult) -> io::Result<()> {
         try!(match *result {
             TrOk => self.write_ok(),
             TrFailed => self.write_failed(),
+            TrTimedOut => self.write_timed_out(),
             TrIgnored => self.write_ignored(),
             TrMetrics(ref mm) => {
                 try!(self.write_metric());
@@ -568,6 +858,7 @@ impl<T: Write> ConsoleTestState<T> {
                 let s = format!("{} {}\n", match *result {
                         TrOk => "ok".to_string(),
                         TrFailed => "failed".to_string(),
+                        TrTimedOut => "timeout".to_string(),
                         TrIgnored => "ignored".to_string(),
                         TrMetrics(ref mm) => mm.fmt_metrics(),
                         TrBench(ref bs) => fmt_bench_samples(bs)
@@ -603,13 +894,46 @@ impl<T: Write> ConsoleTestState<T> {
         Ok(())
     }
 
+    pub fn write_timeouts(&mut self) -> io::Result<()> {
+        try!(self.write_plain("\ntimeouts:\n"));
+        let mut timed_out = self.timed_out.iter()
+                                          .map(|t| t.name.to_string())
+                                          .collect::<Vec<_>>();
+        timed_out.sort();
+        for name in &timed_out {
+            try!(self.write_plain(&format!("    {}\n", name)));
+        }
+        Ok(())
+    }
+
     pub fn write_run_finish(&mut self) -> io::Result<bool> {
         assert!(self.passed + self.failed + self.ignored + self.measured == self.total);
 
         let success = self.failed == 0;
-        if !success {
+        match self.format {
+            OutputFormat::Pretty => {}
+            OutputFormat::Json => {
+                let s = format!("{{ \"type\": \"suite\", \"event\": \"{}\", \
+                                   \"passed\": {}, \"failed\": {}, \
+                                   \"ignored\": {}, \"measured\": {} }}\n",
+                                if success { "ok" } else { "failed" },
+                                self.passed, self.failed, self.ignored,
+                                self.measured);
+                try!(self.write_plain(&s));
+                return Ok(success);
+            }
+            OutputFormat::Junit => {
+                try!(self.write_junit_report());
+                return Ok(success);
+            }
+        }
+
+        if !self.failures.is_empty() {
             try!(self.write_failures());
         }
+        if !self.timed_out.is_empty() {
+            try!(self.write_timeouts());
+        }
 
         try!(self.write_plain("\ntest result: "));
         if success {
@@ -625,6 +949,76 @@ impl<T: Write> ConsoleTestState<T> {
     }
 }
 
+impl<T: Write> ConsoleTestState<T> {
+    fn write_junit_report(&mut self) -> io::Result<()> {
+        let mut report = String::new();
+        report.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        report.push_str("<testsuites>\n");
+        report.push_str(&format!("<testsuite name=\"test\" package=\"test\" \
+                                  id=\"0\" errors=\"0\" failures=\"{}\" \
+                                  tests=\"{}\" skipped=\"{}\">\n",
+                                 self.failed, self.total, self.ignored));
+        for &(ref desc, ref result, ref stdout) in &self.results {
+            // Test names are module paths, so the module becomes the class
+            // name and the last component the name of the test case.
+            let full_name = desc.name.as_slice();
+            let (class_name, test_name) = match full_name.rfind("::") {
+                Some(i) => (&full_name[..i], &full_name[i + 2..]),
+                None => ("", full_name),
+            };
+            report.push_str(&format!("<testcase classname=\"{}\" name=\"{}\"",
+                                     xml_escape(class_name),
+                                     xml_escape(test_name)));
+            match *result {
+                TrOk | TrMetrics(..) => report.push_str("/>\n"),
+                TrIgnored => report.push_str("><skipped/></testcase>\n"),
+                TrBench(ref bs) => {
+                    report.push_str(&format!("><system-out>{}</system-out>\
+                                              </testcase>\n",
+                                             xml_escape(&fmt_bench_samples(bs))));
+                }
+                TrTimedOut => {
+                    report.push_str("><failure type=\"timeout\" \
+                                     message=\"time limit exceeded\"/>\
+                                     </testcase>\n");
+                }
+                TrFailed => {
+                    report.push_str("><failure type=\"assert\"/>");
+                    if !stdout.is_empty() {
+                        let output = String::from_utf8_lossy(stdout);
+                        report.push_str(&format!("<system-out>{}</system-out>",
+                                                 xml_escape(&output)));
+                    }
+                    report.push_str("</testcase>\n");
+                }
+            }
+        }
+        report.push_str("</testsuite>\n</testsuites>\n");
+        self.write_plain(&report)
+    }
+}
+
+// Quote a string as a JSON string literal
+fn json_str(s: &str) -> String {
+    json::as_json(&s).to_string()
+}
+
+// Escape the characters which are special in XML attribute values and text
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 // Format a number with thousands separators
 fn fmt_thousands_sep(mut n: usize, sep: char) -> String {
     use std::fmt::Write;
@@ -665,6 +1059,40 @@ pub fn fmt_bench_samples(bs: &BenchSamples) -> String {
     output
 }
 
+// Prints the names of the tests which would be run, without running them
+pub fn list_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> io::Result<()> {
+    let mut st = try!(ConsoleTestState::new(opts, None::<io::Stdout>));
+
+    let mut ntest = 0;
+    let mut nbench = 0;
+    let mut nmetric = 0;
+
+    for test in filter_tests(opts, tests) {
+        let fntype = match test.testfn {
+            StaticTestFn(..) | DynTestFn(..) => { ntest += 1; "test" }
+            StaticBenchFn(..) | DynBenchFn(..) => { nbench += 1; "bench" }
+            StaticMetricFn(..) | DynMetricFn(..) => { nmetric += 1; "metric" }
+        };
+
+        try!(st.write_plain(&format!("{}: {}\n", test.desc.name, fntype)));
+    }
+
+    fn plural(count: u32, s: &str) -> String {
+        match count {
+            1 => format!("{} {}", 1, s),
+            n => format!("{} {}s", n, s),
+        }
+    }
+
+    if ntest != 0 || nbench != 0 || nmetric != 0 {
+        try!(st.write_plain("\n"));
+    }
+    st.write_plain(&format!("{}, {}, {}\n",
+                            plural(ntest, "test"),
+                            plural(nbench, "benchmark"),
+                            plural(nmetric, "metric")))
+}
+
 // A simple console test runner
 pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn> ) -> io::Result<bool> {
 
@@ -673,9 +1101,10 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn> ) -> io::Res
         match (*event).clone() {
             TeFiltered(ref filtered_tests) => st.write_run_start(filtered_tests.len()),
             TeWait(ref test, padding) => st.write_test_start(test, padding),
+            TeLongRunning(ref test) => st.write_long_running(test),
             TeResult(test, result, stdout) => {
                 try!(st.write_log(&test, &result));
-                try!(st.write_result(&result));
+                try!(st.write_result(&test, &result, &stdout));
                 match result {
                     TrOk => st.passed += 1,
                     TrIgnored => st.ignored += 1,
@@ -702,6 +1131,10 @@ pub fn run_tests_console(opts: &TestOpts, tests: Vec<TestDescAndFn> ) -> io::Res
                         st.failed += 1;
                         st.failures.push((test, stdout));
                     }
+                    TrTimedOut => {
+                        st.failed += 1;
+                        st.timed_out.push(test);
+                    }
                 }
                 Ok(())
             }
@@ -744,6 +1177,8 @@ fn should_sort_failures_before_printing_them() {
         log_out: None,
         out: Raw(Vec::new()),
         use_color: false,
+        format: OutputFormat::Pretty,
+        shuffle_seed: None,
         total: 0,
         passed: 0,
         failed: 0,
@@ -751,7 +1186,11 @@ fn should_sort_failures_before_printing_them() {
         measured: 0,
         max_name_len: 10,
         metrics: MetricMap::new(),
-        failures: vec!((test_b, Vec::new()), (test_a, Vec::new()))
+        failures: vec!((test_b, Vec::new()), (test_a, Vec::new())),
+        timed_out: Vec::new(),
+        results: Vec::new(),
+        warn_timeout: Duration::from_secs(TEST_WARN_TIMEOUT_S),
+        open_line: None,
     };
 
     st.write_failures().unwrap();
@@ -765,6 +1204,146 @@ fn should_sort_failures_before_printing_them() {
     assert!(apos < bpos);
 }
 
+#[test]
+fn should_write_junit_report() {
+    let test_ok = TestDesc {
+        name: StaticTestName("foo::passes"),
+        ignore: false,
+        should_panic: ShouldPanic::No
+    };
+
+    let test_failed = TestDesc {
+        name: StaticTestName("foo::bar::fails"),
+        ignore: false,
+        should_panic: ShouldPanic::No
+    };
+
+    let mut st = ConsoleTestState {
+        log_out: None,
+        out: Raw(Vec::new()),
+        use_color: false,
+        format: OutputFormat::Junit,
+        shuffle_seed: None,
+        total: 2,
+        passed: 1,
+        failed: 1,
+        ignored: 0,
+        measured: 0,
+        max_name_len: 10,
+        metrics: MetricMap::new(),
+        failures: Vec::new(),
+        timed_out: Vec::new(),
+        results: vec!((test_ok, TrOk, Vec::new()),
+                      (test_failed, TrFailed, b"a < b".to_vec())),
+        warn_timeout: Duration::from_secs(TEST_WARN_TIMEOUT_S),
+        open_line: None,
+    };
+
+    assert!(!st.write_run_finish().unwrap());
+    let s = match st.out {
+        Raw(ref m) => String::from_utf8_lossy(&m[..]),
+        Pretty(_) => unreachable!()
+    };
+
+    assert!(s.contains("failures=\"1\" tests=\"2\" skipped=\"0\""));
+    assert!(s.contains("<testcase classname=\"foo\" name=\"passes\"/>"));
+    assert!(s.contains("<testcase classname=\"foo::bar\" name=\"fails\">\
+                        <failure type=\"assert\"/>\
+                        <system-out>a &lt; b</system-out></testcase>"));
+}
+
+#[test]
+fn should_write_long_running_warning_on_its_own_line() {
+    let test = TestDesc {
+        name: StaticTestName("slow"),
+        ignore: false,
+        should_panic: ShouldPanic::No
+    };
+
+    let mut st = ConsoleTestState {
+        log_out: None,
+        out: Raw(Vec::new()),
+        use_color: false,
+        format: OutputFormat::Pretty,
+        shuffle_seed: None,
+        total: 0,
+        passed: 0,
+        failed: 0,
+        ignored: 0,
+        measured: 0,
+        max_name_len: 10,
+        metrics: MetricMap::new(),
+        failures: Vec::new(),
+        timed_out: Vec::new(),
+        results: Vec::new(),
+        warn_timeout: Duration::from_secs(5),
+        open_line: None,
+    };
+
+    st.write_test_start(&test, PadNone).unwrap();
+    st.write_long_running(&test).unwrap();
+    st.write_result(&test, &TrOk, b"").unwrap();
+    st.write_long_running(&test).unwrap();
+    let s = match st.out {
+        Raw(ref m) => String::from_utf8_lossy(&m[..]),
+        Pretty(_) => unreachable!()
+    };
+
+    let lines = s.lines().collect::<Vec<_>>();
+    assert_eq!(lines, [
+        "test slow ... ",
+        "test slow has been running for over 5 seconds",
+        "test slow ... ok",
+        "test slow has been running for over 5 seconds",
+    ]);
+}
+
+#[test]
+fn should_write_json_events() {
+    let test = TestDesc {
+        name: StaticTestName("a \"quoted\" test"),
+        ignore: false,
+        should_panic: ShouldPanic::No
+    };
+
+    let mut st = ConsoleTestState {
+        log_out: None,
+        out: Raw(Vec::new()),
+        use_color: false,
+        format: OutputFormat::Json,
+        shuffle_seed: None,
+        total: 0,
+        passed: 0,
+        failed: 0,
+        ignored: 0,
+        measured: 0,
+        max_name_len: 10,
+        metrics: MetricMap::new(),
+        failures: Vec::new(),
+        timed_out: Vec::new(),
+        results: Vec::new(),
+        warn_timeout: Duration::from_secs(TEST_WARN_TIMEOUT_S),
+        open_line: None,
+    };
+
+    st.write_run_start(1).unwrap();
+    st.write_test_start(&test, PadNone).unwrap();
+    st.write_result(&test, &TrFailed, b"boom\n").unwrap();
+    let s = match st.out {
+        Raw(ref m) => String::from_utf8_lossy(&m[..]),
+        Pretty(_) => unreachable!()
+    };
+
+    let lines = s.lines().collect::<Vec<_>>();
+    assert_eq!(lines, [
+        "{ \"type\": \"suite\", \"event\": \"started\", \"test_count\": 1 }",
+        "{ \"type\": \"test\", \"event\": \"started\", \
+           \"name\": \"a \\\"quoted\\\" test\" }",
+        "{ \"type\": \"test\", \"event\": \"failed\", \
+           \"name\": \"a \\\"quoted\\\" test\", \"stdout\": \"boom\\n\" }",
+    ]);
+}
+
 fn use_color(opts: &TestOpts) -> bool {
     match opts.color {
         AutoColor => !opts.nocapture && stdout_isatty(),
@@ -796,6 +1375,7 @@ fn stdout_isatty() -> bool {
 enum TestEvent {
     TeFiltered(Vec<TestDesc> ),
     TeWait(TestDesc, NamePadding),
+    TeLongRunning(TestDesc),
     TeResult(TestDesc, TestResult, Vec<u8> ),
 }
 
@@ -836,6 +1416,27 @@ fn run_tests<F>(opts: &TestOpts,
 
     let (tx, rx) = channel::<MonitorMsg>();
 
+    // Tests run on their own threads and report back over `rx`, so a hung
+    // test would block us forever. A ticker wakes us up regularly to check
+    // on the tests which are still running; it exits once `tick_rx` is gone.
+    let (tick_tx, tick_rx) = channel::<()>();
+    thread::spawn(move || {
+        while tick_tx.send(()).is_ok() {
+            thread::sleep(Duration::from_millis(TIMEOUT_CHECK_INTERVAL_MS));
+        }
+    });
+
+    struct RunningTest {
+        desc: TestDesc,
+        start: Instant,
+        warned: bool,
+    }
+    let mut running_tests: Vec<RunningTest> = Vec::new();
+    // Tests which have been reported as timed out but haven't finished yet.
+    // They give up their slot so that the run can go on without them, and
+    // whatever they report if they do finish is ignored.
+    let mut abandoned_tests: Vec<TestDesc> = Vec::new();
+
     while pending > 0 || !remaining.is_empty() {
         while pending < concurrency && !remaining.is_empty() {
             let test = remaining.pop().unwrap();
@@ -845,11 +1446,65 @@ fn run_tests<F>(opts: &TestOpts,
                 // that hang forever.
                 try!(callback(TeWait(test.desc.clone(), test.testfn.padding())));
             }
+            running_tests.push(RunningTest {
+                desc: test.desc.clone(),
+                start: Instant::now(),
+                warned: false,
+            });
             run_test(opts, !opts.run_tests, test, tx.clone());
             pending += 1;
         }
 
-        let (desc, result, stdout) = rx.recv().unwrap();
+        let msg = select! {
+            msg = rx.recv() => Some(msg.unwrap()),
+            _tick = tick_rx.recv() => None
+        };
+
+        let (desc, result, stdout) = match msg {
+            Some(msg) => msg,
+            None => {
+                let now = Instant::now();
+                let mut i = 0;
+                while i < running_tests.len() {
+                    let elapsed = now.duration_since(running_tests[i].start);
+                    match opts.test_timeout {
+                        Some(timeout) if elapsed >= timeout => {
+                            // There is no way to stop the test's thread, so
+                            // report it now and stop waiting for its result.
+                            let test = running_tests.remove(i);
+                            if concurrency != 1 {
+                                try!(callback(TeWait(test.desc.clone(), PadNone)));
+                            }
+                            try!(callback(TeResult(test.desc.clone(), TrTimedOut,
+                                                   Vec::new())));
+                            abandoned_tests.push(test.desc);
+                            pending -= 1;
+                            continue
+                        }
+                        _ => {}
+                    }
+                    if !running_tests[i].warned && elapsed >= opts.warn_timeout {
+                        running_tests[i].warned = true;
+                        try!(callback(TeLongRunning(running_tests[i].desc.clone())));
+                    }
+                    i += 1;
+                }
+                continue
+            }
+        };
+
+        match running_tests.iter().position(|t| t.desc == desc) {
+            Some(i) => { running_tests.remove(i); }
+            None => {
+                // A test we already gave up on has finished after all; its
+                // result was reported when it timed out.
+                if let Some(i) = abandoned_tests.iter().position(|t| *t == desc) {
+                    abandoned_tests.remove(i);
+                    continue
+                }
+            }
+        }
+
         if concurrency != 1 {
             try!(callback(TeWait(desc.clone(), PadNone)));
         }
@@ -861,9 +1516,15 @@ fn run_tests<F>(opts: &TestOpts,
         // All benchmarks run at the end, in serial.
         // (this includes metric fns)
         for b in filtered_benchs_and_metrics {
-            try!(callback(TeWait(b.desc.clone(), b.testfn.padding())));
+            let desc = b.desc.clone();
+            try!(callback(TeWait(desc.clone(), b.testfn.padding())));
             run_test(opts, false, b, tx.clone());
-            let (test, result, stdout) = rx.recv().unwrap();
+            // Tests which timed out earlier may still finish in the meantime
+            let mut msg = rx.recv().unwrap();
+            while msg.0 != desc {
+                msg = rx.recv().unwrap();
+            }
+            let (test, result, stdout) = msg;
             try!(callback(TeResult(test, result, stdout)));
         }
     }
@@ -894,16 +1555,27 @@ fn get_concurrency() -> usize {
 pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescAndFn> {
     let mut filtered = tests;
 
-    // Remove tests that don't match the test filter
-    filtered = match opts.filter {
-        None => filtered,
-        Some(ref filter) => {
-            filtered.into_iter().filter(|test| {
-                test.desc.name.as_slice().contains(&filter[..])
-            }).collect()
+    let matches_filter = |test: &TestDescAndFn, filter: &str| {
+        let test_name = test.desc.name.as_slice();
+        if opts.filter_exact {
+            test_name == filter
+        } else {
+            test_name.contains(filter)
         }
     };
 
+    // Remove tests that don't match any of the test filters
+    if !opts.filters.is_empty() {
+        filtered = filtered.into_iter().filter(|test| {
+            opts.filters.iter().any(|filter| matches_filter(test, &filter[..]))
+        }).collect();
+    }
+
+    // Skip tests that match any of the skip filters
+    filtered = filtered.into_iter().filter(|test| {
+        !opts.skip.iter().any(|sf| matches_filter(test, &sf[..]))
+    }).collect();
+
     // Maybe pull out the ignored test and unignore them
     filtered = if !opts.run_ignored {
         filtered
@@ -925,9 +1597,41 @@ pub fn filter_tests(opts: &TestOpts, tests: Vec<TestDescAndFn>) -> Vec<TestDescA
     // Sort the tests alphabetically
     filtered.sort_by(|t1, t2| t1.desc.name.as_slice().cmp(t2.desc.name.as_slice()));
 
+    // Only keep our own shard. This happens after sorting so that every
+    // machine running the same binary agrees on which tests go where.
+    if let Some((index, count)) = opts.shard {
+        filtered = filtered.into_iter().enumerate()
+                           .filter(|&(i, _)| i % count == index)
+                           .map(|(_, test)| test)
+                           .collect();
+    }
+
+    if let Some(seed) = opts.shuffle {
+        shuffle_tests(seed, &mut filtered);
+    }
+
     filtered
 }
 
+// Shuffles the tests with a Fisher-Yates shuffle driven by a small
+// deterministic generator, so that a given seed always yields the same order
+fn shuffle_tests(seed: u64, tests: &mut [TestDescAndFn]) {
+    // SplitMix64, see http://xorshift.di.unimi.it/splitmix64.c
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    };
+
+    for i in (1..tests.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        tests.swap(i, j);
+    }
+}
+
 pub fn convert_benchmarks_to_tests(tests: Vec<TestDescAndFn>) -> Vec<TestDescAndFn> {
     // convert benchmarks to tests, if we're not benchmarking them
     tests.into_iter().map(|x| {
@@ -1223,11 +1927,15 @@ pub mod bench {
 
 #[cfg(test)]
 mod tests {
-    use test::{TrFailed, TrIgnored, TrOk, filter_tests, parse_opts,
+    use test::{TrFailed, TrIgnored, TrOk, TrTimedOut, filter_tests, parse_opts,
                TestDesc, TestDescAndFn, TestOpts, run_test,
                MetricMap,
                StaticTestName, DynTestName, DynTestFn, ShouldPanic};
+    use super::run_tests;
+    use super::TestEvent::{TeLongRunning, TeResult};
     use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
 
     #[test]
     pub fn do_not_run_ignored_tests() {
@@ -1343,6 +2051,121 @@ mod tests {
         assert!((opts.run_ignored));
     }
 
+    fn sleeping_test(name: &'static str, ms: u64) -> TestDescAndFn {
+        TestDescAndFn {
+            desc: TestDesc {
+                name: StaticTestName(name),
+                ignore: false,
+                should_panic: ShouldPanic::No,
+            },
+            testfn: DynTestFn(Box::new(move || {
+                thread::sleep(Duration::from_millis(ms))
+            })),
+        }
+    }
+
+    #[test]
+    fn long_running_tests_are_reported() {
+        let mut opts = TestOpts::new();
+        opts.run_tests = true;
+        opts.warn_timeout = Duration::from_millis(100);
+        let tests = vec!(sleeping_test("slow", 1000), sleeping_test("fast", 0));
+
+        let mut events = Vec::new();
+        run_tests(&opts, tests, |e| { events.push(e); Ok(()) }).unwrap();
+
+        let warned = events.iter().filter_map(|e| match *e {
+            TeLongRunning(ref desc) => Some(desc.name.to_string()),
+            _ => None,
+        }).collect::<Vec<_>>();
+        assert_eq!(warned, ["slow"]);
+        assert!(events.iter().all(|e| match *e {
+            TeResult(_, ref res, _) => *res == TrOk,
+            _ => true,
+        }));
+    }
+
+    #[test]
+    fn timed_out_tests_fail() {
+        let mut opts = TestOpts::new();
+        opts.run_tests = true;
+        opts.test_timeout = Some(Duration::from_millis(100));
+        let tests = vec!(sleeping_test("fast", 0), sleeping_test("hangs", 60 * 1000));
+
+        let mut events = Vec::new();
+        run_tests(&opts, tests, |e| { events.push(e); Ok(()) }).unwrap();
+
+        let results = events.iter().filter_map(|e| match *e {
+            TeResult(ref desc, ref res, _) => Some((desc.name.to_string(),
+                                                    res.clone())),
+            _ => None,
+        }).collect::<Vec<_>>();
+        assert_eq!(results.len(), 2);
+        assert!(results.contains(&("hangs".to_string(), TrTimedOut)));
+        assert!(results.contains(&("fast".to_string(), TrOk)));
+    }
+
+    #[test]
+    fn timed_out_tests_give_up_their_slot() {
+        let mut opts = TestOpts::new();
+        opts.run_tests = true;
+        opts.test_timeout = Some(Duration::from_millis(100));
+        // Enough hanging tests to take up every test thread at once.
+        let hanging = super::get_concurrency();
+        let mut tests = (0..hanging).map(|_| sleeping_test("hangs", 60 * 1000))
+                                    .collect::<Vec<_>>();
+        tests.push(sleeping_test("fast", 0));
+
+        let mut events = Vec::new();
+        run_tests(&opts, tests, |e| { events.push(e); Ok(()) }).unwrap();
+
+        let results = events.iter().filter_map(|e| match *e {
+            TeResult(ref desc, ref res, _) => Some((desc.name.to_string(),
+                                                    res.clone())),
+            _ => None,
+        }).collect::<Vec<_>>();
+        assert_eq!(results.len(), hanging + 1);
+        assert!(results.contains(&("fast".to_string(), TrOk)));
+    }
+
+    #[test]
+    fn parse_warn_timeout() {
+        let args = vec!("progname".to_string(),
+                        "--warn-timeout".to_string(),
+                        "5".to_string());
+        let opts = match parse_opts(&args) {
+            Some(Ok(o)) => o,
+            _ => panic!("Malformed arg in parse_warn_timeout")
+        };
+        assert_eq!(opts.warn_timeout, Duration::from_secs(5));
+
+        let opts = match parse_opts(&["progname".to_string()]) {
+            Some(Ok(o)) => o,
+            _ => panic!("Malformed arg in parse_warn_timeout")
+        };
+        assert_eq!(opts.warn_timeout, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn parse_test_timeout() {
+        let args = vec!("progname".to_string(),
+                        "--test-timeout".to_string(),
+                        "30".to_string());
+        let opts = match parse_opts(&args) {
+            Some(Ok(o)) => o,
+            _ => panic!("Malformed arg in parse_test_timeout")
+        };
+        assert_eq!(opts.test_timeout, Some(Duration::from_secs(30)));
+
+        let args = vec!("progname".to_string(),
+                        "--test-timeout".to_string(),
+                        "0".to_string());
+        match parse_opts(&args) {
+            Some(Err(_)) => {}
+            _ => panic!("--test-timeout=0 should be rejected")
+        }
+    }
+
     #[test]
     pub fn filter_for_ignored_option() {
         // When we run ignored tests the test filter should filter out all the
@@ -1427,6 +2250,93 @@ mod tests {
         }
     }
 
+    fn dummy_tests(names: &[&str]) -> Vec<TestDescAndFn> {
+        names.iter().map(|name| {
+            TestDescAndFn {
+                desc: TestDesc {
+                    name: DynTestName(name.to_string()),
+                    ignore: false,
+                    should_panic: ShouldPanic::No,
+                },
+                testfn: DynTestFn(Box::new(move|| {})),
+            }
+        }).collect()
+    }
+
+    fn names(tests: &[TestDescAndFn]) -> Vec<String> {
+        tests.iter().map(|t| t.desc.name.to_string()).collect()
+    }
+
+    #[test]
+    pub fn filter_multiple_and_skip() {
+        let mut opts = TestOpts::new();
+        opts.filters = vec!("foo".to_string(), "bar".to_string());
+        opts.skip = vec!("slow".to_string());
+
+        let tests = dummy_tests(&["foo::a", "bar::b", "baz::c", "foo::slow"]);
+        assert_eq!(names(&filter_tests(&opts, tests)), ["bar::b", "foo::a"]);
+    }
+
+    #[test]
+    pub fn filter_exact() {
+        let mut opts = TestOpts::new();
+        opts.filters = vec!("foo::a".to_string());
+        opts.filter_exact = true;
+
+        let tests = dummy_tests(&["foo::a", "foo::ab", "bar::foo::a"]);
+        assert_eq!(names(&filter_tests(&opts, tests)), ["foo::a"]);
+    }
+
+    #[test]
+    pub fn shards_partition_tests() {
+        let all = ["a", "b", "c", "d", "e", "f", "g"];
+        let mut seen = Vec::new();
+        for index in 0..3 {
+            let mut opts = TestOpts::new();
+            opts.shard = Some((index, 3));
+            seen.extend(names(&filter_tests(&opts, dummy_tests(&all))));
+        }
+        seen.sort();
+        assert_eq!(seen, all);
+    }
+
+    #[test]
+    pub fn shuffle_is_deterministic() {
+        let all = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"];
+        let mut opts = TestOpts::new();
+        opts.shuffle = Some(42);
+
+        let first = names(&filter_tests(&opts, dummy_tests(&all)));
+        let second = names(&filter_tests(&opts, dummy_tests(&all)));
+        assert_eq!(first, second);
+
+        let mut sorted = first.clone();
+        sorted.sort();
+        assert_eq!(sorted, all);
+    }
+
+    #[test]
+    fn parse_shard_option() {
+        let args = vec!("progname".to_string(),
+                        "--shard".to_string(),
+                        "2/4".to_string());
+        let opts = match parse_opts(&args) {
+            Some(Ok(o)) => o,
+            _ => panic!("Malformed arg in parse_shard_option")
+        };
+        assert_eq!(opts.shard, Some((1, 4)));
+
+        for bad in &["0/4", "5/4", "2", "a/b"] {
+            let args = vec!("progname".to_string(),
+                            "--shard".to_string(),
+                            bad.to_string());
+            match parse_opts(&args) {
+                Some(Err(_)) => {}
+                _ => panic!("--shard {} should be rejected", bad)
+            }
+        }
+    }
+
     #[test]
     pub fn test_metricmap_compare() {
         let mut m1 = MetricMap::new();
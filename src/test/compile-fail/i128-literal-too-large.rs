@@ -0,0 +1,19 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Integer literals are still parsed into 64 bits, whatever their suffix.
+
+#![feature(i128_type)]
+
+fn main() {
+    let x = 340282366920938463463374607431768211455u128; //~ ERROR int literal is too large
+    //~^ HELP integer literals are limited to 64 bits
+    let y = 18446744073709551615u128;
+}
@@ -0,0 +1,22 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Threads spawned in a scope may only borrow data that outlives the scope.
+
+#![feature(thread_scope)]
+
+use std::thread;
+
+fn main() {
+    thread::scope(|s| {
+        let x = 5;
+        s.spawn(|| println!("{}", x)); //~ ERROR does not live long enough
+    });
+}
@@ -0,0 +1,34 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod a {
+    pub fn value() -> u32 { 1 }
+}
+
+mod b {
+    #[cfg(not(changed))]
+    pub fn value() -> u32 { 2 }
+    #[cfg(changed)]
+    pub fn value() -> u32 { 3 }
+}
+
+mod c { pub fn value() -> u32 { 3 } }
+mod d { pub fn value() -> u32 { 4 } }
+mod e { pub fn value() -> u32 { 5 } }
+mod f { pub fn value() -> u32 { 6 } }
+mod g { pub fn value() -> u32 { 7 } }
+mod h { pub fn value() -> u32 { 8 } }
+mod i { pub fn value() -> u32 { 9 } }
+
+fn main() {
+    let rest = c::value() + d::value() + e::value() + f::value() +
+               g::value() + h::value() + i::value();
+    println!("a={} b={} rest={}", a::value(), b::value(), rest);
+}
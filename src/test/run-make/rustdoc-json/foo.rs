@@ -9,6 +9,9 @@
 // except according to those terms.
 
 #![crate_name = "foo"]
+#![feature(staged_api)]
+#![staged_api]
+#![unstable(feature = "foo_docs", reason = "so test")]
 
 //! Very docs
 
@@ -21,5 +24,14 @@ pub mod bar {
     }
 
     /// *wow*
+    #[stable(feature = "doge", since = "1.0.0")]
     pub trait Doge { fn dummy(&self) { } }
+
+    /// Such generic
+    pub struct Wrapper<T> where T: Clone { pub inner: T }
+
+    impl<T: Clone> Doge for Wrapper<T> { }
+
+    /// Not thread safe
+    pub struct Pointer(pub *const u8);
 }
@@ -0,0 +1,39 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -O
+
+// 128-bit `/` and `%` of values LLVM can't see through are lowered to calls to
+// `__divti3` and friends, which compiler-rt lacks on 32-bit targets and on
+// 64-bit Windows. This makes sure they still link there (and give the right
+// answers), with libcore supplying them.
+
+#![feature(i128_type)]
+
+fn opaque<T: std::str::FromStr>(s: &str) -> T {
+    match s.parse() {
+        Ok(x) => x,
+        Err(_) => panic!("bad number {}", s),
+    }
+}
+
+fn main() {
+    let n: u128 = opaque("340282366920938463463374607431768211455");
+    let d: u128 = opaque("18446744073709551629");
+    assert_eq!(n / d, opaque("18446744073709551603"));
+    assert_eq!(n % d, opaque("168"));
+
+    let n: i128 = opaque("-170141183460469231731687303715884105728");
+    let d: i128 = opaque("7");
+    assert_eq!(n / d, opaque("-24305883351495604533098186245126300818"));
+    assert_eq!(n % d, opaque("-2"));
+    assert_eq!(n / -d, opaque("24305883351495604533098186245126300818"));
+    assert_eq!(n % -d, opaque("-2"));
+}
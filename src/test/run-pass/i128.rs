@@ -0,0 +1,98 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(i128_type, i128, wrapping)]
+
+use std::{i128, u128};
+use std::num::Wrapping;
+
+fn main() {
+    let x: i128 = -1;
+    assert_eq!(0, !x);
+    let y: i128 = -2;
+    assert_eq!(!1, y);
+    let z: i128 = 0xABCD_EF;
+    assert_eq!(z * z, 0x734C_C2F2_A521);
+    assert_eq!(z * z * z * z, 0x33EE_0E2A_54E2_59DA_A0E7_8E41);
+    assert_eq!(-z * -z, 0x734C_C2F2_A521);
+    assert_eq!(-z * -z * -z * -z, 0x33EE_0E2A_54E2_59DA_A0E7_8E41);
+    assert_eq!(-z + -z + -z + -z, -0x2AF3_7BC);
+
+    // literals are at most 64 bits wide, so build the wide values by shifting
+    let k: i128 = (0x1234_5678_9ABC_DEFF << 64) | 0xEEDD_CCBB_AA99_8877;
+    assert_eq!(k >> 64, 0x1234_5678_9ABC_DEFF);
+    assert_eq!(k as u64, 0xEEDD_CCBB_AA99_8877);
+    assert_eq!(k + k, (0x2468_ACF1_3579_BDFF << 64) | 0xDDBB_9977_5533_10EE);
+    assert_eq!(-k - k, -((0x2468_ACF1_3579_BDFF << 64) | 0xDDBB_9977_5533_10EE));
+    assert_eq!(-k >> 127, -1);
+    assert_eq!(k.count_ones(), 74);
+    assert_eq!(k.leading_zeros(), 3);
+    assert_eq!(k.trailing_zeros(), 0);
+    assert_eq!(k.swap_bytes().swap_bytes(), k);
+
+    assert_eq!(i128::MIN, 1 << 127);
+    assert_eq!(i128::MAX, !i128::MIN);
+    assert_eq!(i128::MAX.checked_add(1), None);
+    assert_eq!(i128::MIN.checked_sub(1), None);
+    assert_eq!(i128::MAX.wrapping_add(1), i128::MIN);
+    assert_eq!(i128::MIN.overflowing_neg(), (i128::MIN, true));
+    assert_eq!((Wrapping(i128::MAX) + Wrapping(1)).0, i128::MIN);
+
+    let l: u128 = !0;
+    assert_eq!(l, u128::MAX);
+    assert_eq!(l >> 64, 0xFFFF_FFFF_FFFF_FFFF);
+    assert_eq!(l.count_ones(), 128);
+    assert_eq!((l >> 1).leading_zeros(), 1);
+    assert_eq!(l.checked_add(1), None);
+    assert_eq!(l.overflowing_add(1), (0, true));
+    assert_eq!(l.wrapping_mul(l), 1);
+    assert_eq!((l as u64) as u128, 0xFFFF_FFFF_FFFF_FFFF);
+    assert_eq!(-1i128 as u128, l);
+    assert!(l > 0xFFFF_FFFF_FFFF_FFFF);
+    assert!(-k < k);
+
+    assert_eq!(format!("{}", u128::MAX), "340282366920938463463374607431768211455");
+    assert_eq!(format!("{}", i128::MIN), "-170141183460469231731687303715884105728");
+    assert_eq!(format!("{:x}", k), "123456789abcdeffeeddccbbaa998877");
+    assert_eq!(format!("{:b}", l).len(), 128);
+    assert_eq!("340282366920938463463374607431768211455".parse::<u128>(), Ok(u128::MAX));
+    assert_eq!("-170141183460469231731687303715884105728".parse::<i128>(), Ok(i128::MIN));
+    assert!("340282366920938463463374607431768211456".parse::<u128>().is_err());
+
+    div_rem();
+}
+
+// Wider than constant evaluation, which works in 64 bits.
+const THIRD: u128 = (1 << 100) / 3;
+
+fn div_rem() {
+    let k: i128 = (0x1234_5678_9ABC_DEFF << 64) | 0xEEDD_CCBB_AA99_8877;
+    assert_eq!(k / (1 << 64), 0x1234_5678_9ABC_DEFF);
+    assert_eq!(k % 0x1_0000_0000, 0xAA99_8877);
+    assert_eq!(-k / (1 << 64), -0x1234_5678_9ABC_DEFF);
+    assert_eq!(-k % 0x1_0000_0000, -0xAA99_8877);
+    assert_eq!(i128::MIN.checked_div(-1), None);
+    assert_eq!(i128::MIN.overflowing_rem(-1), (0, true));
+
+    let l: u128 = !0;
+    assert_eq!(l / 3, 0x5555_5555_5555_5555 << 64 | 0x5555_5555_5555_5555);
+    assert_eq!(l % 10, 5);
+    assert_eq!(l / l, 1);
+    assert_eq!(k / -k, -1);
+    assert_eq!(i128::MIN / 2, -(1 << 126));
+    assert_eq!(i128::MIN % 3, -2);
+    assert_eq!(THIRD, (1 << 100) / three());
+    assert_eq!(THIRD * 3 + 1, 1 << 100);
+}
+
+// Keeps the division out of reach of constant folding.
+fn three() -> u128 {
+    "3".parse().unwrap()
+}
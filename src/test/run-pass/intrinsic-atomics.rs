@@ -18,6 +18,12 @@ mod rusti {
         pub fn atomic_cxchg<T>(dst: *mut T, old: T, src: T) -> T;
         pub fn atomic_cxchg_acq<T>(dst: *mut T, old: T, src: T) -> T;
         pub fn atomic_cxchg_rel<T>(dst: *mut T, old: T, src: T) -> T;
+        pub fn atomic_cxchg_failrelaxed<T>(dst: *mut T, old: T, src: T) -> T;
+        pub fn atomic_cxchg_acq_failrelaxed<T>(dst: *mut T, old: T, src: T) -> T;
+
+        pub fn atomic_cxchgweak<T>(dst: *mut T, old: T, src: T) -> (T, bool);
+        pub fn atomic_cxchgweak_acq<T>(dst: *mut T, old: T, src: T) -> (T, bool);
+        pub fn atomic_cxchgweak_acqrel_failrelaxed<T>(dst: *mut T, old: T, src: T) -> (T, bool);
 
         pub fn atomic_load<T>(src: *const T) -> T;
         pub fn atomic_load_acq<T>(src: *const T) -> T;
@@ -61,6 +67,34 @@ pub fn main() {
         assert_eq!(rusti::atomic_cxchg_rel(&mut *x, 2, 1), 2);
         assert_eq!(*x, 1);
 
+        assert_eq!(rusti::atomic_cxchg_failrelaxed(&mut *x, 1, 2), 1);
+        assert_eq!(*x, 2);
+
+        assert_eq!(rusti::atomic_cxchg_acq_failrelaxed(&mut *x, 1, 3), 2);
+        assert_eq!(*x, 2);
+
+        // a weak exchange may fail spuriously, so retry until it succeeds
+        loop {
+            match rusti::atomic_cxchgweak(&mut *x, 2, 1) {
+                (2, true) => break,
+                (2, false) => continue,
+                (old, _) => panic!("unexpected old value {}", old),
+            }
+        }
+        assert_eq!(*x, 1);
+
+        assert_eq!(rusti::atomic_cxchgweak_acq(&mut *x, 2, 3), (1, false));
+        assert_eq!(*x, 1);
+
+        loop {
+            if rusti::atomic_cxchgweak_acqrel_failrelaxed(&mut *x, 1, 2) == (1, true) {
+                break
+            }
+        }
+        assert_eq!(*x, 2);
+
+        rusti::atomic_store(&mut *x, 1);
+
         assert_eq!(rusti::atomic_xchg(&mut *x, 0), 1);
         assert_eq!(*x, 0);
 
@@ -0,0 +1,47 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(panic_hook)]
+
+use std::panic::{self, PanicInfo};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread;
+
+static A: AtomicUsize = ATOMIC_USIZE_INIT;
+static B: AtomicUsize = ATOMIC_USIZE_INIT;
+
+fn main() {
+    panic::set_hook(Box::new(|info: &PanicInfo| {
+        assert_eq!(info.payload().downcast_ref::<&'static str>(),
+                   Some(&"foobar"));
+        assert_eq!(info.location().file(), file!());
+        assert!(info.location().line() > 0);
+        assert_eq!(info.thread_name(), Some("child"));
+        A.fetch_add(1, Ordering::SeqCst);
+    }));
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info: &PanicInfo| {
+        B.fetch_add(1, Ordering::SeqCst);
+        hook(info);
+    }));
+
+    let _ = thread::Builder::new().name("child".to_string()).spawn(|| {
+        panic!("foobar");
+    }).unwrap().join();
+
+    assert_eq!(1, A.load(Ordering::SeqCst));
+    assert_eq!(1, B.load(Ordering::SeqCst));
+
+    // Taking the hook back out restores the default one.
+    let _ = panic::take_hook();
+    let _ = thread::spawn(|| panic!("default")).join();
+    assert_eq!(1, A.load(Ordering::SeqCst));
+    assert_eq!(1, B.load(Ordering::SeqCst));
+}
@@ -0,0 +1,66 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// exec-env:RUST_LOG=rust_log_routing=info
+
+#![allow(unknown_features)]
+#![feature(box_syntax, rustc_private)]
+
+#[macro_use]
+extern crate log;
+
+use std::sync::mpsc::{channel, Sender};
+
+pub struct ChannelLogger {
+    tx: Sender<String>
+}
+
+impl log::Logger for ChannelLogger {
+    fn log(&mut self, record: &log::LogRecord) {
+        let fields = record.fields.iter().map(|f| {
+            format!("{}={}", f.key, f.value)
+        }).collect::<Vec<_>>();
+        self.tx.send(format!("{} [{}]", record.args, fields.connect(","))).unwrap();
+    }
+}
+
+mod routed {
+    // Logs from within a routed logger, which must not be routed again.
+    pub struct Echo;
+
+    impl ::log::Logger for Echo {
+        fn log(&mut self, record: &::log::LogRecord) {
+            info!("echo {}", record.args);
+        }
+    }
+
+    pub fn go() {
+        info!({ "id" => 7 }; "routed");
+    }
+}
+
+pub fn main() {
+    let (tx, rx) = channel();
+    log::add_logger("rust_log_routing::routed", box ChannelLogger { tx: tx });
+    log::add_logger("rust_log_routing::routed", box routed::Echo);
+    let (tx, local) = channel();
+    log::set_logger(box ChannelLogger { tx: tx });
+
+    routed::go();
+    info!({ "answer" => 42, "name" => "x" }; "hello {}", "world");
+    info!("plain");
+
+    assert_eq!(rx.recv().unwrap(), "routed [id=7]");
+    assert!(rx.try_recv().is_err());
+    assert_eq!(local.recv().unwrap(), "echo routed []");
+    assert_eq!(local.recv().unwrap(), "hello world [answer=42,name=x]");
+    assert_eq!(local.recv().unwrap(), "plain []");
+    assert!(local.try_recv().is_err());
+}
@@ -0,0 +1,20 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:rustdoc-extern-html-root-url.rs
+// compile-flags:--extern-html-root-url rustdoc_extern_html_root_url=http://example.com/docs
+// ignore-cross-compile
+
+extern crate rustdoc_extern_html_root_url as dep;
+
+// @has extern_html_root_url/fn.foo.html \
+//      '//*[@href="http://example.com/docs/rustdoc_extern_html_root_url/struct.Foo.html"]' \
+//      'Foo'
+pub fn foo(_: dep::Foo) {}